@@ -0,0 +1,769 @@
+//! Compiles the site content in `content/` into JSON the binary embeds.
+//!
+//! Articles are Markdown files with TOML front matter between `+++` lines;
+//! services, portfolio projects and testimonials are TOML files. A file's
+//! name (minus the extension) is its slug, or a testimonial's id. Anything malformed fails the build with the file,
+//! the field and what was wrong, so bad content never reaches the site.
+//!
+//! A file named `<slug>.<locale>.md` or `<slug>.<locale>.toml` translates
+//! that item: it holds only the text fields, any of which may be left out
+//! to show the English. The locale must have a catalog in `locales/` (see
+//! `build/locales.rs`), which build.rs checks and compiles too.
+//!
+//! It also writes the media manifest (see `build/media.rs`), fails the build
+//! when content points at an image or video that isn't in `assets/`, and
+//! bundles the custom icons in `assets/icons/`.
+
+use serde_json::{Map, Value as Json};
+use std::fs;
+use std::path::{Path, PathBuf};
+use toml::Value as Toml;
+
+#[path = "build/locales.rs"]
+mod locales;
+#[path = "build/media.rs"]
+mod media;
+
+/// Shape of a content field
+enum Kind {
+    Str,
+    /// `YYYY-MM-DD`, written as a TOML date or a string
+    Date,
+    /// draft, published or trashed
+    Status,
+    Int,
+    /// Integers are accepted too, so `before = 3` works
+    Float,
+    Bool,
+    StrList,
+    Table(&'static [Field]),
+    Tables(&'static [Field]),
+}
+
+struct Field {
+    name: &'static str,
+    kind: Kind,
+    required: bool,
+}
+
+const fn req(name: &'static str, kind: Kind) -> Field {
+    Field {
+        name,
+        kind,
+        required: true,
+    }
+}
+
+const fn opt(name: &'static str, kind: Kind) -> Field {
+    Field {
+        name,
+        kind,
+        required: false,
+    }
+}
+
+const ARTICLE: &[Field] = &[
+    opt("id", Kind::Str),
+    req("title", Kind::Str),
+    req("date", Kind::Date),
+    req("category", Kind::Str),
+    req("excerpt", Kind::Str),
+    opt("status", Kind::Status),
+    opt("pinned_articles", Kind::StrList),
+    // Slugs the article used to have; they keep redirecting to it
+    opt("redirect_from", Kind::StrList),
+];
+
+/// What `<slug>.<locale>.md` may translate; the body replaces `content`
+const ARTICLE_TEXT: &[Field] = &[
+    opt("title", Kind::Str),
+    opt("category", Kind::Str),
+    opt("excerpt", Kind::Str),
+];
+
+const PERSONA: &[Field] = &[
+    req("title", Kind::Str),
+    req("icon", Kind::Str),
+    req("description", Kind::Str),
+];
+
+/// Personas are translated by position
+const PERSONA_TEXT: &[Field] = &[opt("title", Kind::Str), opt("description", Kind::Str)];
+
+const SERVICE: &[Field] = &[
+    opt("id", Kind::Str),
+    req("order", Kind::Int),
+    req("title", Kind::Str),
+    req("icon", Kind::Str),
+    req("accent_color", Kind::Str),
+    req("tagline", Kind::Str),
+    req("description", Kind::Str),
+    req("long_description", Kind::Str),
+    opt("features", Kind::StrList),
+    opt("personas", Kind::Tables(PERSONA)),
+    opt("pinned_projects", Kind::StrList),
+    opt("status", Kind::Status),
+];
+
+const SERVICE_TEXT: &[Field] = &[
+    opt("title", Kind::Str),
+    opt("tagline", Kind::Str),
+    opt("description", Kind::Str),
+    opt("long_description", Kind::Str),
+    opt("features", Kind::StrList),
+    opt("personas", Kind::Tables(PERSONA_TEXT)),
+];
+
+const RESULT: &[Field] = &[
+    req("metric", Kind::Str),
+    req("before", Kind::Float),
+    req("after", Kind::Float),
+    opt("unit", Kind::Str),
+    opt("lower_is_better", Kind::Bool),
+];
+
+const TESTIMONIAL: &[Field] = &[
+    req("quote", Kind::Str),
+    req("author", Kind::Str),
+    opt("role", Kind::Str),
+    opt("company", Kind::Str),
+    opt("photo", Kind::Str),
+];
+
+/// A standalone quote in `content/testimonials/`, optionally linked to the
+/// project or service it's about
+const TESTIMONIAL_FILE: &[Field] = &[
+    req("quote", Kind::Str),
+    req("author", Kind::Str),
+    opt("role", Kind::Str),
+    opt("company", Kind::Str),
+    opt("photo", Kind::Str),
+    opt("project_slug", Kind::Str),
+    opt("service_slug", Kind::Str),
+];
+
+const PHASE: &[Field] = &[
+    req("name", Kind::Str),
+    opt("duration", Kind::Str),
+    opt("description", Kind::Str),
+];
+
+const IMAGE: &[Field] = &[
+    req("src", Kind::Str),
+    req("alt", Kind::Str),
+    opt("caption", Kind::Str),
+];
+
+const CASE_STUDY: &[Field] = &[
+    opt("results", Kind::Tables(RESULT)),
+    opt("testimonial", Kind::Table(TESTIMONIAL)),
+    opt("timeline", Kind::Tables(PHASE)),
+    opt("gallery", Kind::Tables(IMAGE)),
+    opt("before_screenshot", Kind::Str),
+];
+
+const PROJECT: &[Field] = &[
+    opt("id", Kind::Str),
+    req("title", Kind::Str),
+    req("project_type", Kind::Str),
+    req("description", Kind::Str),
+    req("long_description", Kind::Str),
+    req("external_url", Kind::Str),
+    opt("before_url", Kind::Str),
+    opt("logo", Kind::Str),
+    opt("screenshot", Kind::Str),
+    opt("video", Kind::Str),
+    opt("tech_tags", Kind::StrList),
+    opt("scope", Kind::StrList),
+    opt("featured", Kind::Bool),
+    req("sort_order", Kind::Int),
+    opt("case_study", Kind::Table(CASE_STUDY)),
+    opt("pinned_services", Kind::StrList),
+    opt("status", Kind::Status),
+];
+
+const PROJECT_TEXT: &[Field] = &[
+    opt("title", Kind::Str),
+    opt("project_type", Kind::Str),
+    opt("description", Kind::Str),
+    opt("long_description", Kind::Str),
+    opt("scope", Kind::StrList),
+];
+
+fn main() {
+    let crate_dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap());
+    let root = crate_dir.join("content");
+    let out = PathBuf::from(std::env::var("OUT_DIR").unwrap());
+    println!("cargo:rerun-if-changed={}", root.display());
+    println!(
+        "cargo:rerun-if-changed={}",
+        crate_dir.join("assets").display()
+    );
+    println!(
+        "cargo:rerun-if-changed={}",
+        crate_dir.join("locales").display()
+    );
+
+    let mut errors = Vec::new();
+    let catalogs = locales::Catalogs::load(&crate_dir.join("locales"), &mut errors);
+
+    let (articles, article_texts) = split_translations(load_dir(
+        &root.join("articles"),
+        "md",
+        &mut errors,
+        |text| {
+            split_front_matter(text).and_then(|(front, body)| {
+                let mut table = parse_toml(front)?;
+                if table.contains_key("content") {
+                    return Err("`content` is the Markdown after the front matter".to_string());
+                }
+                table.insert("content".to_string(), Toml::String(body.to_string()));
+                Ok(table)
+            })
+        },
+    ));
+    let mut articles = articles
+        .into_iter()
+        .map(|(slug, path, table)| convert(&slug, &path, table, ARTICLE, &mut errors))
+        .collect::<Vec<_>>();
+
+    let (services, service_texts) = split_translations(load_dir(
+        &root.join("services"),
+        "toml",
+        &mut errors,
+        parse_toml,
+    ));
+    let mut services = services
+        .into_iter()
+        .map(|(slug, path, table)| convert(&slug, &path, table, SERVICE, &mut errors))
+        .collect::<Vec<_>>();
+
+    let (projects, project_texts) = split_translations(load_dir(
+        &root.join("portfolio"),
+        "toml",
+        &mut errors,
+        parse_toml,
+    ));
+    let mut projects = projects
+        .into_iter()
+        .map(|(slug, path, table)| convert(&slug, &path, table, PROJECT, &mut errors))
+        .collect::<Vec<_>>();
+
+    let (testimonials, testimonial_texts) = split_translations(load_dir(
+        &root.join("testimonials"),
+        "toml",
+        &mut errors,
+        parse_toml,
+    ));
+    for (_, _, path, _) in &testimonial_texts {
+        errors.push(format!(
+            "{}: testimonials are quoted as given, so they have no translations",
+            path.display()
+        ));
+    }
+    let testimonials = testimonials
+        .into_iter()
+        .map(|(slug, path, table)| convert(&slug, &path, table, TESTIMONIAL_FILE, &mut errors))
+        .collect::<Vec<_>>();
+
+    let translations = [
+        (&mut articles, article_texts, ARTICLE_TEXT),
+        (&mut services, service_texts, SERVICE_TEXT),
+        (&mut projects, project_texts, PROJECT_TEXT),
+    ];
+    for (items, texts, schema) in translations {
+        attach_translations(items, texts, schema, &catalogs, &mut errors);
+    }
+
+    check_pins(&articles, "pinned_articles", &articles, &mut errors);
+    let article_redirects = take_redirects(&mut articles, &mut errors);
+    check_pins(&services, "pinned_projects", &projects, &mut errors);
+    check_pins(&projects, "pinned_services", &services, &mut errors);
+    check_link(&testimonials, "project_slug", &projects, &mut errors);
+    check_link(&testimonials, "service_slug", &services, &mut errors);
+
+    let media = media::Manifest::scan(&crate_dir);
+    check_media(&articles, &media, &mut errors);
+    check_media(&projects, &media, &mut errors);
+    check_media(&testimonials, &media, &mut errors);
+    let icons = load_icons(&crate_dir.join("assets").join("icons"), &mut errors);
+
+    if !errors.is_empty() {
+        for error in &errors {
+            eprintln!("error: {}", error);
+        }
+        eprintln!(
+            "{} problem(s) in content/, assets/ or locales/; fix them and rebuild",
+            errors.len()
+        );
+        std::process::exit(1);
+    }
+
+    // Newest articles first; services in their declared order; projects by
+    // sort order (the portfolio page still puts featured ones first)
+    articles.sort_by(|a, b| field(b, "date").cmp(field(a, "date")));
+    services.sort_by_key(|s| s["order"].as_i64());
+    for service in &mut services {
+        service.as_object_mut().unwrap().remove("order");
+    }
+    projects.sort_by_key(|p| p["sort_order"].as_i64());
+
+    write(
+        &out,
+        "articles.json",
+        "articles",
+        articles,
+        Some(article_redirects),
+    );
+    write(&out, "services.json", "services", services, None);
+    write(&out, "portfolio.json", "projects", projects, None);
+    write(
+        &out,
+        "testimonials.json",
+        "testimonials",
+        testimonials,
+        None,
+    );
+    write(&out, "media.json", "assets", media.into_assets(), None);
+    let icons = serde_json::to_string(&Json::Object(icons)).expect("icons serialize");
+    fs::write(out.join("icons.json"), icons).expect("OUT_DIR is writable");
+    let locales = serde_json::to_string(&catalogs.into_json()).expect("catalogs serialize");
+    fs::write(out.join("locales.json"), locales).expect("OUT_DIR is writable");
+}
+
+/// Custom icons: each `assets/icons/<name>.svg` is registered as `<name>`
+fn load_icons(dir: &Path, errors: &mut Vec<String>) -> Map<String, Json> {
+    let mut icons = Map::new();
+    let Ok(entries) = fs::read_dir(dir) else {
+        return icons;
+    };
+    let mut paths = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|e| e == "svg"))
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    for path in paths {
+        let name = path.file_stem().unwrap_or_default().to_string_lossy();
+        if !is_slug(&name) {
+            errors.push(format!(
+                "{}: icon names are lowercase words joined by hyphens",
+                path.display()
+            ));
+            continue;
+        }
+        match fs::read_to_string(&path) {
+            Ok(svg) if svg.contains("<svg") => {
+                icons.insert(name.to_string(), Json::String(svg));
+            }
+            Ok(_) => errors.push(format!("{}: not an SVG", path.display())),
+            Err(e) => errors.push(format!("{}: {}", path.display(), e)),
+        }
+    }
+    icons
+}
+
+/// A parsed content file: slug, locale for `<slug>.<locale>.ext`, path, table
+type Entry = (String, Option<String>, PathBuf, toml::Table);
+
+/// Every `*.ext` file in `dir`, by slug, parsed with `parse`
+fn load_dir(
+    dir: &Path,
+    ext: &str,
+    errors: &mut Vec<String>,
+    parse: impl Fn(&str) -> Result<toml::Table, String>,
+) -> Vec<Entry> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            errors.push(format!("{}: {}", dir.display(), e));
+            return Vec::new();
+        }
+    };
+
+    let mut paths = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|e| e == ext))
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    let mut items = Vec::new();
+    for path in paths {
+        let stem = path.file_stem().unwrap().to_string_lossy().into_owned();
+        let (slug, locale) = match stem.split_once('.') {
+            Some((slug, locale)) => (slug.to_string(), Some(locale.to_string())),
+            None => (stem, None),
+        };
+        if !is_slug(&slug) || locale.as_deref().is_some_and(|l| !is_slug(l)) {
+            errors.push(format!(
+                "{}: file names are slugs, or <slug>.<locale> for a translation; use lowercase letters, digits and single hyphens",
+                path.display()
+            ));
+            continue;
+        }
+        let parsed = fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|text| parse(&text));
+        match parsed {
+            Ok(table) => items.push((slug, locale, path, table)),
+            Err(e) => errors.push(format!("{}: {}", path.display(), e)),
+        }
+    }
+    items
+}
+
+/// Separate base files from `<slug>.<locale>` translations
+#[allow(clippy::type_complexity)]
+fn split_translations(entries: Vec<Entry>) -> (Vec<(String, PathBuf, toml::Table)>, Vec<Entry>) {
+    let (translations, base): (Vec<_>, Vec<_>) = entries
+        .into_iter()
+        .partition(|(_, locale, _, _)| locale.is_some());
+    let base = base
+        .into_iter()
+        .map(|(slug, _, path, table)| (slug, path, table))
+        .collect();
+    (base, translations)
+}
+
+/// Check each translation against `schema` and store it under the item's
+/// `translations`, keyed by locale
+fn attach_translations(
+    items: &mut [Json],
+    translations: Vec<Entry>,
+    schema: &[Field],
+    catalogs: &locales::Catalogs,
+    errors: &mut Vec<String>,
+) {
+    for (slug, locale, path, mut table) in translations {
+        let locale = locale.unwrap_or_default();
+        if locale == "en" {
+            errors.push(format!(
+                "{}: English is the {}.* file itself",
+                path.display(),
+                slug
+            ));
+            continue;
+        }
+        if !catalogs.contains(&locale) {
+            errors.push(format!(
+                "{}: there is no locales/{}.ftl, so the site has no \"{}\" pages",
+                path.display(),
+                locale,
+                locale
+            ));
+            continue;
+        }
+        let Some(item) = items.iter_mut().find(|item| item["slug"] == slug) else {
+            errors.push(format!(
+                "{}: nothing has the slug \"{}\" to translate",
+                path.display(),
+                slug
+            ));
+            continue;
+        };
+
+        let content = table.remove("content");
+        let mut text = convert_table(&table, schema, &path.display().to_string(), errors);
+        if let Some(Toml::String(content)) = content {
+            if !content.trim().is_empty() {
+                text.insert(
+                    "content".to_string(),
+                    Json::String(content.trim().to_string()),
+                );
+            }
+        }
+        let personas = text.get("personas").and_then(Json::as_array).map(Vec::len);
+        let base_personas = item["personas"].as_array().map(Vec::len);
+        if personas.unwrap_or(0) > base_personas.unwrap_or(0) {
+            errors.push(format!(
+                "{} `personas`: {} has only {} to translate",
+                path.display(),
+                slug,
+                base_personas.unwrap_or(0)
+            ));
+        }
+
+        item.as_object_mut()
+            .expect("content items are tables")
+            .entry("translations")
+            .or_insert_with(|| Json::Object(Map::new()))
+            .as_object_mut()
+            .expect("translations is a table")
+            .insert(locale, Json::Object(text));
+    }
+}
+
+/// Split `+++`-fenced TOML front matter from the Markdown body
+fn split_front_matter(text: &str) -> Result<(&str, &str), String> {
+    let rest = text
+        .strip_prefix("+++\n")
+        .or_else(|| text.strip_prefix("+++\r\n"))
+        .ok_or("must start with `+++` front matter")?;
+    let end = rest
+        .find("\n+++")
+        .ok_or("front matter has no closing `+++`")?;
+    let body = rest[end + 4..].trim_start_matches(['\r', '\n']);
+    Ok((&rest[..end], body))
+}
+
+fn parse_toml(text: &str) -> Result<toml::Table, String> {
+    text.parse::<toml::Table>()
+        .map_err(|e| e.to_string().trim_end().to_string())
+}
+
+/// Check `table` against `schema` and convert it to JSON, filling defaults
+fn convert(
+    slug: &str,
+    path: &Path,
+    mut table: toml::Table,
+    schema: &[Field],
+    errors: &mut Vec<String>,
+) -> Json {
+    if table.contains_key("slug") {
+        errors.push(format!(
+            "{}: `slug` comes from the file name, remove it",
+            path.display()
+        ));
+    }
+    table.remove("slug");
+    let content = table.remove("content");
+
+    let mut object = convert_table(&table, schema, &path.display().to_string(), errors);
+    object
+        .entry("id")
+        .and_modify(|id| {
+            if id.is_null() {
+                *id = Json::String(slug.to_string());
+            }
+        })
+        .or_insert_with(|| Json::String(slug.to_string()));
+    object.insert("slug".to_string(), Json::String(slug.to_string()));
+    if let Some(Toml::String(content)) = content {
+        object.insert(
+            "content".to_string(),
+            Json::String(content.trim().to_string()),
+        );
+    }
+    Json::Object(object)
+}
+
+fn convert_table(
+    table: &toml::Table,
+    schema: &[Field],
+    at: &str,
+    errors: &mut Vec<String>,
+) -> Map<String, Json> {
+    for key in table.keys() {
+        if !schema.iter().any(|f| f.name == key) {
+            let known = schema.iter().map(|f| f.name).collect::<Vec<_>>().join(", ");
+            errors.push(format!(
+                "{}: unknown field `{}` (expected one of {})",
+                at, key, known
+            ));
+        }
+    }
+
+    let mut object = Map::new();
+    for field in schema {
+        let at = format!("{} `{}`", at, field.name);
+        let value = match table.get(field.name) {
+            Some(value) => convert_value(value, &field.kind, &at, errors),
+            None if field.required => {
+                errors.push(format!("{} is required", at));
+                continue;
+            }
+            None => default_value(&field.kind),
+        };
+        if let Some(value) = value {
+            object.insert(field.name.to_string(), value);
+        }
+    }
+    object
+}
+
+fn convert_value(value: &Toml, kind: &Kind, at: &str, errors: &mut Vec<String>) -> Option<Json> {
+    let mut fail = |expected: &str| {
+        errors.push(format!("{} should be {}", at, expected));
+        None
+    };
+    match (kind, value) {
+        (Kind::Str, Toml::String(s)) => Some(Json::String(s.trim().to_string())),
+        (Kind::Str, _) => fail("a string"),
+        (Kind::Date, Toml::Datetime(d)) if d.date.is_some() && d.time.is_none() => {
+            Some(Json::String(d.to_string()))
+        }
+        (Kind::Date, Toml::String(s)) if is_date(s) => Some(Json::String(s.clone())),
+        (Kind::Date, _) => fail("a date like 2026-01-31"),
+        (Kind::Status, Toml::String(s))
+            if ["draft", "published", "trashed"].contains(&s.as_str()) =>
+        {
+            Some(Json::String(s.clone()))
+        }
+        (Kind::Status, _) => fail("\"draft\", \"published\" or \"trashed\""),
+        (Kind::Int, Toml::Integer(i)) if *i >= 0 => Some(Json::from(*i)),
+        (Kind::Int, _) => fail("a whole number, 0 or more"),
+        (Kind::Float, Toml::Float(f)) => Some(Json::from(*f)),
+        (Kind::Float, Toml::Integer(i)) => Some(Json::from(*i as f64)),
+        (Kind::Float, _) => fail("a number"),
+        (Kind::Bool, Toml::Boolean(b)) => Some(Json::Bool(*b)),
+        (Kind::Bool, _) => fail("true or false"),
+        (Kind::StrList, Toml::Array(items)) if items.iter().all(Toml::is_str) => Some(Json::Array(
+            items
+                .iter()
+                .filter_map(Toml::as_str)
+                .map(|s| Json::String(s.trim().to_string()))
+                .collect(),
+        )),
+        (Kind::StrList, _) => fail("a list of strings"),
+        (Kind::Table(schema), Toml::Table(table)) => {
+            Some(Json::Object(convert_table(table, schema, at, errors)))
+        }
+        (Kind::Table(_), _) => fail("a table"),
+        (Kind::Tables(schema), Toml::Array(items)) => {
+            let mut converted = Vec::new();
+            for (i, item) in items.iter().enumerate() {
+                let at = format!("{}[{}]", at, i);
+                match item {
+                    Toml::Table(table) => {
+                        converted.push(Json::Object(convert_table(table, schema, &at, errors)))
+                    }
+                    _ => errors.push(format!("{} should be a table", at)),
+                }
+            }
+            Some(Json::Array(converted))
+        }
+        (Kind::Tables(_), _) => fail("an array of tables"),
+    }
+}
+
+/// Value for an optional field left out of the file
+fn default_value(kind: &Kind) -> Option<Json> {
+    match kind {
+        Kind::Str | Kind::Date | Kind::Int | Kind::Float => None,
+        Kind::Status => Some(Json::String("published".to_string())),
+        Kind::Bool => Some(Json::Bool(false)),
+        Kind::StrList | Kind::Tables(_) => Some(Json::Array(Vec::new())),
+        Kind::Table(_) => None,
+    }
+}
+
+/// Pinned slugs must name content that exists
+fn check_pins(items: &[Json], key: &str, targets: &[Json], errors: &mut Vec<String>) {
+    for item in items {
+        let Some(pins) = item[key].as_array() else {
+            continue;
+        };
+        for pin in pins.iter().filter_map(Json::as_str) {
+            if !targets.iter().any(|t| t["slug"] == pin) {
+                errors.push(format!(
+                    "{} `{}`: nothing has the slug \"{}\"",
+                    field(item, "slug"),
+                    key,
+                    pin
+                ));
+            }
+        }
+    }
+}
+
+/// A linked slug must name content that exists
+fn check_link(items: &[Json], key: &str, targets: &[Json], errors: &mut Vec<String>) {
+    for item in items {
+        let Some(slug) = item[key].as_str() else {
+            continue;
+        };
+        if !targets.iter().any(|t| t["slug"] == slug) {
+            errors.push(format!(
+                "{} `{}`: nothing has the slug \"{}\"",
+                field(item, "slug"),
+                key,
+                slug
+            ));
+        }
+    }
+}
+
+/// Move each item's `redirect_from` slugs into redirects in the shape of
+/// `SlugRedirects`. An old slug can't still be live or point at two items.
+fn take_redirects(items: &mut [Json], errors: &mut Vec<String>) -> Json {
+    let live: Vec<String> = items.iter().map(|i| field(i, "slug").to_string()).collect();
+    let mut entries: Vec<Json> = Vec::new();
+    for item in items.iter_mut() {
+        let to = field(item, "slug").to_string();
+        let Some(Json::Array(old)) = item
+            .as_object_mut()
+            .and_then(|object| object.remove("redirect_from"))
+        else {
+            continue;
+        };
+        for from in old.iter().filter_map(Json::as_str) {
+            let at = format!("{} `redirect_from`", to);
+            if !is_slug(from) {
+                errors.push(format!("{}: \"{}\" isn't a slug", at, from));
+            } else if live.iter().any(|slug| slug == from) {
+                errors.push(format!("{}: \"{}\" is still a live slug", at, from));
+            } else if let Some(other) = entries.iter().find(|e| e["from"] == from) {
+                errors.push(format!(
+                    "{}: \"{}\" already redirects to {}",
+                    at, from, other["to"]
+                ));
+            } else {
+                let mut entry = Map::new();
+                entry.insert("from".to_string(), Json::String(from.to_string()));
+                entry.insert("to".to_string(), Json::String(to.clone()));
+                entries.push(Json::Object(entry));
+            }
+        }
+    }
+    let mut redirects = Map::new();
+    redirects.insert("entries".to_string(), Json::Array(entries));
+    Json::Object(redirects)
+}
+
+fn check_media(items: &[Json], media: &media::Manifest, errors: &mut Vec<String>) {
+    for item in items {
+        for (key, path) in media::references(item) {
+            if !media.contains(&path) {
+                errors.push(format!(
+                    "{} `{}`: {} is not in assets/",
+                    field(item, "slug"),
+                    key,
+                    path
+                ));
+            }
+        }
+    }
+}
+
+fn field<'a>(item: &'a Json, key: &str) -> &'a str {
+    item[key].as_str().unwrap_or_default()
+}
+
+fn is_slug(s: &str) -> bool {
+    !s.is_empty()
+        && s.split('-').all(|part| {
+            !part.is_empty()
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        })
+}
+
+fn is_date(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    bytes.len() == 10
+        && bytes.iter().enumerate().all(|(i, b)| match i {
+            4 | 7 => *b == b'-',
+            _ => b.is_ascii_digit(),
+        })
+}
+
+fn write(out: &Path, file: &str, key: &str, items: Vec<Json>, redirects: Option<Json>) {
+    let mut data = Map::new();
+    data.insert(key.to_string(), Json::Array(items));
+    if let Some(redirects) = redirects {
+        data.insert("redirects".to_string(), redirects);
+    }
+    let json = serde_json::to_string(&Json::Object(data)).expect("content serializes");
+    fs::write(out.join(file), json).expect("OUT_DIR is writable");
+}
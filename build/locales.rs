@@ -0,0 +1,209 @@
+//! Message catalogs: `locales/<code>.ftl`, checked against English.
+//!
+//! The format is the part of Fluent the site needs. `id = text` starts a
+//! message, indented lines continue it, `#` lines are comments and
+//! `{ $name }` is a placeable filled in at runtime. A translation may leave
+//! messages out (they fall back to English, with a warning) but may not
+//! invent ids or placeables English doesn't have.
+
+use serde_json::{Map, Value as Json};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// The source language every other catalog is checked against
+const SOURCE: &str = "en";
+
+/// One catalog: message id to text
+pub type Catalog = BTreeMap<String, String>;
+
+pub struct Catalogs {
+    catalogs: BTreeMap<String, Catalog>,
+}
+
+impl Catalogs {
+    /// Parse and check every `*.ftl` in `dir`
+    pub fn load(dir: &Path, errors: &mut Vec<String>) -> Catalogs {
+        let mut catalogs = BTreeMap::new();
+        let mut paths = match fs::read_dir(dir) {
+            Ok(entries) => entries
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|path| path.extension().is_some_and(|e| e == "ftl"))
+                .collect::<Vec<_>>(),
+            Err(e) => {
+                errors.push(format!("{}: {}", dir.display(), e));
+                Vec::new()
+            }
+        };
+        paths.sort();
+
+        for path in paths {
+            let code = path.file_stem().unwrap_or_default().to_string_lossy();
+            if code.len() != 2 || !code.chars().all(|c| c.is_ascii_lowercase()) {
+                errors.push(format!(
+                    "{}: catalogs are named by two-letter language code, e.g. es.ftl",
+                    path.display()
+                ));
+                continue;
+            }
+            match fs::read_to_string(&path) {
+                Ok(text) => match parse(&text) {
+                    Ok(catalog) => {
+                        catalogs.insert(code.into_owned(), catalog);
+                    }
+                    Err(problems) => errors.extend(
+                        problems
+                            .into_iter()
+                            .map(|p| format!("{}:{}", path.display(), p)),
+                    ),
+                },
+                Err(e) => errors.push(format!("{}: {}", path.display(), e)),
+            }
+        }
+
+        let catalogs = Catalogs { catalogs };
+        catalogs.check(dir, errors);
+        catalogs
+    }
+
+    pub fn contains(&self, code: &str) -> bool {
+        self.catalogs.contains_key(code)
+    }
+
+    fn check(&self, dir: &Path, errors: &mut Vec<String>) {
+        let Some(source) = self.catalogs.get(SOURCE) else {
+            errors.push(format!("{}: {}.ftl is required", dir.display(), SOURCE));
+            return;
+        };
+        for (code, catalog) in self.catalogs.iter().filter(|(c, _)| *c != SOURCE) {
+            let file = dir.join(format!("{}.ftl", code));
+            for (id, text) in catalog {
+                let Some(english) = source.get(id) else {
+                    errors.push(format!(
+                        "{}: `{}` is not in {}.ftl",
+                        file.display(),
+                        id,
+                        SOURCE
+                    ));
+                    continue;
+                };
+                let known = placeables(english);
+                for name in placeables(text) {
+                    if !known.contains(&name) {
+                        errors.push(format!(
+                            "{}: `{}` uses {{ ${} }}, which the English message doesn't have",
+                            file.display(),
+                            id,
+                            name
+                        ));
+                    }
+                }
+            }
+            let missing = source
+                .keys()
+                .filter(|id| !catalog.contains_key(*id))
+                .count();
+            if missing > 0 {
+                println!(
+                    "cargo:warning={}: {} message(s) untranslated, English is shown instead",
+                    file.display(),
+                    missing
+                );
+            }
+        }
+    }
+
+    /// `{ "en": { id: text }, "es": { ... } }`
+    pub fn into_json(self) -> Json {
+        Json::Object(
+            self.catalogs
+                .into_iter()
+                .map(|(code, catalog)| {
+                    let messages = catalog
+                        .into_iter()
+                        .map(|(id, text)| (id, Json::String(text)))
+                        .collect::<Map<_, _>>();
+                    (code, Json::Object(messages))
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Parse one catalog; errors are `line: problem`
+fn parse(text: &str) -> Result<Catalog, Vec<String>> {
+    let mut catalog = Catalog::new();
+    let mut errors = Vec::new();
+    let mut current: Option<String> = None;
+
+    for (i, line) in text.lines().enumerate() {
+        let number = i + 1;
+        if line.trim().is_empty() || line.trim_start().starts_with('#') {
+            continue;
+        }
+        if line.starts_with([' ', '\t']) {
+            match current.as_ref().and_then(|id| catalog.get_mut(id)) {
+                Some(message) => {
+                    if !message.is_empty() {
+                        message.push('\n');
+                    }
+                    message.push_str(line.trim());
+                }
+                None => errors.push(format!("{}: indented line outside a message", number)),
+            }
+            continue;
+        }
+        let Some((id, value)) = line.split_once('=') else {
+            errors.push(format!("{}: expected `id = text`", number));
+            continue;
+        };
+        let id = id.trim();
+        if !is_id(id) {
+            errors.push(format!(
+                "{}: `{}` isn't a message id; use lowercase words joined by hyphens",
+                number, id
+            ));
+            current = None;
+            continue;
+        }
+        if catalog.contains_key(id) {
+            errors.push(format!("{}: `{}` is defined twice", number, id));
+        }
+        catalog.insert(id.to_string(), value.trim().to_string());
+        current = Some(id.to_string());
+    }
+
+    for (id, text) in &catalog {
+        if text.is_empty() {
+            errors.push(format!(" `{}` is empty", id));
+        }
+        if text.matches('{').count() != text.matches('}').count() {
+            errors.push(format!(" `{}` has an unclosed {{ placeable }}", id));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(catalog)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Names of the `{ $name }` placeables in a message
+fn placeables(text: &str) -> Vec<String> {
+    text.split('{')
+        .skip(1)
+        .filter_map(|rest| rest.split_once('}'))
+        .map(|(inner, _)| inner.trim().trim_start_matches('$').to_string())
+        .collect()
+}
+
+fn is_id(s: &str) -> bool {
+    s.starts_with(|c: char| c.is_ascii_lowercase())
+        && s.split('-').all(|part| {
+            !part.is_empty()
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        })
+}
@@ -0,0 +1,250 @@
+//! Media manifest: every image and video under `assets/`, with its size and
+//! the responsive variants `media.sh` generated for it in `assets/variants/`.
+//! Only variants present at build time are listed, so an image whose
+//! variants weren't generated is served as the original.
+
+use serde_json::{json, Value as Json};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Where `media.sh` writes resized WebP/AVIF copies, mirroring `assets/`
+const VARIANTS_DIR: &str = "assets/variants";
+
+pub struct Manifest {
+    assets: Vec<Json>,
+    paths: HashSet<String>,
+}
+
+impl Manifest {
+    /// Scan `assets/` under the crate root
+    pub fn scan(root: &Path) -> Manifest {
+        let variants_dir = root.join(VARIANTS_DIR);
+        let mut variants: BTreeMap<String, Vec<Json>> = BTreeMap::new();
+        for path in walk(&variants_dir) {
+            if let Some((original, variant)) = parse_variant(root, &variants_dir, &path) {
+                variants.entry(original).or_default().push(variant);
+            }
+        }
+        for list in variants.values_mut() {
+            list.sort_by_key(|v| (v["format"].as_str().map(str::to_owned), v["width"].as_u64()));
+        }
+
+        let mut assets = Vec::new();
+        let mut paths = HashSet::new();
+        for path in walk(&root.join("assets")) {
+            if path.starts_with(&variants_dir) {
+                continue;
+            }
+            let Some(kind) = kind(&path) else {
+                continue;
+            };
+            let rel = relative(root, &path);
+            let size = dimensions(&path);
+            let key = rel.rsplit_once('.').map_or(rel.as_str(), |(stem, _)| stem);
+            let variants = variants.get(key).map_or(&[][..], Vec::as_slice);
+            assets.push(json!({
+                "path": rel,
+                "kind": kind,
+                "width": size.map(|(w, _)| w),
+                "height": size.map(|(_, h)| h),
+                "bytes": fs::metadata(&path).map(|m| m.len()).unwrap_or(0),
+                "variants": complete_variants(variants, size.map(|(w, _)| w)),
+            }));
+            paths.insert(rel);
+        }
+
+        Manifest { assets, paths }
+    }
+
+    pub fn contains(&self, path: &str) -> bool {
+        self.paths.contains(path)
+    }
+
+    pub fn into_assets(self) -> Vec<Json> {
+        self.assets
+    }
+}
+
+/// The formats whose full-width copy exists. With it missing, the largest
+/// screens would get nothing or a blurry upscale, so the image falls back to
+/// the original for that format instead.
+fn complete_variants(variants: &[Json], width: Option<u32>) -> Vec<Json> {
+    let Some(width) = width else {
+        return Vec::new();
+    };
+    let complete: HashSet<&str> = variants
+        .iter()
+        .filter(|v| v["width"].as_u64() == Some(u64::from(width)))
+        .filter_map(|v| v["format"].as_str())
+        .collect();
+    variants
+        .iter()
+        .filter(|v| v["format"].as_str().is_some_and(|f| complete.contains(f)))
+        .cloned()
+        .collect()
+}
+
+/// Media referenced from a converted content item, as (field, path) pairs
+pub fn references(item: &Json) -> Vec<(String, String)> {
+    let mut refs = Vec::new();
+    let mut push = |field: &str, value: &Json| {
+        if let Some(path) = value.as_str() {
+            refs.push((field.to_string(), path.to_string()));
+        }
+    };
+
+    for field in ["logo", "screenshot", "video", "photo"] {
+        push(field, &item[field]);
+    }
+    let case_study = &item["case_study"];
+    push(
+        "case_study.before_screenshot",
+        &case_study["before_screenshot"],
+    );
+    push(
+        "case_study.testimonial.photo",
+        &case_study["testimonial"]["photo"],
+    );
+    if let Some(gallery) = case_study["gallery"].as_array() {
+        for (i, image) in gallery.iter().enumerate() {
+            push(&format!("case_study.gallery[{}].src", i), &image["src"]);
+        }
+    }
+
+    // Article bodies embed images as standalone `![alt](path)` paragraphs
+    if let Some(content) = item["content"].as_str() {
+        for paragraph in content.split("\n\n") {
+            if let Some(src) = image_src(paragraph.trim()) {
+                refs.push(("content".to_string(), src.to_string()));
+            }
+        }
+    }
+
+    refs.retain(|(_, path)| !path.starts_with("http://") && !path.starts_with("https://"));
+    refs
+}
+
+fn image_src(line: &str) -> Option<&str> {
+    let rest = line.strip_prefix("![")?;
+    let (_, rest) = rest.split_once("](")?;
+    let src = rest.strip_suffix(')')?;
+    (!src.is_empty() && !src.contains(char::is_whitespace)).then_some(src)
+}
+
+fn kind(path: &Path) -> Option<&'static str> {
+    let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+    match ext.as_str() {
+        "png" | "jpg" | "jpeg" | "gif" | "webp" | "avif" => Some("image"),
+        "svg" => Some("vector"),
+        "mp4" | "webm" | "mov" => Some("video"),
+        _ => None,
+    }
+}
+
+/// `assets/variants/portfolio/logo-480w.webp` → (`assets/portfolio/logo`, variant)
+fn parse_variant(root: &Path, variants_dir: &Path, path: &Path) -> Option<(String, Json)> {
+    let format = path.extension()?.to_str()?.to_ascii_lowercase();
+    if format != "webp" && format != "avif" {
+        return None;
+    }
+    let rel = path.strip_prefix(variants_dir).ok()?;
+    let stem = rel.with_extension("");
+    let stem = stem.to_str()?.replace('\\', "/");
+    let (original, width) = stem.rsplit_once('-')?;
+    let width: u32 = width.strip_suffix('w')?.parse().ok()?;
+    Some((
+        format!("assets/{}", original),
+        json!({ "path": relative(root, path), "width": width, "format": format }),
+    ))
+}
+
+/// Pixel size read from the file header, for width/height attributes
+fn dimensions(path: &Path) -> Option<(u32, u32)> {
+    let bytes = fs::read(path).ok()?;
+    let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+    match ext.as_str() {
+        "png" if bytes.len() >= 24 && bytes.starts_with(b"\x89PNG") => Some((
+            u32::from_be_bytes(bytes[16..20].try_into().ok()?),
+            u32::from_be_bytes(bytes[20..24].try_into().ok()?),
+        )),
+        "gif" if bytes.len() >= 10 && bytes.starts_with(b"GIF") => Some((
+            u16::from_le_bytes([bytes[6], bytes[7]]) as u32,
+            u16::from_le_bytes([bytes[8], bytes[9]]) as u32,
+        )),
+        "jpg" | "jpeg" => jpeg_dimensions(&bytes),
+        "svg" => svg_dimensions(&String::from_utf8_lossy(&bytes)),
+        _ => None,
+    }
+}
+
+fn jpeg_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    let mut i = 2;
+    while i + 9 < bytes.len() {
+        if bytes[i] != 0xFF {
+            return None;
+        }
+        let marker = bytes[i + 1];
+        let len = u16::from_be_bytes([bytes[i + 2], bytes[i + 3]]) as usize;
+        // SOF markers carry the frame size; C4, C8 and CC are other tables
+        if (0xC0..=0xCF).contains(&marker) && ![0xC4, 0xC8, 0xCC].contains(&marker) {
+            let height = u16::from_be_bytes([bytes[i + 5], bytes[i + 6]]) as u32;
+            let width = u16::from_be_bytes([bytes[i + 7], bytes[i + 8]]) as u32;
+            return Some((width, height));
+        }
+        i += 2 + len;
+    }
+    None
+}
+
+fn svg_dimensions(svg: &str) -> Option<(u32, u32)> {
+    let tag = &svg[svg.find("<svg")?..];
+    let tag = &tag[..tag.find('>')?];
+    let attr = |name: &str| {
+        let start = tag.find(&format!(" {}=\"", name))? + name.len() + 3;
+        let value = &tag[start..];
+        Some(&value[..value.find('"')?])
+    };
+    let number = |s: &str| s.trim_end_matches("px").parse::<f64>().ok();
+
+    if let (Some(w), Some(h)) = (
+        attr("width").and_then(number),
+        attr("height").and_then(number),
+    ) {
+        return Some((w.round() as u32, h.round() as u32));
+    }
+    let view_box = attr("viewBox")?
+        .split([' ', ','])
+        .filter(|s| !s.is_empty())
+        .filter_map(|s| s.parse::<f64>().ok())
+        .collect::<Vec<_>>();
+    match view_box[..] {
+        [_, _, w, h] => Some((w.round() as u32, h.round() as u32)),
+        _ => None,
+    }
+}
+
+/// Every file under `dir`, sorted so the manifest is stable
+fn walk(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let Ok(entries) = fs::read_dir(dir) else {
+        return files;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            files.extend(walk(&path));
+        } else {
+            files.push(path);
+        }
+    }
+    files.sort();
+    files
+}
+
+fn relative(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
@@ -1,25 +1,39 @@
 #!/bin/bash
-# Development script that copies portfolio assets and starts dx serve
+# Development script that downloads the web fonts if they are missing,
+# generates responsive image variants, links them and the portfolio media into
+# the dev build, then starts dx serve
 
-# Copy portfolio assets to the expected location after dx builds
-copy_assets() {
-    sleep 3  # Wait for initial build
-    while true; do
-        if [ -d "target/dx/pounds-consulting/debug/web/public/assets" ]; then
-            mkdir -p target/dx/pounds-consulting/debug/web/public/assets/portfolio
-            cp -r assets/portfolio/* target/dx/pounds-consulting/debug/web/public/assets/portfolio/ 2>/dev/null
+PUBLIC_ASSETS="target/dx/pounds-consulting/debug/web/public/assets"
+
+# Media that content refers to by path isn't bundled by dx. Once the first
+# build has created the output folder, link the folders into it; links
+# rather than copies mean files added later are served without re-running
+# anything.
+link_assets() {
+    until [ -d "$PUBLIC_ASSETS" ]; do
+        sleep 1
+    done
+    for dir in fonts portfolio variants; do
+        if [ -d "assets/$dir" ]; then
+            rm -rf "${PUBLIC_ASSETS:?}/$dir"
+            ln -s "$PWD/assets/$dir" "$PUBLIC_ASSETS/$dir"
         fi
-        sleep 2
     done
 }
 
-# Start the asset copier in the background
-copy_assets &
-COPY_PID=$!
+# fonts.sh skips fonts that are already downloaded; without them the
+# @font-face rules 404 and the site falls back to system fonts
+./fonts.sh || echo "dev.sh: couldn't download fonts, using system fonts" >&2
+
+# Without variants pages serve the original images, so this is optional
+./media.sh || echo "dev.sh: couldn't generate image variants, serving originals" >&2
+
+link_assets &
+LINK_PID=$!
 
 # Cleanup on exit
 cleanup() {
-    kill $COPY_PID 2>/dev/null
+    kill $LINK_PID 2>/dev/null
     exit 0
 }
 trap cleanup EXIT INT TERM
@@ -0,0 +1,40 @@
+#!/bin/bash
+# Downloads the site's web fonts from Google Fonts into assets/fonts/ so
+# visitors load them from this site instead. Only the Latin subset is kept,
+# one woff2 per family and weight, named like montserrat-700.woff2 to match
+# the @font-face rules at the top of assets/main.css. Files that are
+# already there are skipped.
+#
+# Inter, Montserrat and Open Sans are all under the SIL Open Font License.
+
+set -euo pipefail
+cd "$(dirname "$0")"
+
+FONTS=("Inter:500" "Inter:600" "Montserrat:700" "Open Sans:400" "Open Sans:500")
+# Google only serves woff2 to browsers it recognises
+AGENT="Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/124.0 Safari/537.36"
+
+mkdir -p assets/fonts
+
+for spec in "${FONTS[@]}"; do
+    family=${spec%%:*}
+    weight=${spec##*:}
+    out="assets/fonts/$(echo "$family" | tr 'A-Z ' 'a-z-')-$weight.woff2"
+    if [ -s "$out" ]; then
+        continue
+    fi
+
+    css=$(curl -fsSL -A "$AGENT" \
+        "https://fonts.googleapis.com/css2?family=${family// /+}:wght@$weight&display=swap")
+    # Each subset is a block headed by a comment; take the plain latin one
+    url=$(echo "$css" |
+        awk '/\/\* latin \*\//{found=1} found && /src:/{print; exit}' |
+        grep -o 'https://[^)]*\.woff2')
+    if [ -z "$url" ]; then
+        echo "fonts.sh: no latin woff2 for $family $weight" >&2
+        exit 1
+    fi
+
+    curl -fsSL -o "$out" "$url"
+    echo "$out"
+done
@@ -0,0 +1,372 @@
+# English, the source catalog. Every other catalog is checked against it.
+
+## Shared
+
+skip-to-content = Skip to content
+cta-book-free-call = Book a Free Call
+testimonials-title = What Clients Say
+why-work-with-us = Why Work With Us
+cta-schedule-call = Schedule a Call
+cta-start-project = Start Your Project
+cta-not-sure-title = Not Sure What You Need?
+projects-heading = Projects
+view-case-study = View Case Study →
+filter-all = All
+read-more = Read more →
+logo-alt = { $name } logo
+screenshot-alt = { $name } screenshot
+back-to-home = Back to Home
+
+## Header and footer
+
+nav-home = Home
+nav-about = About
+nav-services = Services
+nav-portfolio = Portfolio
+nav-articles = Articles
+nav-contact = Contact
+nav-label = Main
+nav-toggle = Toggle navigation menu
+language-label = Language
+footer-tagline = Technical solutions for growing businesses.
+footer-navigation = Navigation
+footer-newsletter = Newsletter
+footer-contact = Contact
+location = Kansas City, Missouri
+footer-copyright = © 2026 Pounds Consulting LLC. All rights reserved.
+
+## Home
+
+home-hero-title = Your Technical Partner
+home-hero-subtitle = Not a vendor. Not an agency. A partner who treats your business like it matters.
+home-hero-body = We build websites that convert visitors into customers. We automate the manual work eating up your week. We help you make smart technology decisions before you spend money on the wrong thing. Direct communication. Honest advice. Work that lasts.
+home-intro-title = Technology Should Work for You
+home-intro-1 = A slow website costs you 7% in conversions for every extra second it takes to load. Manual processes that could be automated are eating 20+ hours of your week. The wrong technology decision can waste months and thousands of dollars.
+home-intro-2 = We fix these problems. We build fast websites that rank well and convert visitors. We automate the repetitive work that's draining your team. We help you make smart decisions before you commit.
+home-intro-3 = No jargon. No overcomplicated solutions. Just clean work that delivers results.
+home-services-title = What We Do
+home-service-web-title = Web Development
+home-service-web-description = Fast websites that rank in Google, work on every device, and convert visitors into paying customers. Custom built for your business.
+home-service-marketing-title = Digital Marketing
+home-service-marketing-description = Email sequences that nurture leads while you sleep. SMS reminders that reduce no-shows. Customer systems that run themselves.
+home-service-strategy-title = Technical Strategy
+home-service-strategy-description = Get the decision right before you spend the money. Vendor evaluation, build vs buy analysis, architecture reviews.
+home-service-business-title = Business Solutions
+home-service-business-description = Connect your tools with Zapier and custom integrations. Automate the repetitive tasks eating up your team's time.
+home-why-simple-title = Simple solutions
+home-why-simple-description = We recommend what works, not what sounds impressive. Sometimes the answer is a simple website, not a custom app. We'll tell you.
+home-why-communication-title = Reliable communication
+home-why-communication-description = Emails answered within one business day. No ghosting. No surprises. You'll always know where your project stands.
+home-why-experience-title = Proven experience
+home-why-experience-description = Healthcare fintech serving hundreds of thousands of users. Regional businesses opening 3 locations per month. We've built systems that scale.
+home-why-experts-title = Access to experts
+home-why-experts-description = Need a designer? A security specialist? An AI expert? We have a network of brilliant people we can call. Whatever the challenge, we know someone who's solved it.
+cta-ready-title = Ready to get started?
+home-cta-description = Let's talk about what you're trying to build and how we can help.
+
+## About
+
+about-hero-title = We Make Tech Less Painful
+about-hero-subtitle = You shouldn't need a computer science degree to run your business. We handle the technical stuff so you can focus on what you're good at.
+about-intro-title = Hi, I'm Collin
+about-intro-1 = I started Pounds Consulting because too many businesses get overcharged for confusing technology. Whether it's your first website, a system to capture leads, or figuring out which tools you actually need, I'm here to help you make sense of it all.
+about-intro-2 = My background includes building products at Paytient (a healthcare fintech startup serving hundreds of thousands of users), running digital marketing for Club Car Wash during their rapid expansion, and implementing enterprise integrations for clients like Hyatt and Commerce Bank. I have a Computer Science degree and stay current with AI/ML developments by building with tools like Claude and Llama in my spare time.
+about-intro-3 = But what I enjoy most is working directly with people in my community. There's something rewarding about helping a local gym owner get their scheduling system working, or building a website for a business that's been growing through word-of-mouth for years and is finally ready to scale.
+about-github-link = See my work on GitHub →
+about-clients-title = Experience Across Industries
+about-clients-subtitle = From local businesses to venture-backed startups
+about-client-fintech = Healthcare Fintech
+about-client-retail = Regional Retail
+about-client-services = Professional Services
+about-client-local = Local Small Business
+about-skills-title = What We Can Help With
+about-skills-websites = Websites
+about-skill-custom-websites = Custom Websites
+about-skill-mobile = Mobile-Friendly
+about-skill-fast = Fast Loading
+about-skill-easy-update = Easy to Update
+about-skill-seo = SEO Built-In
+about-skills-marketing = Marketing Systems
+about-skill-email = Email Campaigns
+about-skill-text = Text Messaging
+about-skill-scheduling = Online Scheduling
+about-skill-leads = Lead Capture
+about-skill-follow-up = Customer Follow-up
+about-skills-tools = Business Tools
+about-skill-connect = Connect Your Apps
+about-skill-automate = Automate Tasks
+about-skill-portals = Customer Portals
+about-skill-payments = Online Payments
+about-skill-software = Custom Software
+about-skills-guidance = Guidance
+about-skill-what-do-i-need = What Do I Need?
+about-skill-vendors = Vendor Selection
+about-skill-strategy = Tech Strategy
+about-skill-honest = Honest Advice
+about-skill-no-jargon = No Jargon
+about-background-title = Background
+about-now-title = Independent Consulting
+about-now-period = Now
+about-now-1 = Building websites for local businesses who need something better than a template
+about-now-2 = Helping business owners figure out what technology they actually need
+about-now-3 = Setting up marketing systems that run on autopilot
+about-now-4 = Connecting software so you don't have to copy-paste between apps
+about-before-title = Before Going Independent
+about-before-years = 5+ Years Building Software
+about-before-1 = Paytient: Healthcare fintech serving 300,000+ users. Led MFA implementation, redesigned member portal
+about-before-2 = Club Car Wash: Built website and employee portal, ran Google Ads for ~3 store openings/month
+about-before-3 = Enterprise clients: Integrations for Hyatt, Commerce Bank, and others
+about-before-4 = Learned what works at scale, and how to keep things simple
+about-why-title = Why We Do This
+about-why-1 = Too many businesses get overcharged for confusing technology
+about-why-2 = We like solving problems and explaining things in plain English
+about-why-3 = Small businesses deserve the same quality work as big companies
+about-why-4 = It's satisfying to build something that actually helps people
+about-how-title = How We Work
+about-how-listen-title = We Listen
+about-how-listen = Every project starts with a conversation. What's working? What's not? What would make your life easier? We go from there.
+about-how-language-title = We Speak Your Language
+about-how-language = No buzzwords. No alphabet soup of acronyms. Just clear explanations of what we're doing and why it matters.
+about-how-simple-title = We Don't Overdo It
+about-how-simple = The goal is to solve your problem, not to build the fanciest thing possible. We recommend what actually makes sense for your situation.
+about-how-backup-title = We've Got Backup
+about-how-backup = Some problems need specialists. We work with designers, security experts, and other talented pros. You deal with one person, but you get a whole network when you need it.
+about-cta-description = That's fine. Most people aren't when they first reach out. Let's have a conversation and figure it out.
+about-cta-button = Schedule a Free Call
+
+## Services
+
+services-hero-title = Technical Solutions for Every Challenge
+services-hero-subtitle = From AI strategy to web development, mobile apps to business automation. Whatever technical problem you're facing, we can help solve it.
+services-book-discovery = Book a Free Discovery Call
+services-heading = Our Services
+services-learn-more = Learn More
+services-why-contact-title = One Point of Contact
+services-why-contact = No account managers or layers of bureaucracy. You work directly with me, someone who understands both the business and technical sides.
+services-why-network-title = Network of Experts
+services-why-network = Need a designer? A security specialist? A data scientist? I have a network of brilliant people I can bring in for any challenge.
+services-why-honest-title = Honest Advice
+services-why-honest = Sometimes the answer is 'you don't need this' or 'use a cheaper solution.' I'll tell you the truth, even when it means less work for me.
+services-why-fast-title = Fast Response
+services-why-fast = Emails answered within one business day. Usually faster. No waiting weeks for a callback or getting lost in a ticketing system.
+services-process-title = How We Work Together
+services-discovery-title = Start With a Free Discovery Call
+services-discovery = Every project starts with a conversation. We'll discuss your goals, challenges, and what success looks like for you.
+services-promise-title = Our Promise
+services-promise = You'll receive a complete quote with a clear delivery timeline before we ask for a single dollar. No surprises. No pressure. Just honest numbers so you can make the right decision for your business.
+services-book-call = Book Your Free Call
+services-no-obligation = No credit card. No obligation. Just a conversation.
+services-expect-title = What to Expect
+services-step-discovery-title = Discovery Call
+services-step-discovery = Free 30-minute conversation to understand your needs
+services-step-quote-title = Custom Quote
+services-step-quote = Detailed proposal with exact pricing and timeline
+services-step-decide-title = You Decide
+services-step-decide = Review everything, ask questions, no pressure
+services-step-build-title = We Build
+services-step-build = Regular updates until delivery and handoff
+services-discount-title = 50% Off for Those Who Serve
+services-discount = We offer 50% off our hourly rate for those who serve our communities and country. Your service matters.
+services-discount-military = Military (Active Duty & Reserves)
+services-discount-veterans = Veterans
+services-discount-police = Law Enforcement
+services-discount-fire = Fire Fighters
+services-discount-ems = EMTs & Paramedics
+services-discount-cta = Mention your service when you book and we'll apply the discount.
+services-cta-description = That's okay. Most clients come to us with a problem, not a predefined solution. Let's talk through what you're trying to accomplish and figure out the right path forward together.
+services-cta-button = Start a Conversation
+
+## Service detail
+
+service-all-services = All Services
+service-included = What's Included
+service-how-we-help = How We Can Help
+service-personas = Who This Is For
+service-cta-description = Let's talk about how { $service } can help your business.
+service-case-studies = Related Case Studies
+service-others = Explore Other Services
+service-not-found-title = Service Not Found
+service-not-found = The service you're looking for doesn't exist.
+services-view-all = View All Services
+
+## Portfolio
+
+portfolio-hero-title = Work That Speaks for Itself
+portfolio-hero-subtitle = A selection of projects we've delivered. Real clients, real problems, real results.
+portfolio-filter-type = Filter by project type
+portfolio-filter-tech = Filter by technology
+portfolio-see-tag = See every { $tag } project →
+portfolio-no-match = No projects match those filters.
+portfolio-clear-filters = Clear Filters
+portfolio-cta-title = Ready to Join the List?
+portfolio-cta-description = Every project starts with a conversation. Let's talk about what you're building.
+portfolio-featured = Featured
+tag-not-found-title = Technology Not Found
+tag-not-found = We don't have any projects tagged with that technology yet.
+tag-back = Back to Portfolio
+tag-all-projects = ← All Projects
+tag-title = Built with { $tag }
+tag-count-one = 1 project using { $tag }.
+tag-count-other = { $count } projects using { $tag }.
+tag-cta-title = Need Something Built with { $tag }?
+tag-cta-description = Tell us what you're working on and we'll tell you how we'd approach it.
+
+## Case study
+
+project-back = ← Back to Portfolio
+project-media-soon = Screenshot or video coming soon
+project-results = Results
+project-client-says = What the Client Says
+project-timeline = Timeline
+project-gallery = Gallery
+project-scope = Scope of Work
+project-visit = Visit Site →
+project-view-before = View Before →
+project-services-used = Services Used
+project-more = More Projects
+project-not-found-title = Project Not Found
+project-not-found = The project you're looking for doesn't exist.
+project-view-all = View All Projects
+before-after-before = Before
+before-after-after = After
+before-after-before-alt = { $title } before the redesign
+before-after-after-alt = { $title } after the redesign
+before-after-slider = Drag to compare before and after
+gallery-open = Open { $image }
+gallery-previous = Previous image
+gallery-next = Next image
+gallery-close = Close gallery
+testimonials-carousel = Client testimonials
+testimonials-previous = Previous testimonial
+testimonials-show = Show testimonial { $number }
+testimonials-next = Next testimonial
+
+## Articles
+
+articles-hero-title = Articles
+articles-hero-subtitle = Insights, updates, and perspectives on technology and business.
+articles-empty-title = No articles yet
+articles-empty = Check back soon for new content.
+articles-previous = ← Previous
+articles-page = Page { $page } of { $total }
+articles-next = Next →
+articles-cta-title = Have a Question?
+articles-cta-description = We're always happy to discuss technology, business, or potential projects.
+articles-cta-button = Get in Touch
+article-back = ← Back to Articles
+article-reading-time = { $minutes } min read
+article-word-count = { $count } words
+article-toc = On this page
+article-copy-section-link = Copy link to "{ $heading }"
+article-link-copied = Link copied
+article-category-nav = More in { $category }
+article-previous = ← Previous
+article-next = Next →
+article-share = Share this article:
+article-copy-link = Copy Link
+share-native = Share…
+share-email = Email
+article-subscribe-title = Enjoyed this article?
+article-related = Related Reading
+article-not-found-title = Article Not Found
+article-not-found = The article you're looking for doesn't exist.
+article-view-all = View All Articles
+
+## Contact
+
+contact-hero-title = Let's Talk
+contact-hero-subtitle = Have a project in mind? Questions? Just want to network? Book some time.
+contact-schedule-title = Schedule a Call
+contact-schedule = Book a free 30-minute discovery call. We'll discuss your project, answer questions, and figure out if we're a good fit.
+contact-book = Book a Time
+contact-other-ways = Other Ways to Reach Us
+contact-email = Email
+contact-location = Location
+contact-faq-title = Common Questions
+contact-faq-timeline-question = What's a typical project timeline?
+contact-faq-timeline = Simple websites can launch in 2-4 weeks. More complex projects vary based on scope, but we'll provide a realistic timeline during our initial conversation.
+contact-faq-remote-question = Do you work with clients outside Missouri?
+contact-faq-remote = Absolutely. While we're based in Columbia, most of our work is done remotely. We work with clients across the country.
+contact-faq-unsure-question = What if I'm not sure what I need?
+contact-faq-unsure = That's completely fine. Most conversations start with a problem, not a solution. Book a call and we'll help you figure out the right approach.
+contact-faq-billing-question = How does billing work?
+contact-faq-billing = We bill at $71/hour for most work, invoiced monthly. For fixed-scope projects, we provide a complete quote upfront. We offer a 50% discount for military, veterans, law enforcement, and first responders.
+
+## Not found and errors
+
+not-found-title = Page Not Found
+not-found = The page you're looking for doesn't exist.
+not-found-suggestions = Were you looking for:
+error-title = Something Went Wrong
+error-message = This page hit an unexpected problem. Try again, or head back home.
+error-retry = Try Again
+error-crashed = The site stopped working. Reload the page to start again.
+error-reload = Reload Page
+
+## Theme customizer
+
+theme-title = Customize Theme
+theme-close = Close theme customizer
+theme-presets = Presets
+theme-match-system = Match System
+theme-presets-dark = Dark
+theme-presets-light = Light
+theme-presets-mine = My Themes
+theme-custom-colors = Custom Colors
+theme-color-primary = Primary
+theme-color-secondary = Accent
+theme-color-accent = Accent Hover
+theme-color-background = Background
+theme-color-surface = Surface
+theme-color-text-primary = Text Primary
+theme-color-text-secondary = Text Secondary
+theme-color-border = Border
+theme-color-value = { $color } value
+theme-generate-hint = Derive background, surface, border and text shades from the accent color
+theme-generate = Generate Palette from Accent
+theme-contrast = Contrast
+theme-contrast-pair = { $foreground } on { $background }
+theme-contrast-warning = Some text doesn't meet WCAG AA contrast (4.5:1). This theme is previewed but won't be saved until it does.
+theme-save-share = Save & Share
+theme-name = Theme name
+theme-save = Save
+theme-saved = Saved "{ $name }"
+theme-imported = Imported "{ $name }"
+theme-delete = Delete "{ $name }" from my themes
+theme-export-link = Link
+theme-exported = Exported theme
+theme-import-label = Theme to import
+theme-import-placeholder = Paste a theme as JSON, CSS variables, or a share link
+theme-import = Import
+theme-reset = Reset to Site Theme
+theme-current-site = Current: Site Theme ({ $name })
+theme-current = Current: { $name }
+theme-toggle = Customize theme
+
+## Newsletter
+
+subscribe-text = Get new articles by email. No spam; unsubscribe any time.
+subscribe-email-label = Email address
+subscribe-placeholder = you@example.com
+subscribe-button = Subscribe
+subscribe-invalid-email = Enter a valid email address, like you@example.com.
+subscribe-check-inbox = Almost done! We sent a confirmation link to { $email }. Follow it to start getting new articles.
+subscribe-already = You're already subscribed. Thanks for reading!
+subscribe-failed = Something went wrong and we couldn't sign you up. Please try again.
+subscribe-unavailable = Email sign-up isn't available right now. Please check back later.
+newsletter-confirmed-title = You're Subscribed
+newsletter-confirmed = Thanks! We'll email { $email } when we publish new articles.
+newsletter-unsubscribed-title = You've Unsubscribed
+newsletter-unsubscribed = We won't email { $email } again. You can subscribe again from any article.
+newsletter-link-invalid-title = Link Not Valid
+newsletter-link-invalid = This link has expired or was already replaced by a newer one. You can subscribe again from any article.
+newsletter-confirm-subject = Confirm your subscription to { $site }
+newsletter-confirm-intro = Thanks for signing up for new articles from { $site }. Please confirm your email address to start getting them.
+newsletter-confirm-action = Confirm subscription
+newsletter-confirm-ignore = If you didn't sign up, ignore this email and you won't hear from us.
+newsletter-digest-subject-one = New from { $site }: { $title }
+newsletter-digest-subject = { $count } new articles from { $site }
+newsletter-digest-intro = Here's what we've published since our last email.
+newsletter-digest-read = Read the article
+newsletter-email-reason = You're getting this email because you subscribed to articles from { $site }.
+newsletter-email-unsubscribe = Unsubscribe
@@ -0,0 +1,372 @@
+# Spanish
+
+## Shared
+
+skip-to-content = Saltar al contenido
+cta-book-free-call = Reserva una llamada gratis
+testimonials-title = Lo que dicen los clientes
+why-work-with-us = Por qué trabajar con nosotros
+cta-schedule-call = Agenda una llamada
+cta-start-project = Empieza tu proyecto
+cta-not-sure-title = ¿No sabes bien lo que necesitas?
+projects-heading = Proyectos
+view-case-study = Ver caso de estudio →
+filter-all = Todos
+read-more = Leer más →
+logo-alt = Logotipo de { $name }
+screenshot-alt = Captura de pantalla de { $name }
+back-to-home = Volver al inicio
+
+## Header and footer
+
+nav-home = Inicio
+nav-about = Nosotros
+nav-services = Servicios
+nav-portfolio = Portafolio
+nav-articles = Artículos
+nav-contact = Contacto
+nav-label = Principal
+nav-toggle = Abrir o cerrar el menú
+language-label = Idioma
+footer-tagline = Soluciones técnicas para negocios en crecimiento.
+footer-navigation = Navegación
+footer-newsletter = Boletín
+footer-contact = Contacto
+location = Kansas City, Misuri
+footer-copyright = © 2026 Pounds Consulting LLC. Todos los derechos reservados.
+
+## Home
+
+home-hero-title = Tu socio tecnológico
+home-hero-subtitle = Ni un proveedor ni una agencia. Un socio que trata tu negocio como lo que es: importante.
+home-hero-body = Creamos sitios web que convierten visitas en clientes. Automatizamos el trabajo manual que se come tu semana. Te ayudamos a tomar buenas decisiones tecnológicas antes de gastar dinero en lo equivocado. Comunicación directa. Consejos honestos. Trabajo que dura.
+home-intro-title = La tecnología debe trabajar para ti
+home-intro-1 = Un sitio web lento te cuesta un 7 % de conversiones por cada segundo extra de carga. Los procesos manuales que se podrían automatizar se llevan más de 20 horas de tu semana. Una mala decisión tecnológica puede desperdiciar meses y miles de dólares.
+home-intro-2 = Nosotros resolvemos esos problemas. Creamos sitios rápidos que posicionan bien y convierten visitas. Automatizamos el trabajo repetitivo que agota a tu equipo. Te ayudamos a decidir bien antes de comprometerte.
+home-intro-3 = Sin tecnicismos. Sin soluciones complicadas. Solo trabajo limpio que da resultados.
+home-services-title = Lo que hacemos
+home-service-web-title = Desarrollo web
+home-service-web-description = Sitios web rápidos que posicionan en Google, funcionan en cualquier dispositivo y convierten visitas en clientes. Hechos a la medida de tu negocio.
+home-service-marketing-title = Marketing digital
+home-service-marketing-description = Secuencias de correo que cultivan prospectos mientras duermes. Recordatorios por SMS que reducen las ausencias. Sistemas de clientes que funcionan solos.
+home-service-strategy-title = Estrategia tecnológica
+home-service-strategy-description = Acierta con la decisión antes de gastar el dinero. Evaluación de proveedores, análisis de construir o comprar, revisiones de arquitectura.
+home-service-business-title = Soluciones para empresas
+home-service-business-description = Conecta tus herramientas con Zapier e integraciones a medida. Automatiza las tareas repetitivas que consumen el tiempo de tu equipo.
+home-why-simple-title = Soluciones sencillas
+home-why-simple-description = Recomendamos lo que funciona, no lo que suena impresionante. A veces la respuesta es un sitio web sencillo y no una aplicación a medida. Te lo diremos.
+home-why-communication-title = Comunicación confiable
+home-why-communication-description = Respondemos los correos en un día hábil. Sin desapariciones. Sin sorpresas. Siempre sabrás en qué punto está tu proyecto.
+home-why-experience-title = Experiencia comprobada
+home-why-experience-description = Una fintech de salud con cientos de miles de usuarios. Empresas regionales que abren 3 locales al mes. Hemos construido sistemas que escalan.
+home-why-experts-title = Acceso a expertos
+home-why-experts-description = ¿Necesitas un diseñador? ¿Un especialista en seguridad? ¿Un experto en IA? Tenemos una red de gente brillante a la que podemos llamar. Sea cual sea el reto, conocemos a alguien que ya lo resolvió.
+cta-ready-title = ¿Listo para empezar?
+home-cta-description = Hablemos de lo que quieres construir y de cómo podemos ayudarte.
+
+## About
+
+about-hero-title = Hacemos la tecnología menos dolorosa
+about-hero-subtitle = No deberías necesitar un título en informática para llevar tu negocio. Nos encargamos de la parte técnica para que te concentres en lo que haces bien.
+about-intro-title = Hola, soy Collin
+about-intro-1 = Fundé Pounds Consulting porque demasiados negocios pagan de más por tecnología confusa. Ya sea tu primer sitio web, un sistema para captar prospectos o saber qué herramientas necesitas de verdad, estoy aquí para ayudarte a entenderlo todo.
+about-intro-2 = Mi experiencia incluye crear productos en Paytient (una startup fintech de salud con cientos de miles de usuarios), dirigir el marketing digital de Club Car Wash durante su rápida expansión e implementar integraciones empresariales para clientes como Hyatt y Commerce Bank. Soy licenciado en Informática y me mantengo al día en IA y aprendizaje automático construyendo con herramientas como Claude y Llama en mi tiempo libre.
+about-intro-3 = Pero lo que más disfruto es trabajar directamente con la gente de mi comunidad. Es muy gratificante ayudar al dueño de un gimnasio local a poner en marcha su sistema de reservas, o crear el sitio web de un negocio que lleva años creciendo de boca en boca y por fin está listo para dar el salto.
+about-github-link = Mira mi trabajo en GitHub →
+about-clients-title = Experiencia en muchos sectores
+about-clients-subtitle = De negocios locales a startups con capital de riesgo
+about-client-fintech = Fintech de salud
+about-client-retail = Comercio regional
+about-client-services = Servicios profesionales
+about-client-local = Pequeños negocios locales
+about-skills-title = En qué podemos ayudarte
+about-skills-websites = Sitios web
+about-skill-custom-websites = Sitios a medida
+about-skill-mobile = Adaptados a móviles
+about-skill-fast = Carga rápida
+about-skill-easy-update = Fáciles de actualizar
+about-skill-seo = SEO incluido
+about-skills-marketing = Sistemas de marketing
+about-skill-email = Campañas de correo
+about-skill-text = Mensajes de texto
+about-skill-scheduling = Reservas en línea
+about-skill-leads = Captación de prospectos
+about-skill-follow-up = Seguimiento de clientes
+about-skills-tools = Herramientas de negocio
+about-skill-connect = Conecta tus aplicaciones
+about-skill-automate = Automatiza tareas
+about-skill-portals = Portales de clientes
+about-skill-payments = Pagos en línea
+about-skill-software = Software a medida
+about-skills-guidance = Orientación
+about-skill-what-do-i-need = ¿Qué necesito?
+about-skill-vendors = Selección de proveedores
+about-skill-strategy = Estrategia tecnológica
+about-skill-honest = Consejos honestos
+about-skill-no-jargon = Sin tecnicismos
+about-background-title = Trayectoria
+about-now-title = Consultoría independiente
+about-now-period = Hoy
+about-now-1 = Crear sitios web para negocios locales que necesitan algo mejor que una plantilla
+about-now-2 = Ayudar a los dueños de negocios a saber qué tecnología necesitan de verdad
+about-now-3 = Montar sistemas de marketing que funcionan en piloto automático
+about-now-4 = Conectar programas para que no tengas que copiar y pegar entre aplicaciones
+about-before-title = Antes de independizarme
+about-before-years = Más de 5 años creando software
+about-before-1 = Paytient: fintech de salud con más de 300 000 usuarios. Dirigí la implantación de MFA y rediseñé el portal de miembros
+about-before-2 = Club Car Wash: creé el sitio web y el portal de empleados, y gestioné Google Ads para unas 3 aperturas de tienda al mes
+about-before-3 = Clientes corporativos: integraciones para Hyatt, Commerce Bank y otros
+about-before-4 = Aprendí qué funciona a gran escala y cómo mantener las cosas simples
+about-why-title = Por qué hacemos esto
+about-why-1 = Demasiados negocios pagan de más por tecnología confusa
+about-why-2 = Nos gusta resolver problemas y explicar las cosas con claridad
+about-why-3 = Los pequeños negocios merecen la misma calidad que las grandes empresas
+about-why-4 = Es satisfactorio construir algo que de verdad ayuda a la gente
+about-how-title = Cómo trabajamos
+about-how-listen-title = Escuchamos
+about-how-listen = Cada proyecto empieza con una conversación. ¿Qué funciona? ¿Qué no? ¿Qué te haría la vida más fácil? A partir de ahí, avanzamos.
+about-how-language-title = Hablamos tu idioma
+about-how-language = Sin palabras de moda. Sin sopas de siglas. Solo explicaciones claras de lo que hacemos y por qué importa.
+about-how-simple-title = No exageramos
+about-how-simple = El objetivo es resolver tu problema, no construir lo más sofisticado posible. Recomendamos lo que de verdad tiene sentido para tu situación.
+about-how-backup-title = Tenemos respaldo
+about-how-backup = Algunos problemas necesitan especialistas. Trabajamos con diseñadores, expertos en seguridad y otros profesionales con talento. Tratas con una sola persona, pero cuentas con toda una red cuando la necesitas.
+about-cta-description = No pasa nada. La mayoría no lo sabe cuando nos escribe por primera vez. Conversemos y lo averiguamos juntos.
+about-cta-button = Agenda una llamada gratis
+
+## Services
+
+services-hero-title = Soluciones técnicas para cada reto
+services-hero-subtitle = De la estrategia de IA al desarrollo web, de las apps móviles a la automatización de procesos. Sea cual sea tu problema técnico, podemos ayudarte a resolverlo.
+services-book-discovery = Reserva una llamada de diagnóstico gratis
+services-heading = Nuestros servicios
+services-learn-more = Más información
+services-why-contact-title = Un solo punto de contacto
+services-why-contact = Sin ejecutivos de cuenta ni capas de burocracia. Trabajas directamente conmigo, alguien que entiende tanto el negocio como la parte técnica.
+services-why-network-title = Red de expertos
+services-why-network = ¿Necesitas un diseñador? ¿Un especialista en seguridad? ¿Un científico de datos? Tengo una red de gente brillante a la que puedo sumar para cualquier reto.
+services-why-honest-title = Consejos honestos
+services-why-honest = A veces la respuesta es «no lo necesitas» o «usa una solución más barata». Te diré la verdad, aunque eso signifique menos trabajo para mí.
+services-why-fast-title = Respuesta rápida
+services-why-fast = Respondemos los correos en un día hábil, normalmente antes. Sin esperar semanas una llamada ni perderte en un sistema de tickets.
+services-process-title = Cómo trabajamos juntos
+services-discovery-title = Empieza con una llamada de diagnóstico gratis
+services-discovery = Cada proyecto empieza con una conversación. Hablaremos de tus objetivos, tus retos y de cómo se ve el éxito para ti.
+services-promise-title = Nuestra promesa
+services-promise = Recibirás un presupuesto completo con un plazo de entrega claro antes de que te pidamos un solo dólar. Sin sorpresas. Sin presión. Solo números honestos para que tomes la mejor decisión para tu negocio.
+services-book-call = Reserva tu llamada gratis
+services-no-obligation = Sin tarjeta de crédito. Sin compromiso. Solo una conversación.
+services-expect-title = Qué esperar
+services-step-discovery-title = Llamada de diagnóstico
+services-step-discovery = Conversación gratuita de 30 minutos para entender lo que necesitas
+services-step-quote-title = Presupuesto a medida
+services-step-quote = Propuesta detallada con precio y plazos exactos
+services-step-decide-title = Tú decides
+services-step-decide = Revisa todo, pregunta lo que quieras, sin presión
+services-step-build-title = Construimos
+services-step-build = Novedades periódicas hasta la entrega y el traspaso
+services-discount-title = 50 % de descuento para quienes sirven
+services-discount = Ofrecemos un 50 % de descuento en nuestra tarifa por hora a quienes sirven a nuestras comunidades y al país. Tu servicio importa.
+services-discount-military = Militares (en activo y reservistas)
+services-discount-veterans = Veteranos
+services-discount-police = Policía
+services-discount-fire = Bomberos
+services-discount-ems = Técnicos de emergencias y paramédicos
+services-discount-cta = Menciona tu servicio al reservar y aplicaremos el descuento.
+services-cta-description = No pasa nada. La mayoría de los clientes llegan con un problema, no con una solución definida. Hablemos de lo que quieres lograr y encontremos juntos el camino adecuado.
+services-cta-button = Iniciemos una conversación
+
+## Service detail
+
+service-all-services = Todos los servicios
+service-included = Qué incluye
+service-how-we-help = Cómo podemos ayudarte
+service-personas = Para quién es
+service-cta-description = Hablemos de cómo { $service } puede ayudar a tu negocio.
+service-case-studies = Casos de estudio relacionados
+service-others = Descubre otros servicios
+service-not-found-title = Servicio no encontrado
+service-not-found = El servicio que buscas no existe.
+services-view-all = Ver todos los servicios
+
+## Portfolio
+
+portfolio-hero-title = Trabajo que habla por sí solo
+portfolio-hero-subtitle = Una selección de proyectos que hemos entregado. Clientes reales, problemas reales, resultados reales.
+portfolio-filter-type = Filtrar por tipo de proyecto
+portfolio-filter-tech = Filtrar por tecnología
+portfolio-see-tag = Ver todos los proyectos con { $tag } →
+portfolio-no-match = Ningún proyecto coincide con esos filtros.
+portfolio-clear-filters = Quitar filtros
+portfolio-cta-title = ¿Listo para sumarte a la lista?
+portfolio-cta-description = Cada proyecto empieza con una conversación. Hablemos de lo que estás construyendo.
+portfolio-featured = Destacado
+tag-not-found-title = Tecnología no encontrada
+tag-not-found = Todavía no tenemos proyectos con esa tecnología.
+tag-back = Volver al portafolio
+tag-all-projects = ← Todos los proyectos
+tag-title = Hecho con { $tag }
+tag-count-one = 1 proyecto con { $tag }.
+tag-count-other = { $count } proyectos con { $tag }.
+tag-cta-title = ¿Necesitas algo hecho con { $tag }?
+tag-cta-description = Cuéntanos en qué estás trabajando y te diremos cómo lo abordaríamos.
+
+## Case study
+
+project-back = ← Volver al portafolio
+project-media-soon = Captura o video próximamente
+project-results = Resultados
+project-client-says = Lo que dice el cliente
+project-timeline = Cronología
+project-gallery = Galería
+project-scope = Alcance del trabajo
+project-visit = Visitar el sitio →
+project-view-before = Ver el antes →
+project-services-used = Servicios utilizados
+project-more = Más proyectos
+project-not-found-title = Proyecto no encontrado
+project-not-found = El proyecto que buscas no existe.
+project-view-all = Ver todos los proyectos
+before-after-before = Antes
+before-after-after = Después
+before-after-before-alt = { $title } antes del rediseño
+before-after-after-alt = { $title } después del rediseño
+before-after-slider = Arrastra para comparar el antes y el después
+gallery-open = Abrir { $image }
+gallery-previous = Imagen anterior
+gallery-next = Imagen siguiente
+gallery-close = Cerrar galería
+testimonials-carousel = Testimonios de clientes
+testimonials-previous = Testimonio anterior
+testimonials-show = Mostrar el testimonio { $number }
+testimonials-next = Testimonio siguiente
+
+## Articles
+
+articles-hero-title = Artículos
+articles-hero-subtitle = Ideas, novedades y perspectivas sobre tecnología y negocios.
+articles-empty-title = Aún no hay artículos
+articles-empty = Vuelve pronto para ver contenido nuevo.
+articles-previous = ← Anterior
+articles-page = Página { $page } de { $total }
+articles-next = Siguiente →
+articles-cta-title = ¿Tienes alguna pregunta?
+articles-cta-description = Siempre nos encanta hablar de tecnología, negocios o posibles proyectos.
+articles-cta-button = Ponte en contacto
+article-back = ← Volver a los artículos
+article-reading-time = { $minutes } min de lectura
+article-word-count = { $count } palabras
+article-toc = En esta página
+article-copy-section-link = Copiar enlace a "{ $heading }"
+article-link-copied = Enlace copiado
+article-category-nav = Más en { $category }
+article-previous = ← Anterior
+article-next = Siguiente →
+article-share = Comparte este artículo:
+article-copy-link = Copiar enlace
+share-native = Compartir…
+share-email = Correo
+article-subscribe-title = ¿Te gustó este artículo?
+article-related = Lecturas relacionadas
+article-not-found-title = Artículo no encontrado
+article-not-found = El artículo que buscas no existe.
+article-view-all = Ver todos los artículos
+
+## Contact
+
+contact-hero-title = Hablemos
+contact-hero-subtitle = ¿Tienes un proyecto en mente? ¿Dudas? ¿Solo quieres conectar? Reserva un momento.
+contact-schedule-title = Agenda una llamada
+contact-schedule = Reserva una llamada de diagnóstico gratuita de 30 minutos. Hablaremos de tu proyecto, responderemos tus dudas y veremos si encajamos.
+contact-book = Reserva un horario
+contact-other-ways = Otras formas de contactarnos
+contact-email = Correo
+contact-location = Ubicación
+contact-faq-title = Preguntas frecuentes
+contact-faq-timeline-question = ¿Cuánto dura un proyecto normalmente?
+contact-faq-timeline = Un sitio web sencillo puede estar listo en 2 a 4 semanas. Los proyectos más complejos varían según el alcance, pero te daremos un plazo realista en la primera conversación.
+contact-faq-remote-question = ¿Trabajan con clientes fuera de Misuri?
+contact-faq-remote = Por supuesto. Aunque estamos en Columbia, la mayor parte de nuestro trabajo es remoto. Trabajamos con clientes de todo el país.
+contact-faq-unsure-question = ¿Y si no sé bien lo que necesito?
+contact-faq-unsure = No pasa nada. La mayoría de las conversaciones empiezan con un problema, no con una solución. Reserva una llamada y te ayudaremos a encontrar el enfoque adecuado.
+contact-faq-billing-question = ¿Cómo funciona la facturación?
+contact-faq-billing = Cobramos 71 $/hora por la mayoría de los trabajos, con factura mensual. Para proyectos de alcance cerrado damos un presupuesto completo por adelantado. Ofrecemos un 50 % de descuento a militares, veteranos, policías y personal de emergencias.
+
+## Not found and errors
+
+not-found-title = Página no encontrada
+not-found = La página que buscas no existe.
+not-found-suggestions = ¿Buscabas esto?:
+error-title = Algo salió mal
+error-message = Esta página tuvo un problema inesperado. Inténtalo de nuevo o vuelve al inicio.
+error-retry = Reintentar
+error-crashed = El sitio dejó de funcionar. Recarga la página para empezar de nuevo.
+error-reload = Recargar página
+
+## Theme customizer
+
+theme-title = Personalizar tema
+theme-close = Cerrar el personalizador de tema
+theme-presets = Temas predefinidos
+theme-match-system = Igual que el sistema
+theme-presets-dark = Oscuros
+theme-presets-light = Claros
+theme-presets-mine = Mis temas
+theme-custom-colors = Colores personalizados
+theme-color-primary = Primario
+theme-color-secondary = Acento
+theme-color-accent = Acento al pasar
+theme-color-background = Fondo
+theme-color-surface = Superficie
+theme-color-text-primary = Texto principal
+theme-color-text-secondary = Texto secundario
+theme-color-border = Borde
+theme-color-value = Valor de { $color }
+theme-generate-hint = Calcula los tonos de fondo, superficie, borde y texto a partir del color de acento
+theme-generate = Generar paleta desde el acento
+theme-contrast = Contraste
+theme-contrast-pair = { $foreground } sobre { $background }
+theme-contrast-warning = Parte del texto no alcanza el contraste WCAG AA (4.5:1). Puedes ver el tema, pero no se guardará hasta que lo cumpla.
+theme-save-share = Guardar y compartir
+theme-name = Nombre del tema
+theme-save = Guardar
+theme-saved = Se guardó «{ $name }»
+theme-imported = Se importó «{ $name }»
+theme-delete = Eliminar «{ $name }» de mis temas
+theme-export-link = Enlace
+theme-exported = Tema exportado
+theme-import-label = Tema para importar
+theme-import-placeholder = Pega un tema en JSON, como variables CSS o un enlace para compartir
+theme-import = Importar
+theme-reset = Volver al tema del sitio
+theme-current-site = Actual: tema del sitio ({ $name })
+theme-current = Actual: { $name }
+theme-toggle = Personalizar el tema
+
+## Newsletter
+
+subscribe-text = Recibe los nuevos artículos por correo. Sin spam; cancela cuando quieras.
+subscribe-email-label = Correo electrónico
+subscribe-placeholder = tu@ejemplo.com
+subscribe-button = Suscribirme
+subscribe-invalid-email = Escribe un correo válido, como tu@ejemplo.com.
+subscribe-check-inbox = ¡Casi listo! Enviamos un enlace de confirmación a { $email }. Ábrelo para empezar a recibir los nuevos artículos.
+subscribe-already = Ya estás suscrito. ¡Gracias por leernos!
+subscribe-failed = Algo salió mal y no pudimos suscribirte. Inténtalo de nuevo.
+subscribe-unavailable = La suscripción por correo no está disponible en este momento. Vuelve a intentarlo más tarde.
+newsletter-confirmed-title = Suscripción Confirmada
+newsletter-confirmed = ¡Gracias! Escribiremos a { $email } cuando publiquemos nuevos artículos.
+newsletter-unsubscribed-title = Suscripción Cancelada
+newsletter-unsubscribed = No volveremos a escribir a { $email }. Puedes suscribirte de nuevo desde cualquier artículo.
+newsletter-link-invalid-title = Enlace No Válido
+newsletter-link-invalid = Este enlace caducó o fue reemplazado por uno más reciente. Puedes suscribirte de nuevo desde cualquier artículo.
+newsletter-confirm-subject = Confirma tu suscripción a { $site }
+newsletter-confirm-intro = Gracias por suscribirte a los nuevos artículos de { $site }. Confirma tu correo para empezar a recibirlos.
+newsletter-confirm-action = Confirmar suscripción
+newsletter-confirm-ignore = Si no te suscribiste, ignora este correo y no volverás a saber de nosotros.
+newsletter-digest-subject-one = Nuevo en { $site }: { $title }
+newsletter-digest-subject = { $count } artículos nuevos de { $site }
+newsletter-digest-intro = Esto es lo que hemos publicado desde nuestro último correo.
+newsletter-digest-read = Leer el artículo
+newsletter-email-reason = Recibes este correo porque te suscribiste a los artículos de { $site }.
+newsletter-email-unsubscribe = Cancelar suscripción
@@ -0,0 +1,65 @@
+#!/bin/bash
+# Generates responsive WebP and AVIF variants of the raster images in assets/
+# into assets/variants/, mirroring the folder layout:
+#
+#   assets/portfolio/logo.png -> assets/variants/portfolio/logo-480w.webp, ...
+#
+# build.rs picks the variants up on the next build and pages serve them
+# through srcset. Images are never upscaled, and the full width is always
+# included. Files already newer than their source are skipped.
+#
+# Needs ImageMagick 7 (magick) or 6 (convert and identify). AVIF is skipped
+# when the ImageMagick build can't write it; pages then offer WebP alone.
+# The deploy workflow runs this before building, so variants never need
+# committing.
+
+set -euo pipefail
+cd "$(dirname "$0")"
+
+WIDTHS=(480 960 1600)
+
+if command -v magick >/dev/null; then
+    convert_image() { magick "$@"; }
+    identify_image() { magick identify "$@"; }
+elif command -v convert >/dev/null && command -v identify >/dev/null; then
+    convert_image() { convert "$@"; }
+    identify_image() { identify "$@"; }
+else
+    echo "media.sh needs ImageMagick (magick, or convert and identify) on PATH" >&2
+    exit 1
+fi
+
+FORMATS=(webp)
+if convert_image -list format | grep -qi '^ *AVIF.*rw'; then
+    FORMATS+=(avif)
+else
+    echo "media.sh: this ImageMagick can't write AVIF, generating WebP only" >&2
+fi
+
+find assets -path assets/variants -prune -o -type f \
+    \( -iname '*.png' -o -iname '*.jpg' -o -iname '*.jpeg' \) -print |
+while read -r src; do
+    rel="${src#assets/}"
+    stem="assets/variants/${rel%.*}"
+    mkdir -p "$(dirname "$stem")"
+
+    width=$(identify_image -format '%w' "$src[0]")
+    sizes=()
+    for w in "${WIDTHS[@]}"; do
+        if [ "$w" -lt "$width" ]; then
+            sizes+=("$w")
+        fi
+    done
+    sizes+=("$width")
+
+    for w in "${sizes[@]}"; do
+        for format in "${FORMATS[@]}"; do
+            out="$stem-${w}w.$format"
+            if [ "$out" -nt "$src" ]; then
+                continue
+            fi
+            convert_image "$src[0]" -resize "${w}x" -quality 80 "$out"
+            echo "$out"
+        done
+    done
+done
@@ -0,0 +1,48 @@
+#!/bin/bash
+# Converts the Open Graph preview cards in assets/og/ to the PNGs that
+# article and case study pages point their og:image tags at:
+#
+#   assets/og/en/articles/build-vs-buy-software.svg -> .../build-vs-buy-software.png
+#
+# The SVGs are written by `UPDATE_SNAPSHOTS=1 cargo test og_image`. Run
+# fonts.sh first so the cards are set in the site's own fonts. Files already
+# newer than their SVG are skipped.
+#
+# Needs rsvg-convert (librsvg) or ImageMagick 7 (magick).
+
+set -euo pipefail
+cd "$(dirname "$0")"
+
+if command -v rsvg-convert >/dev/null; then
+    convert_svg() { rsvg-convert --format png --output "$2" "$1"; }
+elif command -v magick >/dev/null; then
+    convert_svg() { magick -background none "$1" "$2"; }
+else
+    echo "og.sh needs rsvg-convert or ImageMagick 7 (magick) on PATH" >&2
+    exit 1
+fi
+
+# Let fontconfig find the self-hosted fonts alongside the system ones
+if [ -d assets/fonts ]; then
+    conf=$(mktemp)
+    trap 'rm -f "$conf"' EXIT
+    cat >"$conf" <<CONF
+<?xml version="1.0"?>
+<!DOCTYPE fontconfig SYSTEM "fonts.dtd">
+<fontconfig>
+  <include ignore_missing="yes">/etc/fonts/fonts.conf</include>
+  <dir>$PWD/assets/fonts</dir>
+</fontconfig>
+CONF
+    export FONTCONFIG_FILE="$conf"
+fi
+
+find assets/og -type f -name '*.svg' | sort |
+while read -r src; do
+    out="${src%.svg}.png"
+    if [ "$out" -nt "$src" ]; then
+        continue
+    fi
+    convert_svg "$src" "$out"
+    echo "$out"
+done
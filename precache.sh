@@ -0,0 +1,35 @@
+#!/bin/bash
+# Writes <dir>/precache.json, the list of files sw.js caches on install:
+# the wasm bundle and its JS glue, stylesheets, fonts, SVG icons and the web
+# manifest.
+#
+#   ./precache.sh target/dx/pounds-consulting/release/web/public
+#
+# The version is a hash of the listed files, so a new build gets a fresh
+# cache and the service worker drops the old one.
+
+set -euo pipefail
+
+dir=${1:?usage: precache.sh <built site directory>}
+cd "$dir"
+
+# Link preview cards are for other sites' crawlers, not visitors
+files=$(find . -path ./assets/og -prune -o -type f \
+    \( -name '*.wasm' -o -name '*.js' -o -name '*.css' -o -name '*.woff2' -o -name '*.svg' \
+    -o -name '*.webmanifest' \) \
+    ! -name sw.js -print | sed 's|^\./|/|' | LC_ALL=C sort)
+
+if [ -z "$files" ]; then
+    echo "precache.sh: nothing to precache in $dir" >&2
+    exit 1
+fi
+
+version=$(echo "$files" | sed 's|^/||' | xargs sha256sum | sha256sum | cut -c1-12)
+
+{
+    printf '{\n  "version": "%s",\n  "files": [\n' "$version"
+    echo "$files" | sed 's|.*|    "&"|' | sed '$!s|$|,|'
+    printf '  ]\n}\n'
+} > precache.json
+
+echo "precache.json: $(echo "$files" | wc -l) files, version $version"
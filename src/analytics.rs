@@ -0,0 +1,634 @@
+//! First-party analytics without cookies.
+//!
+//! Nothing identifies a visitor: there are no cookies, IDs or IP addresses,
+//! only what happened on which day. A page view records the page, the page
+//! the visitor came from on this site (kept in memory, so it's forgotten on
+//! reload) and, for the page they landed on, the referring site's host. A
+//! booking is a click on one of the "Book a Time" calendar buttons.
+//!
+//! Hits go to an `AnalyticsSink` installed in `main`. A static site has
+//! nowhere of its own to send them, so none is installed by default: nothing
+//! is recorded and the dashboard says no backend is configured. Off the web,
+//! `configured_sink` appends hits to the JSON Lines file named by
+//! `ANALYTICS_FILE`. Visits while signed in to admin aren't counted.
+
+use crate::i18n::Locale;
+use crate::Route;
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+/// Referrer label for visits that didn't come from another site
+pub const DIRECT: &str = "(direct)";
+
+/// Something a visitor did
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Event {
+    PageView {
+        path: String,
+        /// The previous page on this site
+        from: Option<String>,
+        /// Host of the referring site, for the first page of a visit
+        referrer: Option<String>,
+    },
+    /// A click on a calendar booking button
+    Booking {
+        /// Which button, e.g. `contact` or `service`
+        placement: String,
+        path: String,
+        from: Option<String>,
+    },
+}
+
+/// An event and the day it happened, `YYYY-MM-DD` in UTC
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Hit {
+    pub day: String,
+    #[serde(flatten)]
+    pub event: Event,
+}
+
+/// One day's totals
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct DayStats {
+    /// Views by page, with every language counted under the English path
+    pub views: BTreeMap<String, u32>,
+    pub languages: BTreeMap<String, u32>,
+    /// Landing pages by referring host, or `DIRECT`
+    pub referrers: BTreeMap<String, u32>,
+    /// Bookings by button
+    pub bookings: BTreeMap<String, u32>,
+    /// Bookings by the service page they came from
+    pub conversions: BTreeMap<String, u32>,
+}
+
+impl DayStats {
+    pub fn total_views(&self) -> u32 {
+        self.views.values().sum()
+    }
+
+    pub fn total_bookings(&self) -> u32 {
+        self.bookings.values().sum()
+    }
+}
+
+/// Daily totals, keyed by `YYYY-MM-DD`
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct Stats {
+    pub days: BTreeMap<String, DayStats>,
+}
+
+/// A service page's views and the bookings that followed
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceConversion {
+    pub slug: String,
+    pub views: u32,
+    pub bookings: u32,
+}
+
+impl ServiceConversion {
+    /// Bookings per view, as a percentage
+    pub fn rate(&self) -> f64 {
+        if self.views == 0 {
+            0.0
+        } else {
+            f64::from(self.bookings) * 100.0 / f64::from(self.views)
+        }
+    }
+}
+
+/// The English path of a page, so every language counts as one page
+fn page_key(path: &str) -> (String, Locale) {
+    match path.parse::<Route>() {
+        Ok(route) => (route.unlocalized().to_string(), route.locale()),
+        Err(_) => (path.to_string(), Locale::DEFAULT),
+    }
+}
+
+/// The service a path belongs to, if it's a service page
+fn service_slug(path: &str) -> Option<String> {
+    match path.parse::<Route>().ok()?.unlocalized() {
+        Route::ServiceDetail { slug } => Some(slug),
+        _ => None,
+    }
+}
+
+fn bump(counts: &mut BTreeMap<String, u32>, key: &str) {
+    *counts.entry(key.to_string()).or_default() += 1;
+}
+
+impl Stats {
+    pub fn add(&mut self, hit: &Hit) {
+        let day = self.days.entry(hit.day.clone()).or_default();
+        match &hit.event {
+            Event::PageView {
+                path,
+                from,
+                referrer,
+            } => {
+                let (page, locale) = page_key(path);
+                bump(&mut day.views, &page);
+                bump(&mut day.languages, locale.code());
+                if from.is_none() {
+                    bump(&mut day.referrers, referrer.as_deref().unwrap_or(DIRECT));
+                }
+            }
+            Event::Booking {
+                placement,
+                path,
+                from,
+            } => {
+                bump(&mut day.bookings, placement);
+                // Booked on a service page, or on the next page after one
+                let service = service_slug(path).or_else(|| from.as_deref().and_then(service_slug));
+                if let Some(slug) = service {
+                    bump(&mut day.conversions, &slug);
+                }
+            }
+        }
+    }
+
+    /// The `days` days ending on `today`, oldest first, with empty days
+    /// filled in so a chart has no gaps
+    pub fn last_days(&self, today: &str, days: usize) -> Vec<(String, DayStats)> {
+        let Some(end) = day_number(today) else {
+            return Vec::new();
+        };
+        (0..days as i64)
+            .rev()
+            .map(|back| {
+                let day = format_day(end - back);
+                let stats = self.days.get(&day).cloned().unwrap_or_default();
+                (day, stats)
+            })
+            .collect()
+    }
+}
+
+/// Totals over several days
+pub fn sum(days: &[(String, DayStats)]) -> DayStats {
+    let mut total = DayStats::default();
+    for (_, day) in days {
+        for (into, from) in [
+            (&mut total.views, &day.views),
+            (&mut total.languages, &day.languages),
+            (&mut total.referrers, &day.referrers),
+            (&mut total.bookings, &day.bookings),
+            (&mut total.conversions, &day.conversions),
+        ] {
+            for (key, count) in from {
+                *into.entry(key.clone()).or_default() += count;
+            }
+        }
+    }
+    total
+}
+
+/// Largest counts first, ties by name
+pub fn top(counts: &BTreeMap<String, u32>, n: usize) -> Vec<(String, u32)> {
+    let mut sorted: Vec<_> = counts.iter().map(|(k, v)| (k.clone(), *v)).collect();
+    sorted.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    sorted.truncate(n);
+    sorted
+}
+
+/// Views and bookings for every service page that had either
+pub fn service_conversions(total: &DayStats) -> Vec<ServiceConversion> {
+    let mut services: BTreeMap<String, ServiceConversion> = BTreeMap::new();
+    for (path, views) in &total.views {
+        if let Some(slug) = service_slug(path) {
+            services
+                .entry(slug.clone())
+                .or_insert_with(|| ServiceConversion {
+                    slug,
+                    views: 0,
+                    bookings: 0,
+                })
+                .views += views;
+        }
+    }
+    for (slug, bookings) in &total.conversions {
+        services
+            .entry(slug.clone())
+            .or_insert_with(|| ServiceConversion {
+                slug: slug.clone(),
+                views: 0,
+                bookings: 0,
+            })
+            .bookings += bookings;
+    }
+    let mut services: Vec<_> = services.into_values().collect();
+    services.sort_by(|a, b| b.views.cmp(&a.views).then_with(|| a.slug.cmp(&b.slug)));
+    services
+}
+
+// ==================== Dates ====================
+
+/// Days since 1970-01-01 for a `YYYY-MM-DD` date
+fn day_number(day: &str) -> Option<i64> {
+    let mut parts = day.splitn(3, '-').map(str::parse::<i64>);
+    let (y, m, d) = (
+        parts.next()?.ok()?,
+        parts.next()?.ok()?,
+        parts.next()?.ok()?,
+    );
+    // Howard Hinnant's days_from_civil
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * (m + if m > 2 { -3 } else { 9 }) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    Some(era * 146097 + doe - 719468)
+}
+
+/// `YYYY-MM-DD` for a number of days since 1970-01-01
+fn format_day(days: i64) -> String {
+    // Howard Hinnant's civil_from_days
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400 + i64::from(m <= 2);
+    format!("{:04}-{:02}-{:02}", y, m, d)
+}
+
+/// Today's date in UTC
+pub fn today() -> String {
+    #[cfg(target_arch = "wasm32")]
+    let millis = js_sys::Date::now() as i64;
+    #[cfg(not(target_arch = "wasm32"))]
+    let millis = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as i64);
+    format_day(millis.div_euclid(86_400_000))
+}
+
+// ==================== Sinks ====================
+
+/// Where hits go. A sink also answers for the dashboard, so one backed by
+/// a server would report that server's totals.
+pub trait AnalyticsSink {
+    fn record(&self, hit: &Hit);
+    fn stats(&self) -> Stats;
+}
+
+/// Appends each hit to a JSON Lines file, for running off the web
+#[cfg(not(target_arch = "wasm32"))]
+pub struct FileSink {
+    pub path: std::path::PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl AnalyticsSink for FileSink {
+    fn record(&self, hit: &Hit) {
+        use std::io::Write;
+        let Ok(line) = serde_json::to_string(hit) else {
+            return;
+        };
+        let _ = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| writeln!(file, "{}", line));
+    }
+
+    fn stats(&self) -> Stats {
+        let mut stats = Stats::default();
+        for line in std::fs::read_to_string(&self.path)
+            .unwrap_or_default()
+            .lines()
+        {
+            if let Ok(hit) = serde_json::from_str::<Hit>(line) {
+                stats.add(&hit);
+            }
+        }
+        stats
+    }
+}
+
+/// The `ANALYTICS_FILE` file when set off the web; on the web there's no
+/// backend to send hits to until one is written and installed
+pub fn configured_sink() -> Option<Rc<dyn AnalyticsSink>> {
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(path) = std::env::var_os("ANALYTICS_FILE") {
+        return Some(Rc::new(FileSink { path: path.into() }));
+    }
+    None
+}
+
+thread_local! {
+    static SINK: RefCell<Option<Rc<dyn AnalyticsSink>>> = const { RefCell::new(None) };
+    /// The page being viewed and the one before it, for attribution
+    static PAGES: RefCell<(Option<String>, Option<String>)> = const { RefCell::new((None, None)) };
+}
+
+/// Send hits to `sink` from now on
+pub fn install(sink: Rc<dyn AnalyticsSink>) {
+    SINK.with(|s| *s.borrow_mut() = Some(sink));
+}
+
+fn with_sink<R>(f: impl FnOnce(&dyn AnalyticsSink) -> R) -> Option<R> {
+    let sink = SINK.with(|s| s.borrow().clone())?;
+    Some(f(sink.as_ref()))
+}
+
+/// Totals from the installed sink, or `None` when there isn't one
+pub fn stats() -> Option<Stats> {
+    with_sink(|sink| sink.stats())
+}
+
+fn record(event: Event) {
+    if crate::content::is_authenticated() {
+        return;
+    }
+    let hit = Hit {
+        day: today(),
+        event,
+    };
+    with_sink(|sink| sink.record(&hit));
+}
+
+/// Host of the site that linked here, if it isn't this one
+fn external_referrer() -> Option<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let window = web_sys::window()?;
+        let referrer = window.document()?.referrer();
+        let host = referrer
+            .split_once("://")?
+            .1
+            .split(['/', '?', '#'])
+            .next()?
+            .to_string();
+        let own = window.location().host().ok()?;
+        (!host.is_empty() && host != own).then_some(host)
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
+/// Count a view of `route`
+pub fn track_page_view(route: &Route) {
+    let path = route.to_string();
+    let from = PAGES.with(|pages| {
+        let mut pages = pages.borrow_mut();
+        if pages.0.as_ref() == Some(&path) {
+            return Err(());
+        }
+        pages.1 = pages.0.replace(path.clone());
+        Ok(pages.1.clone())
+    });
+    // Re-rendering the same page isn't another view
+    let Ok(from) = from else {
+        return;
+    };
+    let referrer = if from.is_none() {
+        external_referrer()
+    } else {
+        None
+    };
+    record(Event::PageView {
+        path,
+        from,
+        referrer,
+    });
+}
+
+/// Count a click on a booking button on `route`
+pub fn track_booking(placement: &str, route: &Route) {
+    let from = PAGES.with(|pages| pages.borrow().1.clone());
+    record(Event::Booking {
+        placement: placement.to_string(),
+        path: route.to_string(),
+        from,
+    });
+}
+
+/// Click handler for a booking button that counts a booking from the
+/// current page
+pub fn use_booking_tracker(placement: &'static str) -> Callback<MouseEvent> {
+    let route = use_route::<Route>();
+    use_callback(move |_| track_booking(placement, &route))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Keeps totals in memory, standing in for a real backend in tests
+    #[derive(Default)]
+    pub(crate) struct MemorySink(pub RefCell<Stats>);
+
+    impl AnalyticsSink for MemorySink {
+        fn record(&self, hit: &Hit) {
+            self.0.borrow_mut().add(hit);
+        }
+
+        fn stats(&self) -> Stats {
+            self.0.borrow().clone()
+        }
+    }
+
+    fn view(day: &str, path: &str, from: Option<&str>, referrer: Option<&str>) -> Hit {
+        Hit {
+            day: day.to_string(),
+            event: Event::PageView {
+                path: path.to_string(),
+                from: from.map(String::from),
+                referrer: referrer.map(String::from),
+            },
+        }
+    }
+
+    fn booking(day: &str, placement: &str, path: &str, from: Option<&str>) -> Hit {
+        Hit {
+            day: day.to_string(),
+            event: Event::Booking {
+                placement: placement.to_string(),
+                path: path.to_string(),
+                from: from.map(String::from),
+            },
+        }
+    }
+
+    #[test]
+    fn test_views_count_every_language_as_one_page() {
+        let mut stats = Stats::default();
+        stats.add(&view("2026-03-01", "/services", None, None));
+        stats.add(&view("2026-03-01", "/es/services", Some("/es"), None));
+
+        let day = &stats.days["2026-03-01"];
+        assert_eq!(day.views["/services"], 2);
+        assert_eq!(day.languages["en"], 1);
+        assert_eq!(day.languages["es"], 1);
+    }
+
+    #[test]
+    fn test_referrers_count_landing_pages_only() {
+        let mut stats = Stats::default();
+        stats.add(&view("2026-03-01", "/", None, Some("www.google.com")));
+        stats.add(&view("2026-03-01", "/about", Some("/"), None));
+        stats.add(&view("2026-03-01", "/contact", None, None));
+
+        let referrers = &stats.days["2026-03-01"].referrers;
+        assert_eq!(referrers["www.google.com"], 1);
+        assert_eq!(referrers[DIRECT], 1);
+        assert_eq!(referrers.values().sum::<u32>(), 2);
+    }
+
+    #[test]
+    fn test_bookings_convert_the_service_page_they_follow() {
+        let mut stats = Stats::default();
+        let service = "/services/web-development";
+        stats.add(&booking(
+            "2026-03-01",
+            "service",
+            service,
+            Some("/services"),
+        ));
+        stats.add(&booking("2026-03-01", "contact", "/contact", Some(service)));
+        stats.add(&booking(
+            "2026-03-01",
+            "contact",
+            "/contact",
+            Some("/about"),
+        ));
+        stats.add(&booking(
+            "2026-03-01",
+            "service",
+            "/es/services/ai-consulting",
+            None,
+        ));
+
+        let day = &stats.days["2026-03-01"];
+        assert_eq!(day.total_bookings(), 4);
+        assert_eq!(day.bookings["contact"], 2);
+        assert_eq!(day.conversions["web-development"], 2);
+        assert_eq!(day.conversions["ai-consulting"], 1);
+    }
+
+    #[test]
+    fn test_service_conversion_rates() {
+        let mut stats = Stats::default();
+        for _ in 0..4 {
+            stats.add(&view("2026-03-01", "/services/web-development", None, None));
+        }
+        stats.add(&booking(
+            "2026-03-01",
+            "service",
+            "/services/web-development",
+            None,
+        ));
+        let days = stats.last_days("2026-03-01", 1);
+        let services = service_conversions(&sum(&days));
+
+        assert_eq!(services.len(), 1);
+        assert_eq!(services[0].slug, "web-development");
+        assert_eq!((services[0].views, services[0].bookings), (4, 1));
+        assert_eq!(services[0].rate(), 25.0);
+    }
+
+    #[test]
+    fn test_last_days_fill_gaps_across_months() {
+        let mut stats = Stats::default();
+        stats.add(&view("2026-02-27", "/", None, None));
+        stats.add(&view("2026-03-01", "/", None, None));
+
+        let days = stats.last_days("2026-03-01", 3);
+        let labels: Vec<_> = days.iter().map(|(d, _)| d.as_str()).collect();
+        assert_eq!(labels, ["2026-02-27", "2026-02-28", "2026-03-01"]);
+        let views: Vec<_> = days.iter().map(|(_, s)| s.total_views()).collect();
+        assert_eq!(views, [1, 0, 1]);
+    }
+
+    #[test]
+    fn test_dates_round_trip() {
+        assert_eq!(day_number("1970-01-01"), Some(0));
+        assert_eq!(format_day(0), "1970-01-01");
+        for day in ["2024-02-29", "2025-12-31", "2026-01-01", "2000-03-01"] {
+            assert_eq!(format_day(day_number(day).unwrap()), day);
+        }
+        assert_eq!(day_number("not a date"), None);
+    }
+
+    #[test]
+    fn test_file_sink_reads_back_what_it_wrote() {
+        let path =
+            std::env::temp_dir().join(format!("analytics-test-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let sink = FileSink { path: path.clone() };
+        sink.record(&view("2026-03-01", "/", None, None));
+        sink.record(&booking("2026-03-01", "contact", "/contact", Some("/")));
+
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(text.lines().count(), 2);
+        assert!(text
+            .lines()
+            .next()
+            .unwrap()
+            .contains("\"type\":\"page_view\""));
+        let day = &sink.stats().days["2026-03-01"];
+        assert_eq!((day.total_views(), day.total_bookings()), (1, 1));
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_repeat_views_of_the_same_page_count_once() {
+        /// Remembers hits instead of storing them
+        struct Recorder(RefCell<Vec<Hit>>);
+        impl AnalyticsSink for Recorder {
+            fn record(&self, hit: &Hit) {
+                self.0.borrow_mut().push(hit.clone());
+            }
+            fn stats(&self) -> Stats {
+                let mut stats = Stats::default();
+                for hit in self.0.borrow().iter() {
+                    stats.add(hit);
+                }
+                stats
+            }
+        }
+
+        let recorder = Rc::new(Recorder(RefCell::new(Vec::new())));
+        install(recorder.clone());
+        PAGES.with(|pages| pages.replace((None, None)));
+
+        track_page_view(&Route::Services {});
+        track_page_view(&Route::Services {});
+        let service = Route::ServiceDetail {
+            slug: "web-development".to_string(),
+        };
+        track_page_view(&service);
+        track_booking("service", &service);
+
+        let hits = recorder.0.borrow();
+        assert_eq!(hits.len(), 3);
+        assert_eq!(
+            hits[1].event,
+            Event::PageView {
+                path: "/services/web-development".to_string(),
+                from: Some("/services".to_string()),
+                referrer: None,
+            }
+        );
+        assert_eq!(
+            hits[2].event,
+            Event::Booking {
+                placement: "service".to_string(),
+                path: "/services/web-development".to_string(),
+                from: Some("/services".to_string()),
+            }
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use super::ResponsiveImage;
+use crate::content::{GalleryImage, ProjectPhase, ProjectResult};
+use crate::i18n::use_i18n;
+use dioxus::prelude::*;
+
+/// Drag-to-compare slider with the before screenshot laid over the after one
+#[component]
+pub fn BeforeAfterSlider(before: String, after: String, title: String) -> Element {
+    let i18n = use_i18n();
+    let mut position = use_signal(|| 50u8);
+    let reveal = 100 - position();
+
+    rsx! {
+        div { class: "before-after",
+            ResponsiveImage {
+                class: "before-after-image",
+                src: after.clone(),
+                alt: i18n.t_args("before-after-after-alt", &[("title", &title)]),
+                sizes: "(max-width: 1200px) 100vw, 1200px"
+            }
+            ResponsiveImage {
+                class: "before-after-image before-after-overlay",
+                style: format!("clip-path: inset(0 {}% 0 0);", reveal),
+                src: before.clone(),
+                alt: i18n.t_args("before-after-before-alt", &[("title", &title)]),
+                sizes: "(max-width: 1200px) 100vw, 1200px"
+            }
+            div { class: "before-after-divider", style: "left: {position}%;" }
+            span { class: "before-after-label before-after-label-before", {i18n.t("before-after-before")} }
+            span { class: "before-after-label before-after-label-after", {i18n.t("before-after-after")} }
+            input {
+                class: "before-after-range",
+                r#type: "range",
+                min: "0",
+                max: "100",
+                value: "{position}",
+                aria_label: i18n.t("before-after-slider"),
+                oninput: move |evt| {
+                    if let Ok(value) = evt.value().parse::<u8>() {
+                        position.set(value.min(100));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Before/after metric cards with the change highlighted
+#[component]
+pub fn ResultsGrid(results: Vec<ProjectResult>) -> Element {
+    rsx! {
+        div { class: "case-study-results",
+            for result in results {
+                div { key: "{result.metric}", class: "result-card glass-card",
+                    span {
+                        class: if result.improved() { "result-change improved" } else { "result-change" },
+                        "{result.format_change()}"
+                    }
+                    h4 { class: "result-metric", "{result.metric}" }
+                    p { class: "result-values",
+                        span { class: "result-before", "{result.format_before()}" }
+                        " → "
+                        strong { class: "result-after", "{result.format_after()}" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Ordered list of project phases
+#[component]
+pub fn ProjectTimeline(phases: Vec<ProjectPhase>) -> Element {
+    rsx! {
+        ol { class: "case-study-timeline",
+            for (index, phase) in phases.into_iter().enumerate() {
+                li { key: "{index}", class: "timeline-phase",
+                    span { class: "timeline-marker", "{index + 1}" }
+                    div { class: "timeline-content",
+                        h4 { "{phase.name}" }
+                        if !phase.duration.is_empty() {
+                            span { class: "timeline-duration", "{phase.duration}" }
+                        }
+                        if !phase.description.is_empty() {
+                            p { "{phase.description}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Thumbnail grid that opens images in a lightbox
+#[component]
+pub fn ProjectGallery(images: Vec<GalleryImage>) -> Element {
+    let i18n = use_i18n();
+    let mut open = use_signal(|| None::<usize>);
+    let count = images.len();
+
+    rsx! {
+        div { class: "case-study-gallery",
+            for (index, image) in images.iter().enumerate() {
+                figure { key: "{index}", class: "gallery-item",
+                    button {
+                        class: "gallery-thumb",
+                        aria_label: i18n.t_args("gallery-open", &[("image", &image.alt)]),
+                        onclick: move |_| open.set(Some(index)),
+                        ResponsiveImage {
+                            src: image.src.clone(),
+                            alt: image.alt.clone(),
+                            sizes: "(max-width: 768px) 50vw, 400px"
+                        }
+                    }
+                    if let Some(caption) = &image.caption {
+                        figcaption { "{caption}" }
+                    }
+                }
+            }
+        }
+
+        if let Some(image) = open().and_then(|index| images.get(index)) {
+            div {
+                class: "gallery-lightbox",
+                role: "dialog",
+                aria_label: "{image.alt}",
+                onclick: move |_| open.set(None),
+                div {
+                    class: "gallery-lightbox-content",
+                    onclick: move |evt| evt.stop_propagation(),
+                    ResponsiveImage { src: image.src.clone(), alt: image.alt.clone(), lazy: false }
+                    if let Some(caption) = &image.caption {
+                        p { class: "gallery-lightbox-caption", "{caption}" }
+                    }
+                    if count > 1 {
+                        button {
+                            class: "gallery-nav gallery-prev",
+                            aria_label: i18n.t("gallery-previous"),
+                            onclick: move |_| open.set(open().map(|i| (i + count - 1) % count)),
+                            "‹"
+                        }
+                        button {
+                            class: "gallery-nav gallery-next",
+                            aria_label: i18n.t("gallery-next"),
+                            onclick: move |_| open.set(open().map(|i| (i + 1) % count)),
+                            "›"
+                        }
+                    }
+                    button {
+                        class: "gallery-close",
+                        aria_label: i18n.t("gallery-close"),
+                        onclick: move |_| open.set(None),
+                        "×"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::analytics::use_booking_tracker;
+use crate::i18n::use_i18n;
 use crate::Route;
 use dioxus::prelude::*;
 
@@ -8,6 +10,8 @@ pub fn CtaSection(
     button_text: String,
     #[props(default = false)] use_calendar_link: bool,
 ) -> Element {
+    let i18n = use_i18n();
+    let track_booking = use_booking_tracker("cta_section");
     rsx! {
         section { class: "cta-section",
             div { class: "cta-container",
@@ -16,13 +20,14 @@ pub fn CtaSection(
                 if use_calendar_link {
                     a {
                         href: "https://calendar.app.google/LNasBbmDr8LXNEuu5",
+                        onclick: move |evt| track_booking.call(evt),
                         target: "_blank",
                         rel: "noopener noreferrer",
                         class: "btn btn-primary",
                         "{button_text}"
                     }
                 } else {
-                    Link { to: Route::Contact {}, class: "btn btn-primary",
+                    Link { to: i18n.route(Route::Contact {}), class: "btn btn-primary",
                         "{button_text}"
                     }
                 }
@@ -0,0 +1,52 @@
+use crate::i18n::use_i18n;
+use dioxus::prelude::*;
+use std::rc::Rc;
+
+/// `id` of every page's `<main>`, where the skip link lands
+pub const MAIN_CONTENT_ID: &str = "main-content";
+
+/// An element captured with `onmounted`, so focus can be moved to it later
+pub type FocusTarget = Signal<Option<Rc<MountedData>>>;
+
+/// Move keyboard focus to `target`, if it has mounted
+pub fn focus(target: FocusTarget) {
+    if let Some(element) = target.peek().clone() {
+        spawn(async move {
+            let _ = element.set_focus(true).await;
+        });
+    }
+}
+
+/// First tab stop on every page, hidden until focused. Focus is moved in
+/// code rather than by following the `#` link, which the router would
+/// treat as navigation.
+#[component]
+pub fn SkipLink() -> Element {
+    let i18n = use_i18n();
+    rsx! {
+        a {
+            class: "skip-link",
+            href: "#{MAIN_CONTENT_ID}",
+            onclick: move |evt: MouseEvent| {
+                evt.prevent_default();
+                focus_main_content();
+            },
+            {i18n.t("skip-to-content")}
+        }
+    }
+}
+
+fn focus_main_content() {
+    #[cfg(target_arch = "wasm32")]
+    {
+        use web_sys::wasm_bindgen::JsCast;
+
+        if let Some(main) = web_sys::window()
+            .and_then(|w| w.document())
+            .and_then(|d| d.get_element_by_id(MAIN_CONTENT_ID))
+            .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok())
+        {
+            let _ = main.focus();
+        }
+    }
+}
@@ -1,45 +1,64 @@
-use crate::components::{ThemeCustomizer, ThemeToggleButton};
+use crate::components::{SubscribeForm, ThemeCustomizer, ThemeToggleButton};
+use crate::content::load_settings;
+use crate::i18n::use_i18n;
+use crate::navigation::nav_items;
 use crate::Route;
 use dioxus::prelude::*;
 
 #[component]
 pub fn Footer() -> Element {
+    let i18n = use_i18n();
     let theme_panel_open = use_signal(|| false);
+    let settings = load_settings();
+    let items: Vec<_> = nav_items(&settings)
+        .into_iter()
+        .map(|item| item.localized(i18n))
+        .collect();
+    let visitor_themes = settings.features.visitor_themes;
 
     rsx! {
         // Theme customizer panel (renders when open)
-        ThemeCustomizer { is_open: theme_panel_open }
+        if visitor_themes {
+            ThemeCustomizer { is_open: theme_panel_open }
+        }
 
         footer { class: "footer",
             div { class: "footer-container",
                 div { class: "footer-brand",
-                    Link { to: Route::Home {}, class: "footer-logo",
+                    Link { to: i18n.route(Route::Home {}), class: "footer-logo",
                         span { class: "logo-text", "POUNDS" }
                         span { class: "logo-accent", "CONSULTING" }
                     }
                     p { class: "footer-tagline",
-                        "Technical solutions for growing businesses."
+                        {i18n.t("footer-tagline")}
+                    }
+                    div { class: "footer-subscribe",
+                        h2 { class: "footer-heading", {i18n.t("footer-newsletter")} }
+                        p { class: "footer-subscribe-text", {i18n.t("subscribe-text")} }
+                        SubscribeForm { id: "footer-subscribe" }
                     }
                 }
 
                 div { class: "footer-nav",
-                    h2 { class: "footer-heading", "Navigation" }
-                    Link { to: Route::Home {}, class: "footer-link", "Home" }
-                    Link { to: Route::About {}, class: "footer-link", "About" }
-                    Link { to: Route::Services {}, class: "footer-link", "Services" }
-                    Link { to: Route::Portfolio {}, class: "footer-link", "Portfolio" }
-                    Link { to: Route::Articles {}, class: "footer-link", "Articles" }
-                    Link { to: Route::Contact {}, class: "footer-link", "Contact" }
+                    h2 { class: "footer-heading", {i18n.t("footer-navigation")} }
+                    for item in items.iter() {
+                        Link {
+                            key: "{item.id}",
+                            to: item.route.clone(),
+                            class: "footer-link",
+                            "{item.label}"
+                        }
+                    }
                 }
 
                 div { class: "footer-contact",
-                    h2 { class: "footer-heading", "Contact" }
+                    h2 { class: "footer-heading", {i18n.t("footer-contact")} }
                     a {
                         href: "mailto:collin@poundsconsulting.net",
                         class: "footer-link",
                         "collin@poundsconsulting.net"
                     }
-                    p { class: "footer-location", "Kansas City, Missouri" }
+                    p { class: "footer-location", {i18n.t("location")} }
                 }
             }
 
@@ -63,9 +82,11 @@ pub fn Footer() -> Element {
                         }
                         "github.com/collinpounds"
                     }
-                    ThemeToggleButton { is_open: theme_panel_open }
+                    if visitor_themes {
+                        ThemeToggleButton { is_open: theme_panel_open }
+                    }
                 }
-                p { "© 2026 Pounds Consulting LLC. All rights reserved." }
+                p { {i18n.t("footer-copyright")} }
             }
         }
     }
@@ -1,68 +1,67 @@
+use crate::components::{focus, FocusTarget};
+use crate::content::load_settings;
+use crate::i18n::{use_i18n, Locale};
+use crate::navigation::nav_items;
 use crate::Route;
 use dioxus::prelude::*;
 
 #[component]
 pub fn Header() -> Element {
+    let i18n = use_i18n();
     let mut mobile_menu_open = use_signal(|| false);
+    let mut menu_toggle: FocusTarget = use_signal(|| None);
     let current_route = use_route::<Route>();
+    let items: Vec<_> = nav_items(&load_settings())
+        .into_iter()
+        .map(|item| item.localized(i18n))
+        .collect();
 
-    // Helper to determine if a route is active
-    let is_active = |route: &Route| -> bool {
-        std::mem::discriminant(&current_route) == std::mem::discriminant(route)
-    };
-
-    let home_class = if is_active(&Route::Home {}) {
-        "nav-link active"
-    } else {
-        "nav-link"
-    };
-    let about_class = if is_active(&Route::About {}) {
-        "nav-link active"
-    } else {
-        "nav-link"
-    };
-    let services_class = if is_active(&Route::Services {}) {
-        "nav-link active"
-    } else {
-        "nav-link"
-    };
-    let portfolio_class = if is_active(&Route::Portfolio {}) {
-        "nav-link active"
-    } else {
-        "nav-link"
-    };
-    let articles_class = if is_active(&Route::Articles {}) {
-        "nav-link active"
-    } else {
-        "nav-link"
-    };
-    let contact_class = if is_active(&Route::Contact {}) {
-        "nav-link nav-cta active"
-    } else {
-        "nav-link nav-cta"
+    // Helper to determine the class for a nav link
+    let link_class = |id: &str, route: &Route| -> &'static str {
+        let active = std::mem::discriminant(&current_route) == std::mem::discriminant(route);
+        match (id == "contact", active) {
+            (true, true) => "nav-link nav-cta active",
+            (true, false) => "nav-link nav-cta",
+            (false, true) => "nav-link active",
+            (false, false) => "nav-link",
+        }
     };
 
     rsx! {
-        header { class: "header",
+        header {
+            class: "header",
+            // Escape closes the mobile menu and puts focus back on its toggle
+            onkeydown: move |evt: KeyboardEvent| {
+                if evt.key() == Key::Escape && mobile_menu_open() {
+                    mobile_menu_open.set(false);
+                    focus(menu_toggle);
+                }
+            },
             div { class: "header-container",
-                Link { to: Route::Home {}, class: "footer-logo",
+                Link { to: i18n.route(Route::Home {}), class: "footer-logo",
                     span { class: "logo-text", "POUNDS" }
                     span { class: "logo-accent", "/ CONSULTING" }
                 }
 
-                nav { class: "nav-desktop",
-                    Link { to: Route::Home {}, class: "{home_class}", "Home" }
-                    Link { to: Route::About {}, class: "{about_class}", "About" }
-                    Link { to: Route::Services {}, class: "{services_class}", "Services" }
-                    Link { to: Route::Portfolio {}, class: "{portfolio_class}", "Portfolio" }
-                    Link { to: Route::Articles {}, class: "{articles_class}", "Articles" }
-                    Link { to: Route::Contact {}, class: "{contact_class}", "Contact" }
+                nav { class: "nav-desktop", "aria-label": i18n.t("nav-label"),
+                    for item in items.iter() {
+                        Link {
+                            key: "{item.id}",
+                            to: item.route.clone(),
+                            class: link_class(&item.id, &item.route),
+                            "{item.label}"
+                        }
+                    }
                 }
 
+                LocaleSwitcher { current: current_route.clone() }
+
                 button {
-                    class: "mobile-menu-toggle",
-                    "aria-label": "Toggle navigation menu",
+                    class: if mobile_menu_open() { "mobile-menu-toggle open" } else { "mobile-menu-toggle" },
+                    "aria-label": i18n.t("nav-toggle"),
                     "aria-expanded": "{mobile_menu_open()}",
+                    "aria-controls": "mobile-navigation",
+                    onmounted: move |evt: MountedEvent| menu_toggle.set(Some(evt.data())),
                     onclick: move |_| mobile_menu_open.set(!mobile_menu_open()),
                     span { class: "hamburger-line" }
                     span { class: "hamburger-line" }
@@ -71,45 +70,54 @@ pub fn Header() -> Element {
             }
 
             if mobile_menu_open() {
-                nav { class: "nav-mobile",
-                    Link {
-                        to: Route::Home {},
-                        class: "{home_class}",
-                        onclick: move |_| mobile_menu_open.set(false),
-                        "Home"
-                    }
-                    Link {
-                        to: Route::About {},
-                        class: "{about_class}",
-                        onclick: move |_| mobile_menu_open.set(false),
-                        "About"
-                    }
-                    Link {
-                        to: Route::Services {},
-                        class: "{services_class}",
-                        onclick: move |_| mobile_menu_open.set(false),
-                        "Services"
-                    }
-                    Link {
-                        to: Route::Portfolio {},
-                        class: "{portfolio_class}",
-                        onclick: move |_| mobile_menu_open.set(false),
-                        "Portfolio"
-                    }
-                    Link {
-                        to: Route::Articles {},
-                        class: "{articles_class}",
-                        onclick: move |_| mobile_menu_open.set(false),
-                        "Articles"
-                    }
-                    Link {
-                        to: Route::Contact {},
-                        class: "{contact_class}",
-                        onclick: move |_| mobile_menu_open.set(false),
-                        "Contact"
+                nav { id: "mobile-navigation", class: "nav-mobile", "aria-label": i18n.t("nav-label"),
+                    for (i, item) in items.iter().enumerate() {
+                        Link {
+                            key: "{item.id}",
+                            to: item.route.clone(),
+                            class: link_class(&item.id, &item.route),
+                            // Opening the menu moves focus into it
+                            onmounted: move |evt: MountedEvent| {
+                                if i == 0 {
+                                    spawn(async move {
+                                        let _ = evt.data().set_focus(true).await;
+                                    });
+                                }
+                            },
+                            onclick: move |_| mobile_menu_open.set(false),
+                            "{item.label}"
+                        }
                     }
                 }
             }
         }
     }
 }
+
+/// Links to the current page in each language, labelled in that language.
+/// A missing page has no counterpart, so it links to each home page.
+#[component]
+fn LocaleSwitcher(current: Route) -> Element {
+    let i18n = use_i18n();
+    let current = match current.unlocalized() {
+        Route::NotFound { .. } => Route::Home {},
+        route => route,
+    };
+
+    rsx! {
+        nav { class: "locale-switcher", "aria-label": i18n.t("language-label"),
+            for locale in Locale::ALL {
+                Link {
+                    key: "{locale}",
+                    to: current.localized(locale),
+                    class: if locale == i18n.locale { "locale-link active" } else { "locale-link" },
+                    lang: locale.code(),
+                    "hreflang": locale.code(),
+                    title: locale.native_name(),
+                    span { "aria-hidden": "true", "{locale.code().to_uppercase()}" }
+                    span { class: "sr-only", "{locale.native_name()}" }
+                }
+            }
+        }
+    }
+}
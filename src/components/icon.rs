@@ -1,73 +1,9 @@
+use crate::content::icons::custom_icon;
+pub use crate::content::icons::IconName;
 use dioxus::prelude::*;
 
-/// Icon names available in the system
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum IconName {
-    // Services
-    Brain,      // AI Consulting
-    Globe,      // Web Development
-    Smartphone, // Mobile App Development
-    Target,     // Product Management
-    Megaphone,  // Digital Marketing
-    Compass,    // Technical Strategy
-    Zap,        // Business Solutions
-
-    // Personas
-    Briefcase,     // Business Owner
-    Code,          // Tech Leader
-    Settings,      // Operations Manager
-    Rocket,        // Startup/New Business
-    TrendingUp,    // Growing Company
-    ShoppingCart,  // E-commerce
-    Lightbulb,     // Startup Founder
-    Building,      // Enterprise
-    Wrench,        // Service Business
-    Seedling,      // Small/Growing
-    Layers,        // Scaling Team
-    BarChart,      // Data-Driven
-    Calendar,      // Service Provider
-    Ticket,        // Membership
-    GraduationCap, // Non-Technical
-    Scale,         // Decision Maker
-    Handshake,     // Acquiring Company
-    RefreshCw,     // Operations/Refresh
-
-    // Why Choose Us
-    Users,         // Network
-    MessageCircle, // Honest Advice
-    Clock,         // Fast Response
-
-    // General
-    Check,        // Checkmark
-    ArrowRight,   // Arrow
-    ArrowLeft,    // Back arrow
-    Star,         // Featured
-    Award,        // First Responder
-    Plus,         // Add
-    Edit,         // Edit
-    Trash,        // Delete
-    Eye,          // View
-    EyeOff,       // Hide
-    Search,       // Search
-    Menu,         // Menu
-    X,            // Close
-    ChevronDown,  // Dropdown
-    ExternalLink, // External link
-    Github,       // GitHub
-    Mail,         // Email
-    Phone,        // Phone
-    MapPin,       // Location
-    Home,         // Home
-    FileText,     // Articles
-    Folder,       // Portfolio
-    User,         // About/Profile
-    LogOut,       // Logout
-    Lock,         // Password/Security
-    Palette,      // Theme
-    Save,         // Save
-    Download,     // Download/Export
-    Upload,       // Upload/Import
-}
+/// Drawn for names that are neither built in nor registered (a circled "?")
+const FALLBACK_ICON: &str = r#"<circle cx="12" cy="12" r="10"/><path d="M9.09 9a3 3 0 0 1 5.83 1c0 2-3 3-3 3"/><path d="M12 17h.01"/>"#;
 
 #[component]
 pub fn Icon(
@@ -77,11 +13,17 @@ pub fn Icon(
     #[props(default = 2.0)] stroke_width: f32,
     #[props(default = String::new())] class: String,
 ) -> Element {
-    let svg_content = get_icon_path(name);
+    let svg_content = match &name {
+        IconName::Custom(custom) => {
+            custom_icon(custom).unwrap_or_else(|| FALLBACK_ICON.to_string())
+        }
+        builtin => get_icon_path(builtin).to_string(),
+    };
+    let unknown = if name.is_known() { "" } else { " icon-unknown" };
 
     rsx! {
         svg {
-            class: "icon {class}",
+            class: "icon{unknown} {class}",
             width: "{size}",
             height: "{size}",
             view_box: "0 0 24 24",
@@ -95,71 +37,7 @@ pub fn Icon(
     }
 }
 
-/// Parse a string icon name to IconName enum
-pub fn parse_icon_name(name: &str) -> Option<IconName> {
-    match name.to_lowercase().as_str() {
-        "brain" => Some(IconName::Brain),
-        "globe" => Some(IconName::Globe),
-        "smartphone" => Some(IconName::Smartphone),
-        "target" => Some(IconName::Target),
-        "megaphone" => Some(IconName::Megaphone),
-        "compass" => Some(IconName::Compass),
-        "zap" => Some(IconName::Zap),
-        "briefcase" => Some(IconName::Briefcase),
-        "code" => Some(IconName::Code),
-        "settings" => Some(IconName::Settings),
-        "rocket" => Some(IconName::Rocket),
-        "trending-up" | "trendingup" => Some(IconName::TrendingUp),
-        "shopping-cart" | "shoppingcart" => Some(IconName::ShoppingCart),
-        "lightbulb" => Some(IconName::Lightbulb),
-        "building" => Some(IconName::Building),
-        "wrench" => Some(IconName::Wrench),
-        "seedling" => Some(IconName::Seedling),
-        "layers" => Some(IconName::Layers),
-        "bar-chart" | "barchart" => Some(IconName::BarChart),
-        "calendar" => Some(IconName::Calendar),
-        "ticket" => Some(IconName::Ticket),
-        "graduation-cap" | "graduationcap" => Some(IconName::GraduationCap),
-        "scale" => Some(IconName::Scale),
-        "handshake" => Some(IconName::Handshake),
-        "refresh-cw" | "refreshcw" => Some(IconName::RefreshCw),
-        "users" => Some(IconName::Users),
-        "message-circle" | "messagecircle" => Some(IconName::MessageCircle),
-        "clock" => Some(IconName::Clock),
-        "check" => Some(IconName::Check),
-        "arrow-right" | "arrowright" => Some(IconName::ArrowRight),
-        "arrow-left" | "arrowleft" => Some(IconName::ArrowLeft),
-        "star" => Some(IconName::Star),
-        "award" => Some(IconName::Award),
-        "plus" => Some(IconName::Plus),
-        "edit" => Some(IconName::Edit),
-        "trash" => Some(IconName::Trash),
-        "eye" => Some(IconName::Eye),
-        "eye-off" | "eyeoff" => Some(IconName::EyeOff),
-        "search" => Some(IconName::Search),
-        "menu" => Some(IconName::Menu),
-        "x" | "close" => Some(IconName::X),
-        "chevron-down" | "chevrondown" => Some(IconName::ChevronDown),
-        "external-link" | "externallink" => Some(IconName::ExternalLink),
-        "github" => Some(IconName::Github),
-        "mail" => Some(IconName::Mail),
-        "phone" => Some(IconName::Phone),
-        "map-pin" | "mappin" => Some(IconName::MapPin),
-        "home" => Some(IconName::Home),
-        "file-text" | "filetext" => Some(IconName::FileText),
-        "folder" => Some(IconName::Folder),
-        "user" => Some(IconName::User),
-        "log-out" | "logout" => Some(IconName::LogOut),
-        "lock" => Some(IconName::Lock),
-        "palette" => Some(IconName::Palette),
-        "save" => Some(IconName::Save),
-        "download" => Some(IconName::Download),
-        "upload" => Some(IconName::Upload),
-        _ => None,
-    }
-}
-
-fn get_icon_path(name: IconName) -> &'static str {
+fn get_icon_path(name: &IconName) -> &'static str {
     match name {
         // Services
         IconName::Brain => {
@@ -331,5 +209,6 @@ fn get_icon_path(name: IconName) -> &'static str {
         IconName::Upload => {
             r#"<path d="M21 15v4a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2v-4"/><polyline points="17 8 12 3 7 8"/><line x1="12" x2="12" y1="3" y2="15"/>"#
         }
+        IconName::Custom(_) => FALLBACK_ICON,
     }
 }
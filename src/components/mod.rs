@@ -1,13 +1,36 @@
+mod case_study;
 mod cta_section;
+mod focus;
 mod footer;
 mod header;
 mod icon;
+mod not_found;
+mod portfolio_card;
+mod redirect;
+mod responsive_image;
 mod service_card;
+mod share;
+mod subscribe_form;
+mod testimonials;
 mod theme_customizer;
 
+pub use crate::content::icons::{custom_icon_names, register_bundled_icons};
+pub use case_study::{BeforeAfterSlider, ProjectGallery, ProjectTimeline, ResultsGrid};
 pub use cta_section::CtaSection;
+pub use focus::{focus, FocusTarget, SkipLink, MAIN_CONTENT_ID};
 pub use footer::Footer;
 pub use header::Header;
-pub use icon::{parse_icon_name, Icon, IconName};
+pub use icon::{Icon, IconName};
+#[cfg(target_arch = "wasm32")]
+pub use not_found::install_panic_hook;
+pub use not_found::{NotFoundPanel, PageErrorBoundary};
+pub use portfolio_card::PortfolioCard;
+pub use redirect::Redirect;
+pub use responsive_image::ResponsiveImage;
 pub use service_card::ServiceCard;
+pub use share::{copy_to_clipboard, og_image_path, OgKind, ShareButtons, SocialMeta};
+#[cfg(test)]
+pub use share::{OG_IMAGE_HEIGHT, OG_IMAGE_WIDTH};
+pub use subscribe_form::SubscribeForm;
+pub use testimonials::{TestimonialCarousel, TestimonialList, TestimonialQuote};
 pub use theme_customizer::{ThemeCustomizer, ThemeToggleButton};
@@ -0,0 +1,171 @@
+use crate::i18n::{message, use_i18n, Locale};
+use crate::suggestions::suggest_for_path;
+use crate::Route;
+use dioxus::prelude::*;
+
+/// Shared not-found block with links to pages whose slug resembles `path`
+#[component]
+pub fn NotFoundPanel(
+    title: String,
+    message: String,
+    path: String,
+    back_to: Route,
+    back_label: String,
+) -> Element {
+    let i18n = use_i18n();
+    let suggestions = suggest_for_path(&path);
+
+    rsx! {
+        section { class: "hero hero-short not-found",
+            div { class: "hero-content",
+                h1 { class: "hero-title", "{title}" }
+                p { class: "hero-subtitle", "{message}" }
+
+                if !suggestions.is_empty() {
+                    div { class: "not-found-suggestions",
+                        p { class: "not-found-suggestions-label", {i18n.t("not-found-suggestions")} }
+                        ul {
+                            for suggestion in suggestions {
+                                li { key: "{suggestion.route}",
+                                    Link { to: i18n.route(suggestion.route.clone()), "{suggestion.label}" }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                Link { to: i18n.route(back_to), class: "btn btn-primary", "{back_label}" }
+            }
+        }
+    }
+}
+
+/// Friendly replacement for a page that failed to render
+#[component]
+pub fn ErrorFallback(on_retry: EventHandler<MouseEvent>) -> Element {
+    let i18n = use_i18n();
+    rsx! {
+        section { class: "hero hero-short",
+            div { class: "hero-content",
+                h1 { class: "hero-title", {i18n.t("error-title")} }
+                p { class: "hero-subtitle",
+                    {i18n.t("error-message")}
+                }
+                div { class: "not-found-actions",
+                    button {
+                        class: "btn btn-primary",
+                        onclick: move |evt| on_retry.call(evt),
+                        {i18n.t("error-retry")}
+                    }
+                    Link { to: i18n.route(Route::Home {}), class: "btn btn-secondary", {i18n.t("back-to-home")} }
+                }
+            }
+        }
+    }
+}
+
+/// Catches errors from `children`, e.g. content that fails to load, and
+/// shows `ErrorFallback` in their place
+#[component]
+pub fn PageErrorBoundary(children: Element) -> Element {
+    rsx! {
+        ErrorBoundary {
+            handle_error: |errors: ErrorContext| rsx! {
+                ErrorFallback { on_retry: move |_| errors.clear_errors() }
+            },
+            {children}
+        }
+    }
+}
+
+/// Page-wide notice for after a panic, when nothing can render any more
+#[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+struct CrashNotice {
+    title: String,
+    message: String,
+    reload: String,
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+impl CrashNotice {
+    fn new(locale: Locale) -> Self {
+        Self {
+            title: message(locale, "error-title", &[]),
+            message: message(locale, "error-crashed", &[]),
+            reload: message(locale, "error-reload", &[]),
+        }
+    }
+
+    /// Replace the page with the notice. It's built with DOM calls since the
+    /// app can't render any more; reloading is a link back to this page.
+    #[cfg(target_arch = "wasm32")]
+    fn show(&self, document: &web_sys::Document) -> Option<()> {
+        let element = |tag: &str, class: &str, text: &str| {
+            let element = document.create_element(tag).ok()?;
+            element.set_class_name(class);
+            element.set_text_content(Some(text));
+            Some(element)
+        };
+        let section = element("section", "hero hero-short crash-notice", "")?;
+        section.set_attribute("role", "alert").ok()?;
+        let content = element("div", "hero-content", "")?;
+        let reload = element("a", "btn btn-primary", &self.reload)?;
+        reload
+            .set_attribute("href", &document.location()?.href().ok()?)
+            .ok()?;
+        for child in [
+            element("h1", "hero-title", &self.title)?,
+            element("p", "hero-subtitle", &self.message)?,
+            reload,
+        ] {
+            content.append_child(&child).ok()?;
+        }
+        section.append_child(&content).ok()?;
+        document.body()?.replace_children_with_node_1(&section);
+        Some(())
+    }
+}
+
+/// A panic stops the app for good, so no `ErrorBoundary` gets to show its
+/// fallback and the page would just freeze. Replace it with a notice that
+/// offers a reload, in the page's language.
+///
+/// The dev server installs its own hook over this one for its error overlay.
+#[cfg(target_arch = "wasm32")]
+pub fn install_panic_hook() {
+    let previous = std::panic::take_hook();
+    // Looked up now, while the catalogs are known to be in a good state
+    let notices: Vec<(Locale, CrashNotice)> = Locale::ALL
+        .into_iter()
+        .map(|locale| (locale, CrashNotice::new(locale)))
+        .collect();
+    std::panic::set_hook(Box::new(move |info| {
+        previous(info);
+        let Some(document) = web_sys::window().and_then(|w| w.document()) else {
+            return;
+        };
+        let locale = document
+            .document_element()
+            .and_then(|html| html.get_attribute("lang"))
+            .and_then(|lang| lang.parse().ok())
+            .unwrap_or(Locale::DEFAULT);
+        if let Some((_, notice)) = notices.iter().find(|(l, _)| *l == locale) {
+            notice.show(&document);
+        }
+    }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crash_notice_offers_a_reload_in_each_language() {
+        for locale in Locale::ALL {
+            let notice = CrashNotice::new(locale);
+            assert_eq!(notice.reload, message(locale, "error-reload", &[]));
+            assert!(!notice.title.is_empty() && !notice.message.is_empty());
+        }
+        assert!(CrashNotice::new(Locale::Es).reload.contains("Recargar"));
+    }
+}
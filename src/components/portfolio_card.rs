@@ -0,0 +1,53 @@
+use super::ResponsiveImage;
+use crate::content::PortfolioProject;
+use crate::i18n::use_i18n;
+use crate::Route;
+use dioxus::prelude::*;
+
+/// Summary card linking to a project's case study
+#[component]
+pub fn PortfolioCard(project: PortfolioProject) -> Element {
+    let i18n = use_i18n();
+    rsx! {
+        Link {
+            to: i18n.route(Route::PortfolioDetail { slug: project.slug.clone() }),
+            class: "portfolio-card-link",
+
+            div { class: if project.featured { "portfolio-card glass-card featured" } else { "portfolio-card glass-card" },
+                if project.featured {
+                    span { class: "portfolio-featured-badge", {i18n.t("portfolio-featured")} }
+                }
+                div { class: "portfolio-header",
+                    if let Some(logo) = &project.logo {
+                        ResponsiveImage {
+                            class: "portfolio-card-logo",
+                            src: logo.clone(),
+                            alt: i18n.t_args("logo-alt", &[("name", &project.title)]),
+                            sizes: "180px",
+                            width: 180,
+                            height: 180
+                        }
+                    }
+                    div { class: "portfolio-header-text",
+                        h3 { class: "portfolio-title", "{project.title}" }
+                        span { class: "portfolio-type", "{project.project_type}" }
+                    }
+                }
+                p { class: "portfolio-description", "{project.description}" }
+                div { class: "portfolio-tech",
+                    for tag in &project.tech_tags {
+                        span { class: "tech-tag", "{tag}" }
+                    }
+                }
+                ul { class: "portfolio-scope",
+                    for item in &project.scope {
+                        li { "{item}" }
+                    }
+                }
+                span { class: "portfolio-link btn btn-primary",
+                    {i18n.t("view-case-study")}
+                }
+            }
+        }
+    }
+}
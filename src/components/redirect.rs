@@ -0,0 +1,14 @@
+use crate::Route;
+use dioxus::prelude::*;
+
+/// Replace the current history entry with `to`, e.g. for a renamed slug
+#[component]
+pub fn Redirect(to: Route) -> Element {
+    let navigator = use_navigator();
+
+    use_effect(move || {
+        navigator.replace(to.clone());
+    });
+
+    rsx! {}
+}
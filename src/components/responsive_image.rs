@@ -0,0 +1,67 @@
+use crate::content::media::{load_media, ImageFormat};
+use dioxus::prelude::*;
+
+/// Image from `assets/` that offers its AVIF/WebP variants through
+/// `<picture>` and takes its intrinsic size from the media manifest
+#[component]
+pub fn ResponsiveImage(
+    /// Path as content stores it, e.g. `assets/portfolio/logo.png`
+    src: String,
+    alt: String,
+    #[props(default)] class: String,
+    style: Option<String>,
+    /// How wide the image renders, so the browser can pick a variant
+    #[props(default = "100vw".to_string())]
+    sizes: String,
+    /// Overrides the manifest size, for images drawn into a fixed box
+    width: Option<u32>,
+    height: Option<u32>,
+    #[props(default = true)] lazy: bool,
+) -> Element {
+    let media = load_media();
+    let asset = media.find(&src);
+    let width = width.or(asset.and_then(|a| a.width)).map(|w| w.to_string());
+    let height = height
+        .or(asset.and_then(|a| a.height))
+        .map(|h| h.to_string());
+    let url = if src.starts_with("http://") || src.starts_with("https://") {
+        src.clone()
+    } else {
+        format!("/{}", src.trim_start_matches('/'))
+    };
+    let sources = asset
+        .map(|a| {
+            ImageFormat::PREFERRED
+                .iter()
+                .filter_map(|format| a.srcset(*format).map(|srcset| (format.mime(), srcset)))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    let loading = if lazy { "lazy" } else { "eager" };
+
+    let image = rsx! {
+        img {
+            class: "{class}",
+            style,
+            src: "{url}",
+            alt: "{alt}",
+            width,
+            height,
+            loading,
+            decoding: "async"
+        }
+    };
+
+    // Without variants a bare <img> keeps existing layouts untouched
+    if sources.is_empty() {
+        return image;
+    }
+    rsx! {
+        picture {
+            for (mime, srcset) in sources {
+                source { r#type: mime, "srcset": srcset, "sizes": "{sizes}" }
+            }
+            {image}
+        }
+    }
+}
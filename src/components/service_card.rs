@@ -1,14 +1,12 @@
-use super::icon::{parse_icon_name, Icon};
+use super::icon::{Icon, IconName};
 use dioxus::prelude::*;
 
 #[component]
-pub fn ServiceCard(title: String, description: String, icon: String) -> Element {
+pub fn ServiceCard(title: String, description: String, icon: IconName) -> Element {
     rsx! {
         div { class: "service-card",
             div { class: "service-icon",
-                if let Some(icon_name) = parse_icon_name(&icon) {
-                    Icon { name: icon_name, size: 40, color: "var(--color-secondary)".to_string() }
-                }
+                Icon { name: icon, size: 40, color: "var(--color-secondary)".to_string() }
             }
             h3 { class: "service-title", "{title}" }
             p { class: "service-description", "{description}" }
@@ -0,0 +1,217 @@
+use crate::content::theme_share::percent_encode;
+use crate::i18n::{use_i18n, Locale};
+use dioxus::prelude::*;
+
+/// Size of the generated preview images, the one Open Graph recommends
+pub const OG_IMAGE_WIDTH: u32 = 1200;
+pub const OG_IMAGE_HEIGHT: u32 = 630;
+
+/// Pages with a generated preview image
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OgKind {
+    Article,
+    CaseStudy,
+}
+
+impl OgKind {
+    fn dir(self) -> &'static str {
+        match self {
+            OgKind::Article => "articles",
+            OgKind::CaseStudy => "portfolio",
+        }
+    }
+}
+
+/// Where the preview image for a page lives, e.g.
+/// `/assets/og/en/articles/build-vs-buy-software.png`
+pub fn og_image_path(kind: OgKind, locale: Locale, slug: &str, extension: &str) -> String {
+    format!(
+        "/assets/og/{}/{}/{}.{}",
+        locale.code(),
+        kind.dir(),
+        slug,
+        extension
+    )
+}
+
+/// LinkedIn's share dialog for `url`; it reads the title and image from the
+/// page's meta tags
+fn linkedin_share_url(url: &str) -> String {
+    format!(
+        "https://www.linkedin.com/sharing/share-offsite/?url={}",
+        percent_encode(url)
+    )
+}
+
+/// A new email with `title` as the subject and the link in the body
+fn email_share_url(title: &str, text: &str, url: &str) -> String {
+    format!(
+        "mailto:?subject={}&body={}",
+        percent_encode(title),
+        percent_encode(&format!("{}\n\n{}", text, url))
+    )
+}
+
+/// Open Graph and Twitter card tags, so shared links unfurl with a title,
+/// summary and the page's preview image. `url` and `image` are absolute.
+#[component]
+pub fn SocialMeta(title: String, description: String, url: String, image: String) -> Element {
+    rsx! {
+        document::Meta { property: "og:type", content: "article" }
+        document::Meta { property: "og:title", content: "{title}" }
+        document::Meta { property: "og:description", content: "{description}" }
+        document::Meta { property: "og:url", content: "{url}" }
+        document::Meta { property: "og:image", content: "{image}" }
+        document::Meta { property: "og:image:width", content: "{OG_IMAGE_WIDTH}" }
+        document::Meta { property: "og:image:height", content: "{OG_IMAGE_HEIGHT}" }
+        document::Meta { property: "og:image:alt", content: "{title}" }
+        document::Meta { name: "twitter:card", content: "summary_large_image" }
+    }
+}
+
+/// Whether the browser has the Web Share API
+#[cfg(target_arch = "wasm32")]
+fn can_share() -> bool {
+    web_sys::window().is_some_and(|window| {
+        js_sys::Reflect::has(&window.navigator(), &"share".into()).unwrap_or(false)
+    })
+}
+
+/// Open the system share sheet for a page
+#[cfg(target_arch = "wasm32")]
+async fn share(title: &str, text: &str, url: &str) {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let data = web_sys::ShareData::new();
+    data.set_title(title);
+    data.set_text(text);
+    data.set_url(url);
+    // Cancelling the share sheet rejects the promise
+    let _ = wasm_bindgen_futures::JsFuture::from(window.navigator().share_with_data(&data)).await;
+}
+
+/// Put `text` on the clipboard, returning whether it worked. Browsers only
+/// offer the clipboard on secure pages and may refuse the write.
+pub async fn copy_to_clipboard(text: &str) -> bool {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let Some(window) = web_sys::window() else {
+            return false;
+        };
+        let clipboard = window.navigator().clipboard();
+        if clipboard.is_undefined() {
+            return false;
+        }
+        wasm_bindgen_futures::JsFuture::from(clipboard.write_text(text))
+            .await
+            .is_ok()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = text;
+        false
+    }
+}
+
+/// Copy link, LinkedIn and email buttons for the page at `url`. Where the
+/// browser has the Web Share API a Share button opens the system share
+/// sheet as well.
+#[component]
+pub fn ShareButtons(title: String, text: String, url: String) -> Element {
+    let i18n = use_i18n();
+    let mut copied = use_signal(|| false);
+    #[cfg_attr(not(target_arch = "wasm32"), allow(unused_mut))]
+    let mut can_share = use_signal(|| false);
+
+    use_effect(move || {
+        #[cfg(target_arch = "wasm32")]
+        can_share.set(self::can_share());
+    });
+
+    #[cfg_attr(not(target_arch = "wasm32"), allow(unused_variables))]
+    let share_data = (title.clone(), text.clone(), url.clone());
+    let copy_url = url.clone();
+
+    rsx! {
+        div { class: "article-share",
+            span { class: "share-label", id: "share-label", {i18n.t("article-share")} }
+            div { class: "share-buttons", role: "group", "aria-labelledby": "share-label",
+                if can_share() {
+                    button {
+                        class: "share-btn share-btn-native",
+                        r#type: "button",
+                        onclick: move |_| {
+                            #[cfg(target_arch = "wasm32")]
+                            {
+                                let (title, text, url) = share_data.clone();
+                                spawn(async move { share(&title, &text, &url).await });
+                            }
+                        },
+                        {i18n.t("share-native")}
+                    }
+                }
+                button {
+                    class: "share-btn",
+                    r#type: "button",
+                    onclick: move |_| {
+                        let url = copy_url.clone();
+                        spawn(async move { copied.set(copy_to_clipboard(&url).await) });
+                    },
+                    {i18n.t("article-copy-link")}
+                }
+                a {
+                    href: linkedin_share_url(&url),
+                    target: "_blank",
+                    rel: "noopener noreferrer",
+                    class: "share-btn",
+                    "LinkedIn"
+                }
+                a {
+                    href: email_share_url(&title, &text, &url),
+                    class: "share-btn",
+                    {i18n.t("share-email")}
+                }
+            }
+            span { class: "sr-only", role: "status",
+                if copied() { {i18n.t("article-link-copied")} }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_share_urls_are_encoded() {
+        let url = "https://poundsconsulting.net/articles/build-vs-buy?x=1&y=2";
+        assert_eq!(
+            linkedin_share_url(url),
+            "https://www.linkedin.com/sharing/share-offsite/?url=\
+             https%3A%2F%2Fpoundsconsulting.net%2Farticles%2Fbuild-vs-buy%3Fx%3D1%26y%3D2"
+        );
+
+        let email = email_share_url("Build & Buy?", "Worth a read", url);
+        assert!(email.starts_with("mailto:?subject=Build%20%26%20Buy%3F&body="));
+        assert!(email.contains("Worth%20a%20read%0A%0Ahttps%3A%2F%2F"));
+        assert_eq!(
+            email.matches('&').count(),
+            1,
+            "only the body separator is unescaped"
+        );
+    }
+
+    #[test]
+    fn test_og_image_paths() {
+        assert_eq!(
+            og_image_path(OgKind::Article, Locale::En, "build-vs-buy", "png"),
+            "/assets/og/en/articles/build-vs-buy.png"
+        );
+        assert_eq!(
+            og_image_path(OgKind::CaseStudy, Locale::Es, "acme", "svg"),
+            "/assets/og/es/portfolio/acme.svg"
+        );
+    }
+}
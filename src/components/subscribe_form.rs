@@ -0,0 +1,77 @@
+use crate::i18n::use_i18n;
+use crate::newsletter::{self, normalize_email, SubscriberStatus};
+use dioxus::prelude::*;
+
+/// Newsletter sign-up. Subscribers get a confirmation email and only
+/// receive digests once they follow its link. Without a newsletter backend
+/// installed it says sign-up is unavailable rather than promising an email. `id` keeps the label pointing
+/// at the right field when a page has more than one form.
+#[component]
+pub fn SubscribeForm(id: String) -> Element {
+    let i18n = use_i18n();
+    let mut email = use_signal(String::new);
+    // Outcome of the last attempt: a confirmation or an error to show
+    let mut status = use_signal(|| Option::<Result<String, String>>::None);
+
+    let handle_submit = move |evt: FormEvent| {
+        evt.prevent_default();
+        if !newsletter::is_configured() {
+            status.set(Some(Err(i18n.t("subscribe-unavailable"))));
+            return;
+        }
+        let Some(address) = normalize_email(&email()) else {
+            status.set(Some(Err(i18n.t("subscribe-invalid-email"))));
+            return;
+        };
+        status.set(Some(match newsletter::subscribe(&address, i18n.locale) {
+            Ok(subscriber) if subscriber.status == SubscriberStatus::Confirmed => {
+                Ok(i18n.t("subscribe-already"))
+            }
+            Ok(_) => {
+                email.set(String::new());
+                Ok(i18n.t_args("subscribe-check-inbox", &[("email", &address)]))
+            }
+            Err(_) => Err(i18n.t("subscribe-failed")),
+        }));
+    };
+
+    let input_id = format!("{}-email", id);
+    let status_id = format!("{}-status", id);
+
+    rsx! {
+        form { class: "subscribe-form", novalidate: true, onsubmit: handle_submit,
+            label { class: "sr-only", r#for: "{input_id}", {i18n.t("subscribe-email-label")} }
+            div { class: "subscribe-row",
+                input {
+                    id: "{input_id}",
+                    class: "form-input subscribe-input",
+                    r#type: "email",
+                    autocomplete: "email",
+                    placeholder: i18n.t("subscribe-placeholder"),
+                    "aria-describedby": "{status_id}",
+                    "aria-invalid": "{matches!(status(), Some(Err(_)))}",
+                    value: "{email}",
+                    oninput: move |evt| email.set(evt.value()),
+                }
+                button { class: "btn btn-primary subscribe-button", r#type: "submit",
+                    {i18n.t("subscribe-button")}
+                }
+            }
+            p {
+                id: "{status_id}",
+                class: match status() {
+                    Some(Err(_)) => "subscribe-status subscribe-error",
+                    _ => "subscribe-status",
+                },
+                role: "status",
+                "aria-live": "polite",
+                {
+                    match status() {
+                        Some(Ok(message)) | Some(Err(message)) => message,
+                        None => String::new(),
+                    }
+                }
+            }
+        }
+    }
+}
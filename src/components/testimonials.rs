@@ -0,0 +1,131 @@
+use super::ResponsiveImage;
+use crate::content::Testimonial;
+use crate::i18n::use_i18n;
+use dioxus::prelude::*;
+
+/// Time each quote stays up before the carousel advances
+const ROTATE_MS: u32 = 7000;
+
+/// Client quote with photo, author and role/company
+#[component]
+pub fn TestimonialQuote(testimonial: Testimonial) -> Element {
+    rsx! {
+        figure { class: "testimonial glass-card",
+            blockquote { class: "testimonial-quote", "“{testimonial.quote}”" }
+            figcaption { class: "testimonial-attribution",
+                if let Some(photo) = &testimonial.photo {
+                    ResponsiveImage {
+                        class: "testimonial-photo",
+                        src: photo.clone(),
+                        alt: "",
+                        sizes: "48px",
+                        width: 48,
+                        height: 48
+                    }
+                }
+                div { class: "testimonial-person",
+                    strong { "{testimonial.author}" }
+                    if let Some(attribution) = testimonial.attribution() {
+                        span { class: "testimonial-role", "{attribution}" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Stacked quotes for a service or case study
+#[component]
+pub fn TestimonialList(testimonials: Vec<Testimonial>) -> Element {
+    rsx! {
+        div { class: "testimonial-list",
+            for (index, testimonial) in testimonials.into_iter().enumerate() {
+                TestimonialQuote { key: "{index}", testimonial }
+            }
+        }
+    }
+}
+
+/// One quote at a time, advancing on a timer while nobody is hovering or
+/// focused on it. Using the controls stops the timer for good, so the quote
+/// someone picked stays put.
+///
+/// The slide is only announced to screen readers once it has stopped
+/// moving; announcing every automatic change would interrupt them.
+#[component]
+pub fn TestimonialCarousel(testimonials: Vec<Testimonial>) -> Element {
+    let i18n = use_i18n();
+    let mut current = use_signal(|| 0usize);
+    let mut paused = use_signal(|| false);
+    let mut stopped = use_signal(|| false);
+    let count = testimonials.len();
+    // The timer outlives this render, so it reads the length through a memo
+    // that follows the prop
+    let live_count = use_memo(use_reactive!(|count| count));
+
+    use_future(move || async move {
+        loop {
+            gloo_timers::future::TimeoutFuture::new(ROTATE_MS).await;
+            let count = live_count();
+            if !paused() && !stopped() && count > 1 {
+                current.set((current() + 1) % count);
+            }
+        }
+    });
+
+    let Some(testimonial) = testimonials.get(current() % count.max(1)).cloned() else {
+        return rsx! {};
+    };
+    let rotating = count > 1 && !paused() && !stopped();
+    let mut show = move |index: usize| {
+        stopped.set(true);
+        current.set(index);
+    };
+
+    rsx! {
+        div {
+            class: "testimonial-carousel",
+            role: "region",
+            aria_roledescription: "carousel",
+            aria_label: i18n.t("testimonials-carousel"),
+            onmouseenter: move |_| paused.set(true),
+            onmouseleave: move |_| paused.set(false),
+            onfocusin: move |_| paused.set(true),
+            onfocusout: move |_| paused.set(false),
+
+            div {
+                class: "testimonial-carousel-slide",
+                aria_live: if rotating { "off" } else { "polite" },
+                TestimonialQuote { key: "{current}", testimonial }
+            }
+
+            if count > 1 {
+                div { class: "testimonial-carousel-controls",
+                    button {
+                        class: "carousel-arrow",
+                        aria_label: i18n.t("testimonials-previous"),
+                        onclick: move |_| show((current() + count - 1) % count),
+                        "‹"
+                    }
+                    div { class: "carousel-dots",
+                        for index in 0..count {
+                            button {
+                                key: "{index}",
+                                class: if index == current() { "carousel-dot active" } else { "carousel-dot" },
+                                aria_label: i18n.t_args("testimonials-show", &[("number", &(index + 1).to_string())]),
+                                aria_current: if index == current() { "true" } else { "false" },
+                                onclick: move |_| show(index),
+                            }
+                        }
+                    }
+                    button {
+                        class: "carousel-arrow",
+                        aria_label: i18n.t("testimonials-next"),
+                        onclick: move |_| show((current() + 1) % count),
+                        "›"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,9 +1,26 @@
-use crate::content::{apply_theme_to_dom, load_theme, save_theme, ThemeConfig};
+use crate::components::{focus, FocusTarget};
+use crate::content::{
+    apply_theme_to_dom, clear_shared_theme, load_saved_themes, load_settings, load_theme,
+    load_theme_mode, save_saved_themes, save_theme, save_theme_mode, system_prefers_light,
+    system_theme, theme_share_link, Color, ThemeColor, ThemeConfig, ThemeMode,
+};
+use crate::i18n::{use_i18n, I18n};
 use dioxus::prelude::*;
 
 #[component]
 pub fn ThemeCustomizer(is_open: Signal<bool>) -> Element {
+    let i18n = use_i18n();
     let mut current_theme = use_signal(load_theme);
+    let mut theme_mode = use_signal(load_theme_mode);
+    let mut saved_themes = use_signal(load_saved_themes);
+    let mut theme_name = use_signal(String::new);
+    let mut export_text = use_signal(String::new);
+    let mut import_text = use_signal(String::new);
+    // Outcome of the last save/import: Ok(message) or Err(reason)
+    let mut io_status = use_signal(|| Option::<Result<String, String>>::None);
+    // First and last controls, where Tab wraps around inside the panel
+    let mut close_button: FocusTarget = use_signal(|| None);
+    let mut last_control: FocusTarget = use_signal(|| None);
 
     // Apply theme to DOM whenever it changes
     use_effect(move || {
@@ -11,35 +28,94 @@ pub fn ThemeCustomizer(is_open: Signal<bool>) -> Element {
     });
 
     let mut apply_preset = move |preset: ThemeConfig| {
+        clear_shared_theme();
         current_theme.set(preset.clone());
+        theme_mode.set(ThemeMode::Manual);
         save_theme(&preset);
         apply_theme_to_dom(&preset);
     };
 
-    let mut update_color = move |field: &'static str, value: String| {
+    let match_system = move |_| {
+        clear_shared_theme();
+        let theme = system_theme(system_prefers_light());
+        current_theme.set(theme.clone());
+        theme_mode.set(ThemeMode::System);
+        save_theme_mode(ThemeMode::System);
+        apply_theme_to_dom(&theme);
+    };
+
+    let mut update_color = move |field: ThemeColor, value: String| {
+        // Wait until a half-typed value parses
+        let Ok(value) = value.parse::<Color>() else {
+            return;
+        };
+        clear_shared_theme();
         current_theme.with_mut(|theme| {
-            match field {
-                "primary" => theme.primary = value,
-                "secondary" => theme.secondary = value,
-                "accent" => theme.accent = value,
-                "background" => theme.background = value,
-                "surface" => theme.surface = value,
-                "text_primary" => theme.text_primary = value,
-                "text_secondary" => theme.text_secondary = value,
-                "border" => theme.border = value,
-                _ => {}
-            }
+            theme.set_color(field, value);
             theme.name = "Custom".to_string();
         });
-        save_theme(&current_theme());
+        theme_mode.set(ThemeMode::Manual);
+        // Always preview, but only persist themes that stay readable
+        if current_theme().meets_aa() {
+            save_theme(&current_theme());
+        }
         apply_theme_to_dom(&current_theme());
     };
 
-    let reset_to_default = move |_| {
-        let default = ThemeConfig::default_gold();
-        current_theme.set(default.clone());
-        save_theme(&default);
-        apply_theme_to_dom(&default);
+    let reset_to_brand = move |_| {
+        clear_shared_theme();
+        let brand = load_settings().brand.theme;
+        current_theme.set(brand.clone());
+        theme_mode.set(ThemeMode::Brand);
+        save_theme_mode(ThemeMode::Brand);
+        apply_theme_to_dom(&brand);
+    };
+
+    let generate_palette = move |_| {
+        let theme = current_theme();
+        apply_preset(ThemeConfig::from_brand_color(
+            "Custom",
+            theme.secondary,
+            theme.is_dark(),
+        ));
+    };
+
+    let save_named = move |_| {
+        let theme = ThemeConfig {
+            name: theme_name().trim().to_string(),
+            ..current_theme()
+        };
+        let mut themes = saved_themes();
+        match themes.save(theme.clone()) {
+            Ok(()) => {
+                save_saved_themes(&themes);
+                saved_themes.set(themes);
+                apply_preset(theme.clone());
+                theme_name.set(String::new());
+                io_status.set(Some(Ok(
+                    i18n.t_args("theme-saved", &[("name", &theme.name)])
+                )));
+            }
+            Err(err) => io_status.set(Some(Err(err))),
+        }
+    };
+
+    let delete_current = move |_| {
+        let mut themes = saved_themes();
+        themes.remove(&current_theme().name);
+        save_saved_themes(&themes);
+        saved_themes.set(themes);
+    };
+
+    let import_theme = move |_| match ThemeConfig::import(&import_text()) {
+        Ok(theme) => {
+            io_status.set(Some(Ok(
+                i18n.t_args("theme-imported", &[("name", &theme.name)])
+            )));
+            import_text.set(String::new());
+            apply_preset(theme);
+        }
+        Err(err) => io_status.set(Some(Err(err))),
     };
 
     let close_panel = move |_| {
@@ -50,19 +126,55 @@ pub fn ThemeCustomizer(is_open: Signal<bool>) -> Element {
         return rsx! {};
     }
 
+    let following_system = theme_mode() == ThemeMode::System;
+    // Only highlight a preset the visitor picked themselves
+    let active_preset = if theme_mode() == ThemeMode::Manual {
+        current_theme().name.clone()
+    } else {
+        String::new()
+    };
+    let is_saved_theme = saved_themes().find(&current_theme().name).is_some();
+    let checks = current_theme().contrast_checks();
+    let readable = current_theme().meets_aa();
+
     rsx! {
         // Backdrop
         div {
             class: "theme-customizer-backdrop",
+            "aria-hidden": "true",
             onclick: close_panel
         }
 
         // Sidebar panel
-        div { class: "theme-customizer-panel",
+        div {
+            id: "theme-customizer",
+            class: "theme-customizer-panel",
+            role: "dialog",
+            "aria-modal": "true",
+            "aria-labelledby": "theme-customizer-title",
+            onkeydown: move |evt: KeyboardEvent| {
+                if evt.key() == Key::Escape {
+                    is_open.set(false);
+                }
+            },
+
+            // Tabbing past either end lands on a guard that wraps focus
+            // around, so it can't escape to the page behind the backdrop
+            div {
+                class: "focus-guard",
+                tabindex: "0",
+                onfocus: move |_| focus(last_control)
+            }
+
             div { class: "theme-customizer-header",
-                h3 { "Customize Theme" }
+                h3 { id: "theme-customizer-title", {i18n.t("theme-title")} }
                 button {
                     class: "theme-customizer-close",
+                    "aria-label": i18n.t("theme-close"),
+                    onmounted: move |evt: MountedEvent| {
+                        close_button.set(Some(evt.data()));
+                        focus(close_button);
+                    },
                     onclick: close_panel,
                     "×"
                 }
@@ -71,104 +183,248 @@ pub fn ThemeCustomizer(is_open: Signal<bool>) -> Element {
             div { class: "theme-customizer-content",
                 // Preset themes section
                 div { class: "theme-customizer-section",
-                    h4 { "Presets" }
-                    div { class: "theme-presets-grid",
-                        for preset in ThemeConfig::all_presets() {
-                            button {
-                                key: "{preset.name}",
-                                class: if current_theme().name == preset.name { "theme-preset-btn active" } else { "theme-preset-btn" },
-                                onclick: {
-                                    let preset = preset.clone();
-                                    move |_| apply_preset(preset.clone())
-                                },
-                                div {
-                                    class: "theme-preset-swatch",
-                                    style: "background: linear-gradient(135deg, {preset.background} 50%, {preset.secondary} 50%);"
-                                }
-                                span { "{preset.name}" }
-                            }
+                    h4 { {i18n.t("theme-presets")} }
+                    button {
+                        class: if following_system { "theme-preset-btn theme-system-btn active" } else { "theme-preset-btn theme-system-btn" },
+                        onclick: match_system,
+                        div {
+                            class: "theme-preset-swatch",
+                            style: "background: linear-gradient(135deg, #FAFAF7 50%, #1A1A1A 50%);"
+                        }
+                        span { {i18n.t("theme-match-system")} }
+                    }
+                    PresetGroup {
+                        title: i18n.t("theme-presets-dark"),
+                        presets: ThemeConfig::dark_presets(),
+                        active: active_preset.clone(),
+                        on_select: apply_preset
+                    }
+                    PresetGroup {
+                        title: i18n.t("theme-presets-light"),
+                        presets: ThemeConfig::light_presets(),
+                        active: active_preset.clone(),
+                        on_select: apply_preset
+                    }
+                    if !saved_themes().themes.is_empty() {
+                        PresetGroup {
+                            title: i18n.t("theme-presets-mine"),
+                            presets: saved_themes().themes,
+                            active: active_preset.clone(),
+                            on_select: apply_preset
                         }
                     }
                 }
 
                 // Custom colors section
                 div { class: "theme-customizer-section",
-                    h4 { "Custom Colors" }
+                    h4 { {i18n.t("theme-custom-colors")} }
                     div { class: "theme-color-inputs",
-                        ColorInput {
-                            label: "Primary",
-                            value: current_theme().primary.clone(),
-                            on_change: move |v| update_color("primary", v)
+                        for field in ThemeColor::ALL {
+                            ColorInput {
+                                key: "{field.key()}",
+                                id: "theme-color-{field.key()}",
+                                label: color_label(i18n, field.label()),
+                                value: current_theme().color(field).to_string(),
+                                on_change: move |v| update_color(field, v)
+                            }
                         }
-                        ColorInput {
-                            label: "Accent (Gold)",
-                            value: current_theme().secondary.clone(),
-                            on_change: move |v| update_color("secondary", v)
+                    }
+                    button {
+                        class: "btn btn-secondary theme-generate-btn",
+                        title: i18n.t("theme-generate-hint"),
+                        onclick: generate_palette,
+                        {i18n.t("theme-generate")}
+                    }
+                }
+
+                // Contrast section
+                div { class: "theme-customizer-section",
+                    h4 { {i18n.t("theme-contrast")} }
+                    ul { class: "theme-contrast-list",
+                        for check in checks {
+                            li {
+                                key: "{check.foreground}-{check.background}",
+                                class: "theme-contrast-item",
+                                span {
+                                    {i18n.t_args("theme-contrast-pair", &[
+                                        ("foreground", &color_label(i18n, check.foreground)),
+                                        ("background", &color_label(i18n, check.background)),
+                                    ])}
+                                }
+                                span { class: "theme-contrast-ratio",
+                                    "{check.ratio:.2}:1"
+                                }
+                                span {
+                                    class: if check.level().passes_aa() { "theme-contrast-badge pass" } else { "theme-contrast-badge fail" },
+                                    "{check.level().label()}"
+                                }
+                            }
                         }
-                        ColorInput {
-                            label: "Accent Hover",
-                            value: current_theme().accent.clone(),
-                            on_change: move |v| update_color("accent", v)
+                    }
+                    if !readable {
+                        p { class: "theme-contrast-warning",
+                            {i18n.t("theme-contrast-warning")}
+                        }
+                    }
+                }
+
+                // Save, share and import
+                div { class: "theme-customizer-section",
+                    h4 { {i18n.t("theme-save-share")} }
+                    div { class: "theme-save-row",
+                        input {
+                            class: "theme-name-input",
+                            r#type: "text",
+                            "aria-label": i18n.t("theme-name"),
+                            placeholder: i18n.t("theme-name"),
+                            maxlength: "40",
+                            value: "{theme_name}",
+                            oninput: move |evt| theme_name.set(evt.value())
+                        }
+                        button {
+                            class: "btn btn-secondary",
+                            disabled: theme_name().trim().is_empty(),
+                            onclick: save_named,
+                            {i18n.t("theme-save")}
                         }
-                        ColorInput {
-                            label: "Background",
-                            value: current_theme().background.clone(),
-                            on_change: move |v| update_color("background", v)
+                    }
+                    if is_saved_theme {
+                        button {
+                            class: "btn-link",
+                            onclick: delete_current,
+                            {i18n.t_args("theme-delete", &[("name", &current_theme().name)])}
                         }
-                        ColorInput {
-                            label: "Surface",
-                            value: current_theme().surface.clone(),
-                            on_change: move |v| update_color("surface", v)
+                    }
+
+                    div { class: "theme-export-buttons",
+                        button {
+                            class: "btn btn-secondary",
+                            onclick: move |_| export_text.set(current_theme().to_json()),
+                            "JSON"
                         }
-                        ColorInput {
-                            label: "Text Primary",
-                            value: current_theme().text_primary.clone(),
-                            on_change: move |v| update_color("text_primary", v)
+                        button {
+                            class: "btn btn-secondary",
+                            onclick: move |_| export_text.set(current_theme().to_css()),
+                            "CSS"
                         }
-                        ColorInput {
-                            label: "Text Secondary",
-                            value: current_theme().text_secondary.clone(),
-                            on_change: move |v| update_color("text_secondary", v)
+                        button {
+                            class: "btn btn-secondary",
+                            onclick: move |_| export_text.set(theme_share_link(&current_theme())),
+                            {i18n.t("theme-export-link")}
                         }
-                        ColorInput {
-                            label: "Border",
-                            value: current_theme().border.clone(),
-                            on_change: move |v| update_color("border", v)
+                    }
+                    if !export_text().is_empty() {
+                        textarea {
+                            class: "theme-io-text",
+                            "aria-label": i18n.t("theme-exported"),
+                            readonly: true,
+                            rows: "6",
+                            value: "{export_text}"
                         }
                     }
+
+                    textarea {
+                        class: "theme-io-text",
+                        "aria-label": i18n.t("theme-import-label"),
+                        rows: "4",
+                        placeholder: i18n.t("theme-import-placeholder"),
+                        value: "{import_text}",
+                        oninput: move |evt| import_text.set(evt.value())
+                    }
+                    button {
+                        class: "btn btn-secondary",
+                        disabled: import_text().trim().is_empty(),
+                        onclick: import_theme,
+                        {i18n.t("theme-import")}
+                    }
+
+                    match io_status() {
+                        Some(Ok(msg)) => rsx! { p { class: "theme-io-status", "{msg}" } },
+                        Some(Err(err)) => rsx! { p { class: "theme-contrast-warning", "{err}" } },
+                        None => rsx! {},
+                    }
                 }
 
                 // Actions
                 div { class: "theme-customizer-actions",
                     button {
                         class: "btn btn-secondary",
-                        onclick: reset_to_default,
-                        "Reset to Default"
+                        onmounted: move |evt: MountedEvent| last_control.set(Some(evt.data())),
+                        onclick: reset_to_brand,
+                        {i18n.t("theme-reset")}
                     }
                 }
 
                 // Current theme indicator
                 div { class: "theme-customizer-current",
-                    "Current: {current_theme().name}"
+                    if theme_mode() == ThemeMode::Brand {
+                        {i18n.t_args("theme-current-site", &[("name", &current_theme().name)])}
+                    } else {
+                        {i18n.t_args("theme-current", &[("name", &current_theme().name)])}
+                    }
                 }
             }
+
+            div {
+                class: "focus-guard",
+                tabindex: "0",
+                onfocus: move |_| focus(close_button)
+            }
         }
     }
 }
 
 #[component]
-fn ColorInput(label: String, value: String, on_change: EventHandler<String>) -> Element {
+fn PresetGroup(
+    title: String,
+    presets: Vec<ThemeConfig>,
+    active: String,
+    on_select: EventHandler<ThemeConfig>,
+) -> Element {
+    rsx! {
+        h5 { class: "theme-preset-group-title", "{title}" }
+        div { class: "theme-presets-grid",
+            for preset in presets {
+                button {
+                    key: "{preset.name}",
+                    class: if active == preset.name { "theme-preset-btn active" } else { "theme-preset-btn" },
+                    onclick: {
+                        let preset = preset.clone();
+                        move |_| on_select.call(preset.clone())
+                    },
+                    div {
+                        class: "theme-preset-swatch",
+                        style: "background: linear-gradient(135deg, {preset.background} 50%, {preset.secondary} 50%);"
+                    }
+                    span { "{preset.name}" }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ColorInput(
+    id: String,
+    label: String,
+    value: String,
+    on_change: EventHandler<String>,
+) -> Element {
+    let i18n = use_i18n();
+
     rsx! {
         div { class: "theme-color-input",
-            label { "{label}" }
+            label { r#for: "{id}", "{label}" }
             div { class: "theme-color-input-row",
                 input {
+                    id: "{id}",
                     r#type: "color",
                     value: "{value}",
                     oninput: move |evt| on_change.call(evt.value())
                 }
                 input {
                     r#type: "text",
+                    "aria-label": i18n.t_args("theme-color-value", &[("color", &label)]),
                     value: "{value}",
                     oninput: move |evt| on_change.call(evt.value()),
                     maxlength: "7",
@@ -179,15 +435,43 @@ fn ColorInput(label: String, value: String, on_change: EventHandler<String>) ->
     }
 }
 
+/// A theme color's name in the visitor's language, given its English label
+fn color_label(i18n: I18n, label: &str) -> String {
+    ThemeColor::ALL
+        .iter()
+        .find(|field| field.label() == label)
+        .map_or(label.to_string(), |field| {
+            i18n.t(&format!("theme-color-{}", field.key().replace('_', "-")))
+        })
+}
+
 /// Toggle button component for the footer
 #[component]
 pub fn ThemeToggleButton(is_open: Signal<bool>) -> Element {
+    let i18n = use_i18n();
+    let mut button: FocusTarget = use_signal(|| None);
+    let mut was_open = use_signal(|| false);
+
+    // However the panel closes, focus comes back to the button that opened it
+    use_effect(move || {
+        let open = is_open();
+        if *was_open.peek() && !open {
+            focus(button);
+        }
+        was_open.set(open);
+    });
+
     rsx! {
         button {
             class: "theme-toggle-btn",
-            title: "Customize Theme",
+            title: i18n.t("theme-title"),
+            "aria-label": i18n.t("theme-toggle"),
+            "aria-expanded": "{is_open()}",
+            "aria-controls": "theme-customizer",
+            onmounted: move |evt: MountedEvent| button.set(Some(evt.data())),
             onclick: move |_| is_open.set(!is_open()),
             svg {
+                "aria-hidden": "true",
                 xmlns: "http://www.w3.org/2000/svg",
                 width: "20",
                 height: "20",
@@ -0,0 +1,319 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// An opaque sRGB color.
+///
+/// Parses `#RGB`, `#RRGGBB`, `rgb()` and `hsl()` notation and always
+/// serializes back to uppercase `#RRGGBB`, so saved themes keep their format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const WHITE: Color = Color::hex(0xFFFFFF);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Build from a `0xRRGGBB` literal
+    pub const fn hex(value: u32) -> Self {
+        Self {
+            r: ((value >> 16) & 0xFF) as u8,
+            g: ((value >> 8) & 0xFF) as u8,
+            b: (value & 0xFF) as u8,
+        }
+    }
+
+    /// Build from hue in degrees and saturation/lightness from 0.0 to 1.0
+    pub fn from_hsl(h: f64, s: f64, l: f64) -> Self {
+        let h = h.rem_euclid(360.0) / 360.0;
+        let s = s.clamp(0.0, 1.0);
+        let l = l.clamp(0.0, 1.0);
+        if s == 0.0 {
+            let v = channel(l);
+            return Self::rgb(v, v, v);
+        }
+
+        let q = if l < 0.5 {
+            l * (1.0 + s)
+        } else {
+            l + s - l * s
+        };
+        let p = 2.0 * l - q;
+        let hue_to_rgb = |t: f64| {
+            let t = t.rem_euclid(1.0);
+            if t < 1.0 / 6.0 {
+                p + (q - p) * 6.0 * t
+            } else if t < 0.5 {
+                q
+            } else if t < 2.0 / 3.0 {
+                p + (q - p) * (2.0 / 3.0 - t) * 6.0
+            } else {
+                p
+            }
+        };
+        Self::rgb(
+            channel(hue_to_rgb(h + 1.0 / 3.0)),
+            channel(hue_to_rgb(h)),
+            channel(hue_to_rgb(h - 1.0 / 3.0)),
+        )
+    }
+
+    /// Hue in degrees and saturation/lightness from 0.0 to 1.0
+    pub fn to_hsl(self) -> (f64, f64, f64) {
+        let r = self.r as f64 / 255.0;
+        let g = self.g as f64 / 255.0;
+        let b = self.b as f64 / 255.0;
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.0;
+        if max == min {
+            return (0.0, 0.0, l);
+        }
+
+        let d = max - min;
+        let s = if l > 0.5 {
+            d / (2.0 - max - min)
+        } else {
+            d / (max + min)
+        };
+        let h = if max == r {
+            (g - b) / d + if g < b { 6.0 } else { 0.0 }
+        } else if max == g {
+            (b - r) / d + 2.0
+        } else {
+            (r - g) / d + 4.0
+        };
+        (h * 60.0, s, l)
+    }
+
+    /// WCAG relative luminance, from 0.0 to 1.0
+    pub fn luminance(self) -> f64 {
+        let linear = |channel: u8| {
+            let c = channel as f64 / 255.0;
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * linear(self.r) + 0.7152 * linear(self.g) + 0.0722 * linear(self.b)
+    }
+
+    /// WCAG contrast ratio against another color, from 1.0 to 21.0
+    pub fn contrast_ratio(self, other: Color) -> f64 {
+        let (a, b) = (self.luminance(), other.luminance());
+        let (lighter, darker) = if a > b { (a, b) } else { (b, a) };
+        (lighter + 0.05) / (darker + 0.05)
+    }
+
+    /// Raise HSL lightness by `amount` (0.0 to 1.0)
+    pub fn lighten(self, amount: f64) -> Self {
+        let (h, s, l) = self.to_hsl();
+        Self::from_hsl(h, s, l + amount)
+    }
+
+    /// Lower HSL lightness by `amount` (0.0 to 1.0)
+    pub fn darken(self, amount: f64) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Shade for hovering a control in this color: lighter on dark themes,
+    /// darker on light ones
+    pub fn hover_tint(self, dark_theme: bool) -> Self {
+        if dark_theme {
+            self.lighten(0.08)
+        } else {
+            self.darken(0.08)
+        }
+    }
+
+    /// Shade for a pressed control in this color
+    pub fn active_tint(self, dark_theme: bool) -> Self {
+        if dark_theme {
+            self.darken(0.06)
+        } else {
+            self.darken(0.14)
+        }
+    }
+}
+
+/// Round a 0.0-1.0 value to a color channel
+fn channel(value: f64) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02X}{:02X}{:02X}", self.r, self.g, self.b)
+    }
+}
+
+impl FromStr for Color {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        let lower = value.to_ascii_lowercase();
+        let parsed = if let Some(digits) = value.strip_prefix('#') {
+            parse_hex_digits(digits)
+        } else if let Some(args) = function_args(&lower, "rgb") {
+            parse_rgb(&args)
+        } else if let Some(args) = function_args(&lower, "hsl") {
+            parse_hsl(&args)
+        } else {
+            None
+        };
+        parsed.ok_or_else(|| format!("\"{}\" isn't a color (use #RRGGBB, rgb() or hsl())", value))
+    }
+}
+
+fn parse_hex_digits(digits: &str) -> Option<Color> {
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let expanded: String = match digits.len() {
+        3 => digits.chars().flat_map(|c| [c, c]).collect(),
+        6 => digits.to_string(),
+        _ => return None,
+    };
+    u32::from_str_radix(&expanded, 16).ok().map(Color::hex)
+}
+
+/// The comma- or space-separated arguments of `name(...)`
+fn function_args<'a>(value: &'a str, name: &str) -> Option<Vec<&'a str>> {
+    let inner = value.strip_prefix(name)?.trim_start();
+    let inner = inner.strip_prefix('(')?.strip_suffix(')')?;
+    Some(
+        inner
+            .split([',', ' '])
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .collect(),
+    )
+}
+
+/// `rgb(r, g, b)` with 0-255 or percentage channels
+fn parse_rgb(args: &[&str]) -> Option<Color> {
+    let [r, g, b] = args else {
+        return None;
+    };
+    let component = |s: &str| {
+        let value = match s.strip_suffix('%') {
+            Some(pct) => pct.parse::<f64>().ok()? / 100.0 * 255.0,
+            None => s.parse::<f64>().ok()?,
+        };
+        (0.0..=255.0).contains(&value).then(|| value.round() as u8)
+    };
+    Some(Color::rgb(component(r)?, component(g)?, component(b)?))
+}
+
+/// `hsl(h, s%, l%)` with the hue in degrees
+fn parse_hsl(args: &[&str]) -> Option<Color> {
+    let [h, s, l] = args else {
+        return None;
+    };
+    let hue = h.trim_end_matches("deg").parse::<f64>().ok()?;
+    let percent = |v: &str| {
+        let value = v.strip_suffix('%')?.parse::<f64>().ok()?;
+        (0.0..=100.0).contains(&value).then_some(value / 100.0)
+    };
+    Some(Color::from_hsl(hue, percent(s)?, percent(l)?))
+}
+
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hex() {
+        assert_eq!("#D4A017".parse(), Ok(Color::rgb(212, 160, 23)));
+        assert_eq!("#d4a017".parse(), Ok(Color::rgb(212, 160, 23)));
+        assert_eq!("#FFF".parse(), Ok(Color::WHITE));
+        assert!("FFFFFF".parse::<Color>().is_err());
+        assert!("#GGGGGG".parse::<Color>().is_err());
+        assert!("#FFFF".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn test_parse_rgb() {
+        assert_eq!("rgb(212, 160, 23)".parse(), Ok(Color::rgb(212, 160, 23)));
+        assert_eq!("RGB(212 160 23)".parse(), Ok(Color::rgb(212, 160, 23)));
+        assert_eq!("rgb(100%, 0%, 0%)".parse(), Ok(Color::rgb(255, 0, 0)));
+        assert!("rgb(256, 0, 0)".parse::<Color>().is_err());
+        assert!("rgb(1, 2)".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn test_parse_hsl() {
+        assert_eq!("hsl(0, 100%, 50%)".parse(), Ok(Color::rgb(255, 0, 0)));
+        assert_eq!("hsl(120deg 100% 25%)".parse(), Ok(Color::rgb(0, 128, 0)));
+        assert_eq!("hsl(0, 0%, 100%)".parse(), Ok(Color::WHITE));
+        assert!("hsl(0, 100, 50%)".parse::<Color>().is_err());
+        assert!("hsl(0, 120%, 50%)".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn test_display_is_uppercase_hex() {
+        assert_eq!(Color::rgb(212, 160, 23).to_string(), "#D4A017");
+        assert_eq!(Color::hex(0x0A0B0C).to_string(), "#0A0B0C");
+    }
+
+    #[test]
+    fn test_serde_uses_hex_strings() {
+        let json = serde_json::to_string(&Color::hex(0xD4A017)).unwrap();
+        assert_eq!(json, "\"#D4A017\"");
+
+        let parsed: Color = serde_json::from_str("\"rgb(212, 160, 23)\"").unwrap();
+        assert_eq!(parsed, Color::hex(0xD4A017));
+        assert!(serde_json::from_str::<Color>("\"nope\"").is_err());
+    }
+
+    #[test]
+    fn test_hsl_roundtrip() {
+        for color in [
+            Color::hex(0xD4A017),
+            Color::hex(0x2E8B57),
+            Color::hex(0x4A90D9),
+            Color::hex(0x808080),
+            Color::hex(0x000000),
+            Color::WHITE,
+        ] {
+            let (h, s, l) = color.to_hsl();
+            assert_eq!(Color::from_hsl(h, s, l), color);
+        }
+    }
+
+    #[test]
+    fn test_contrast_ratio() {
+        assert!((Color::hex(0x000000).contrast_ratio(Color::WHITE) - 21.0).abs() < 0.01);
+        assert!((Color::WHITE.contrast_ratio(Color::WHITE) - 1.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_tints() {
+        let gold = Color::hex(0xD4A017);
+        assert!(gold.hover_tint(true).luminance() > gold.luminance());
+        assert!(gold.hover_tint(false).luminance() < gold.luminance());
+        assert!(gold.active_tint(false).luminance() < gold.hover_tint(false).luminance());
+        assert_eq!(Color::WHITE.lighten(0.5), Color::WHITE);
+    }
+}
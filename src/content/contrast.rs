@@ -0,0 +1,151 @@
+use super::storage::ThemeConfig;
+
+/// Minimum contrast for normal body text under WCAG 2.1 AA
+pub const WCAG_AA: f64 = 4.5;
+/// Minimum contrast for normal body text under WCAG 2.1 AAA
+pub const WCAG_AAA: f64 = 7.0;
+/// Minimum contrast for large text (18pt+, or 14pt+ bold) under AA
+pub const WCAG_AA_LARGE: f64 = 3.0;
+
+/// WCAG conformance level reached by a color pair
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ContrastLevel {
+    Fail,
+    AaLarge,
+    Aa,
+    Aaa,
+}
+
+impl ContrastLevel {
+    pub fn from_ratio(ratio: f64) -> Self {
+        if ratio >= WCAG_AAA {
+            ContrastLevel::Aaa
+        } else if ratio >= WCAG_AA {
+            ContrastLevel::Aa
+        } else if ratio >= WCAG_AA_LARGE {
+            ContrastLevel::AaLarge
+        } else {
+            ContrastLevel::Fail
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ContrastLevel::Fail => "Fail",
+            ContrastLevel::AaLarge => "AA Large",
+            ContrastLevel::Aa => "AA",
+            ContrastLevel::Aaa => "AAA",
+        }
+    }
+
+    /// Whether body text at this level is readable enough to ship
+    pub fn passes_aa(&self) -> bool {
+        matches!(self, ContrastLevel::Aa | ContrastLevel::Aaa)
+    }
+}
+
+/// Contrast result for one text color against one background color
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContrastCheck {
+    pub foreground: &'static str,
+    pub background: &'static str,
+    pub ratio: f64,
+}
+
+impl ContrastCheck {
+    pub fn level(&self) -> ContrastLevel {
+        ContrastLevel::from_ratio(self.ratio)
+    }
+}
+
+impl ThemeConfig {
+    /// Whether the theme has a dark background
+    pub fn is_dark(&self) -> bool {
+        self.background.luminance() < 0.5
+    }
+
+    /// Contrast of both text colors against both background colors
+    pub fn contrast_checks(&self) -> Vec<ContrastCheck> {
+        let texts = [
+            ("Text Primary", self.text_primary),
+            ("Text Secondary", self.text_secondary),
+        ];
+        let backgrounds = [("Background", self.background), ("Surface", self.surface)];
+
+        let mut checks = Vec::new();
+        for (fg_name, fg) in texts {
+            for (bg_name, bg) in backgrounds {
+                checks.push(ContrastCheck {
+                    foreground: fg_name,
+                    background: bg_name,
+                    ratio: fg.contrast_ratio(bg),
+                });
+            }
+        }
+        checks
+    }
+
+    /// Whether every text/background pair meets WCAG AA
+    pub fn meets_aa(&self) -> bool {
+        self.contrast_checks().iter().all(|c| c.level().passes_aa())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::color::Color;
+
+    #[test]
+    fn test_contrast_ratio_known_value() {
+        // #767676 on white is the classic just-passing AA grey
+        let ratio = Color::hex(0x767676).contrast_ratio(Color::WHITE);
+        assert!((WCAG_AA..4.6).contains(&ratio));
+    }
+
+    #[test]
+    fn test_contrast_ratio_is_symmetric() {
+        let gold = Color::hex(0xD4A017);
+        let dark = Color::hex(0x1A1A1A);
+        assert_eq!(gold.contrast_ratio(dark), dark.contrast_ratio(gold));
+    }
+
+    #[test]
+    fn test_contrast_level_thresholds() {
+        assert_eq!(ContrastLevel::from_ratio(2.0), ContrastLevel::Fail);
+        assert_eq!(ContrastLevel::from_ratio(3.5), ContrastLevel::AaLarge);
+        assert_eq!(ContrastLevel::from_ratio(4.5), ContrastLevel::Aa);
+        assert_eq!(ContrastLevel::from_ratio(7.0), ContrastLevel::Aaa);
+    }
+
+    #[test]
+    fn test_all_presets_meet_wcag_aa() {
+        for preset in ThemeConfig::all_presets() {
+            for check in preset.contrast_checks() {
+                assert!(
+                    check.level().passes_aa(),
+                    "{} on {} in {} has contrast {:?}, below AA",
+                    check.foreground,
+                    check.background,
+                    preset.name,
+                    check.ratio
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_unreadable_theme_fails_aa() {
+        let theme = ThemeConfig {
+            text_primary: Color::hex(0x222222),
+            ..ThemeConfig::default_gold()
+        };
+        assert!(!theme.meets_aa());
+    }
+
+    #[test]
+    fn test_is_dark() {
+        assert!(ThemeConfig::default_gold().is_dark());
+        assert!(!ThemeConfig::light_gold().is_dark());
+    }
+}
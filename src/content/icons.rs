@@ -0,0 +1,352 @@
+//! Icon names stored in content, and the registry of custom icon SVGs.
+//!
+//! `components::Icon` draws them; this side only knows names, so content
+//! types can hold icons without depending on the UI.
+
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+/// Icon names available in the system
+///
+/// Content stores icons by name (`"trending-up"`), so this serializes as that
+/// name. Names that aren't built in load as `Custom` rather than failing,
+/// which keeps older content readable; validation reports them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum IconName {
+    // Services
+    Brain,      // AI Consulting
+    Globe,      // Web Development
+    Smartphone, // Mobile App Development
+    Target,     // Product Management
+    Megaphone,  // Digital Marketing
+    Compass,    // Technical Strategy
+    Zap,        // Business Solutions
+
+    // Personas
+    Briefcase,     // Business Owner
+    Code,          // Tech Leader
+    Settings,      // Operations Manager
+    Rocket,        // Startup/New Business
+    TrendingUp,    // Growing Company
+    ShoppingCart,  // E-commerce
+    Lightbulb,     // Startup Founder
+    Building,      // Enterprise
+    Wrench,        // Service Business
+    Seedling,      // Small/Growing
+    Layers,        // Scaling Team
+    BarChart,      // Data-Driven
+    Calendar,      // Service Provider
+    Ticket,        // Membership
+    GraduationCap, // Non-Technical
+    Scale,         // Decision Maker
+    Handshake,     // Acquiring Company
+    RefreshCw,     // Operations/Refresh
+
+    // Why Choose Us
+    Users,         // Network
+    MessageCircle, // Honest Advice
+    Clock,         // Fast Response
+
+    // General
+    Check,        // Checkmark
+    ArrowRight,   // Arrow
+    ArrowLeft,    // Back arrow
+    Star,         // Featured
+    Award,        // First Responder
+    Plus,         // Add
+    Edit,         // Edit
+    Trash,        // Delete
+    Eye,          // View
+    EyeOff,       // Hide
+    Search,       // Search
+    Menu,         // Menu
+    X,            // Close
+    ChevronDown,  // Dropdown
+    ExternalLink, // External link
+    Github,       // GitHub
+    Mail,         // Email
+    Phone,        // Phone
+    MapPin,       // Location
+    Home,         // Home
+    FileText,     // Articles
+    Folder,       // Portfolio
+    User,         // About/Profile
+    LogOut,       // Logout
+    Lock,         // Password/Security
+    Palette,      // Theme
+    Save,         // Save
+    Download,     // Download/Export
+    Upload,       // Upload/Import
+
+    /// Any other name: an SVG added with `register_icon`, or the fallback
+    /// icon until one is
+    Custom(String),
+}
+
+impl IconName {
+    /// Every built-in icon, in gallery order
+    pub const ALL: &'static [IconName] = &[
+        IconName::Brain,
+        IconName::Globe,
+        IconName::Smartphone,
+        IconName::Target,
+        IconName::Megaphone,
+        IconName::Compass,
+        IconName::Zap,
+        IconName::Briefcase,
+        IconName::Code,
+        IconName::Settings,
+        IconName::Rocket,
+        IconName::TrendingUp,
+        IconName::ShoppingCart,
+        IconName::Lightbulb,
+        IconName::Building,
+        IconName::Wrench,
+        IconName::Seedling,
+        IconName::Layers,
+        IconName::BarChart,
+        IconName::Calendar,
+        IconName::Ticket,
+        IconName::GraduationCap,
+        IconName::Scale,
+        IconName::Handshake,
+        IconName::RefreshCw,
+        IconName::Users,
+        IconName::MessageCircle,
+        IconName::Clock,
+        IconName::Check,
+        IconName::ArrowRight,
+        IconName::ArrowLeft,
+        IconName::Star,
+        IconName::Award,
+        IconName::Plus,
+        IconName::Edit,
+        IconName::Trash,
+        IconName::Eye,
+        IconName::EyeOff,
+        IconName::Search,
+        IconName::Menu,
+        IconName::X,
+        IconName::ChevronDown,
+        IconName::ExternalLink,
+        IconName::Github,
+        IconName::Mail,
+        IconName::Phone,
+        IconName::MapPin,
+        IconName::Home,
+        IconName::FileText,
+        IconName::Folder,
+        IconName::User,
+        IconName::LogOut,
+        IconName::Lock,
+        IconName::Palette,
+        IconName::Save,
+        IconName::Download,
+        IconName::Upload,
+    ];
+
+    /// Name content uses for this icon, e.g. `trending-up`
+    pub fn name(&self) -> &str {
+        match self {
+            IconName::Brain => "brain",
+            IconName::Globe => "globe",
+            IconName::Smartphone => "smartphone",
+            IconName::Target => "target",
+            IconName::Megaphone => "megaphone",
+            IconName::Compass => "compass",
+            IconName::Zap => "zap",
+            IconName::Briefcase => "briefcase",
+            IconName::Code => "code",
+            IconName::Settings => "settings",
+            IconName::Rocket => "rocket",
+            IconName::TrendingUp => "trending-up",
+            IconName::ShoppingCart => "shopping-cart",
+            IconName::Lightbulb => "lightbulb",
+            IconName::Building => "building",
+            IconName::Wrench => "wrench",
+            IconName::Seedling => "seedling",
+            IconName::Layers => "layers",
+            IconName::BarChart => "bar-chart",
+            IconName::Calendar => "calendar",
+            IconName::Ticket => "ticket",
+            IconName::GraduationCap => "graduation-cap",
+            IconName::Scale => "scale",
+            IconName::Handshake => "handshake",
+            IconName::RefreshCw => "refresh-cw",
+            IconName::Users => "users",
+            IconName::MessageCircle => "message-circle",
+            IconName::Clock => "clock",
+            IconName::Check => "check",
+            IconName::ArrowRight => "arrow-right",
+            IconName::ArrowLeft => "arrow-left",
+            IconName::Star => "star",
+            IconName::Award => "award",
+            IconName::Plus => "plus",
+            IconName::Edit => "edit",
+            IconName::Trash => "trash",
+            IconName::Eye => "eye",
+            IconName::EyeOff => "eye-off",
+            IconName::Search => "search",
+            IconName::Menu => "menu",
+            IconName::X => "x",
+            IconName::ChevronDown => "chevron-down",
+            IconName::ExternalLink => "external-link",
+            IconName::Github => "github",
+            IconName::Mail => "mail",
+            IconName::Phone => "phone",
+            IconName::MapPin => "map-pin",
+            IconName::Home => "home",
+            IconName::FileText => "file-text",
+            IconName::Folder => "folder",
+            IconName::User => "user",
+            IconName::LogOut => "log-out",
+            IconName::Lock => "lock",
+            IconName::Palette => "palette",
+            IconName::Save => "save",
+            IconName::Download => "download",
+            IconName::Upload => "upload",
+            IconName::Custom(name) => name,
+        }
+    }
+
+    /// Resolve a name from content. Case and hyphens don't matter for
+    /// built-in icons; anything else becomes `Custom`.
+    pub fn from_name(name: &str) -> IconName {
+        let name = name.trim().to_lowercase();
+        if name == "close" {
+            return IconName::X;
+        }
+        let squashed = name.replace('-', "");
+        IconName::ALL
+            .iter()
+            .find(|icon| icon.name().replace('-', "") == squashed)
+            .cloned()
+            .unwrap_or(IconName::Custom(name))
+    }
+
+    /// Built in, or a custom icon that has been registered
+    pub fn is_known(&self) -> bool {
+        match self {
+            IconName::Custom(name) => custom_icon(name).is_some(),
+            _ => true,
+        }
+    }
+}
+
+impl From<String> for IconName {
+    fn from(name: String) -> Self {
+        IconName::from_name(&name)
+    }
+}
+
+impl From<IconName> for String {
+    fn from(icon: IconName) -> Self {
+        icon.name().to_string()
+    }
+}
+
+impl std::fmt::Display for IconName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+thread_local! {
+    /// Markup for custom icons, by name
+    static CUSTOM_ICONS: RefCell<BTreeMap<String, String>> = const { RefCell::new(BTreeMap::new()) };
+}
+
+/// Make `svg` render for `IconName::Custom(name)`. Draw it like the
+/// built-ins: a 24×24 view box, stroked with `currentColor`. A wrapping
+/// `<svg>` element is dropped, since `Icon` supplies its own.
+pub fn register_icon(name: &str, svg: &str) {
+    let inner = svg
+        .find("<svg")
+        .and_then(|start| Some(start + svg[start..].find('>')? + 1))
+        .and_then(|start| Some(&svg[start..svg.rfind("</svg>")?]))
+        .unwrap_or(svg);
+    CUSTOM_ICONS.with(|icons| {
+        icons
+            .borrow_mut()
+            .insert(name.trim().to_lowercase(), inner.trim().to_string())
+    });
+}
+
+/// Register the SVGs in `assets/icons/`, which build.rs bundles by file name
+pub fn register_bundled_icons() {
+    let icons: BTreeMap<String, String> =
+        serde_json::from_str(include_str!(concat!(env!("OUT_DIR"), "/icons.json")))
+            .expect("build.rs emits icons as a name to SVG map");
+    for (name, svg) in icons {
+        register_icon(&name, &svg);
+    }
+}
+
+/// Names of every registered custom icon
+pub fn custom_icon_names() -> Vec<String> {
+    CUSTOM_ICONS.with(|icons| icons.borrow().keys().cloned().collect())
+}
+
+/// Markup registered for a custom icon
+pub(crate) fn custom_icon(name: &str) -> Option<String> {
+    CUSTOM_ICONS.with(|icons| icons.borrow().get(name).cloned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names_round_trip() {
+        for icon in IconName::ALL {
+            assert_eq!(&IconName::from_name(icon.name()), icon);
+        }
+        assert_eq!(IconName::from_name("TrendingUp"), IconName::TrendingUp);
+        assert_eq!(IconName::from_name("shoppingcart"), IconName::ShoppingCart);
+        assert_eq!(IconName::from_name("close"), IconName::X);
+        assert_eq!(
+            IconName::from_name("Brian"),
+            IconName::Custom("brian".to_string())
+        );
+    }
+
+    #[test]
+    fn test_serde_uses_names() {
+        let json = serde_json::to_string(&IconName::BarChart).unwrap();
+        assert_eq!(json, "\"bar-chart\"");
+        let icon: IconName = serde_json::from_str("\"refreshcw\"").unwrap();
+        assert_eq!(icon, IconName::RefreshCw);
+        // Unknown names still load, so older content doesn't break
+        let icon: IconName = serde_json::from_str("\"typo\"").unwrap();
+        assert_eq!(icon, IconName::Custom("typo".to_string()));
+        assert!(!icon.is_known());
+    }
+
+    #[test]
+    fn test_register_icon_strips_svg_wrapper() {
+        register_icon(
+            "Tractor",
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24"><circle r="2"/></svg>"#,
+        );
+        assert_eq!(
+            custom_icon("tractor").as_deref(),
+            Some(r#"<circle r="2"/>"#)
+        );
+        assert!(IconName::from_name("tractor").is_known());
+        assert!(custom_icon_names().contains(&"tractor".to_string()));
+
+        register_icon("bare", r#"<path d="M0 0"/>"#);
+        assert_eq!(custom_icon("bare").as_deref(), Some(r#"<path d="M0 0"/>"#));
+    }
+
+    #[test]
+    fn test_builtin_names_are_unique() {
+        let names = IconName::ALL
+            .iter()
+            .map(|icon| icon.name().replace('-', ""))
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(names.len(), IconName::ALL.len());
+    }
+}
@@ -0,0 +1,191 @@
+use serde::Deserialize;
+
+/// Images and videos shipped in `assets/`, as scanned by build.rs
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct MediaLibrary {
+    pub assets: Vec<MediaAsset>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct MediaAsset {
+    /// Relative to the site root, e.g. `assets/portfolio/logo.png`
+    pub path: String,
+    pub kind: MediaKind,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub bytes: u64,
+    /// Resized copies from `media.sh`, smallest first per format
+    #[serde(default)]
+    pub variants: Vec<MediaVariant>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MediaKind {
+    Image,
+    /// SVG; scales on its own, so it never has variants
+    Vector,
+    Video,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct MediaVariant {
+    pub path: String,
+    pub width: u32,
+    pub format: ImageFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageFormat {
+    Avif,
+    Webp,
+}
+
+impl ImageFormat {
+    /// Best compression first, the order `<source>` elements should go in
+    pub const PREFERRED: [ImageFormat; 2] = [ImageFormat::Avif, ImageFormat::Webp];
+
+    pub fn mime(self) -> &'static str {
+        match self {
+            ImageFormat::Avif => "image/avif",
+            ImageFormat::Webp => "image/webp",
+        }
+    }
+}
+
+impl Default for MediaLibrary {
+    fn default() -> Self {
+        serde_json::from_str(include_str!(concat!(env!("OUT_DIR"), "/media.json")))
+            .expect("build.rs emits the media manifest in this shape")
+    }
+}
+
+impl MediaLibrary {
+    /// Look up an asset by the path content stores, with or without a leading `/`
+    pub fn find(&self, path: &str) -> Option<&MediaAsset> {
+        let path = path.trim_start_matches('/');
+        self.assets.iter().find(|a| a.path == path)
+    }
+}
+
+impl MediaAsset {
+    /// `srcset` value for one format, e.g. `/assets/variants/a-480w.webp 480w, …`
+    pub fn srcset(&self, format: ImageFormat) -> Option<String> {
+        let entries = self
+            .variants
+            .iter()
+            .filter(|v| v.format == format)
+            .map(|v| format!("/{} {}w", v.path, v.width))
+            .collect::<Vec<_>>();
+        (!entries.is_empty()).then(|| entries.join(", "))
+    }
+
+    /// File name for pickers and lists
+    pub fn name(&self) -> &str {
+        self.path.rsplit('/').next().unwrap_or(&self.path)
+    }
+
+    /// Human readable file size
+    pub fn size_label(&self) -> String {
+        match self.bytes {
+            b if b >= 1024 * 1024 => format!("{:.1} MB", b as f64 / (1024.0 * 1024.0)),
+            b if b >= 1024 => format!("{} KB", b / 1024),
+            b => format!("{} B", b),
+        }
+    }
+}
+
+/// The media library compiled into this build
+pub fn load_media() -> MediaLibrary {
+    MediaLibrary::default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn asset(variants: Vec<MediaVariant>) -> MediaAsset {
+        MediaAsset {
+            path: "assets/portfolio/logo.png".to_string(),
+            kind: MediaKind::Image,
+            width: Some(1600),
+            height: Some(800),
+            bytes: 2048,
+            variants,
+        }
+    }
+
+    fn variant(width: u32, format: ImageFormat) -> MediaVariant {
+        let ext = match format {
+            ImageFormat::Avif => "avif",
+            ImageFormat::Webp => "webp",
+        };
+        MediaVariant {
+            path: format!("assets/variants/portfolio/logo-{}w.{}", width, ext),
+            width,
+            format,
+        }
+    }
+
+    #[test]
+    fn test_compiled_manifest_covers_content_media() {
+        let media = load_media();
+        assert!(!media.assets.is_empty());
+        for project in crate::content::PortfolioData::default().projects {
+            if let Some(logo) = project.logo {
+                assert!(media.find(&logo).is_some(), "{} is missing", logo);
+            }
+        }
+        let svg = media
+            .assets
+            .iter()
+            .find(|a| a.path.ends_with(".svg"))
+            .unwrap();
+        assert_eq!(svg.kind, MediaKind::Vector);
+        assert!(media
+            .assets
+            .iter()
+            .filter(|a| a.kind == MediaKind::Image)
+            .all(|a| a.width.is_some() && a.height.is_some()));
+    }
+
+    #[test]
+    fn test_find_ignores_leading_slash() {
+        let media = MediaLibrary {
+            assets: vec![asset(Vec::new())],
+        };
+        assert!(media.find("/assets/portfolio/logo.png").is_some());
+        assert!(media.find("assets/portfolio/logo.png").is_some());
+        assert!(media.find("assets/portfolio/other.png").is_none());
+    }
+
+    #[test]
+    fn test_srcset_per_format() {
+        let image = asset(vec![
+            variant(480, ImageFormat::Avif),
+            variant(480, ImageFormat::Webp),
+            variant(960, ImageFormat::Webp),
+        ]);
+        assert_eq!(
+            image.srcset(ImageFormat::Webp).unwrap(),
+            "/assets/variants/portfolio/logo-480w.webp 480w, /assets/variants/portfolio/logo-960w.webp 960w"
+        );
+        assert_eq!(
+            image.srcset(ImageFormat::Avif).unwrap(),
+            "/assets/variants/portfolio/logo-480w.avif 480w"
+        );
+        assert_eq!(asset(Vec::new()).srcset(ImageFormat::Webp), None);
+    }
+
+    #[test]
+    fn test_name_and_size_label() {
+        let mut image = asset(Vec::new());
+        assert_eq!(image.name(), "logo.png");
+        assert_eq!(image.size_label(), "2 KB");
+        image.bytes = 3 * 1024 * 1024 / 2;
+        assert_eq!(image.size_label(), "1.5 MB");
+        image.bytes = 12;
+        assert_eq!(image.size_label(), "12 B");
+    }
+}
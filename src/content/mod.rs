@@ -1,5 +1,17 @@
+pub mod color;
+pub mod contrast;
+pub mod icons;
+pub mod media;
+pub mod overrides;
+pub mod palette;
+pub mod redirects;
+pub mod related;
 pub mod storage;
+pub mod theme_share;
+pub mod translations;
 pub mod types;
+pub mod validation;
 
+pub use color::Color;
 pub use storage::*;
 pub use types::*;
@@ -0,0 +1,192 @@
+//! Admin edits to the content compiled in from `content/`.
+//!
+//! Only what admin changed is stored: edited and added items by id, the ids
+//! of compiled items deleted for good, and redirects recorded by renames.
+//! Loading lays them over whatever the current build compiled in, so
+//! content edited in `content/` after admin saved still shows through for
+//! everything admin didn't touch.
+
+use super::redirects::{SlugRedirects, Slugged};
+use serde::{Deserialize, Serialize};
+
+/// Content admin can edit: items addressed by id and slug, plus the
+/// redirects left by renamed slugs
+pub trait Collection: Default {
+    type Item: Slugged + Clone + PartialEq;
+
+    fn into_parts(self) -> (Vec<Self::Item>, SlugRedirects);
+    fn from_parts(items: Vec<Self::Item>, redirects: SlugRedirects) -> Self;
+}
+
+/// What admin changed relative to the compiled content
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(bound(deserialize = "T: Deserialize<'de>"))]
+pub struct ContentOverrides<T> {
+    /// Edited and added items; each replaces the compiled item with its id.
+    /// The aliases read copies saved before overrides, which held every item.
+    #[serde(
+        default,
+        alias = "articles",
+        alias = "services",
+        alias = "projects",
+        alias = "testimonials"
+    )]
+    pub items: Vec<T>,
+    /// Ids of compiled items deleted for good
+    #[serde(default)]
+    pub removed: Vec<String>,
+    /// Redirects recorded in admin, on top of the compiled ones
+    #[serde(default)]
+    pub redirects: SlugRedirects,
+}
+
+impl<T> Default for ContentOverrides<T> {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            removed: Vec::new(),
+            redirects: SlugRedirects::default(),
+        }
+    }
+}
+
+/// `compiled` with `overrides` laid over it
+pub fn merge<C: Collection>(compiled: C, overrides: ContentOverrides<C::Item>) -> C {
+    let (mut items, mut redirects) = compiled.into_parts();
+    items.retain(|item| !overrides.removed.iter().any(|id| id == item.id()));
+    for item in overrides.items {
+        match items.iter().position(|i| i.id() == item.id()) {
+            Some(pos) => items[pos] = item,
+            None => items.push(item),
+        }
+    }
+    for entry in &overrides.redirects.entries {
+        redirects.record(&entry.from, &entry.to);
+    }
+    // A slug that's live again no longer redirects, even if the build says so
+    for item in &items {
+        redirects.release(item.slug());
+    }
+    C::from_parts(items, redirects)
+}
+
+/// What `current` changes relative to `compiled`, for storing
+pub fn diff<C: Collection>(compiled: C, current: C) -> ContentOverrides<C::Item> {
+    let (compiled_items, compiled_redirects) = compiled.into_parts();
+    let (items, redirects) = current.into_parts();
+    let removed = compiled_items
+        .iter()
+        .filter(|c| !items.iter().any(|i| i.id() == c.id()))
+        .map(|c| c.id().to_string())
+        .collect();
+    let items = items
+        .into_iter()
+        .filter(|item| !compiled_items.contains(item))
+        .collect();
+    let entries = redirects
+        .entries
+        .into_iter()
+        .filter(|entry| !compiled_redirects.entries.contains(entry))
+        .collect();
+    ContentOverrides {
+        items,
+        removed,
+        redirects: SlugRedirects { entries },
+    }
+}
+
+/// The compiled version of `item`, when admin's copy differs from it.
+/// Items added in admin have no compiled version.
+pub fn deployed_version<'a, T: Slugged + PartialEq>(compiled: &'a [T], item: &T) -> Option<&'a T> {
+    compiled.iter().find(|c| c.id() == item.id() && *c != item)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::{Article, ArticleStatus, ArticlesData};
+
+    fn article(id: &str, slug: &str) -> Article {
+        Article {
+            id: id.to_string(),
+            slug: slug.to_string(),
+            title: id.to_string(),
+            status: ArticleStatus::Published,
+            ..Article::new()
+        }
+    }
+
+    fn compiled() -> ArticlesData {
+        let mut data = ArticlesData::from_parts(
+            vec![article("a", "first"), article("b", "second")],
+            SlugRedirects::default(),
+        );
+        data.redirects.record("old-first", "first");
+        data
+    }
+
+    #[test]
+    fn test_unchanged_content_stores_nothing() {
+        assert_eq!(diff(compiled(), compiled()), ContentOverrides::default());
+        assert_eq!(merge(compiled(), ContentOverrides::default()), compiled());
+    }
+
+    #[test]
+    fn test_edits_round_trip_through_overrides() {
+        let mut current = compiled();
+        current.upsert(article("a", "renamed"));
+        current.upsert(article("c", "added"));
+        current.articles.retain(|a| a.id != "b");
+
+        let overrides = diff(compiled(), current.clone());
+        assert_eq!(overrides.items.len(), 2);
+        assert_eq!(overrides.removed, vec!["b".to_string()]);
+        assert_eq!(merge(compiled(), overrides), current);
+    }
+
+    #[test]
+    fn test_later_compiled_edits_show_through() {
+        let mut edited = compiled();
+        edited.upsert(article("a", "renamed"));
+        let overrides = diff(compiled(), edited);
+
+        // A later build changes the article admin didn't touch and adds one
+        let mut rebuilt = compiled();
+        rebuilt.articles[1].title = "Second, revised".to_string();
+        rebuilt.articles.push(article("d", "fourth"));
+
+        let merged = merge(rebuilt, overrides);
+        let titles: Vec<&str> = merged.articles.iter().map(|a| a.title.as_str()).collect();
+        assert_eq!(titles, ["a", "Second, revised", "d"]);
+        assert_eq!(merged.redirects.resolve("first"), Some("renamed"));
+        assert_eq!(merged.redirects.resolve("old-first"), Some("renamed"));
+    }
+
+    #[test]
+    fn test_deployed_version_only_for_edited_compiled_items() {
+        let compiled = compiled();
+        let mut edited = article("a", "first");
+        assert_eq!(deployed_version(&compiled.articles, &edited), None);
+        edited.title = "Edited".to_string();
+        assert_eq!(
+            deployed_version(&compiled.articles, &edited),
+            Some(&compiled.articles[0])
+        );
+        assert_eq!(
+            deployed_version(&compiled.articles, &article("new", "new")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_copies_saved_before_overrides_still_load() {
+        let mut saved = compiled();
+        saved.articles[0].title = "Edited".to_string();
+        let json = serde_json::to_string(&saved).unwrap();
+
+        let overrides: ContentOverrides<Article> = serde_json::from_str(&json).unwrap();
+        let merged = merge(compiled(), overrides);
+        assert_eq!(merged.articles[0].title, "Edited");
+        assert_eq!(merged.articles.len(), 2);
+    }
+}
@@ -0,0 +1,150 @@
+use super::color::Color;
+use super::storage::{ThemeColor, ThemeConfig};
+
+impl ThemeConfig {
+    /// Derive a full theme from a single brand color.
+    ///
+    /// Background, surface, border and text shades share the brand's hue with
+    /// most of the saturation removed, so they read as neutral but still tinted.
+    /// The brand color itself becomes the accent, with its hover tint as the
+    /// secondary accent.
+    pub fn from_brand_color(name: &str, brand: Color, dark: bool) -> Self {
+        let (hue, saturation, _) = brand.to_hsl();
+        let neutral = |max_saturation: f64, lightness: f64| {
+            Color::from_hsl(hue, saturation.min(max_saturation), lightness)
+        };
+
+        let (background, surface, border, text_primary, text_secondary) = if dark {
+            (
+                neutral(0.15, 0.08),
+                neutral(0.15, 0.13),
+                neutral(0.12, 0.22),
+                neutral(0.10, 0.96),
+                neutral(0.08, 0.78),
+            )
+        } else {
+            (
+                neutral(0.20, 0.98),
+                Color::WHITE,
+                neutral(0.15, 0.87),
+                neutral(0.20, 0.10),
+                neutral(0.12, 0.32),
+            )
+        };
+
+        ThemeConfig {
+            name: name.to_string(),
+            primary: text_primary,
+            secondary: brand,
+            accent: brand.hover_tint(dark),
+            background,
+            surface,
+            text_primary,
+            text_secondary,
+            border,
+        }
+    }
+
+    /// Hover and pressed shades for the accent colors, as CSS variables
+    pub fn state_colors(&self) -> Vec<(String, Color)> {
+        let dark = self.is_dark();
+        [ThemeColor::Secondary, ThemeColor::Accent]
+            .into_iter()
+            .flat_map(|field| {
+                let color = self.color(field);
+                [
+                    (format!("{}-hover", field.css_var()), color.hover_tint(dark)),
+                    (
+                        format!("{}-active", field.css_var()),
+                        color.active_tint(dark),
+                    ),
+                ]
+            })
+            .collect()
+    }
+
+    /// Every CSS variable the theme sets: the eight colors plus state shades
+    pub fn css_vars(&self) -> Vec<(String, Color)> {
+        let mut vars: Vec<(String, Color)> = ThemeColor::ALL
+            .into_iter()
+            .map(|field| (field.css_var(), self.color(field)))
+            .collect();
+        vars.extend(self.state_colors());
+        vars
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Brand colors across the hue wheel plus a few awkward extremes
+    fn sample_brands() -> Vec<Color> {
+        let mut brands: Vec<Color> = (0..12)
+            .map(|i| Color::from_hsl(i as f64 * 30.0, 0.75, 0.5))
+            .collect();
+        brands.extend([
+            Color::hex(0xD4A017),
+            Color::hex(0xFFFF00),
+            Color::hex(0x0000FF),
+            Color::hex(0x000000),
+            Color::WHITE,
+        ]);
+        brands
+    }
+
+    #[test]
+    fn test_generated_palettes_meet_aa() {
+        for brand in sample_brands() {
+            for dark in [true, false] {
+                let theme = ThemeConfig::from_brand_color("Generated", brand, dark);
+                assert!(
+                    theme.meets_aa(),
+                    "{} ({}) fails AA: {:?}",
+                    brand,
+                    if dark { "dark" } else { "light" },
+                    theme.contrast_checks()
+                );
+                assert_eq!(theme.is_dark(), dark);
+            }
+        }
+    }
+
+    #[test]
+    fn test_generated_palette_keeps_brand_color() {
+        let gold = Color::hex(0xD4A017);
+        let theme = ThemeConfig::from_brand_color("Gold", gold, true);
+        assert_eq!(theme.secondary, gold);
+        assert_eq!(theme.name, "Gold");
+    }
+
+    #[test]
+    fn test_generated_neutrals_share_brand_hue() {
+        let blue = Color::hex(0x2563EB);
+        let theme = ThemeConfig::from_brand_color("Blue", blue, false);
+        let (brand_hue, _, _) = blue.to_hsl();
+        let (bg_hue, bg_saturation, _) = theme.border.to_hsl();
+        assert!((brand_hue - bg_hue).abs() < 10.0);
+        assert!(bg_saturation <= 0.16);
+    }
+
+    #[test]
+    fn test_css_vars_include_state_colors() {
+        let theme = ThemeConfig::default_gold();
+        let vars = theme.css_vars();
+        let names: Vec<&str> = vars.iter().map(|(name, _)| name.as_str()).collect();
+
+        assert_eq!(vars.len(), 12);
+        assert!(names.contains(&"--color-background"));
+        assert!(names.contains(&"--color-secondary-hover"));
+        assert!(names.contains(&"--color-accent-active"));
+
+        let hover = vars
+            .iter()
+            .find(|(name, _)| name == "--color-secondary-hover")
+            .unwrap()
+            .1;
+        // Dark theme: hovering brightens the accent
+        assert!(hover.luminance() > theme.secondary.luminance());
+    }
+}
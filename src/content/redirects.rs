@@ -0,0 +1,207 @@
+use serde::{Deserialize, Serialize};
+
+/// Maps slugs that used to exist to the slug content lives at now
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct SlugRedirects {
+    pub entries: Vec<SlugRedirect>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SlugRedirect {
+    pub from: String,
+    pub to: String,
+}
+
+impl SlugRedirects {
+    /// Record that content moved from `from` to `to`.
+    ///
+    /// Existing redirects pointing at `from` are repointed to `to`, so chains
+    /// never build up, and any redirect away from `to` is dropped because that
+    /// slug is live again.
+    pub fn record(&mut self, from: &str, to: &str) {
+        if from == to || from.is_empty() || to.is_empty() {
+            return;
+        }
+
+        for entry in self.entries.iter_mut() {
+            if entry.to == from {
+                entry.to = to.to_string();
+            }
+        }
+        self.entries
+            .retain(|e| e.from != to && e.from != from && e.from != e.to);
+        self.entries.push(SlugRedirect {
+            from: from.to_string(),
+            to: to.to_string(),
+        });
+    }
+
+    /// Drop any redirect away from `slug`, e.g. when new content claims it
+    pub fn release(&mut self, slug: &str) {
+        self.entries.retain(|e| e.from != slug);
+    }
+
+    /// Where an old slug points now, if it has moved
+    pub fn resolve(&self, slug: &str) -> Option<&str> {
+        let mut current = self.entries.iter().find(|e| e.from == slug)?.to.as_str();
+        // Bounded so a hand-edited cycle can't loop forever
+        for _ in 0..self.entries.len() {
+            match self.entries.iter().find(|e| e.from == current) {
+                Some(entry) => current = &entry.to,
+                None => break,
+            }
+        }
+        (current != slug).then_some(current)
+    }
+}
+
+/// Content addressed by a slug that keeps redirects when the slug changes
+pub trait Slugged {
+    fn id(&self) -> &str;
+    fn slug(&self) -> &str;
+    /// Only published content leaves a redirect behind when renamed
+    fn is_published(&self) -> bool;
+}
+
+/// Whether `slug` is free for the item with `id`.
+///
+/// Old slugs that redirect to another item are reserved so their inbound
+/// links keep working.
+pub fn slug_available<T: Slugged>(
+    items: &[T],
+    redirects: &SlugRedirects,
+    id: &str,
+    slug: &str,
+) -> bool {
+    if items.iter().any(|i| i.id() != id && i.slug() == slug) {
+        return false;
+    }
+    match redirects.resolve(slug) {
+        Some(target) => items.iter().any(|i| i.id() == id && i.slug() == target),
+        None => true,
+    }
+}
+
+/// Insert or replace an item by id, recording a redirect when a published
+/// item's slug changes.
+pub fn upsert_slugged<T: Slugged>(items: &mut Vec<T>, redirects: &mut SlugRedirects, item: T) {
+    match items.iter().position(|i| i.id() == item.id()) {
+        Some(pos) => {
+            let previous = &items[pos];
+            if previous.slug() != item.slug() && previous.is_published() {
+                let old_slug = previous.slug().to_string();
+                redirects.record(&old_slug, item.slug());
+            } else {
+                redirects.release(item.slug());
+            }
+            items[pos] = item;
+        }
+        None => {
+            redirects.release(item.slug());
+            items.push(item);
+        }
+    }
+}
+
+/// First of `base`, `base-2`, `base-3`, ... that `is_taken` rejects
+pub fn unique_slug(base: &str, is_taken: impl Fn(&str) -> bool) -> String {
+    if !is_taken(base) {
+        return base.to_string();
+    }
+    (2..)
+        .map(|n| format!("{}-{}", base, n))
+        .find(|candidate| !is_taken(candidate))
+        .unwrap_or_else(|| base.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_and_resolve() {
+        let mut redirects = SlugRedirects::default();
+        redirects.record("old-slug", "new-slug");
+
+        assert_eq!(redirects.resolve("old-slug"), Some("new-slug"));
+        assert_eq!(redirects.resolve("new-slug"), None);
+        assert_eq!(redirects.resolve("unrelated"), None);
+    }
+
+    #[test]
+    fn test_record_collapses_chains() {
+        let mut redirects = SlugRedirects::default();
+        redirects.record("a", "b");
+        redirects.record("b", "c");
+
+        assert_eq!(redirects.resolve("a"), Some("c"));
+        assert_eq!(redirects.resolve("b"), Some("c"));
+        assert!(redirects.entries.iter().all(|e| e.to == "c"));
+    }
+
+    #[test]
+    fn test_record_rename_back_drops_loop() {
+        let mut redirects = SlugRedirects::default();
+        redirects.record("a", "b");
+        redirects.record("b", "a");
+
+        assert_eq!(redirects.resolve("a"), None);
+        assert_eq!(redirects.resolve("b"), Some("a"));
+        assert_eq!(redirects.entries.len(), 1);
+    }
+
+    #[test]
+    fn test_record_ignores_noop() {
+        let mut redirects = SlugRedirects::default();
+        redirects.record("same", "same");
+        redirects.record("", "new");
+
+        assert!(redirects.entries.is_empty());
+    }
+
+    #[test]
+    fn test_release() {
+        let mut redirects = SlugRedirects::default();
+        redirects.record("old", "new");
+        redirects.release("old");
+
+        assert!(redirects.entries.is_empty());
+        assert_eq!(redirects.resolve("old"), None);
+    }
+
+    #[test]
+    fn test_resolve_survives_cycle() {
+        let redirects = SlugRedirects {
+            entries: vec![
+                SlugRedirect {
+                    from: "a".to_string(),
+                    to: "b".to_string(),
+                },
+                SlugRedirect {
+                    from: "b".to_string(),
+                    to: "a".to_string(),
+                },
+            ],
+        };
+
+        // Terminates rather than spinning
+        let _ = redirects.resolve("a");
+    }
+
+    #[test]
+    fn test_unique_slug() {
+        let taken = ["foo", "foo-2"];
+        assert_eq!(unique_slug("bar", |s| taken.contains(&s)), "bar");
+        assert_eq!(unique_slug("foo", |s| taken.contains(&s)), "foo-3");
+    }
+
+    #[test]
+    fn test_redirects_roundtrip() {
+        let mut redirects = SlugRedirects::default();
+        redirects.record("old", "new");
+
+        let json = serde_json::to_string(&redirects).unwrap();
+        let deserialized: SlugRedirects = serde_json::from_str(&json).unwrap();
+        assert_eq!(redirects, deserialized);
+    }
+}
@@ -0,0 +1,398 @@
+use super::types::{
+    tag_slug, Article, ArticleStatus, ArticlesData, PortfolioData, PortfolioProject, Service,
+    ServicesData,
+};
+use std::collections::HashSet;
+
+/// Links shown in each related-content section
+pub const RELATED_LIMIT: usize = 3;
+
+// Signal weights. Text similarity is a 0.0-1.0 ratio, so it's scaled up to
+// break ties between items that share the same tags or category.
+const TAG_WEIGHT: f64 = 3.0;
+const CATEGORY_WEIGHT: f64 = 2.0;
+const SERVICE_WEIGHT: f64 = 4.0;
+const TEXT_WEIGHT: f64 = 10.0;
+
+/// Words too common to say anything about what a page covers
+const STOP_WORDS: &[&str] = &[
+    "about", "after", "also", "and", "are", "but", "can", "for", "from", "has", "have", "how",
+    "into", "its", "just", "more", "not", "our", "out", "that", "the", "their", "them", "then",
+    "there", "they", "this", "was", "what", "when", "which", "who", "will", "with", "you", "your",
+];
+
+/// What a piece of content is about, reduced to comparable sets
+#[derive(Debug, Default)]
+struct Profile {
+    tags: HashSet<String>,
+    categories: HashSet<String>,
+    services: HashSet<String>,
+    words: HashSet<String>,
+}
+
+impl Profile {
+    fn score(&self, other: &Profile) -> f64 {
+        let shared = |a: &HashSet<String>, b: &HashSet<String>| a.intersection(b).count() as f64;
+        TAG_WEIGHT * shared(&self.tags, &other.tags)
+            + CATEGORY_WEIGHT * shared(&self.categories, &other.categories)
+            + SERVICE_WEIGHT * shared(&self.services, &other.services)
+            + TEXT_WEIGHT * jaccard(&self.words, &other.words)
+    }
+}
+
+fn service_profile(service: &Service) -> Profile {
+    let mut text = vec![
+        service.title.as_str(),
+        service.tagline.as_str(),
+        service.description.as_str(),
+    ];
+    text.extend(service.features.iter().map(String::as_str));
+    Profile {
+        services: HashSet::from([service.slug.clone()]),
+        words: words(&text),
+        ..Profile::default()
+    }
+}
+
+fn project_profile(project: &PortfolioProject) -> Profile {
+    let mut text = vec![
+        project.title.as_str(),
+        project.description.as_str(),
+        project.long_description.as_str(),
+    ];
+    text.extend(project.scope.iter().map(String::as_str));
+    text.extend(project.tech_tags.iter().map(String::as_str));
+    Profile {
+        tags: project.tech_tags.iter().map(|t| tag_slug(t)).collect(),
+        categories: HashSet::from([tag_slug(&project.project_type)]),
+        services: project.pinned_services.iter().cloned().collect(),
+        words: words(&text),
+    }
+}
+
+fn article_profile(article: &Article) -> Profile {
+    Profile {
+        categories: HashSet::from([tag_slug(&article.category)]),
+        services: linked_slugs(&article.content, "/services/"),
+        words: words(&[&article.title, &article.excerpt, &article.content]),
+        ..Profile::default()
+    }
+}
+
+/// Lowercase words of three or more letters, minus stop words
+fn words(texts: &[&str]) -> HashSet<String> {
+    texts
+        .iter()
+        .flat_map(|text| text.split(|c: char| !c.is_alphanumeric()))
+        .filter(|word| word.len() >= 3)
+        .map(str::to_lowercase)
+        .filter(|word| !STOP_WORDS.contains(&word.as_str()))
+        .collect()
+}
+
+/// Share of words two texts have in common, from 0.0 to 1.0
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f64 / union as f64
+}
+
+/// Slugs of internal links under `prefix` in the text, e.g. "/services/ai-consulting"
+fn linked_slugs(text: &str, prefix: &str) -> HashSet<String> {
+    text.match_indices(prefix)
+        .map(|(start, _)| {
+            text[start + prefix.len()..]
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric() || *c == '-')
+                .collect::<String>()
+        })
+        .filter(|slug| !slug.is_empty())
+        .collect()
+}
+
+/// Pinned items first, in pinned order, then the best-scoring candidates.
+///
+/// Unknown pins are skipped. Candidates that share nothing score zero and are
+/// left out, so a section can come back shorter than `limit` or empty.
+fn rank<'a, T>(
+    pinned: &[String],
+    candidates: impl Iterator<Item = &'a T>,
+    slug: impl Fn(&T) -> &str,
+    score: impl Fn(&T) -> f64,
+    limit: usize,
+) -> Vec<&'a T>
+where
+    T: 'a,
+{
+    let candidates: Vec<&T> = candidates.collect();
+    let mut picked: Vec<&T> = pinned
+        .iter()
+        .filter_map(|pin| candidates.iter().find(|c| slug(c) == pin).copied())
+        .take(limit)
+        .collect();
+
+    let mut scored: Vec<(f64, usize, &T)> = candidates
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| !picked.iter().any(|p| slug(p) == slug(c)))
+        .map(|(index, c)| (score(c), index, *c))
+        .filter(|(score, _, _)| *score > 0.0)
+        .collect();
+    // Highest score first; equal scores keep content order
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0).then(a.1.cmp(&b.1)));
+
+    let room = limit.saturating_sub(picked.len());
+    picked.extend(scored.into_iter().take(room).map(|(_, _, c)| c));
+    picked
+}
+
+/// Case studies to show on a service page
+pub fn related_projects<'a>(
+    service: &Service,
+    portfolio: &'a PortfolioData,
+    limit: usize,
+) -> Vec<&'a PortfolioProject> {
+    let profile = service_profile(service);
+    rank(
+        &service.pinned_projects,
+        portfolio.sorted().into_iter(),
+        |p| &p.slug,
+        |p| profile.score(&project_profile(p)),
+        limit,
+    )
+}
+
+/// Services a case study used
+pub fn related_services<'a>(
+    project: &PortfolioProject,
+    services: &'a ServicesData,
+    limit: usize,
+) -> Vec<&'a Service> {
+    let profile = project_profile(project);
+    rank(
+        &project.pinned_services,
+        services.services.iter(),
+        |s| &s.slug,
+        |s| profile.score(&service_profile(s)),
+        limit,
+    )
+}
+
+/// Other published articles to read next
+pub fn related_articles<'a>(
+    article: &Article,
+    articles: &'a ArticlesData,
+    limit: usize,
+) -> Vec<&'a Article> {
+    let profile = article_profile(article);
+    rank(
+        &article.pinned_articles,
+        articles
+            .articles
+            .iter()
+            .filter(|a| a.slug != article.slug && matches!(a.status, ArticleStatus::Published)),
+        |a| &a.slug,
+        |a| profile.score(&article_profile(a)),
+        limit,
+    )
+}
+
+/// The published articles just before and after `article` in its
+/// category, by date: the older one, then the newer one
+pub fn category_neighbors<'a>(
+    article: &Article,
+    articles: &'a ArticlesData,
+) -> (Option<&'a Article>, Option<&'a Article>) {
+    let mut same_category: Vec<&Article> = articles
+        .articles
+        .iter()
+        .filter(|a| a.category == article.category && matches!(a.status, ArticleStatus::Published))
+        .collect();
+    same_category.sort_by(|a, b| (&a.date, &a.slug).cmp(&(&b.date, &b.slug)));
+    let Some(i) = same_category.iter().position(|a| a.slug == article.slug) else {
+        return (None, None);
+    };
+    (
+        i.checked_sub(1).map(|before| same_category[before]),
+        same_category.get(i + 1).copied(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn article(slug: &str, category: &str, content: &str) -> Article {
+        Article {
+            slug: slug.to_string(),
+            title: slug.to_string(),
+            category: category.to_string(),
+            content: content.to_string(),
+            status: ArticleStatus::Published,
+            ..Article::new()
+        }
+    }
+
+    fn articles(list: Vec<Article>) -> ArticlesData {
+        ArticlesData {
+            articles: list,
+            redirects: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_words_drop_short_and_stop_words() {
+        let set = words(&["The CRM and an API for your Website"]);
+        assert!(set.contains("crm"));
+        assert!(set.contains("api"));
+        assert!(set.contains("website"));
+        assert!(!set.contains("the"));
+        assert!(!set.contains("an"));
+        assert!(!set.contains("your"));
+    }
+
+    #[test]
+    fn test_linked_slugs() {
+        let slugs = linked_slugs(
+            "See [AI](/services/ai-consulting) and /services/web-development.",
+            "/services/",
+        );
+        assert_eq!(
+            slugs,
+            HashSet::from(["ai-consulting".to_string(), "web-development".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_related_articles_prefer_shared_category_and_links() {
+        let current = article("current", "Automation", "See /services/business-solutions");
+        let data = articles(vec![
+            current.clone(),
+            article("unrelated", "Design", "colors typography"),
+            article("same-category", "Automation", "spreadsheets"),
+            article("same-link", "Design", "/services/business-solutions"),
+        ]);
+
+        let related: Vec<&str> = related_articles(&current, &data, 3)
+            .iter()
+            .map(|a| a.slug.as_str())
+            .collect();
+        assert_eq!(related, vec!["same-link", "same-category"]);
+    }
+
+    #[test]
+    fn test_related_articles_skip_self_and_drafts() {
+        let current = article("current", "Automation", "workflow");
+        let mut draft = article("draft", "Automation", "workflow");
+        draft.status = ArticleStatus::Draft;
+        let data = articles(vec![current.clone(), draft]);
+
+        assert!(related_articles(&current, &data, 3).is_empty());
+    }
+
+    #[test]
+    fn test_category_neighbors_by_date() {
+        let dated = |slug: &str, category: &str, date: &str| Article {
+            date: date.to_string(),
+            ..article(slug, category, "")
+        };
+        let mut draft = dated("draft", "Advice", "2026-01-15");
+        draft.status = ArticleStatus::Draft;
+        let data = articles(vec![
+            dated("newest", "Advice", "2026-03-01"),
+            dated("oldest", "Advice", "2026-01-01"),
+            dated("other-category", "Strategy", "2026-01-20"),
+            draft,
+            dated("middle", "Advice", "2026-02-01"),
+        ]);
+        let slugs = |slug: &str| {
+            let current = data.articles.iter().find(|a| a.slug == slug).unwrap();
+            let (before, after) = category_neighbors(current, &data);
+            (
+                before.map(|a| a.slug.as_str()),
+                after.map(|a| a.slug.as_str()),
+            )
+        };
+
+        assert_eq!(slugs("middle"), (Some("oldest"), Some("newest")));
+        assert_eq!(slugs("oldest"), (None, Some("middle")));
+        assert_eq!(slugs("newest"), (Some("middle"), None));
+        assert_eq!(slugs("other-category"), (None, None));
+        // A draft isn't listed, so it has no place in the order
+        assert_eq!(slugs("draft"), (None, None));
+    }
+
+    #[test]
+    fn test_pins_come_first_and_unknown_pins_are_skipped() {
+        let mut current = article("current", "Automation", "workflow");
+        current.pinned_articles = vec!["missing".to_string(), "pinned".to_string()];
+        let data = articles(vec![
+            current.clone(),
+            article("scored", "Automation", "workflow"),
+            article("pinned", "Design", "nothing shared"),
+        ]);
+
+        let related: Vec<&str> = related_articles(&current, &data, 3)
+            .iter()
+            .map(|a| a.slug.as_str())
+            .collect();
+        assert_eq!(related, vec!["pinned", "scored"]);
+    }
+
+    #[test]
+    fn test_pinned_services_lead_services_used() {
+        let services = ServicesData::default();
+        let portfolio = PortfolioData::default();
+        let mut project = portfolio.projects[0].clone();
+        project.pinned_services = vec!["product-management".to_string()];
+
+        let used = related_services(&project, &services, RELATED_LIMIT);
+        assert_eq!(used[0].slug, "product-management");
+        assert!(used.len() <= RELATED_LIMIT);
+    }
+
+    #[test]
+    fn test_every_default_page_has_related_content() {
+        let services = ServicesData::default();
+        let portfolio = PortfolioData::default();
+        let articles = ArticlesData::default();
+
+        for service in &services.services {
+            assert!(
+                !related_projects(service, &portfolio, RELATED_LIMIT).is_empty(),
+                "no case studies for {}",
+                service.slug
+            );
+        }
+        for project in &portfolio.projects {
+            assert!(
+                !related_services(project, &services, RELATED_LIMIT).is_empty(),
+                "no services for {}",
+                project.slug
+            );
+        }
+        for article in &articles.articles {
+            assert!(
+                !related_articles(article, &articles, RELATED_LIMIT).is_empty(),
+                "no related reading for {}",
+                article.slug
+            );
+        }
+    }
+
+    #[test]
+    fn test_pinning_a_project_links_it_to_the_service() {
+        let mut portfolio = PortfolioData::default();
+        let services = ServicesData::default();
+        let service = &services.services[0];
+        let last = portfolio.projects.len() - 1;
+        portfolio.projects[last].pinned_services = vec![service.slug.clone()];
+        let project_slug = portfolio.projects[last].slug.clone();
+
+        // The shared service slug outweighs text overlap alone
+        let related = related_projects(service, &portfolio, RELATED_LIMIT);
+        assert_eq!(related[0].slug, project_slug);
+    }
+}
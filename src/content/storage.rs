@@ -1,27 +1,34 @@
-use super::types::{ArticlesData, PortfolioData, ServicesData, SiteSettings};
+use super::color::Color;
+use super::overrides;
+use super::theme_share::SavedThemes;
+use super::types::{ArticlesData, PortfolioData, ServicesData, SiteSettings, TestimonialsData};
 use serde::{Deserialize, Serialize};
 use web_sys::wasm_bindgen::JsCast;
-use web_sys::window;
 
 const SETTINGS_KEY: &str = "site_settings";
 const ARTICLES_KEY: &str = "site_articles";
+const TESTIMONIALS_KEY: &str = "site_testimonials";
+const SERVICES_KEY: &str = "site_services";
+const PORTFOLIO_KEY: &str = "site_portfolio";
 const AUTH_KEY: &str = "admin_auth";
 const ARTICLES_VERSION_KEY: &str = "articles_version";
-const CURRENT_ARTICLES_VERSION: &str = "v3"; // Increment this to force refresh
+const CURRENT_ARTICLES_VERSION: &str = "v4"; // Increment this to force refresh
 const THEME_KEY: &str = "site_theme";
+const THEME_MODE_KEY: &str = "site_theme_mode";
+const SAVED_THEMES_KEY: &str = "site_saved_themes";
 
 /// Theme configuration with all 8 CSS color variables
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ThemeConfig {
     pub name: String,
-    pub primary: String,
-    pub secondary: String,
-    pub accent: String,
-    pub background: String,
-    pub surface: String,
-    pub text_primary: String,
-    pub text_secondary: String,
-    pub border: String,
+    pub primary: Color,
+    pub secondary: Color,
+    pub accent: Color,
+    pub background: Color,
+    pub surface: Color,
+    pub text_primary: Color,
+    pub text_secondary: Color,
+    pub border: Color,
 }
 
 impl Default for ThemeConfig {
@@ -30,92 +37,219 @@ impl Default for ThemeConfig {
     }
 }
 
+/// One of the eight editable colors in a `ThemeConfig`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThemeColor {
+    Primary,
+    Secondary,
+    Accent,
+    Background,
+    Surface,
+    TextPrimary,
+    TextSecondary,
+    Border,
+}
+
+impl ThemeColor {
+    /// Every theme color, in display order
+    pub const ALL: [ThemeColor; 8] = [
+        ThemeColor::Primary,
+        ThemeColor::Secondary,
+        ThemeColor::Accent,
+        ThemeColor::Background,
+        ThemeColor::Surface,
+        ThemeColor::TextPrimary,
+        ThemeColor::TextSecondary,
+        ThemeColor::Border,
+    ];
+
+    /// Stable identifier, matching the `ThemeConfig` field name
+    pub fn key(&self) -> &'static str {
+        match self {
+            ThemeColor::Primary => "primary",
+            ThemeColor::Secondary => "secondary",
+            ThemeColor::Accent => "accent",
+            ThemeColor::Background => "background",
+            ThemeColor::Surface => "surface",
+            ThemeColor::TextPrimary => "text_primary",
+            ThemeColor::TextSecondary => "text_secondary",
+            ThemeColor::Border => "border",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ThemeColor::Primary => "Primary",
+            ThemeColor::Secondary => "Accent",
+            ThemeColor::Accent => "Accent Hover",
+            ThemeColor::Background => "Background",
+            ThemeColor::Surface => "Surface",
+            ThemeColor::TextPrimary => "Text Primary",
+            ThemeColor::TextSecondary => "Text Secondary",
+            ThemeColor::Border => "Border",
+        }
+    }
+
+    /// CSS custom property, e.g. `--color-text-primary`
+    pub fn css_var(&self) -> String {
+        format!("--color-{}", self.key().replace('_', "-"))
+    }
+}
+
 impl ThemeConfig {
+    pub fn color(&self, field: ThemeColor) -> Color {
+        match field {
+            ThemeColor::Primary => self.primary,
+            ThemeColor::Secondary => self.secondary,
+            ThemeColor::Accent => self.accent,
+            ThemeColor::Background => self.background,
+            ThemeColor::Surface => self.surface,
+            ThemeColor::TextPrimary => self.text_primary,
+            ThemeColor::TextSecondary => self.text_secondary,
+            ThemeColor::Border => self.border,
+        }
+    }
+
+    pub fn set_color(&mut self, field: ThemeColor, value: Color) {
+        match field {
+            ThemeColor::Primary => self.primary = value,
+            ThemeColor::Secondary => self.secondary = value,
+            ThemeColor::Accent => self.accent = value,
+            ThemeColor::Background => self.background = value,
+            ThemeColor::Surface => self.surface = value,
+            ThemeColor::TextPrimary => self.text_primary = value,
+            ThemeColor::TextSecondary => self.text_secondary = value,
+            ThemeColor::Border => self.border = value,
+        }
+    }
+
     pub fn default_gold() -> Self {
         Self {
             name: "Default Gold".to_string(),
-            primary: "#FAFAFA".to_string(),
-            secondary: "#D4A017".to_string(),
-            accent: "#E6B422".to_string(),
-            background: "#1A1A1A".to_string(),
-            surface: "#2A2A2A".to_string(),
-            text_primary: "#FAFAFA".to_string(),
-            text_secondary: "#CCCCCC".to_string(),
-            border: "#3A3A3A".to_string(),
+            primary: Color::hex(0xFAFAFA),
+            secondary: Color::hex(0xD4A017),
+            accent: Color::hex(0xE6B422),
+            background: Color::hex(0x1A1A1A),
+            surface: Color::hex(0x2A2A2A),
+            text_primary: Color::hex(0xFAFAFA),
+            text_secondary: Color::hex(0xCCCCCC),
+            border: Color::hex(0x3A3A3A),
         }
     }
 
     pub fn metallic_dark() -> Self {
         Self {
             name: "Metallic Dark".to_string(),
-            primary: "#F5F5F0".to_string(),
-            secondary: "#B8860B".to_string(),
-            accent: "#CD950C".to_string(),
-            background: "#0D0D0D".to_string(),
-            surface: "#1A1A1A".to_string(),
-            text_primary: "#F5F5F0".to_string(),
-            text_secondary: "#E8E8E0".to_string(),
-            border: "#2A2A2A".to_string(),
+            primary: Color::hex(0xF5F5F0),
+            secondary: Color::hex(0xB8860B),
+            accent: Color::hex(0xCD950C),
+            background: Color::hex(0x0D0D0D),
+            surface: Color::hex(0x1A1A1A),
+            text_primary: Color::hex(0xF5F5F0),
+            text_secondary: Color::hex(0xE8E8E0),
+            border: Color::hex(0x2A2A2A),
         }
     }
 
     pub fn blue_steel() -> Self {
         Self {
             name: "Blue Steel".to_string(),
-            primary: "#E8E8F0".to_string(),
-            secondary: "#4A90D9".to_string(),
-            accent: "#5BA0E9".to_string(),
-            background: "#0A0A12".to_string(),
-            surface: "#12121A".to_string(),
-            text_primary: "#E8E8F0".to_string(),
-            text_secondary: "#B8B8C8".to_string(),
-            border: "#2A2A3A".to_string(),
+            primary: Color::hex(0xE8E8F0),
+            secondary: Color::hex(0x4A90D9),
+            accent: Color::hex(0x5BA0E9),
+            background: Color::hex(0x0A0A12),
+            surface: Color::hex(0x12121A),
+            text_primary: Color::hex(0xE8E8F0),
+            text_secondary: Color::hex(0xB8B8C8),
+            border: Color::hex(0x2A2A3A),
         }
     }
 
     pub fn emerald() -> Self {
         Self {
             name: "Emerald".to_string(),
-            primary: "#E8F0E8".to_string(),
-            secondary: "#2E8B57".to_string(),
-            accent: "#3CB371".to_string(),
-            background: "#0A120A".to_string(),
-            surface: "#121A12".to_string(),
-            text_primary: "#E8F0E8".to_string(),
-            text_secondary: "#B8C8B8".to_string(),
-            border: "#2A3A2A".to_string(),
+            primary: Color::hex(0xE8F0E8),
+            secondary: Color::hex(0x2E8B57),
+            accent: Color::hex(0x3CB371),
+            background: Color::hex(0x0A120A),
+            surface: Color::hex(0x121A12),
+            text_primary: Color::hex(0xE8F0E8),
+            text_secondary: Color::hex(0xB8C8B8),
+            border: Color::hex(0x2A3A2A),
         }
     }
 
     pub fn crimson() -> Self {
         Self {
             name: "Crimson".to_string(),
-            primary: "#F0E8E8".to_string(),
-            secondary: "#DC143C".to_string(),
-            accent: "#E63950".to_string(),
-            background: "#120A0A".to_string(),
-            surface: "#1A1212".to_string(),
-            text_primary: "#F0E8E8".to_string(),
-            text_secondary: "#C8B8B8".to_string(),
-            border: "#3A2A2A".to_string(),
+            primary: Color::hex(0xF0E8E8),
+            secondary: Color::hex(0xDC143C),
+            accent: Color::hex(0xE63950),
+            background: Color::hex(0x120A0A),
+            surface: Color::hex(0x1A1212),
+            text_primary: Color::hex(0xF0E8E8),
+            text_secondary: Color::hex(0xC8B8B8),
+            border: Color::hex(0x3A2A2A),
         }
     }
 
     pub fn monochrome() -> Self {
         Self {
             name: "Monochrome".to_string(),
-            primary: "#F0F0F0".to_string(),
-            secondary: "#808080".to_string(),
-            accent: "#A0A0A0".to_string(),
-            background: "#0A0A0A".to_string(),
-            surface: "#141414".to_string(),
-            text_primary: "#F0F0F0".to_string(),
-            text_secondary: "#B0B0B0".to_string(),
-            border: "#303030".to_string(),
+            primary: Color::hex(0xF0F0F0),
+            secondary: Color::hex(0x808080),
+            accent: Color::hex(0xA0A0A0),
+            background: Color::hex(0x0A0A0A),
+            surface: Color::hex(0x141414),
+            text_primary: Color::hex(0xF0F0F0),
+            text_secondary: Color::hex(0xB0B0B0),
+            border: Color::hex(0x303030),
         }
     }
 
-    pub fn all_presets() -> Vec<ThemeConfig> {
+    pub fn light_gold() -> Self {
+        Self {
+            name: "Light Gold".to_string(),
+            primary: Color::hex(0x1A1A1A),
+            secondary: Color::hex(0x8A6100),
+            accent: Color::hex(0xA87A00),
+            background: Color::hex(0xFAFAF7),
+            surface: Color::hex(0xFFFFFF),
+            text_primary: Color::hex(0x1A1A1A),
+            text_secondary: Color::hex(0x4A4A4A),
+            border: Color::hex(0xE0DDD5),
+        }
+    }
+
+    pub fn light_slate() -> Self {
+        Self {
+            name: "Light Slate".to_string(),
+            primary: Color::hex(0x0F172A),
+            secondary: Color::hex(0x1D4ED8),
+            accent: Color::hex(0x2563EB),
+            background: Color::hex(0xF8FAFC),
+            surface: Color::hex(0xFFFFFF),
+            text_primary: Color::hex(0x0F172A),
+            text_secondary: Color::hex(0x475569),
+            border: Color::hex(0xE2E8F0),
+        }
+    }
+
+    pub fn paper() -> Self {
+        Self {
+            name: "Paper".to_string(),
+            primary: Color::hex(0x111111),
+            secondary: Color::hex(0x404040),
+            accent: Color::hex(0x5A5A5A),
+            background: Color::hex(0xFFFFFF),
+            surface: Color::hex(0xF4F4F4),
+            text_primary: Color::hex(0x111111),
+            text_secondary: Color::hex(0x555555),
+            border: Color::hex(0xDDDDDD),
+        }
+    }
+
+    pub fn dark_presets() -> Vec<ThemeConfig> {
         vec![
             Self::default_gold(),
             Self::metallic_dark(),
@@ -125,6 +259,63 @@ impl ThemeConfig {
             Self::monochrome(),
         ]
     }
+
+    pub fn light_presets() -> Vec<ThemeConfig> {
+        vec![Self::light_gold(), Self::light_slate(), Self::paper()]
+    }
+
+    pub fn all_presets() -> Vec<ThemeConfig> {
+        let mut presets = Self::dark_presets();
+        presets.extend(Self::light_presets());
+        presets
+    }
+}
+
+/// How the visitor's theme is chosen
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeMode {
+    /// Use the site's brand theme from `SiteSettings`
+    #[default]
+    Brand,
+    /// Follow the OS `prefers-color-scheme` setting
+    System,
+    /// Use the preset or custom theme the visitor picked
+    Manual,
+}
+
+/// Theme used in `ThemeMode::System` for a light or dark OS preference
+pub fn system_theme(prefers_light: bool) -> ThemeConfig {
+    if prefers_light {
+        ThemeConfig::light_gold()
+    } else {
+        ThemeConfig::default_gold()
+    }
+}
+
+/// The theme to show a visitor.
+///
+/// The admin's brand theme is the baseline. A visitor's own choice, or a
+/// theme previewed from a share link, is laid over it only while
+/// `features.visitor_themes` is on.
+pub fn resolve_theme(
+    settings: &SiteSettings,
+    mode: ThemeMode,
+    saved: Option<ThemeConfig>,
+    shared: Option<ThemeConfig>,
+    prefers_light: bool,
+) -> ThemeConfig {
+    if !settings.features.visitor_themes {
+        return settings.brand.theme.clone();
+    }
+    if let Some(shared) = shared {
+        return shared;
+    }
+    match mode {
+        ThemeMode::Brand => settings.brand.theme.clone(),
+        ThemeMode::System => system_theme(prefers_light),
+        ThemeMode::Manual => saved.unwrap_or_else(|| settings.brand.theme.clone()),
+    }
 }
 
 /// Apply theme to DOM by updating CSS variables on :root
@@ -132,30 +323,136 @@ pub fn apply_theme_to_dom(theme: &ThemeConfig) {
     if let Some(window) = window() {
         if let Some(doc) = window.document() {
             if let Some(root) = doc.document_element() {
+                // Lets CSS adjust glass effects and hardcoded shades for light themes
+                let scheme = if theme.is_dark() { "dark" } else { "light" };
+                let _ = root.set_attribute("data-color-scheme", scheme);
                 if let Ok(html) = root.dyn_into::<web_sys::HtmlElement>() {
                     let style = html.style();
-                    let _ = style.set_property("--color-primary", &theme.primary);
-                    let _ = style.set_property("--color-secondary", &theme.secondary);
-                    let _ = style.set_property("--color-accent", &theme.accent);
-                    let _ = style.set_property("--color-background", &theme.background);
-                    let _ = style.set_property("--color-surface", &theme.surface);
-                    let _ = style.set_property("--color-text-primary", &theme.text_primary);
-                    let _ = style.set_property("--color-text-secondary", &theme.text_secondary);
-                    let _ = style.set_property("--color-border", &theme.border);
+                    let _ = style.set_property("color-scheme", scheme);
+                    for (var, color) in theme.css_vars() {
+                        let _ = style.set_property(&var, &color.to_string());
+                    }
                 }
             }
         }
     }
 }
 
-/// Load theme from localStorage, or return default
+/// Whether the OS reports a light color scheme preference
+pub fn system_prefers_light() -> bool {
+    window()
+        .and_then(|w| {
+            w.match_media("(prefers-color-scheme: light)")
+                .ok()
+                .flatten()
+        })
+        .is_some_and(|mql| mql.matches())
+}
+
+/// Re-apply the system theme whenever the OS light/dark setting changes
+pub fn watch_system_theme() {
+    use web_sys::wasm_bindgen::closure::Closure;
+
+    let Some(mql) = window().and_then(|w| {
+        w.match_media("(prefers-color-scheme: light)")
+            .ok()
+            .flatten()
+    }) else {
+        return;
+    };
+
+    let on_change = Closure::<dyn FnMut()>::new(|| {
+        if load_theme_mode() == ThemeMode::System {
+            apply_theme_to_dom(&load_theme());
+        }
+    });
+    mql.set_onchange(Some(on_change.as_ref().unchecked_ref()));
+    // Lives for the whole session
+    on_change.forget();
+}
+
+/// Load the visitor's theme mode, defaulting to the brand theme. A theme
+/// saved before modes existed has no mode, and stays the visitor's choice.
+pub fn load_theme_mode() -> ThemeMode {
+    get_from_storage(THEME_MODE_KEY).unwrap_or_else(|| {
+        if read_item(THEME_KEY).is_some() {
+            ThemeMode::Manual
+        } else {
+            ThemeMode::default()
+        }
+    })
+}
+
+/// Save the visitor's theme mode
+pub fn save_theme_mode(mode: ThemeMode) -> bool {
+    set_to_storage(THEME_MODE_KEY, &mode)
+}
+
+/// Load the active theme: the brand theme plus any visitor overlay
 pub fn load_theme() -> ThemeConfig {
-    get_from_storage(THEME_KEY).unwrap_or_default()
+    resolve_theme(
+        &load_settings(),
+        load_theme_mode(),
+        get_from_storage(THEME_KEY),
+        load_shared_theme(),
+        system_prefers_light(),
+    )
 }
 
-/// Save theme to localStorage
+/// Save theme to localStorage and switch to manual mode
 pub fn save_theme(theme: &ThemeConfig) -> bool {
-    set_to_storage(THEME_KEY, theme)
+    save_theme_mode(ThemeMode::Manual) && set_to_storage(THEME_KEY, theme)
+}
+
+/// Load the visitor's named custom themes
+pub fn load_saved_themes() -> SavedThemes {
+    get_from_storage(SAVED_THEMES_KEY).unwrap_or_default()
+}
+
+/// Save the visitor's named custom themes
+pub fn save_saved_themes(themes: &SavedThemes) -> bool {
+    set_to_storage(SAVED_THEMES_KEY, themes)
+}
+
+/// Theme shared through a `#theme=...` link in the current URL, if any
+pub fn load_shared_theme() -> Option<ThemeConfig> {
+    let hash = window()?.location().hash().ok()?;
+    if !hash.contains("theme=") {
+        return None;
+    }
+    ThemeConfig::from_url_fragment(&hash).ok()
+}
+
+/// Drop a `#theme=...` preview from the URL once the visitor picks a theme
+pub fn clear_shared_theme() {
+    let Some(window) = window() else {
+        return;
+    };
+    if !window
+        .location()
+        .hash()
+        .is_ok_and(|hash| hash.contains("theme="))
+    {
+        return;
+    }
+    let path = window.location().pathname().unwrap_or_default();
+    let search = window.location().search().unwrap_or_default();
+    if let Ok(history) = window.history() {
+        let _ = history.replace_state_with_url(
+            &web_sys::wasm_bindgen::JsValue::NULL,
+            "",
+            Some(&format!("{}{}", path, search)),
+        );
+    }
+}
+
+/// Link to the current page that previews `theme`
+pub fn theme_share_link(theme: &ThemeConfig) -> String {
+    let base = window()
+        .and_then(|w| w.location().href().ok())
+        .map(|href| href.split('#').next().unwrap_or_default().to_string())
+        .unwrap_or_default();
+    format!("{}#{}", base, theme.to_url_fragment())
 }
 
 /// Load settings from localStorage, or return defaults
@@ -168,36 +465,91 @@ pub fn save_settings(settings: &SiteSettings) -> bool {
     set_to_storage(SETTINGS_KEY, settings)
 }
 
-/// Load articles - returns compile-time defaults so all visitors see them
+/// Articles compiled in from content/articles/, with admin's edits and
+/// renames laid over them
 pub fn load_articles() -> ArticlesData {
-    // Articles are now compiled into the binary so all visitors can see them
-    // To add/edit articles, modify ArticlesData::default() in types.rs
-    ArticlesData::default()
+    overrides::merge(
+        ArticlesData::default(),
+        get_from_storage(ARTICLES_KEY).unwrap_or_default(),
+    )
 }
 
-/// Save articles to localStorage
+/// Save articles to localStorage, keeping only what differs from the
+/// compiled-in articles
 pub fn save_articles(articles: &ArticlesData) -> bool {
-    set_to_storage(ARTICLES_KEY, articles)
+    set_to_storage(
+        ARTICLES_KEY,
+        &overrides::diff(ArticlesData::default(), articles.clone()),
+    )
+}
+
+/// Testimonials compiled in from content/testimonials/, with admin's edits
+/// laid over them
+pub fn load_testimonials() -> TestimonialsData {
+    overrides::merge(
+        TestimonialsData::default(),
+        get_from_storage(TESTIMONIALS_KEY).unwrap_or_default(),
+    )
 }
 
-/// Load portfolio projects (static data, not persisted)
+/// Save testimonials to localStorage, keeping only what differs from the
+/// compiled-in testimonials
+pub fn save_testimonials(testimonials: &TestimonialsData) -> bool {
+    set_to_storage(
+        TESTIMONIALS_KEY,
+        &overrides::diff(TestimonialsData::default(), testimonials.clone()),
+    )
+}
+
+/// Published portfolio projects for the public site
 pub fn load_portfolio() -> PortfolioData {
-    PortfolioData::default()
+    load_all_portfolio().published()
+}
+
+/// Every portfolio project including drafts and trash, for admin: the
+/// compiled-in projects with admin's edits laid over them
+pub fn load_all_portfolio() -> PortfolioData {
+    overrides::merge(
+        PortfolioData::default(),
+        get_from_storage(PORTFOLIO_KEY).unwrap_or_default(),
+    )
+}
+
+/// Save portfolio projects to localStorage, keeping only what differs from
+/// the compiled-in projects
+pub fn save_portfolio(portfolio: &PortfolioData) -> bool {
+    set_to_storage(
+        PORTFOLIO_KEY,
+        &overrides::diff(PortfolioData::default(), portfolio.clone()),
+    )
 }
 
-/// Load services (static data, not persisted)
+/// Published services for the public site
 pub fn load_services() -> ServicesData {
-    ServicesData::default()
+    load_all_services().published()
+}
+
+/// Every service including drafts and trash, for admin: the compiled-in
+/// services with admin's edits laid over them
+pub fn load_all_services() -> ServicesData {
+    overrides::merge(
+        ServicesData::default(),
+        get_from_storage(SERVICES_KEY).unwrap_or_default(),
+    )
+}
+
+/// Save services to localStorage, keeping only what differs from the
+/// compiled-in services
+pub fn save_services(services: &ServicesData) -> bool {
+    set_to_storage(
+        SERVICES_KEY,
+        &overrides::diff(ServicesData::default(), services.clone()),
+    )
 }
 
 /// Check if user is authenticated
 pub fn is_authenticated() -> bool {
-    if let Some(window) = window() {
-        if let Ok(Some(storage)) = window.local_storage() {
-            return storage.get_item(AUTH_KEY).ok().flatten().is_some();
-        }
-    }
-    false
+    read_item(AUTH_KEY).is_some()
 }
 
 /// Set authentication (login)
@@ -219,24 +571,64 @@ pub fn verify_password(password: &str, settings: &SiteSettings) -> bool {
     password == settings.admin_password_hash
 }
 
+/// The browser window. There's none off the web (tests, tooling), where
+/// `web_sys::window` would panic.
+fn window() -> Option<web_sys::Window> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        web_sys::window()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
+/// Raw localStorage read
+#[cfg(target_arch = "wasm32")]
+fn read_item(key: &str) -> Option<String> {
+    window()?.local_storage().ok()??.get_item(key).ok()?
+}
+
+/// Raw localStorage write
+#[cfg(target_arch = "wasm32")]
+fn write_item(key: &str, value: &str) -> bool {
+    window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .is_some_and(|storage| storage.set_item(key, value).is_ok())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+thread_local! {
+    /// Stands in for localStorage off the web (tests, tooling). It starts
+    /// empty, so callers get defaults until something is saved.
+    static MEMORY_STORAGE: std::cell::RefCell<std::collections::HashMap<String, String>> =
+        std::cell::RefCell::default();
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn read_item(key: &str) -> Option<String> {
+    MEMORY_STORAGE.with(|storage| storage.borrow().get(key).cloned())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn write_item(key: &str, value: &str) -> bool {
+    MEMORY_STORAGE.with(|storage| {
+        storage
+            .borrow_mut()
+            .insert(key.to_string(), value.to_string())
+    });
+    true
+}
+
 /// Generic helper to get JSON from localStorage
 fn get_from_storage<T: serde::de::DeserializeOwned>(key: &str) -> Option<T> {
-    let window = window()?;
-    let storage = window.local_storage().ok()??;
-    let value = storage.get_item(key).ok()??;
-    serde_json::from_str(&value).ok()
+    serde_json::from_str(&read_item(key)?).ok()
 }
 
 /// Generic helper to set JSON to localStorage
 fn set_to_storage<T: serde::Serialize>(key: &str, value: &T) -> bool {
-    if let Some(window) = window() {
-        if let Ok(Some(storage)) = window.local_storage() {
-            if let Ok(json) = serde_json::to_string(value) {
-                return storage.set_item(key, &json).is_ok();
-            }
-        }
-    }
-    false
+    serde_json::to_string(value).is_ok_and(|json| write_item(key, &json))
 }
 
 /// Initialize storage with defaults if empty or outdated
@@ -246,11 +638,11 @@ pub fn init_storage() {
         save_settings(&SiteSettings::default());
     }
 
-    // Check articles version - force refresh if version changed
+    // Check articles version - force refresh if version changed. Before v4
+    // a full copy of the articles was stored and never read, so it only
+    // holds stale text; dropping it leaves no edits, just the compiled ones.
     let stored_version: Option<String> = get_from_storage(ARTICLES_VERSION_KEY);
-    let needs_refresh = stored_version.as_deref() != Some(CURRENT_ARTICLES_VERSION);
-
-    if needs_refresh || get_from_storage::<ArticlesData>(ARTICLES_KEY).is_none() {
+    if stored_version.as_deref() != Some(CURRENT_ARTICLES_VERSION) {
         save_articles(&ArticlesData::default());
         set_to_storage(ARTICLES_VERSION_KEY, &CURRENT_ARTICLES_VERSION.to_string());
     }
@@ -263,6 +655,9 @@ pub fn clear_all_data() {
         if let Ok(Some(storage)) = window.local_storage() {
             let _ = storage.remove_item(SETTINGS_KEY);
             let _ = storage.remove_item(ARTICLES_KEY);
+            let _ = storage.remove_item(TESTIMONIALS_KEY);
+            let _ = storage.remove_item(SERVICES_KEY);
+            let _ = storage.remove_item(PORTFOLIO_KEY);
             let _ = storage.remove_item(AUTH_KEY);
         }
     }
@@ -273,14 +668,27 @@ pub fn clear_all_data() {
 pub fn export_data() -> Option<String> {
     let settings = load_settings();
     let articles = load_articles();
+    let testimonials = load_testimonials();
+    let services = load_all_services();
+    let portfolio = load_all_portfolio();
 
     #[derive(serde::Serialize)]
     struct ExportData {
         settings: SiteSettings,
         articles: ArticlesData,
+        testimonials: TestimonialsData,
+        services: ServicesData,
+        portfolio: PortfolioData,
     }
 
-    serde_json::to_string_pretty(&ExportData { settings, articles }).ok()
+    serde_json::to_string_pretty(&ExportData {
+        settings,
+        articles,
+        testimonials,
+        services,
+        portfolio,
+    })
+    .ok()
 }
 
 /// Import data from JSON string
@@ -290,6 +698,10 @@ pub fn import_data(json: &str) -> Result<(), String> {
     struct ImportData {
         settings: SiteSettings,
         articles: ArticlesData,
+        #[serde(default)]
+        testimonials: TestimonialsData,
+        services: Option<ServicesData>,
+        portfolio: Option<PortfolioData>,
     }
 
     let data: ImportData =
@@ -297,6 +709,14 @@ pub fn import_data(json: &str) -> Result<(), String> {
 
     save_settings(&data.settings);
     save_articles(&data.articles);
+    save_testimonials(&data.testimonials);
+    // Older exports predate editable services and projects
+    if let Some(services) = &data.services {
+        save_services(services);
+    }
+    if let Some(portfolio) = &data.portfolio {
+        save_portfolio(portfolio);
+    }
 
     Ok(())
 }
@@ -304,6 +724,7 @@ pub fn import_data(json: &str) -> Result<(), String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::content::{ArticleStatus, BrandSettings, Testimonial};
 
     // ==================== ThemeConfig Tests ====================
 
@@ -317,57 +738,19 @@ mod tests {
     }
 
     #[test]
-    fn test_all_presets_have_valid_hex_colors() {
+    fn test_all_presets_serialize_hex_colors() {
         for preset in ThemeConfig::all_presets() {
-            // All color fields should be valid hex colors
-            assert!(
-                preset.primary.starts_with('#') && preset.primary.len() == 7,
-                "Invalid primary color in {}: {}",
-                preset.name,
-                preset.primary
-            );
-            assert!(
-                preset.secondary.starts_with('#') && preset.secondary.len() == 7,
-                "Invalid secondary color in {}: {}",
-                preset.name,
-                preset.secondary
-            );
-            assert!(
-                preset.accent.starts_with('#') && preset.accent.len() == 7,
-                "Invalid accent color in {}: {}",
-                preset.name,
-                preset.accent
-            );
-            assert!(
-                preset.background.starts_with('#') && preset.background.len() == 7,
-                "Invalid background color in {}: {}",
-                preset.name,
-                preset.background
-            );
-            assert!(
-                preset.surface.starts_with('#') && preset.surface.len() == 7,
-                "Invalid surface color in {}: {}",
-                preset.name,
-                preset.surface
-            );
-            assert!(
-                preset.text_primary.starts_with('#') && preset.text_primary.len() == 7,
-                "Invalid text_primary color in {}: {}",
-                preset.name,
-                preset.text_primary
-            );
-            assert!(
-                preset.text_secondary.starts_with('#') && preset.text_secondary.len() == 7,
-                "Invalid text_secondary color in {}: {}",
-                preset.name,
-                preset.text_secondary
-            );
-            assert!(
-                preset.border.starts_with('#') && preset.border.len() == 7,
-                "Invalid border color in {}: {}",
-                preset.name,
-                preset.border
-            );
+            let json = serde_json::to_value(&preset).unwrap();
+            for field in ThemeColor::ALL {
+                let value = json[field.key()].as_str().unwrap();
+                assert!(
+                    value.starts_with('#') && value.len() == 7,
+                    "{} in {} serialized as {}",
+                    field.key(),
+                    preset.name,
+                    value
+                );
+            }
         }
     }
 
@@ -387,8 +770,9 @@ mod tests {
 
     #[test]
     fn test_all_presets_count() {
-        let presets = ThemeConfig::all_presets();
-        assert_eq!(presets.len(), 6); // gold, metallic, blue, emerald, crimson, monochrome
+        assert_eq!(ThemeConfig::dark_presets().len(), 6); // gold, metallic, blue, emerald, crimson, monochrome
+        assert_eq!(ThemeConfig::light_presets().len(), 3); // light gold, light slate, paper
+        assert_eq!(ThemeConfig::all_presets().len(), 9);
     }
 
     #[test]
@@ -408,17 +792,17 @@ mod tests {
         assert_eq!(ThemeConfig::emerald().name, "Emerald");
         assert_eq!(ThemeConfig::crimson().name, "Crimson");
         assert_eq!(ThemeConfig::monochrome().name, "Monochrome");
+        assert_eq!(ThemeConfig::light_gold().name, "Light Gold");
+        assert_eq!(ThemeConfig::light_slate().name, "Light Slate");
+        assert_eq!(ThemeConfig::paper().name, "Paper");
     }
 
     #[test]
     fn test_dark_themes_have_dark_backgrounds() {
-        // All themes should have dark backgrounds (low RGB values)
-        for preset in ThemeConfig::all_presets() {
-            let bg = &preset.background;
-            // Parse hex to check it's dark (first digit after # should be 0-2)
-            let r = u8::from_str_radix(&bg[1..3], 16).unwrap();
-            let g = u8::from_str_radix(&bg[3..5], 16).unwrap();
-            let b = u8::from_str_radix(&bg[5..7], 16).unwrap();
+        // All dark themes should have dark backgrounds (low RGB values)
+        for preset in ThemeConfig::dark_presets() {
+            let bg = preset.background;
+            let (r, g, b) = (bg.r, bg.g, bg.b);
 
             // For dark theme, RGB values should be low (< 50)
             assert!(
@@ -433,11 +817,9 @@ mod tests {
     #[test]
     fn test_light_text_on_dark_backgrounds() {
         // Text primary should be light for contrast
-        for preset in ThemeConfig::all_presets() {
-            let text = &preset.text_primary;
-            let r = u8::from_str_radix(&text[1..3], 16).unwrap();
-            let g = u8::from_str_radix(&text[3..5], 16).unwrap();
-            let b = u8::from_str_radix(&text[5..7], 16).unwrap();
+        for preset in ThemeConfig::dark_presets() {
+            let text = preset.text_primary;
+            let (r, g, b) = (text.r, text.g, text.b);
 
             // For light text, RGB values should be high (> 200)
             assert!(
@@ -449,6 +831,228 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_light_themes_have_light_backgrounds_and_dark_text() {
+        for preset in ThemeConfig::light_presets() {
+            let bg = preset.background;
+            let (r, g, b) = (bg.r, bg.g, bg.b);
+            assert!(
+                r > 220 && g > 220 && b > 220,
+                "Background {} in {} is not light enough",
+                bg,
+                preset.name
+            );
+
+            let text = preset.text_primary;
+            let (r, g, b) = (text.r, text.g, text.b);
+            assert!(
+                r < 60 && g < 60 && b < 60,
+                "Text primary {} in {} is not dark enough for light backgrounds",
+                text,
+                preset.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_system_theme_follows_preference() {
+        assert!(!system_theme(true).is_dark());
+        assert!(system_theme(false).is_dark());
+    }
+
+    #[test]
+    fn test_resolve_theme_defaults_to_brand() {
+        let settings = SiteSettings {
+            brand: BrandSettings {
+                theme: ThemeConfig::emerald(),
+                ..SiteSettings::default().brand
+            },
+            ..Default::default()
+        };
+        let theme = resolve_theme(&settings, ThemeMode::default(), None, None, false);
+        assert_eq!(theme, ThemeConfig::emerald());
+    }
+
+    #[test]
+    fn test_resolve_theme_visitor_overlay() {
+        let settings = SiteSettings::default();
+        let saved = Some(ThemeConfig::crimson());
+
+        assert_eq!(
+            resolve_theme(&settings, ThemeMode::Manual, saved, None, false),
+            ThemeConfig::crimson()
+        );
+        assert_eq!(
+            resolve_theme(&settings, ThemeMode::System, None, None, true),
+            ThemeConfig::light_gold()
+        );
+        // Manual mode with nothing saved falls back to the brand theme
+        assert_eq!(
+            resolve_theme(&settings, ThemeMode::Manual, None, None, false),
+            settings.brand.theme
+        );
+    }
+
+    #[test]
+    fn test_resolve_theme_ignores_visitor_when_disabled() {
+        let mut settings = SiteSettings::default();
+        settings.features.visitor_themes = false;
+
+        let saved = Some(ThemeConfig::crimson());
+        assert_eq!(
+            resolve_theme(&settings, ThemeMode::Manual, saved, None, false),
+            settings.brand.theme
+        );
+        assert_eq!(
+            resolve_theme(&settings, ThemeMode::System, None, None, true),
+            settings.brand.theme
+        );
+    }
+
+    #[test]
+    fn test_resolve_theme_shared_link_previews() {
+        let mut settings = SiteSettings::default();
+        let shared = Some(ThemeConfig::blue_steel());
+        let saved = Some(ThemeConfig::crimson());
+
+        assert_eq!(
+            resolve_theme(
+                &settings,
+                ThemeMode::Manual,
+                saved.clone(),
+                shared.clone(),
+                false
+            ),
+            ThemeConfig::blue_steel()
+        );
+
+        settings.features.visitor_themes = false;
+        assert_eq!(
+            resolve_theme(&settings, ThemeMode::Manual, saved, shared, false),
+            settings.brand.theme
+        );
+    }
+
+    #[test]
+    fn test_theme_color_fields_roundtrip() {
+        let mut theme = ThemeConfig::default_gold();
+        for field in ThemeColor::ALL {
+            theme.set_color(field, Color::hex(0x123456));
+            assert_eq!(theme.color(field), Color::hex(0x123456), "{:?}", field);
+        }
+        assert_eq!(
+            ThemeColor::TextSecondary.css_var(),
+            "--color-text-secondary"
+        );
+    }
+
+    #[test]
+    fn test_theme_mode_default_and_serialization() {
+        assert_eq!(ThemeMode::default(), ThemeMode::Brand);
+        assert_eq!(
+            serde_json::to_string(&ThemeMode::Manual).unwrap(),
+            "\"manual\""
+        );
+    }
+
+    #[test]
+    fn test_theme_saved_without_a_mode_stays_manual() {
+        assert_eq!(load_theme_mode(), ThemeMode::Brand);
+
+        // A visitor who picked a theme before modes existed
+        let theme = ThemeConfig::light_gold();
+        set_to_storage(THEME_KEY, &theme);
+        assert_eq!(load_theme_mode(), ThemeMode::Manual);
+        assert_eq!(load_theme(), theme);
+
+        save_theme_mode(ThemeMode::System);
+        assert_eq!(load_theme_mode(), ThemeMode::System);
+    }
+
+    #[test]
+    fn test_renamed_article_slug_resolves_after_saving() {
+        let mut articles = load_articles();
+        let mut article = articles
+            .articles
+            .iter()
+            .find(|a| a.status == ArticleStatus::Published)
+            .unwrap()
+            .clone();
+        let old_slug = article.slug.clone();
+        article.slug = "a-brand-new-slug".to_string();
+        articles.upsert(article.clone());
+        assert!(save_articles(&articles));
+
+        let loaded = load_articles();
+        assert_eq!(
+            loaded
+                .find_published(&old_slug)
+                .map(|(a, moved)| (&a.id, moved)),
+            Some((&article.id, true))
+        );
+        assert_eq!(
+            loaded
+                .find_published("a-brand-new-slug")
+                .map(|(a, _)| &a.id),
+            Some(&article.id)
+        );
+        // The old slug stays reserved for its inbound links
+        assert!(!loaded.is_slug_available("someone-else", &old_slug));
+        // Articles admin didn't touch still come from the build
+        assert_eq!(
+            loaded.articles.len(),
+            ArticlesData::default().articles.len()
+        );
+    }
+
+    #[test]
+    fn test_init_storage_keeps_saved_article_edits() {
+        init_storage();
+        let mut articles = load_articles();
+        articles.articles[0].title = "Edited in admin".to_string();
+        save_articles(&articles);
+
+        init_storage();
+        assert_eq!(load_articles().articles[0].title, "Edited in admin");
+    }
+
+    #[test]
+    fn test_saved_service_edit_leaves_other_services_to_the_build() {
+        let compiled = ServicesData::default();
+        let mut services = load_all_services();
+        services.services[0].tagline = "Edited in admin".to_string();
+        assert!(save_services(&services));
+
+        // Only the edit is stored
+        let stored: overrides::ContentOverrides<crate::content::Service> =
+            get_from_storage(SERVICES_KEY).unwrap();
+        assert_eq!(stored.items.len(), 1);
+
+        let loaded = load_all_services();
+        assert_eq!(loaded.services[0].tagline, "Edited in admin");
+        assert_eq!(loaded.services[1..], compiled.services[1..]);
+    }
+
+    #[test]
+    fn test_testimonials_saved_before_overrides_still_load() {
+        let quote = Testimonial {
+            quote: "They shipped on time.".to_string(),
+            author: "Pat".to_string(),
+            ..Testimonial::new()
+        };
+        let saved = TestimonialsData {
+            testimonials: vec![quote.clone()],
+        };
+        assert!(set_to_storage(TESTIMONIALS_KEY, &saved));
+
+        let loaded = load_testimonials();
+        assert_eq!(loaded.find(&quote.id), Some(&quote));
+        assert_eq!(
+            loaded.testimonials.len(),
+            TestimonialsData::default().testimonials.len() + 1
+        );
+    }
+
     // ==================== Password Verification Tests ====================
 
     #[test]
@@ -467,6 +1071,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::field_reassign_with_default)]
     fn test_verify_password_custom() {
         let mut settings = SiteSettings::default();
         settings.admin_password_hash = "custom_password".to_string();
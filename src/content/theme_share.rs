@@ -0,0 +1,437 @@
+use super::color::Color;
+use super::storage::{ThemeColor, ThemeConfig};
+use serde::{Deserialize, Serialize};
+
+/// Longest name allowed for a saved theme
+const MAX_THEME_NAME_LEN: usize = 40;
+/// Name given to imported themes that don't carry one
+const IMPORTED_THEME_NAME: &str = "Imported";
+
+impl ThemeConfig {
+    /// Check the theme has a usable name
+    pub fn validate(&self) -> Result<(), String> {
+        let name = self.name.trim();
+        if name.is_empty() {
+            return Err("Theme name can't be empty".to_string());
+        }
+        if name.chars().count() > MAX_THEME_NAME_LEN {
+            return Err(format!(
+                "Theme name must be {} characters or fewer",
+                MAX_THEME_NAME_LEN
+            ));
+        }
+        Ok(())
+    }
+
+    /// Pretty-printed JSON export
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// Parse and validate a JSON export
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let theme: ThemeConfig =
+            serde_json::from_str(json).map_err(|e| format!("Invalid JSON: {}", e))?;
+        theme.validate()?;
+        Ok(theme)
+    }
+
+    /// A `:root { --color-*: ... }` block that can be pasted into a stylesheet,
+    /// including the derived hover/active shades
+    pub fn to_css(&self) -> String {
+        // A `*/` in the name would end the comment early
+        let name = self.name.replace("*/", "* /");
+        let mut css = format!("/* Theme: {} */\n:root {{\n", name);
+        for (var, color) in self.css_vars() {
+            css.push_str(&format!("    {}: {};\n", var, color));
+        }
+        css.push_str("}\n");
+        css
+    }
+
+    /// Parse a CSS custom-property block as produced by `to_css`.
+    ///
+    /// Every base `--color-*` variable must be present, in any notation `Color`
+    /// understands; other declarations are ignored. The name comes from a
+    /// `/* Theme: ... */` comment if there is one.
+    pub fn from_css(css: &str) -> Result<Self, String> {
+        let name = css
+            .split_once("/* Theme:")
+            .and_then(|(_, rest)| rest.split_once("*/"))
+            .map(|(name, _)| name.trim().to_string())
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| IMPORTED_THEME_NAME.to_string());
+
+        let mut theme = ThemeConfig {
+            name,
+            ..Default::default()
+        };
+        for field in ThemeColor::ALL {
+            let var = field.css_var();
+            let value = css
+                .split(['{', '}', ';'])
+                .filter_map(|decl| decl.split_once(':'))
+                .find(|(prop, _)| prop.trim() == var)
+                .map(|(_, value)| value.trim())
+                .ok_or_else(|| format!("Missing {} ({})", var, field.label()))?;
+            let color = value
+                .parse::<Color>()
+                .map_err(|e| format!("{}: {}", field.label(), e))?;
+            theme.set_color(field, color);
+        }
+
+        theme.validate()?;
+        Ok(theme)
+    }
+
+    /// Compact URL fragment, e.g. `theme=FAFAFA,D4A017,...&name=Default%20Gold`
+    pub fn to_url_fragment(&self) -> String {
+        let colors: Vec<String> = ThemeColor::ALL
+            .iter()
+            .map(|field| self.color(*field).to_string().replacen('#', "", 1))
+            .collect();
+        format!(
+            "theme={}&name={}",
+            colors.join(","),
+            percent_encode(&self.name)
+        )
+    }
+
+    /// Parse a fragment from `to_url_fragment`, with or without the leading `#`
+    pub fn from_url_fragment(fragment: &str) -> Result<Self, String> {
+        let fragment = fragment.trim().trim_start_matches('#');
+
+        let mut colors = None;
+        let mut name = None;
+        for pair in fragment.split('&') {
+            match pair.split_once('=') {
+                Some(("theme", value)) => colors = Some(value),
+                Some(("name", value)) => name = Some(percent_decode(value)?),
+                _ => {}
+            }
+        }
+
+        let colors: Vec<&str> = colors
+            .ok_or("Link doesn't contain a theme")?
+            .split(',')
+            .collect();
+        if colors.len() != ThemeColor::ALL.len() {
+            return Err(format!(
+                "Theme link has {} colors, expected {}",
+                colors.len(),
+                ThemeColor::ALL.len()
+            ));
+        }
+
+        let mut theme = ThemeConfig {
+            name: name
+                .filter(|n| !n.trim().is_empty())
+                .unwrap_or_else(|| IMPORTED_THEME_NAME.to_string()),
+            ..Default::default()
+        };
+        for (field, color) in ThemeColor::ALL.into_iter().zip(colors) {
+            let color = format!("#{}", color)
+                .parse::<Color>()
+                .map_err(|e| format!("{}: {}", field.label(), e))?;
+            theme.set_color(field, color);
+        }
+
+        theme.validate()?;
+        Ok(theme)
+    }
+
+    /// Import a theme pasted as JSON, CSS, or a share link
+    pub fn import(text: &str) -> Result<Self, String> {
+        let text = text.trim();
+        if text.starts_with('{') {
+            Self::from_json(text)
+        } else if text.contains("--color-") {
+            Self::from_css(text)
+        } else if let Some((_, fragment)) = text.split_once('#') {
+            Self::from_url_fragment(fragment)
+        } else if text.starts_with("theme=") {
+            Self::from_url_fragment(text)
+        } else {
+            Err("Paste a theme as JSON, CSS variables, or a share link".to_string())
+        }
+    }
+}
+
+/// A visitor's named custom themes
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct SavedThemes {
+    pub themes: Vec<ThemeConfig>,
+}
+
+impl SavedThemes {
+    /// Add a theme, replacing any saved theme with the same name.
+    ///
+    /// Preset names are reserved so a saved theme can't shadow a preset.
+    /// Names are compared ignoring case, as they are everywhere else.
+    pub fn save(&mut self, theme: ThemeConfig) -> Result<(), String> {
+        theme.validate()?;
+        let name = theme.name.trim();
+        if ThemeConfig::all_presets()
+            .iter()
+            .any(|p| same_name(&p.name, name))
+        {
+            return Err(format!("\"{}\" is a preset name, pick another", name));
+        }
+
+        let theme = ThemeConfig {
+            name: name.to_string(),
+            ..theme
+        };
+        match self
+            .themes
+            .iter_mut()
+            .find(|t| same_name(&t.name, &theme.name))
+        {
+            Some(existing) => *existing = theme,
+            None => self.themes.push(theme),
+        }
+        Ok(())
+    }
+
+    pub fn remove(&mut self, name: &str) {
+        self.themes.retain(|t| !same_name(&t.name, name));
+    }
+
+    pub fn find(&self, name: &str) -> Option<&ThemeConfig> {
+        self.themes.iter().find(|t| same_name(&t.name, name))
+    }
+}
+
+/// Whether two theme names are the same, ignoring case and surrounding space
+fn same_name(a: &str, b: &str) -> bool {
+    a.trim().eq_ignore_ascii_case(b.trim())
+}
+
+/// Percent-encode everything except unreserved URL characters
+pub(crate) fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+fn percent_decode(value: &str) -> Result<String, String> {
+    let bytes = value.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                let hex = value
+                    .get(i + 1..i + 3)
+                    .and_then(|h| u8::from_str_radix(h, 16).ok())
+                    .ok_or("Theme link has a malformed name")?;
+                out.push(hex);
+                i += 3;
+            }
+            b'+' => {
+                out.push(b' ');
+                i += 1;
+            }
+            b => {
+                out.push(b);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8(out).map_err(|_| "Theme link has a malformed name".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn custom() -> ThemeConfig {
+        ThemeConfig {
+            name: "Ocean & Sand".to_string(),
+            secondary: Color::hex(0x1E90FF),
+            ..ThemeConfig::default_gold()
+        }
+    }
+
+    #[test]
+    fn test_presets_validate() {
+        for preset in ThemeConfig::all_presets() {
+            assert_eq!(preset.validate(), Ok(()), "{}", preset.name);
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_input() {
+        let blank = ThemeConfig {
+            name: "  ".to_string(),
+            ..ThemeConfig::default_gold()
+        };
+        assert!(blank.validate().is_err());
+
+        let long = ThemeConfig {
+            name: "x".repeat(MAX_THEME_NAME_LEN + 1),
+            ..ThemeConfig::default_gold()
+        };
+        assert!(long.validate().is_err());
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        for theme in ThemeConfig::all_presets().into_iter().chain([custom()]) {
+            assert_eq!(ThemeConfig::from_json(&theme.to_json()), Ok(theme));
+        }
+    }
+
+    #[test]
+    fn test_from_json_rejects_invalid() {
+        assert!(ThemeConfig::from_json("not json").is_err());
+
+        let bad = custom().to_json().replace("#FAFAFA", "#12");
+        assert!(ThemeConfig::from_json(&bad).is_err());
+    }
+
+    #[test]
+    fn test_css_roundtrip() {
+        for theme in ThemeConfig::all_presets().into_iter().chain([custom()]) {
+            assert_eq!(ThemeConfig::from_css(&theme.to_css()), Ok(theme));
+        }
+    }
+
+    #[test]
+    fn test_css_export_format() {
+        let css = ThemeConfig::default_gold().to_css();
+        assert!(css.contains(":root {"));
+        assert!(css.contains("--color-text-primary: #FAFAFA;"));
+        assert!(css.contains("--color-secondary: #D4A017;"));
+        assert!(css.contains("--color-secondary-hover: "));
+    }
+
+    #[test]
+    fn test_to_css_keeps_the_name_inside_its_comment() {
+        let theme = ThemeConfig {
+            name: "Evil */ body { display: none } /*".to_string(),
+            ..custom()
+        };
+        let css = theme.to_css();
+        let comment = css.lines().next().unwrap();
+        assert_eq!(comment.matches("*/").count(), 1);
+        assert!(comment.ends_with(" */"));
+        assert_eq!(
+            ThemeConfig::from_css(&css).unwrap().name,
+            "Evil * / body { display: none } /*"
+        );
+    }
+
+    #[test]
+    fn test_from_css_accepts_other_notations() {
+        let css = ThemeConfig::default_gold()
+            .to_css()
+            .replace(
+                "--color-secondary: #D4A017",
+                "--color-secondary: rgb(212, 160, 23)",
+            )
+            .replace(
+                "--color-border: #3A3A3A",
+                "--color-border: hsl(0, 0%, 22.75%)",
+            );
+        assert_eq!(ThemeConfig::from_css(&css), Ok(ThemeConfig::default_gold()));
+    }
+
+    #[test]
+    fn test_from_css_without_name_or_with_missing_var() {
+        let css = ThemeConfig::emerald().to_css();
+        let unnamed = css.lines().skip(1).collect::<Vec<_>>().join("\n");
+        assert_eq!(ThemeConfig::from_css(&unnamed).unwrap().name, "Imported");
+
+        let missing = css.replace("    --color-border: #2A3A2A;\n", "");
+        let err = ThemeConfig::from_css(&missing).unwrap_err();
+        assert!(err.contains("--color-border"));
+    }
+
+    #[test]
+    fn test_url_fragment_roundtrip() {
+        for theme in ThemeConfig::all_presets().into_iter().chain([custom()]) {
+            let fragment = theme.to_url_fragment();
+            assert!(!fragment.contains(' ') && !fragment.contains('#'));
+            assert_eq!(ThemeConfig::from_url_fragment(&fragment), Ok(theme.clone()));
+            assert_eq!(
+                ThemeConfig::from_url_fragment(&format!("#{}", fragment)),
+                Ok(theme)
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_url_fragment_rejects_invalid() {
+        assert!(ThemeConfig::from_url_fragment("name=Foo").is_err());
+        assert!(ThemeConfig::from_url_fragment("theme=FFFFFF,000000").is_err());
+        assert!(ThemeConfig::from_url_fragment(
+            "theme=ZZZZZZ,000000,000000,000000,000000,000000,000000,000000"
+        )
+        .is_err());
+        assert!(ThemeConfig::from_url_fragment(
+            "theme=FFFFFF,000000,000000,000000,000000,000000,000000,000000&name=%G1"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_import_detects_format() {
+        let theme = custom();
+        assert_eq!(ThemeConfig::import(&theme.to_json()), Ok(theme.clone()));
+        assert_eq!(ThemeConfig::import(&theme.to_css()), Ok(theme.clone()));
+        let link = format!("https://example.com/about#{}", theme.to_url_fragment());
+        assert_eq!(ThemeConfig::import(&link), Ok(theme));
+        assert!(ThemeConfig::import("hello").is_err());
+    }
+
+    #[test]
+    fn test_saved_themes_replace_by_name() {
+        let mut saved = SavedThemes::default();
+        saved.save(custom()).unwrap();
+
+        let updated = ThemeConfig {
+            primary: Color::WHITE,
+            ..custom()
+        };
+        saved.save(updated.clone()).unwrap();
+
+        assert_eq!(saved.themes.len(), 1);
+        assert_eq!(saved.find("Ocean & Sand"), Some(&updated));
+
+        let renamed = ThemeConfig {
+            name: "OCEAN & SAND".to_string(),
+            ..custom()
+        };
+        saved.save(renamed.clone()).unwrap();
+        assert_eq!(saved.themes.len(), 1);
+        assert_eq!(saved.find("ocean & sand"), Some(&renamed));
+
+        saved.remove("Ocean & Sand");
+        assert!(saved.themes.is_empty());
+    }
+
+    #[test]
+    fn test_saved_themes_reject_preset_names() {
+        let mut saved = SavedThemes::default();
+        let shadow = ThemeConfig {
+            name: "emerald".to_string(),
+            ..custom()
+        };
+        assert!(saved.save(shadow).is_err());
+        assert!(saved.themes.is_empty());
+    }
+
+    #[test]
+    fn test_percent_encoding_roundtrip() {
+        for name in ["Default Gold", "Ocean & Sand", "Café #2", "a=b"] {
+            assert_eq!(percent_decode(&percent_encode(name)).unwrap(), name);
+        }
+    }
+}
@@ -0,0 +1,218 @@
+//! Content text in other languages.
+//!
+//! build.rs attaches each `<slug>.<locale>` file in `content/` to the item
+//! it translates. Only text is translated: slugs, images, links and tags are
+//! shared, and any field left empty shows the English.
+
+use super::types::{Article, ArticlesData, PortfolioData, PortfolioProject, Service, ServicesData};
+use crate::i18n::Locale;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// An item's translations, by locale
+pub type Translations<T> = BTreeMap<Locale, T>;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ArticleText {
+    pub title: String,
+    pub category: String,
+    pub excerpt: String,
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ServiceText {
+    pub title: String,
+    pub tagline: String,
+    pub description: String,
+    pub long_description: String,
+    /// Replaces the whole list when not empty
+    pub features: Vec<String>,
+    /// Matched to the English personas by position
+    pub personas: Vec<PersonaText>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct PersonaText {
+    pub title: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ProjectText {
+    pub title: String,
+    pub project_type: String,
+    pub description: String,
+    pub long_description: String,
+    /// Replaces the whole list when not empty
+    pub scope: Vec<String>,
+}
+
+fn translate(field: &mut String, text: &str) {
+    if !text.is_empty() {
+        *field = text.to_string();
+    }
+}
+
+fn translate_list(field: &mut Vec<String>, text: &[String]) {
+    if !text.is_empty() {
+        *field = text.to_vec();
+    }
+}
+
+impl Article {
+    /// The article as shown in `locale`
+    pub fn localized(&self, locale: Locale) -> Article {
+        let mut article = self.clone();
+        if let Some(text) = self.translations.get(&locale) {
+            translate(&mut article.title, &text.title);
+            translate(&mut article.category, &text.category);
+            translate(&mut article.excerpt, &text.excerpt);
+            translate(&mut article.content, &text.content);
+        }
+        article
+    }
+}
+
+impl Service {
+    /// The service as shown in `locale`
+    pub fn localized(&self, locale: Locale) -> Service {
+        let mut service = self.clone();
+        if let Some(text) = self.translations.get(&locale) {
+            translate(&mut service.title, &text.title);
+            translate(&mut service.tagline, &text.tagline);
+            translate(&mut service.description, &text.description);
+            translate(&mut service.long_description, &text.long_description);
+            translate_list(&mut service.features, &text.features);
+            for (persona, text) in service.personas.iter_mut().zip(&text.personas) {
+                translate(&mut persona.title, &text.title);
+                translate(&mut persona.description, &text.description);
+            }
+        }
+        service
+    }
+}
+
+impl PortfolioProject {
+    /// The project as shown in `locale`
+    pub fn localized(&self, locale: Locale) -> PortfolioProject {
+        let mut project = self.clone();
+        if let Some(text) = self.translations.get(&locale) {
+            translate(&mut project.title, &text.title);
+            translate(&mut project.project_type, &text.project_type);
+            translate(&mut project.description, &text.description);
+            translate(&mut project.long_description, &text.long_description);
+            translate_list(&mut project.scope, &text.scope);
+        }
+        project
+    }
+}
+
+impl ArticlesData {
+    /// Every article as shown in `locale`
+    pub fn localized(mut self, locale: Locale) -> Self {
+        for article in &mut self.articles {
+            *article = article.localized(locale);
+        }
+        self
+    }
+}
+
+impl ServicesData {
+    /// Every service as shown in `locale`
+    pub fn localized(mut self, locale: Locale) -> Self {
+        for service in &mut self.services {
+            *service = service.localized(locale);
+        }
+        self
+    }
+}
+
+impl PortfolioData {
+    /// Every project as shown in `locale`
+    pub fn localized(mut self, locale: Locale) -> Self {
+        for project in &mut self.projects {
+            *project = project.localized(locale);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::{load_all_portfolio, load_all_services, load_articles};
+
+    #[test]
+    fn test_untranslated_fields_fall_back_to_english() {
+        let mut service = Service::new();
+        service.title = "Web Development".to_string();
+        service.tagline = "Fast sites".to_string();
+        service.translations.insert(
+            Locale::Es,
+            ServiceText {
+                title: "Desarrollo web".to_string(),
+                ..Default::default()
+            },
+        );
+
+        let spanish = service.localized(Locale::Es);
+        assert_eq!(spanish.title, "Desarrollo web");
+        assert_eq!(spanish.tagline, "Fast sites");
+        assert_eq!(service.localized(Locale::En).title, "Web Development");
+    }
+
+    #[test]
+    fn test_personas_are_translated_by_position() {
+        let mut service = Service::new();
+        service.personas = load_all_services().services[0].personas.clone();
+        let english = service.personas.clone();
+        service.translations.insert(
+            Locale::Es,
+            ServiceText {
+                personas: vec![PersonaText {
+                    title: "Dueños de negocios".to_string(),
+                    description: String::new(),
+                }],
+                ..Default::default()
+            },
+        );
+
+        let spanish = service.localized(Locale::Es);
+        assert_eq!(spanish.personas[0].title, "Dueños de negocios");
+        assert_eq!(spanish.personas[0].description, english[0].description);
+        assert_eq!(spanish.personas[1..], english[1..]);
+    }
+
+    #[test]
+    fn test_bundled_content_has_spanish_translations() {
+        let has_spanish = |translated: bool, kind: &str| {
+            assert!(translated, "no {} is translated into Spanish", kind);
+        };
+        has_spanish(
+            load_articles()
+                .articles
+                .iter()
+                .any(|a| a.translations.contains_key(&Locale::Es)),
+            "article",
+        );
+        has_spanish(
+            load_all_services()
+                .services
+                .iter()
+                .any(|s| s.translations.contains_key(&Locale::Es)),
+            "service",
+        );
+        has_spanish(
+            load_all_portfolio()
+                .projects
+                .iter()
+                .any(|p| p.translations.contains_key(&Locale::Es)),
+            "project",
+        );
+    }
+}
@@ -1,4 +1,12 @@
+use super::color::Color;
+use super::icons::IconName;
+use super::overrides::Collection;
+use super::redirects::{slug_available, unique_slug, upsert_slugged, SlugRedirects, Slugged};
+use super::storage::ThemeConfig;
+use super::translations::{ArticleText, ProjectText, ServiceText, Translations};
 use serde::{Deserialize, Serialize};
+use std::cell::OnceCell;
+use std::thread::LocalKey;
 
 /// Site-wide settings including branding and feature toggles
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -41,11 +49,65 @@ impl Default for DiscountSettings {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(from = "StoredBrandSettings")]
 pub struct BrandSettings {
     pub name: String,
     pub tagline: String,
-    pub primary_color: String,
-    pub accent_color: String,
+    /// Site-wide theme every visitor starts from
+    pub theme: ThemeConfig,
+}
+
+/// Brand colors every site had before the brand theme existed
+const LEGACY_PRIMARY_COLOR: &str = "#C9A227";
+const LEGACY_ACCENT_COLOR: &str = "#D4AF37";
+
+/// `BrandSettings` as saved, including the two colors settings had before
+/// the brand theme replaced them
+#[derive(Deserialize)]
+struct StoredBrandSettings {
+    name: String,
+    tagline: String,
+    #[serde(default)]
+    theme: Option<ThemeConfig>,
+    #[serde(default)]
+    primary_color: Option<String>,
+    #[serde(default)]
+    accent_color: Option<String>,
+}
+
+impl From<StoredBrandSettings> for BrandSettings {
+    fn from(stored: StoredBrandSettings) -> Self {
+        let theme = stored.theme.unwrap_or_else(|| {
+            legacy_theme(
+                stored.primary_color.as_deref(),
+                stored.accent_color.as_deref(),
+            )
+        });
+        Self {
+            name: stored.name,
+            tagline: stored.tagline,
+            theme,
+        }
+    }
+}
+
+/// The brand theme for settings saved with only `primary_color` and
+/// `accent_color`: a palette generated from the primary color with the
+/// accent kept as the hover tint. Untouched defaults keep the default theme.
+fn legacy_theme(primary: Option<&str>, accent: Option<&str>) -> ThemeConfig {
+    let unchanged = primary.is_none_or(|c| c.eq_ignore_ascii_case(LEGACY_PRIMARY_COLOR))
+        && accent.is_none_or(|c| c.eq_ignore_ascii_case(LEGACY_ACCENT_COLOR));
+    if unchanged {
+        return ThemeConfig::default_gold();
+    }
+    let Some(brand) = primary.or(accent).and_then(|c| c.parse::<Color>().ok()) else {
+        return ThemeConfig::default_gold();
+    };
+    let mut theme = ThemeConfig::from_brand_color("Custom", brand, true);
+    if let Some(accent) = accent.and_then(|c| c.parse::<Color>().ok()) {
+        theme.accent = accent;
+    }
+    theme
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -55,6 +117,18 @@ pub struct FeatureToggles {
     pub articles: bool,
     pub contact: bool,
     pub testimonials: bool,
+    /// Let visitors override the brand theme with their own
+    #[serde(default = "default_true")]
+    pub visitor_themes: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Services and projects predate drafts, so saved ones without a status are live
+fn default_published() -> ArticleStatus {
+    ArticleStatus::Published
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -77,6 +151,12 @@ pub struct Article {
     pub excerpt: String,
     pub content: String,
     pub status: ArticleStatus,
+    /// Slugs of articles always listed first under "Related reading"
+    #[serde(default)]
+    pub pinned_articles: Vec<String>,
+    /// Title, category, excerpt and body in other languages
+    #[serde(default)]
+    pub translations: Translations<ArticleText>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -91,6 +171,9 @@ pub enum ArticleStatus {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ArticlesData {
     pub articles: Vec<Article>,
+    /// Old article slugs and where they point now
+    #[serde(default)]
+    pub redirects: SlugRedirects,
 }
 
 /// Service offering
@@ -99,20 +182,29 @@ pub struct Service {
     pub id: String,
     pub slug: String,
     pub title: String,
-    pub icon: String,
+    pub icon: IconName,
     pub tagline: String,
     pub description: String,
     pub long_description: String,
     pub features: Vec<String>,
     pub personas: Vec<Persona>,
     pub accent_color: String,
+    /// Slugs of projects always listed first under "Related case studies"
+    #[serde(default)]
+    pub pinned_projects: Vec<String>,
+    /// Drafts and trashed services are only visible in admin
+    #[serde(default = "default_published")]
+    pub status: ArticleStatus,
+    /// Copy, features and personas in other languages
+    #[serde(default)]
+    pub translations: Translations<ServiceText>,
 }
 
 /// Target persona for a service
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Persona {
     pub title: String,
-    pub icon: String,
+    pub icon: IconName,
     pub description: String,
 }
 
@@ -120,6 +212,8 @@ pub struct Persona {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ServicesData {
     pub services: Vec<Service>,
+    #[serde(default)]
+    pub redirects: SlugRedirects,
 }
 
 /// Portfolio project/case study
@@ -138,12 +232,112 @@ pub struct PortfolioProject {
     pub video: Option<String>,
     pub tech_tags: Vec<String>,
     pub scope: Vec<String>,
+    /// Shown first on the portfolio index, with a badge
+    #[serde(default)]
+    pub featured: bool,
+    /// Position on the portfolio index after featured projects, lowest first
+    #[serde(default)]
+    pub sort_order: u32,
+    /// Measured outcomes, testimonial, timeline and gallery for the case study
+    #[serde(default)]
+    pub case_study: CaseStudy,
+    /// Slugs of services delivered on this project, listed first under "Services used"
+    #[serde(default)]
+    pub pinned_services: Vec<String>,
+    /// Drafts and trashed projects are only visible in admin
+    #[serde(default = "default_published")]
+    pub status: ArticleStatus,
+    /// Copy and scope of work in other languages
+    #[serde(default)]
+    pub translations: Translations<ProjectText>,
+}
+
+/// Structured case-study detail; every section is optional and hidden when empty
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct CaseStudy {
+    #[serde(default)]
+    pub results: Vec<ProjectResult>,
+    #[serde(default)]
+    pub testimonial: Option<Testimonial>,
+    #[serde(default)]
+    pub timeline: Vec<ProjectPhase>,
+    #[serde(default)]
+    pub gallery: Vec<GalleryImage>,
+    /// Screenshot of the site before the project, compared against `screenshot`
+    #[serde(default)]
+    pub before_screenshot: Option<String>,
+}
+
+/// A measured before/after outcome, e.g. page load time
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProjectResult {
+    pub metric: String,
+    pub before: f64,
+    pub after: f64,
+    /// Appended to values ("%", "s", "ms") or prefixed when it is "$"
+    #[serde(default)]
+    pub unit: String,
+    /// Set for metrics where a drop is the improvement (load time, bounce rate)
+    #[serde(default)]
+    pub lower_is_better: bool,
+}
+
+/// A client quote with attribution
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Testimonial {
+    /// Empty for quotes embedded in a case study
+    #[serde(default)]
+    pub id: String,
+    pub quote: String,
+    pub author: String,
+    #[serde(default)]
+    pub role: Option<String>,
+    #[serde(default)]
+    pub company: Option<String>,
+    /// Headshot path under the site root
+    #[serde(default)]
+    pub photo: Option<String>,
+    /// Portfolio project this quote is about, shown on its case study
+    #[serde(default)]
+    pub project_slug: Option<String>,
+    /// Service this quote is about, shown on its detail page
+    #[serde(default)]
+    pub service_slug: Option<String>,
+}
+
+/// Container for all testimonials: those in `content/testimonials/` with
+/// admin's edits laid over them
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TestimonialsData {
+    pub testimonials: Vec<Testimonial>,
+}
+
+/// One phase of the project timeline
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProjectPhase {
+    pub name: String,
+    /// Free-form length, e.g. "2 weeks"
+    #[serde(default)]
+    pub duration: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+/// Image in a case-study gallery
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GalleryImage {
+    pub src: String,
+    pub alt: String,
+    #[serde(default)]
+    pub caption: Option<String>,
 }
 
 /// Container for all portfolio projects
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct PortfolioData {
     pub projects: Vec<PortfolioProject>,
+    #[serde(default)]
+    pub redirects: SlugRedirects,
 }
 
 impl Default for SiteSettings {
@@ -152,8 +346,7 @@ impl Default for SiteSettings {
             brand: BrandSettings {
                 name: "Pounds Consulting".to_string(),
                 tagline: "Technical Solutions for Growing Businesses".to_string(),
-                primary_color: "#C9A227".to_string(),
-                accent_color: "#D4AF37".to_string(),
+                theme: ThemeConfig::default_gold(),
             },
             features: FeatureToggles {
                 portfolio: true,
@@ -161,6 +354,7 @@ impl Default for SiteSettings {
                 articles: true,
                 contact: true,
                 testimonials: false,
+                visitor_themes: true,
             },
             pages: vec![
                 PageConfig {
@@ -213,1580 +407,663 @@ impl Default for SiteSettings {
     }
 }
 
-impl Default for ArticlesData {
-    fn default() -> Self {
-        Self {
-            articles: vec![
-                Article {
-                    id: "do-you-need-custom-website".to_string(),
-                    title: "Do You Actually Need a Custom Website?".to_string(),
-                    slug: "do-you-need-custom-website".to_string(),
-                    date: "2026-01-02".to_string(),
-                    category: "Advice".to_string(),
-                    excerpt: "Before spending thousands on a custom site, here's how to figure out if you actually need one.".to_string(),
-                    content: r#"A lot of businesses pay for custom websites when they don't need them. Here's how to figure out what's right for you.
-
-## When a Template Works Fine
-
-If your business fits a common pattern (local service business, restaurant, small retail shop) a template will probably work. Tools like <a href="https://squarespace.com" target="_blank" rel="noopener noreferrer">Squarespace</a>, <a href="https://wix.com" target="_blank" rel="noopener noreferrer">Wix</a>, and <a href="https://wordpress.com" target="_blank" rel="noopener noreferrer">WordPress.com</a> have come a long way. You can have a professional-looking site up in a weekend for under $200/year.
-
-Templates work when:
-- Your site mostly shows information (hours, location, services, contact)
-- You don't need to collect data or process payments in unusual ways
-- You're okay looking similar to other businesses in your industry
-- You can handle basic updates yourself
-
-If you're selling products, <a href="https://shopify.com" target="_blank" rel="noopener noreferrer">Shopify</a> handles most e-commerce needs out of the box. Their template ecosystem is mature and their checkout is optimized for conversions.
-
-## When You Need Something Custom
-
-Custom makes sense when:
-- You need your website to DO something specific (booking systems, customer portals, calculators)
-- You're integrating with other software your business uses
-- Your business model doesn't fit standard templates
-- Speed matters to your bottom line. Research from <a href="https://web.dev/performance/" target="_blank" rel="noopener noreferrer">Google's performance team</a> shows that <a href="https://web.dev/vitals/" target="_blank" rel="noopener noreferrer">every second of load time costs roughly 7% in conversions</a>
-- You need to stand out in a crowded market
-
-## The Middle Ground
-
-There's a third option many people miss: start with a template, then add custom pieces. Use Squarespace for your main site, but build a custom tool for that one specific thing you need. Connect them with <a href="https://zapier.com" target="_blank" rel="noopener noreferrer">Zapier</a> or a simple API integration. This saves money and gets you moving faster.
-
-## The Real Question
-
-Don't ask "should I build custom?" Ask "what do I need this website to accomplish?" Start there, and the right answer usually becomes obvious.
-
-If you're not sure, we're happy to talk through it. No sales pitch, just honest advice about what makes sense for your situation."#.to_string(),
-                    status: ArticleStatus::Published,
-                },
-                Article {
-                    id: "red-flags-hiring-developer".to_string(),
-                    title: "Red Flags When Hiring a Developer".to_string(),
-                    slug: "red-flags-hiring-developer".to_string(),
-                    date: "2025-12-31".to_string(),
-                    category: "Advice".to_string(),
-                    excerpt: "How to spot problems before you've wasted time and money on the wrong hire.".to_string(),
-                    content: r#"Hiring a developer or agency can feel like a gamble. Here are warning signs we've seen lead to bad outcomes.
-
-## They Can't Explain Things Simply
-
-Good developers can explain technical concepts in plain English. If someone hides behind jargon or makes you feel stupid for asking questions, that's a problem. You'll be working with this person, so communication matters.
-
-## They Promise Everything Will Be Easy
-
-Software is rarely easy. If someone says your project will be quick and cheap without asking many questions first, they either don't understand what you're asking for, or they're telling you what you want to hear.
-
-## No Portfolio or References
-
-Everyone has to start somewhere, but experienced developers should be able to show you past work. Check their <a href="https://github.com" target="_blank" rel="noopener noreferrer">GitHub profile</a> if they have one. Look at reviews on <a href="https://clutch.co" target="_blank" rel="noopener noreferrer">Clutch</a> for agencies. Ask for references and actually call them. Ask what went wrong on the project (something always does) and how the developer handled it.
-
-## They Want All the Money Upfront
-
-Standard practice is milestone-based payments. Some money upfront is reasonable (usually 25-50%), but if they want 100% before starting, walk away. You lose all leverage if something goes wrong. Platforms like <a href="https://toptal.com" target="_blank" rel="noopener noreferrer">Toptal</a> and <a href="https://upwork.com" target="_blank" rel="noopener noreferrer">Upwork</a> offer escrow protection for this reason.
-
-## They Don't Ask About Your Business
-
-A developer who jumps straight to technical solutions without understanding your business goals will build the wrong thing. Good developers ask lots of questions first. They want to understand the problem before proposing solutions.
-
-## Unusually Low Prices
-
-If one bid is half the price of everyone else, something's wrong. Either they don't understand the scope, they're going to cut corners, or they'll hit you with change orders later. Quality development work has a floor. You can find <a href="https://arc.dev/developer-salaries" target="_blank" rel="noopener noreferrer">typical developer rates by region</a> to calibrate your expectations.
-
-## What to Look For Instead
-
-- Clear communication in plain English
-- Honest about challenges and tradeoffs
-- Asks good questions about your goals before talking solutions
-- Has relevant experience they can demonstrate with real examples
-- Reasonable pricing with clear milestones and deliverables
-- Responsive during the sales process (it only gets worse after you sign)
-- Written agreements that specify what you're getting"#.to_string(),
-                    status: ArticleStatus::Published,
-                },
-                Article {
-                    id: "questions-before-building-app".to_string(),
-                    title: "5 Questions to Answer Before Building an App".to_string(),
-                    slug: "questions-before-building-app".to_string(),
-                    date: "2025-12-29".to_string(),
-                    category: "Strategy".to_string(),
-                    excerpt: "Most app projects fail because people skip these questions. Don't be one of them.".to_string(),
-                    content: r#"Before you spend money building an app, make sure you can answer these questions clearly.
-
-## 1. What Problem Does This Solve?
-
-Not "what would be cool to have" but what actual problem are real people experiencing that this app fixes? If you can't describe the problem in one sentence, you're not ready to build. <a href="https://www.ycombinator.com/library/4D-yc-s-essential-startup-advice" target="_blank" rel="noopener noreferrer">Y Combinator's advice to startups</a> applies here: make something people want.
-
-## 2. Who Exactly Will Use This?
-
-"Everyone" is not an answer. Who specifically? How old are they? What do they do? Where will they find your app? The more specific you can be, the better your app will be.
-
-## 3. How Will People Find It?
-
-This is where most apps die. Building it is the easy part. Getting people to actually download and use it is hard. The <a href="https://developer.apple.com/app-store/" target="_blank" rel="noopener noreferrer">Apple App Store</a> has over 2 million apps. <a href="https://play.google.com/store" target="_blank" rel="noopener noreferrer">Google Play</a> has even more. What's your plan to stand out? Be specific.
-
-## 4. What's the Simplest Version That Solves the Problem?
-
-Your first version should do one thing well. Not ten things. Not five things. One thing. You can add more later. Most apps fail because they try to do too much too soon. The startup world calls this an MVP (minimum viable product), and <a href="https://www.productplan.com/glossary/minimum-viable-product/" target="_blank" rel="noopener noreferrer">getting it right is an art</a>.
-
-## 5. How Will You Make Money?
-
-Apps cost money to build and maintain. How does this pay for itself? Subscriptions? One-time purchase? Advertising? In-app purchases? Both Apple and Google <a href="https://developer.apple.com/app-store/subscriptions/" target="_blank" rel="noopener noreferrer">take 15-30% of in-app purchases</a>. Factor that into your math.
-
-## Still Want to Build?
-
-If you can answer all five questions clearly, you might be ready. If not, spend more time on these before writing any code. The cheapest time to change your mind is before you start building.
-
-We're happy to help you think through these questions. Sometimes a 30-minute conversation saves months of wasted effort."#.to_string(),
-                    status: ArticleStatus::Published,
-                },
-                Article {
-                    id: "why-software-projects-fail".to_string(),
-                    title: "Why Most Software Projects Fail (And How to Avoid It)".to_string(),
-                    slug: "why-software-projects-fail".to_string(),
-                    date: "2025-12-26".to_string(),
-                    category: "Strategy".to_string(),
-                    excerpt: "After years of building software, we've seen the same mistakes over and over. Here's how to avoid them.".to_string(),
-                    content: r#"Most software projects fail. The <a href="https://www.standishgroup.com/sample_research_files/CHAOSReport2015-Final.pdf" target="_blank" rel="noopener noreferrer">Standish Group's research</a> has tracked this for decades: only about a third of software projects succeed. But it's rarely the code that kills them. It's the decisions made before any code was written.
-
-## The Scope Keeps Growing
-
-This is the number one killer. You start with a simple idea, then keep adding "just one more thing." Each feature seems small, but they add up. Before you know it, you're building something completely different from what you planned.
-
-**How to avoid it:** Write down exactly what version 1 will do. Put it somewhere visible. Track it in a tool like <a href="https://linear.app" target="_blank" rel="noopener noreferrer">Linear</a> or <a href="https://www.atlassian.com/software/jira" target="_blank" rel="noopener noreferrer">Jira</a>. Every time someone suggests a new feature, ask "Is this essential for launch, or can it wait for version 2?"
-
-## Nobody Agrees on What "Done" Means
-
-The project drags on forever because there's no clear finish line. Different people have different ideas of what success looks like.
-
-**How to avoid it:** Before you start, define what "done" means in writing. What has to work? What can be imperfect? Get everyone to agree on this upfront. The <a href="https://agilemanifesto.org" target="_blank" rel="noopener noreferrer">Agile Manifesto</a> calls this working software over comprehensive documentation.
-
-## Building Before Validating
-
-People spend months building something, then find out nobody wants it. Or they want something slightly different.
-
-**How to avoid it:** Before building the full product, test your idea cheaply. Mock-ups in <a href="https://figma.com" target="_blank" rel="noopener noreferrer">Figma</a>, landing pages, manual versions of the process. Find out if people will actually pay for this before you invest heavily.
-
-## Poor Communication
-
-The developer builds what they understood, not what you meant. Weeks of work get thrown away because of a misunderstanding.
-
-**How to avoid it:** Over-communicate. Check in frequently. Review work in progress, not just finished features. Ask "Can you show me what you have so far?" regularly.
-
-## No One Is In Charge
-
-When everyone is responsible, no one is. Decisions don't get made. Problems don't get solved.
-
-**How to avoid it:** One person needs to be the decision-maker. They don't have to be right about everything, but someone has to be able to break ties and keep things moving.
-
-## The Pattern
-
-Notice something? Most of these problems are about communication and planning, not technology. The technical part is usually the easy part. Getting humans aligned is the hard part."#.to_string(),
-                    status: ArticleStatus::Published,
-                },
-                Article {
-                    id: "true-cost-free-website-builders".to_string(),
-                    title: "The True Cost of 'Free' Website Builders".to_string(),
-                    slug: "true-cost-free-website-builders".to_string(),
-                    date: "2025-12-24".to_string(),
-                    category: "Advice".to_string(),
-                    excerpt: "Free sounds great until you add up what you're actually paying. Here's what those website builders really cost.".to_string(),
-                    content: r#"<a href="https://wix.com" target="_blank" rel="noopener noreferrer">Wix</a>, <a href="https://squarespace.com" target="_blank" rel="noopener noreferrer">Squarespace</a>, and similar tools advertise low prices, but the actual cost is usually higher than it looks.
-
-## The Monthly Fees Add Up
-
-That $16/month plan is $192/year. Over 5 years, you've spent nearly $1,000, and you still don't own anything. Cancel your subscription and your site disappears.
-
-## The Real Plans Cost More
-
-The cheap plan usually has their branding on your site and missing features you'll need. Check the <a href="https://www.squarespace.com/pricing" target="_blank" rel="noopener noreferrer">Squarespace pricing page</a> closely. Once you add a custom domain, remove ads, and get the features you actually need, you're often at $30-50/month.
-
-## The Hidden Costs
-
-- **Apps and plugins:** Many features require paid add-ons
-- **Transaction fees:** Selling something? <a href="https://stripe.com/pricing" target="_blank" rel="noopener noreferrer">Stripe charges 2.9% + 30 cents</a>. The website builder often adds their own fee on top
-- **Storage and bandwidth:** Heavy use costs extra
-- **Email:** Usually separate and extra
-- **Support:** Good luck getting help on the cheap plans
-
-## What You're Giving Up
-
-### Portability
-Your site lives on their platform. Want to move? You're starting over. That design, those pages, that SEO you built up, none of it transfers.
-
-### Control
-You can only do what their platform allows. Need something custom? Too bad. Their servers slow? Nothing you can do.
-
-### Ownership
-You're renting, not owning. They can change prices, features, or terms whenever they want.
-
-## When It's Still Worth It
-
-Despite all this, website builders make sense when:
-- You need something up fast and cheap
-- Your needs are simple and standard
-- You're testing an idea before investing more
-- You genuinely can't afford anything else right now
-
-## The Alternative
-
-A custom website hosted on platforms like <a href="https://vercel.com" target="_blank" rel="noopener noreferrer">Vercel</a> or <a href="https://netlify.com" target="_blank" rel="noopener noreferrer">Netlify</a> costs more upfront but often less over time. You own it. You control it. You can move it. For a business that plans to be around for years, the math usually favors custom.
-
-Run the numbers for your specific situation. Sometimes the "expensive" option is actually cheaper in the long run."#.to_string(),
-                    status: ArticleStatus::Published,
-                },
-                Article {
-                    id: "what-to-expect-working-with-us".to_string(),
-                    title: "What to Expect When Working With Us".to_string(),
-                    slug: "what-to-expect-working-with-us".to_string(),
-                    date: "2025-12-20".to_string(),
-                    category: "About Us".to_string(),
-                    excerpt: "Here's how we work with clients, what we expect from you, and what you can expect from us.".to_string(),
-                    content: r#"Every consultant works differently. Here's how we do things so you know what you're getting into.
-
-## How Projects Start
-
-We start with a conversation, usually 30 minutes to an hour. No charge. <a href="https://calendar.app.google/NxuWY3RDGE5Miaan7" target="_blank" rel="noopener noreferrer">Book a time that works for you</a>. We want to understand what you're trying to accomplish, what you've tried, and what's getting in the way.
-
-After that, we'll tell you honestly whether we think we can help. Sometimes the answer is "you don't need us" or "someone else would be a better fit." We'd rather say that upfront than waste your time and money.
-
-## How We Work
-
-### Communication
-We respond to emails within one business day. Usually faster. We believe in short, frequent check-ins rather than long silences followed by big reveals.
-
-### Honesty
-We tell you the truth, even when it's not what you want to hear. If your idea won't work, we'll say so. If something is taking longer than expected, you'll know right away.
-
-### Simplicity
-We look for the simplest solution that solves your problem. Not the coolest technology. Not the most impressive architecture. The simplest thing that works.
-
-## What We Expect From You
-
-### Availability
-We need you to be reachable. Projects stall when we can't get answers to questions. You don't need to be available 24/7, but we need reasonable response times.
-
-### Decisions
-Someone needs to be able to make decisions. If every question requires a committee meeting, projects drag on forever.
-
-### Honesty
-If something isn't working for you, tell us. We can't fix problems we don't know about.
-
-## Pricing
-
-We work on a project basis with clear milestones and deliverables. You'll know the total cost before we start. We don't nickel-and-dime with change orders for small stuff.
-
-For ongoing work, we offer monthly retainers. Fixed price, predictable costs.
-
-## Ready to Talk?
-
-If this sounds like a good fit, <a href="https://calendar.app.google/NxuWY3RDGE5Miaan7" target="_blank" rel="noopener noreferrer">schedule a free discovery call</a>. No commitment, no sales pressure. Just a chance to see if working together makes sense. You can also <a href="/portfolio" target="_blank" rel="noopener noreferrer">check out our past work</a> first."#.to_string(),
-                    status: ArticleStatus::Published,
-                },
-                // New Article 1
-                Article {
-                    id: "website-costing-you-customers".to_string(),
-                    title: "How to Tell If Your Website Is Costing You Customers".to_string(),
-                    slug: "website-costing-you-customers".to_string(),
-                    date: "2026-01-12".to_string(),
-                    category: "Strategy".to_string(),
-                    excerpt: "Your website might be driving away customers without you knowing. Here's how to find out.".to_string(),
-                    content: r#"Most business owners have no idea how their website is actually performing. They assume if it loads, it's fine. But a slow or confusing website is like having a salesperson who mumbles and takes smoke breaks during pitches.
-
-## Check Your Speed
-
-Run your site through <a href="https://pagespeed.web.dev/" target="_blank" rel="noopener noreferrer">Google's PageSpeed Insights</a>. Anything below 50 on mobile is costing you money. <a href="https://gtmetrix.com" target="_blank" rel="noopener noreferrer">GTmetrix</a> gives you more detail on exactly what's slowing things down.
-
-The math is brutal: <a href="https://web.dev/vitals/" target="_blank" rel="noopener noreferrer">Google's Core Web Vitals research</a> shows that sites meeting their performance thresholds see 24% fewer visitors abandon the page before it loads.
-
-## Watch Real Users
-
-Tools like <a href="https://hotjar.com" target="_blank" rel="noopener noreferrer">Hotjar</a> or <a href="https://clarity.microsoft.com" target="_blank" rel="noopener noreferrer">Microsoft Clarity</a> (which is free) let you see recordings of actual visitors using your site. You'll watch them get confused, click the wrong things, and give up. It's painful but enlightening.
-
-## Check Your Mobile Experience
-
-Pull out your phone and try to complete the main action on your site. Buy something. Fill out a contact form. Find your phone number. If it's frustrating for you, imagine how it feels for someone who's never been there.
-
-Over 60% of web traffic is mobile. If your mobile experience is bad, most of your visitors are having a bad experience.
-
-## Look at Your Analytics
-
-If you have <a href="https://analytics.google.com" target="_blank" rel="noopener noreferrer">Google Analytics</a> set up, check your bounce rate. Above 70% on key pages? People are landing and immediately leaving. Check where they're dropping off in your conversion funnel.
-
-## The Fixes
-
-Most website performance problems come down to a few things:
-- Images that aren't optimized (use <a href="https://tinypng.com" target="_blank" rel="noopener noreferrer">TinyPNG</a> or WebP format)
-- Too many third-party scripts (tracking pixels, chat widgets, etc.)
-- Cheap hosting that can't handle traffic
-- Bloated themes or page builders
-
-Sometimes a few quick fixes solve the problem. Sometimes you need to start fresh. But you won't know until you measure."#.to_string(),
-                    status: ArticleStatus::Published,
-                },
-                // New Article 2
-                Article {
-                    id: "build-vs-buy-software".to_string(),
-                    title: "Build vs Buy: When to Use Off-the-Shelf Software".to_string(),
-                    slug: "build-vs-buy-software".to_string(),
-                    date: "2026-01-10".to_string(),
-                    category: "Strategy".to_string(),
-                    excerpt: "Custom software isn't always the answer. Here's how to decide when to build and when to buy.".to_string(),
-                    content: r#"The decision to build custom software or buy an existing solution is one of the most expensive choices a business makes. Get it wrong and you'll waste months and tens of thousands of dollars.
-
-## When to Buy
-
-Buy when the problem is common and the solution is mature. Thousands of companies need CRM software. That's why <a href="https://salesforce.com" target="_blank" rel="noopener noreferrer">Salesforce</a>, <a href="https://hubspot.com" target="_blank" rel="noopener noreferrer">HubSpot</a>, and dozens of others exist. You're not special enough to need custom here.
-
-Use <a href="https://g2.com" target="_blank" rel="noopener noreferrer">G2</a> or <a href="https://capterra.com" target="_blank" rel="noopener noreferrer">Capterra</a> to find existing solutions. Read the reviews. Try the free trials. Can something get you 80% of what you need? That's probably good enough.
-
-## When to Build
-
-Build when:
-- Your business process is genuinely unique (not just different, unique)
-- Off-the-shelf solutions require so many workarounds that they become unmaintainable
-- The software IS your product or a core competitive advantage
-- You've outgrown existing solutions and need something that scales differently
-
-## The Hybrid Approach
-
-Often the best answer is to buy the basics and build the differentiators. Use <a href="https://shopify.com" target="_blank" rel="noopener noreferrer">Shopify</a> for your store but build a custom recommendation engine. Use <a href="https://hubspot.com/crm" target="_blank" rel="noopener noreferrer">HubSpot's free CRM</a> but build custom integrations to your proprietary systems.
-
-<a href="https://zapier.com" target="_blank" rel="noopener noreferrer">Zapier</a> and <a href="https://make.com" target="_blank" rel="noopener noreferrer">Make</a> let you connect off-the-shelf tools without writing code. Start there before jumping to custom development.
-
-## The Real Cost of Building
-
-Custom software costs more than most people expect. It's not just the initial build. There's:
-- Ongoing maintenance and updates
-- Security patches
-- Hosting costs
-- Bug fixes when something breaks at 2 AM
-
-<a href="https://gartner.com" target="_blank" rel="noopener noreferrer">Gartner research</a> suggests that maintenance costs 15-20% of the original development cost per year. A $50,000 project costs $7,500-10,000 annually to maintain.
-
-## The Decision Framework
-
-Ask yourself:
-1. Does a solution already exist that solves at least 80% of my problem?
-2. If yes, can I live with the other 20% or work around it?
-3. If I must build, is this a core business function worth the ongoing investment?
-4. Have I actually tried the existing solutions, or am I assuming they won't work?
-
-Most businesses should buy more and build less. But when building is the right choice, it can be a massive competitive advantage."#.to_string(),
-                    status: ArticleStatus::Published,
-                },
-                // New Article 3
-                Article {
-                    id: "small-business-ai-mistakes".to_string(),
-                    title: "What Small Businesses Get Wrong About AI".to_string(),
-                    slug: "small-business-ai-mistakes".to_string(),
-                    date: "2026-01-08".to_string(),
-                    category: "Advice".to_string(),
-                    excerpt: "AI can help your business, but probably not the way you think. Here's what actually works.".to_string(),
-                    content: r#"Everyone's talking about AI. Most of what you hear is either hype or fear. Here's what small businesses actually need to know.
-
-## Mistake 1: Thinking You Need Custom AI
-
-You probably don't need to train a custom model or hire an AI team. The tools that already exist can handle most business use cases.
-
-<a href="https://anthropic.com" target="_blank" rel="noopener noreferrer">Claude</a> can draft emails, summarize documents, and answer customer questions. <a href="https://openai.com/chatgpt" target="_blank" rel="noopener noreferrer">ChatGPT</a> can help with content creation and research. <a href="https://zapier.com/ai" target="_blank" rel="noopener noreferrer">Zapier's AI features</a> can automate workflows. These work out of the box.
-
-## Mistake 2: Automating Before Understanding
-
-AI amplifies what you already do. If your process is broken, AI will do broken things faster. Before automating anything, make sure the manual version works.
-
-## Mistake 3: Ignoring What Actually Works
-
-The most useful AI for small businesses isn't flashy. It's:
-
-**Customer service:** AI chatbots that handle common questions 24/7. <a href="https://intercom.com" target="_blank" rel="noopener noreferrer">Intercom</a> and <a href="https://zendesk.com" target="_blank" rel="noopener noreferrer">Zendesk</a> both have AI features that work well.
-
-**Content creation:** Drafting blog posts, social media, emails. Still needs human editing, but saves hours.
-
-**Data entry:** Extracting information from documents, emails, forms. <a href="https://zapier.com" target="_blank" rel="noopener noreferrer">Zapier</a> and <a href="https://make.com" target="_blank" rel="noopener noreferrer">Make</a> can do this.
-
-**Meeting notes:** Tools like <a href="https://otter.ai" target="_blank" rel="noopener noreferrer">Otter.ai</a> transcribe and summarize meetings automatically.
-
-## Mistake 4: Expecting Magic
-
-AI makes mistakes. Sometimes confident, convincing mistakes. Always have a human review anything customer-facing or legally important. AI is a tool, not a replacement for judgment.
-
-## Where to Start
-
-Pick one small, low-risk task. Maybe drafting the first version of your weekly email newsletter. Or summarizing customer feedback. Try it for a month. Measure if it actually saved time.
-
-Most businesses find a few great use cases and lots of things that don't work. That's normal. The goal is finding your few great ones."#.to_string(),
-                    status: ArticleStatus::Published,
-                },
-                // New Article 4
-                Article {
-                    id: "first-developer-meeting".to_string(),
-                    title: "How to Prepare for Your First Developer Meeting".to_string(),
-                    slug: "first-developer-meeting".to_string(),
-                    date: "2026-01-06".to_string(),
-                    category: "Advice".to_string(),
-                    excerpt: "Make the most of your first meeting with a developer by coming prepared with the right information.".to_string(),
-                    content: r#"You've decided to hire a developer. Now you're about to have your first meeting. Here's how to make it productive instead of confusing.
-
-## What to Bring
-
-### The Problem
-Be ready to explain what's not working. Not the technical details, but the business problem. "Customers can't easily book appointments" is better than "I need an online booking system."
-
-### Examples
-Show them websites or apps that do something similar to what you want. "I like how Calendly handles scheduling" gives a developer more information than a paragraph of description.
-
-### Your Constraints
-Be upfront about budget, timeline, and non-negotiables. It saves everyone time if you're $5,000 and they're $50,000.
-
-## What to Ask
-
-### Their Process
-How do they work? Weekly check-ins? What happens when there's a change request? How do they handle unexpected problems?
-
-### Similar Projects
-Have they built something like this before? Can they show you? Talk to that client?
-
-### The Team
-Who actually does the work? Some agencies sell with senior people and deliver with juniors. You want to know who you'll be working with.
-
-### What Could Go Wrong
-A good developer will be honest about risks. If they say nothing could go wrong, they're either lying or inexperienced.
-
-## Red Flags During the Meeting
+impl SiteSettings {
+    /// Pages sorted by their navigation order
+    pub fn pages_in_order(&self) -> Vec<PageConfig> {
+        let mut pages = self.pages.clone();
+        pages.sort_by_key(|p| p.order);
+        pages
+    }
 
-- They talk more than they listen
-- They start proposing solutions before understanding your problem
-- They promise an exact price without asking many questions
-- They can't explain things in plain English
-- They seem annoyed by your questions
+    pub fn page_mut(&mut self, id: &str) -> Option<&mut PageConfig> {
+        self.pages.iter_mut().find(|p| p.id == id)
+    }
 
-## Green Flags
+    /// Move a page from one navigation position to another and renumber
+    /// `order` sequentially from 1. Positions refer to the sorted order.
+    pub fn move_page(&mut self, from: usize, to: usize) {
+        let mut pages = self.pages_in_order();
+        if from >= pages.len() || to >= pages.len() {
+            return;
+        }
+        let page = pages.remove(from);
+        pages.insert(to, page);
+        for (i, page) in pages.iter_mut().enumerate() {
+            page.order = i as u32 + 1;
+        }
+        self.pages = pages;
+    }
+}
 
-- They ask lots of clarifying questions
-- They push back on requirements that don't make sense
-- They're honest about what they don't know
-- They suggest starting smaller than you proposed
-- They explain the tradeoffs of different approaches
+// Articles, services, portfolio projects and testimonials are authored as
+// files in `content/`; build.rs checks them and compiles them to JSON at
+// build time.
 
-## After the Meeting
+impl Default for ArticlesData {
+    fn default() -> Self {
+        compiled_content(
+            &COMPILED_ARTICLES,
+            include_str!(concat!(env!("OUT_DIR"), "/articles.json")),
+        )
+    }
+}
 
-A good developer will follow up with a summary of what they understood and next steps. If you have to chase them, imagine how it'll be during the project.
+impl Default for PortfolioData {
+    fn default() -> Self {
+        compiled_content(
+            &COMPILED_PORTFOLIO,
+            include_str!(concat!(env!("OUT_DIR"), "/portfolio.json")),
+        )
+    }
+}
 
-Trust your gut. You're going to work closely with this person. If something feels off in the first meeting, it won't get better."#.to_string(),
-                    status: ArticleStatus::Published,
-                },
-                // New Article 5
-                Article {
-                    id: "roi-business-automation".to_string(),
-                    title: "The Real ROI of Business Automation".to_string(),
-                    slug: "roi-business-automation".to_string(),
-                    date: "2026-01-04".to_string(),
-                    category: "Strategy".to_string(),
-                    excerpt: "Automation saves time, but how much? Here's how to calculate whether it's worth it for your business.".to_string(),
-                    content: r#"Everyone talks about automation saving time. But how do you know if it's actually worth investing in? Here's how to think about it.
+impl Default for ServicesData {
+    fn default() -> Self {
+        compiled_content(
+            &COMPILED_SERVICES,
+            include_str!(concat!(env!("OUT_DIR"), "/services.json")),
+        )
+    }
+}
 
-## The Basic Math
+impl Default for TestimonialsData {
+    fn default() -> Self {
+        compiled_content(
+            &COMPILED_TESTIMONIALS,
+            include_str!(concat!(env!("OUT_DIR"), "/testimonials.json")),
+        )
+    }
+}
 
-Calculate how long a task takes manually. Multiply by how often you do it. Multiply by your hourly rate (or the rate of whoever's doing it). That's the cost of not automating.
+thread_local! {
+    // Parsed on first use; every load starts from a copy
+    static COMPILED_ARTICLES: OnceCell<ArticlesData> = const { OnceCell::new() };
+    static COMPILED_PORTFOLIO: OnceCell<PortfolioData> = const { OnceCell::new() };
+    static COMPILED_SERVICES: OnceCell<ServicesData> = const { OnceCell::new() };
+    static COMPILED_TESTIMONIALS: OnceCell<TestimonialsData> = const { OnceCell::new() };
+}
 
-Example: A 15-minute task done 4 times a day at $50/hour costs $250/week, or $13,000/year in labor.
+/// Content compiled by build.rs, deserialized the first time it's asked for
+fn compiled_content<T: Clone + serde::de::DeserializeOwned>(
+    cell: &'static LocalKey<OnceCell<T>>,
+    json: &'static str,
+) -> T {
+    cell.with(|cell| {
+        cell.get_or_init(|| {
+            serde_json::from_str(json).expect("build.rs emits content in the shape of these types")
+        })
+        .clone()
+    })
+}
 
-## What's Worth Automating
+impl Slugged for Article {
+    fn id(&self) -> &str {
+        &self.id
+    }
+    fn slug(&self) -> &str {
+        &self.slug
+    }
+    fn is_published(&self) -> bool {
+        matches!(self.status, ArticleStatus::Published)
+    }
+}
 
-<a href="https://zapier.com" target="_blank" rel="noopener noreferrer">Zapier's State of Business Automation report</a> found that the biggest time savings come from:
+impl Collection for ArticlesData {
+    type Item = Article;
 
-**Lead management:** Automatically adding form submissions to your CRM, sending follow-up emails, notifying sales. <a href="https://hubspot.com" target="_blank" rel="noopener noreferrer">HubSpot</a> does much of this out of the box.
+    fn into_parts(self) -> (Vec<Article>, SlugRedirects) {
+        (self.articles, self.redirects)
+    }
+    fn from_parts(articles: Vec<Article>, redirects: SlugRedirects) -> Self {
+        Self {
+            articles,
+            redirects,
+        }
+    }
+}
 
-**Appointment scheduling:** Letting customers book themselves instead of back-and-forth emails. <a href="https://calendly.com" target="_blank" rel="noopener noreferrer">Calendly</a> and <a href="https://cal.com" target="_blank" rel="noopener noreferrer">Cal.com</a> handle this.
+impl Collection for ServicesData {
+    type Item = Service;
 
-**Invoice and payment follow-up:** Automatic reminders for unpaid invoices. <a href="https://stripe.com/billing" target="_blank" rel="noopener noreferrer">Stripe Billing</a> and <a href="https://quickbooks.intuit.com" target="_blank" rel="noopener noreferrer">QuickBooks</a> can do this.
+    fn into_parts(self) -> (Vec<Service>, SlugRedirects) {
+        (self.services, self.redirects)
+    }
+    fn from_parts(services: Vec<Service>, redirects: SlugRedirects) -> Self {
+        Self {
+            services,
+            redirects,
+        }
+    }
+}
 
-**Data entry:** Moving information between systems. Forms to spreadsheets, orders to inventory, leads to CRM.
+impl Collection for PortfolioData {
+    type Item = PortfolioProject;
 
-## What's Not Worth Automating
+    fn into_parts(self) -> (Vec<PortfolioProject>, SlugRedirects) {
+        (self.projects, self.redirects)
+    }
+    fn from_parts(projects: Vec<PortfolioProject>, redirects: SlugRedirects) -> Self {
+        Self {
+            projects,
+            redirects,
+        }
+    }
+}
 
-- Tasks you do once a month for 5 minutes
-- Processes that aren't standardized yet
-- Anything requiring human judgment on every instance
-- Things where the automation costs more than the time saved
+impl Collection for TestimonialsData {
+    type Item = Testimonial;
 
-## How to Start
+    /// Testimonials have no pages, so nothing redirects
+    fn into_parts(self) -> (Vec<Testimonial>, SlugRedirects) {
+        (self.testimonials, SlugRedirects::default())
+    }
+    fn from_parts(testimonials: Vec<Testimonial>, _: SlugRedirects) -> Self {
+        Self { testimonials }
+    }
+}
 
-1. Track your time for a week. What tasks are repetitive?
-2. Pick the one that's most frequent AND most annoying
-3. Check if <a href="https://zapier.com" target="_blank" rel="noopener noreferrer">Zapier</a> or <a href="https://make.com" target="_blank" rel="noopener noreferrer">Make</a> can connect the tools you're already using
-4. Start simple. One automation that handles one thing.
-5. Measure the before and after
+/// A testimonial's file name is its id; with no page of its own it never
+/// leaves a redirect behind
+impl Slugged for Testimonial {
+    fn id(&self) -> &str {
+        &self.id
+    }
+    fn slug(&self) -> &str {
+        &self.id
+    }
+    fn is_published(&self) -> bool {
+        false
+    }
+}
 
-## The Compound Effect
+impl Slugged for Service {
+    fn id(&self) -> &str {
+        &self.id
+    }
+    fn slug(&self) -> &str {
+        &self.slug
+    }
+    fn is_published(&self) -> bool {
+        matches!(self.status, ArticleStatus::Published)
+    }
+}
 
-The real value of automation isn't just the time saved. It's that automated tasks don't get forgotten, don't have bad days, and don't quit. Every automation you build is an asset that keeps working.
+impl Slugged for PortfolioProject {
+    fn id(&self) -> &str {
+        &self.id
+    }
+    fn slug(&self) -> &str {
+        &self.slug
+    }
+    fn is_published(&self) -> bool {
+        matches!(self.status, ArticleStatus::Published)
+    }
+}
 
-Small businesses that systematically automate routine work create compounding efficiency advantages over competitors still doing everything manually."#.to_string(),
-                    status: ArticleStatus::Published,
-                },
-                // New Article 6
-                Article {
-                    id: "competitor-website-faster".to_string(),
-                    title: "Why Your Competitor's Website Loads Faster (And Why It Matters)".to_string(),
-                    slug: "competitor-website-faster".to_string(),
-                    date: "2025-12-28".to_string(),
-                    category: "Strategy".to_string(),
-                    excerpt: "Website speed affects your search rankings, conversion rates, and bottom line. Here's how to fix it.".to_string(),
-                    content: r#"Pull up your website and a competitor's side by side. Which loads faster? If it's not yours, you're losing money.
+/// Slugs must already be in `generate_slug` form so links never change shape
+fn validate_slug(slug: &str) -> Result<(), String> {
+    if slug.is_empty() {
+        return Err("Slug is required".to_string());
+    }
+    if Article::generate_slug(slug) != slug {
+        return Err(format!(
+            "Slug \"{}\" may only contain lowercase letters, numbers and hyphens",
+            slug
+        ));
+    }
+    Ok(())
+}
 
-## Why Speed Matters
+fn validate_icon(icon: &IconName, field: &str) -> Result<(), String> {
+    if icon.is_known() {
+        Ok(())
+    } else {
+        Err(format!("{} icon \"{}\" isn't a known icon", field, icon))
+    }
+}
 
-Google explicitly uses site speed in search rankings. <a href="https://developers.google.com/search/docs/appearance/core-web-vitals" target="_blank" rel="noopener noreferrer">Their Core Web Vitals update</a> made it official: slow sites rank lower.
+/// Optional links must be absolute http(s) URLs
+fn validate_url(url: &str, field: &str) -> Result<(), String> {
+    if url.starts_with("https://") || url.starts_with("http://") {
+        Ok(())
+    } else {
+        Err(format!("{} must start with http:// or https://", field))
+    }
+}
 
-But rankings aren't the only issue. Every second of load time <a href="https://web.dev/why-speed-matters/" target="_blank" rel="noopener noreferrer">costs you roughly 7% in conversions</a>. If your site makes $100,000/year and takes 2 extra seconds to load, that's potentially $14,000 you're leaving on the table.
+impl Service {
+    pub fn new() -> Self {
+        Self {
+            id: generate_id(),
+            slug: String::new(),
+            title: String::new(),
+            icon: IconName::Briefcase,
+            tagline: String::new(),
+            description: String::new(),
+            long_description: String::new(),
+            features: Vec::new(),
+            personas: Vec::new(),
+            accent_color: "#D4A017".to_string(),
+            pinned_projects: Vec::new(),
+            status: ArticleStatus::Draft,
+            translations: Translations::new(),
+        }
+    }
 
-## Common Reasons Your Site Is Slow
+    /// Check required fields, the slug, the accent color and every icon name
+    pub fn validate(&self) -> Result<(), String> {
+        if self.title.trim().is_empty() {
+            return Err("Title is required".to_string());
+        }
+        validate_slug(&self.slug)?;
+        validate_icon(&self.icon, "Service")?;
+        self.accent_color
+            .parse::<Color>()
+            .map_err(|e| format!("Accent color: {}", e))?;
+        if self.description.trim().is_empty() {
+            return Err("Description is required".to_string());
+        }
+        for persona in &self.personas {
+            if persona.title.trim().is_empty() {
+                return Err("Every persona needs a title".to_string());
+            }
+            validate_icon(&persona.icon, &format!("Persona \"{}\"", persona.title))?;
+        }
+        Ok(())
+    }
+}
 
-### Cheap Hosting
-Shared hosting for $5/month means your site shares a server with hundreds of others. When traffic spikes, everyone slows down. <a href="https://cloudflare.com" target="_blank" rel="noopener noreferrer">Cloudflare</a> can help by caching your content on servers worldwide.
+impl PortfolioProject {
+    pub fn new() -> Self {
+        Self {
+            id: generate_id(),
+            slug: String::new(),
+            title: String::new(),
+            project_type: String::new(),
+            description: String::new(),
+            long_description: String::new(),
+            external_url: String::new(),
+            before_url: None,
+            logo: None,
+            screenshot: None,
+            video: None,
+            tech_tags: Vec::new(),
+            scope: Vec::new(),
+            featured: false,
+            sort_order: 0,
+            case_study: CaseStudy::default(),
+            pinned_services: Vec::new(),
+            status: ArticleStatus::Draft,
+            translations: Translations::new(),
+        }
+    }
 
-### Unoptimized Images
-A single large image can slow your entire page. Use <a href="https://tinypng.com" target="_blank" rel="noopener noreferrer">TinyPNG</a> or convert to WebP format. Better yet, use a CDN that handles this automatically.
+    /// Check required fields, the slug and the external links
+    pub fn validate(&self) -> Result<(), String> {
+        if self.title.trim().is_empty() {
+            return Err("Title is required".to_string());
+        }
+        validate_slug(&self.slug)?;
+        if self.project_type.trim().is_empty() {
+            return Err("Project type is required".to_string());
+        }
+        if self.description.trim().is_empty() {
+            return Err("Description is required".to_string());
+        }
+        validate_url(&self.external_url, "Site URL")?;
+        if let Some(before_url) = &self.before_url {
+            validate_url(before_url, "Before URL")?;
+        }
+        Ok(())
+    }
 
-### Too Many Plugins
-Every WordPress plugin adds code. Most add code even on pages where they're not used. Each one makes your site slower.
+    /// Whether the project lists a tech tag with this slug
+    pub fn has_tag(&self, slug: &str) -> bool {
+        self.tech_tags.iter().any(|tag| tag_slug(tag) == slug)
+    }
 
-### Heavy Page Builders
-Those drag-and-drop builders are convenient, but they generate bloated code. A simple page built with a page builder can be 10x heavier than the same page coded directly.
+    /// Before and after screenshots, when both exist for the comparison slider
+    pub fn before_after(&self) -> Option<(&str, &str)> {
+        let before = self.case_study.before_screenshot.as_deref()?;
+        let after = self.screenshot.as_deref()?;
+        (!before.is_empty() && !after.is_empty()).then_some((before, after))
+    }
+}
 
-### Third-Party Scripts
-Every analytics tool, chat widget, and tracking pixel adds load time. The Facebook pixel, Google Analytics, Intercom chat, HotJar recordings. They add up.
+impl Article {
+    pub fn new() -> Self {
+        let id = generate_id();
+        Self {
+            id: id.clone(),
+            title: String::new(),
+            slug: String::new(),
+            date: chrono_today(),
+            category: "General".to_string(),
+            excerpt: String::new(),
+            content: String::new(),
+            status: ArticleStatus::Draft,
+            pinned_articles: vec![],
+            translations: Translations::new(),
+        }
+    }
 
-## How to Check
+    /// Generate a URL-friendly slug from the title
+    pub fn generate_slug(title: &str) -> String {
+        title
+            .to_lowercase()
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { '-' })
+            .collect::<String>()
+            .split('-')
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+}
 
-Run your site through <a href="https://pagespeed.web.dev/" target="_blank" rel="noopener noreferrer">PageSpeed Insights</a>. Score below 50 on mobile? You have work to do. <a href="https://gtmetrix.com" target="_blank" rel="noopener noreferrer">GTmetrix</a> shows you exactly what's slowing things down.
+impl ArticlesData {
+    /// Find a published article by slug, following redirects for old slugs.
+    ///
+    /// Returns the article and whether the slug was redirected.
+    pub fn find_published(&self, slug: &str) -> Option<(&Article, bool)> {
+        let published = |s: &str| {
+            self.articles
+                .iter()
+                .find(|a| a.slug == s && matches!(a.status, ArticleStatus::Published))
+        };
+        if let Some(article) = published(slug) {
+            return Some((article, false));
+        }
+        self.redirects
+            .resolve(slug)
+            .and_then(published)
+            .map(|article| (article, true))
+    }
 
-<a href="https://tools.pingdom.com" target="_blank" rel="noopener noreferrer">Pingdom</a> tests from different locations. Your site might be fast locally but slow for visitors across the country.
+    /// Whether `slug` is free for the article with `id`.
+    ///
+    /// Old slugs that redirect to another article are reserved so their
+    /// inbound links keep working.
+    pub fn is_slug_available(&self, id: &str, slug: &str) -> bool {
+        slug_available(&self.articles, &self.redirects, id, slug)
+    }
 
-## Quick Wins
+    /// A free variant of `slug` for the article with `id`, e.g. `foo-2`
+    pub fn suggest_slug(&self, id: &str, slug: &str) -> String {
+        unique_slug(slug, |candidate| !self.is_slug_available(id, candidate))
+    }
 
-- Enable caching (if you're on WordPress, use a caching plugin)
-- Compress images before uploading
-- Remove plugins you're not actively using
-- Consider a CDN like <a href="https://cloudflare.com" target="_blank" rel="noopener noreferrer">Cloudflare</a> (free tier works for most small sites)
-- Audit your third-party scripts; remove ones that aren't earning their keep
+    /// Insert or replace an article, recording a redirect when a published
+    /// article's slug changes.
+    pub fn upsert(&mut self, article: Article) {
+        upsert_slugged(&mut self.articles, &mut self.redirects, article);
+    }
+}
 
-## When You Need More
+impl ServicesData {
+    /// Only the published services, for the public site
+    pub fn published(mut self) -> Self {
+        self.services
+            .retain(|s| matches!(s.status, ArticleStatus::Published));
+        self
+    }
 
-Sometimes the foundation is the problem. A site built on bloated technology can only be optimized so much. At some point, starting fresh with a clean, fast foundation is the better investment."#.to_string(),
-                    status: ArticleStatus::Published,
-                },
-                // New Article 7
-                Article {
-                    id: "email-marketing-service-business".to_string(),
-                    title: "Email Marketing That Actually Works for Service Businesses".to_string(),
-                    slug: "email-marketing-service-business".to_string(),
-                    date: "2025-12-22".to_string(),
-                    category: "Advice".to_string(),
-                    excerpt: "Email marketing isn't just for e-commerce. Here's how service businesses can use it to stay top of mind and win more clients.".to_string(),
-                    content: r#"Service businesses often think email marketing is just for online stores. It's not. Email is one of the best ways to stay in front of potential clients until they're ready to buy.
+    pub fn is_slug_available(&self, id: &str, slug: &str) -> bool {
+        slug_available(&self.services, &self.redirects, id, slug)
+    }
 
-## Why Email Still Works
+    pub fn suggest_slug(&self, id: &str, slug: &str) -> String {
+        unique_slug(slug, |candidate| !self.is_slug_available(id, candidate))
+    }
 
-Social media algorithms decide who sees your posts. Email goes directly to their inbox. Open rates for business email <a href="https://mailchimp.com/resources/email-marketing-benchmarks/" target="_blank" rel="noopener noreferrer">average 20-25%</a>. That beats social media reach by a mile.
+    /// Insert or replace a service, redirecting a published service's old slug
+    pub fn upsert(&mut self, service: Service) {
+        upsert_slugged(&mut self.services, &mut self.redirects, service);
+    }
 
-## What to Send
+    /// Find a service by slug, following redirects for old slugs
+    pub fn find(&self, slug: &str) -> Option<(&Service, bool)> {
+        let find = |s: &str| self.services.iter().find(|service| service.slug == s);
+        if let Some(service) = find(slug) {
+            return Some((service, false));
+        }
+        self.redirects
+            .resolve(slug)
+            .and_then(find)
+            .map(|service| (service, true))
+    }
+}
 
-### The Welcome Sequence
-When someone joins your list, send 3-5 emails over a couple weeks. Introduce yourself. Share your best advice. Help them before asking for anything.
+impl PortfolioData {
+    /// Only the published projects, for the public site
+    pub fn published(mut self) -> Self {
+        self.projects
+            .retain(|p| matches!(p.status, ArticleStatus::Published));
+        self
+    }
 
-### Regular Value
-Monthly or bi-weekly updates with useful information. Not sales pitches. Tips they can actually use. Industry news that affects them. Answers to common questions you hear.
+    pub fn is_slug_available(&self, id: &str, slug: &str) -> bool {
+        slug_available(&self.projects, &self.redirects, id, slug)
+    }
 
-### Occasional Offers
-After you've provided value, you've earned the right to ask. A promotion, a new service, a seasonal offer. But keep the ratio heavily tilted toward value.
+    pub fn suggest_slug(&self, id: &str, slug: &str) -> String {
+        unique_slug(slug, |candidate| !self.is_slug_available(id, candidate))
+    }
 
-## Tools for Service Businesses
+    /// Insert or replace a project, redirecting a published project's old slug
+    pub fn upsert(&mut self, project: PortfolioProject) {
+        upsert_slugged(&mut self.projects, &mut self.redirects, project);
+    }
 
-<a href="https://mailchimp.com" target="_blank" rel="noopener noreferrer">Mailchimp</a> is fine for getting started. Free up to 500 contacts. <a href="https://convertkit.com" target="_blank" rel="noopener noreferrer">ConvertKit</a> is better for creators and consultants. <a href="https://klaviyo.com" target="_blank" rel="noopener noreferrer">Klaviyo</a> is overkill unless you're doing serious e-commerce.
+    /// Find a project by slug, following redirects for old slugs
+    pub fn find(&self, slug: &str) -> Option<(&PortfolioProject, bool)> {
+        let find = |s: &str| self.projects.iter().find(|project| project.slug == s);
+        if let Some(project) = find(slug) {
+            return Some((project, false));
+        }
+        self.redirects
+            .resolve(slug)
+            .and_then(find)
+            .map(|project| (project, true))
+    }
 
-The tool matters less than actually sending emails consistently.
+    /// Projects in display order: featured first, then by `sort_order`
+    pub fn sorted(&self) -> Vec<&PortfolioProject> {
+        let mut projects: Vec<&PortfolioProject> = self.projects.iter().collect();
+        projects.sort_by_key(|p| (!p.featured, p.sort_order));
+        projects
+    }
 
-## Building Your List
+    /// Projects matching an optional project type and tech tag slug, in display order
+    pub fn filter(&self, project_type: Option<&str>, tag: Option<&str>) -> Vec<&PortfolioProject> {
+        self.sorted()
+            .into_iter()
+            .filter(|p| project_type.is_none_or(|t| p.project_type == t))
+            .filter(|p| tag.is_none_or(|t| p.has_tag(t)))
+            .collect()
+    }
 
-- Add a signup form to your website (not a popup, a visible form)
-- Offer something useful in exchange: a guide, a checklist, a video
-- Collect emails at in-person events
-- Ask satisfied clients if they'd like to hear from you
+    /// Distinct project types, in display order of their first project
+    pub fn project_types(&self) -> Vec<&str> {
+        let mut types: Vec<&str> = Vec::new();
+        for project in self.sorted() {
+            if !types.contains(&project.project_type.as_str()) {
+                types.push(&project.project_type);
+            }
+        }
+        types
+    }
 
-## The Metrics That Matter
+    /// Distinct tech tags with how many projects use each, most used first
+    pub fn tech_tags(&self) -> Vec<(&str, usize)> {
+        let mut tags: Vec<(&str, usize)> = Vec::new();
+        for tag in self.projects.iter().flat_map(|p| &p.tech_tags) {
+            let slug = tag_slug(tag);
+            match tags.iter_mut().find(|(t, _)| tag_slug(t) == slug) {
+                Some((_, count)) => *count += 1,
+                None => tags.push((tag, 1)),
+            }
+        }
+        tags.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        tags
+    }
 
-**Open rate:** Are people actually reading? Below 15% means your subject lines are boring or your list is stale.
+    /// Display name of the tech tag with this slug
+    pub fn tag_name(&self, slug: &str) -> Option<&str> {
+        self.projects
+            .iter()
+            .flat_map(|p| &p.tech_tags)
+            .find(|tag| tag_slug(tag) == slug)
+            .map(String::as_str)
+    }
+}
 
-**Click rate:** Are they taking action? 2-3% is typical.
+impl ProjectResult {
+    /// Percentage change from before to after, or None when before is zero
+    pub fn change_percent(&self) -> Option<f64> {
+        (self.before != 0.0).then(|| (self.after - self.before) / self.before.abs() * 100.0)
+    }
 
-**Unsubscribes:** A few per email is normal. A spike means you sent something off-putting.
+    /// Whether the metric moved in the right direction
+    pub fn improved(&self) -> bool {
+        if self.lower_is_better {
+            self.after < self.before
+        } else {
+            self.after > self.before
+        }
+    }
 
-## Starting Simple
+    pub fn format_before(&self) -> String {
+        format_metric(self.before, &self.unit)
+    }
 
-Don't overthink it. Start with one email per month to past and potential clients. Share something useful. Include a clear call to action. See what happens.
+    pub fn format_after(&self) -> String {
+        format_metric(self.after, &self.unit)
+    }
 
-Most service businesses find that just showing up consistently in inboxes wins more business than any fancy campaign. The bar is low because most of your competitors aren't doing it at all."#.to_string(),
-                    status: ArticleStatus::Published,
-                },
-            ],
+    /// Signed change for the badge, e.g. "+45%" or "-1.2s" when before is zero
+    pub fn format_change(&self) -> String {
+        match self.change_percent() {
+            Some(pct) => format!("{:+.0}%", pct),
+            None => {
+                let diff = self.after - self.before;
+                let sign = if diff < 0.0 { "-" } else { "+" };
+                format!("{}{}", sign, format_metric(diff.abs(), &self.unit))
+            }
         }
     }
 }
 
-impl Default for PortfolioData {
-    fn default() -> Self {
+impl Testimonial {
+    pub fn new() -> Self {
         Self {
-            projects: vec![
-                PortfolioProject {
-                    id: "paytient".to_string(),
-                    slug: "paytient".to_string(),
-                    title: "Paytient".to_string(),
-                    project_type: "Product Development".to_string(),
-                    description: "Contributed to a healthcare fintech startup serving hundreds of thousands of users. Removed friction from the onboarding flow, redesigned the my.paytient.com landing page, and led a team of 6 engineers implementing multi-factor authentication.".to_string(),
-                    long_description: r#"## The Challenge
-
-Paytient is a healthcare fintech company that helps employees pay for medical expenses over time. With hundreds of thousands of users depending on the platform, every interaction matters.
-
-## What We Did
-
-### Onboarding Flow Optimization
-We identified friction points in the member onboarding process and redesigned the flow to reduce drop-off rates. By simplifying the steps and improving the UI, we helped more users successfully complete registration.
-
-### Landing Page Redesign
-The my.paytient.com member portal landing page needed a refresh. We designed and implemented a cleaner, more intuitive experience that better communicated available benefits and next steps.
-
-### Multi-Factor Authentication
-Security is critical for financial applications. We led a team of 6 engineers to implement MFA across the entire platform, protecting user accounts while maintaining a smooth experience.
-
-## Results
-
-- Improved onboarding completion rates
-- Better member engagement on the portal
-- Enterprise-grade security with MFA
-- Scalable architecture supporting continued growth"#.to_string(),
-                    external_url: "https://my.paytient.com".to_string(),
-                    before_url: None,
-                    logo: Some("assets/portfolio/paytient-logo.svg".to_string()),
-                    screenshot: None,
-                    video: None,
-                    tech_tags: vec!["React".to_string(), "UX".to_string(), "MFA".to_string(), "Team Lead".to_string()],
-                    scope: vec![
-                        "Streamlined onboarding by removing unnecessary friction step".to_string(),
-                        "Redesigned member landing page for better engagement".to_string(),
-                        "Led 6-person team implementing MFA across the platform".to_string(),
-                    ],
-                },
-                PortfolioProject {
-                    id: "club-car-wash".to_string(),
-                    slug: "club-car-wash".to_string(),
-                    title: "Club Car Wash".to_string(),
-                    project_type: "Website + Portal + Digital Marketing".to_string(),
-                    description: "Complete digital transformation for a growing regional car wash chain. Built and managed the public-facing website, developed an internal employee portal, and ran ongoing Google Ads campaigns to support new store openings.".to_string(),
-                    long_description: r#"## The Challenge
-
-Club Car Wash is a rapidly expanding car wash chain opening approximately 3 new stores per month. They needed a complete digital presence that could scale with their growth.
-
-## What We Did
-
-### Public Website
-Designed and developed a customer-facing website that showcases locations, membership options, and the Club Car Wash brand. The site is built for easy updates as new locations open.
-
-### Employee Portal
-Created an internal portal for employee operations, streamlining internal communication and processes across all locations.
-
-### Digital Marketing
-Managed Google Ads campaigns coordinated with new store openings, driving local awareness and membership sign-ups in each new market.
-
-## Results
-
-- Scalable website supporting rapid expansion
-- Centralized employee portal across all locations
-- Successful launch marketing for ~3 store openings per month
-- 1+ year of ongoing maintenance and support"#.to_string(),
-                    external_url: "https://clubcarwash.com".to_string(),
-                    before_url: None,
-                    logo: Some("assets/portfolio/club-car-wash-logo-for-Black-Background_Transparent.png".to_string()),
-                    screenshot: None,
-                    video: None,
-                    tech_tags: vec!["React".to_string(), "Custom CMS".to_string(), "Google Ads".to_string()],
-                    scope: vec![
-                        "Public website design and development".to_string(),
-                        "Employee portal for internal operations".to_string(),
-                        "Google Ad campaign management (~3 store openings/month)".to_string(),
-                        "Ongoing maintenance and support for 1 year".to_string(),
-                    ],
-                },
-                PortfolioProject {
-                    id: "old-hawthorne".to_string(),
-                    slug: "old-hawthorne".to_string(),
-                    title: "Old Hawthorne Country Club".to_string(),
-                    project_type: "Website Consulting".to_string(),
-                    description: "Consulting work for a local country club community in Columbia, Missouri. Made targeted adjustments to improve the site's look and navigation, including replacing the dated beige wallpaper background with a cleaner design.".to_string(),
-                    long_description: r#"## The Challenge
-
-Old Hawthorne Country Club had an existing website that felt dated and didn't reflect the quality of the community. They needed targeted improvements without a complete rebuild.
-
-## What We Did
-
-### Visual Refresh
-The most obvious issue was a dated beige wallpaper background that made the site feel old. We replaced it with a cleaner, more modern design that better represents the club.
-
-### Navigation Improvements
-Reorganized the site navigation to make it easier for members and prospective members to find information about amenities, membership, and events.
-
-### UX Enhancements
-Made various usability improvements throughout the site to create a more polished experience.
-
-## Results
-
-- Modern, professional appearance
-- Improved navigation and usability
-- Better reflection of the club's quality"#.to_string(),
-                    external_url: "https://oldhawthorne.com".to_string(),
-                    before_url: None,
-                    logo: Some("assets/portfolio/old-hawthorne-logo.png".to_string()),
-                    screenshot: None,
-                    video: None,
-                    tech_tags: vec!["Consulting".to_string(), "UI Cleanup".to_string(), "UX".to_string()],
-                    scope: vec![
-                        "Replaced dated beige wallpaper background".to_string(),
-                        "Improved site navigation and layout".to_string(),
-                    ],
-                },
-                PortfolioProject {
-                    id: "gracie-humaita-columbia".to_string(),
-                    slug: "gracie-humaita-columbia".to_string(),
-                    title: "Gracie Humaita Columbia".to_string(),
-                    project_type: "Website + SMTP Integration".to_string(),
-                    description: "Website for a Brazilian Jiu-Jitsu academy with integrated email automation. Designed to showcase class schedules, instructor profiles, and drive new student sign-ups with automated follow-up.".to_string(),
-                    long_description: r#"## The Challenge
-
-Gracie Humaita Columbia is a Brazilian Jiu-Jitsu academy that needed a professional web presence to attract new students and communicate with their community.
-
-## What We Did
-
-### Website Design
-Created a mobile-first website that showcases the academy's programs, class schedules, and instructor profiles. The design reflects the Gracie Humaita brand while being accessible to beginners.
-
-### Lead Capture
-Built a lead capture system to collect information from prospective students interested in trying classes.
-
-### Email Automation
-Integrated SMTP-based email automation to automatically follow up with new leads, keeping them engaged until they come in for their first class.
-
-## Results
-
-- Professional web presence for the academy
-- Automated lead follow-up saving staff time
-- Mobile-friendly design for on-the-go access"#.to_string(),
-                    external_url: "https://graciehumaitacolumbia.com".to_string(),
-                    before_url: Some("https://web.archive.org/web/20190723164913/http://www.graciehumaitacolumbia.com/".to_string()),
-                    logo: Some("assets/portfolio/gracie-humaita-columbia-logo.png".to_string()),
-                    screenshot: None,
-                    video: None,
-                    tech_tags: vec!["Mobile-First".to_string(), "Lead Capture".to_string(), "SMTP".to_string()],
-                    scope: vec![
-                        "Automated email follow-up for new leads".to_string(),
-                        "Class schedule and instructor profiles".to_string(),
-                    ],
-                },
-                PortfolioProject {
-                    id: "att-indianapolis".to_string(),
-                    slug: "att-indianapolis".to_string(),
-                    title: "American Top Team Indianapolis".to_string(),
-                    project_type: "Website Replacement".to_string(),
-                    description: "Replaced a broken, outdated website for a martial arts training facility. Built a clean, professional site with focus on easy navigation for prospective students.".to_string(),
-                    long_description: r#"## The Challenge
-
-American Top Team Indianapolis had an old, broken website that wasn't functioning properly. Prospective students couldn't find information about classes or contact the gym.
-
-## What We Did
-
-### Complete Replacement
-Rather than trying to fix the broken site, we built a completely new website from scratch with modern technology and design.
-
-### Professional Design
-Created a clean, professional design that showcases the gym's programs and instructors. The site reflects ATT's brand while being welcoming to beginners.
-
-### SEO Optimization
-Built the site with SEO best practices to help the gym appear in local search results.
-
-## Results
-
-- Fully functional website replacing broken one
-- Professional appearance reflecting ATT brand
-- Easy navigation for prospective students
-- Improved local search visibility"#.to_string(),
-                    external_url: "https://attindianapolis.com".to_string(),
-                    before_url: Some("https://web.archive.org/web/20200530220933/http://www.attindianapolis.com/".to_string()),
-                    logo: Some("assets/portfolio/att-indianapolis-logo.png".to_string()),
-                    screenshot: None,
-                    video: None,
-                    tech_tags: vec!["Responsive".to_string(), "SEO".to_string()],
-                    scope: vec![
-                        "Replaced old broken website".to_string(),
-                        "Clean, professional design".to_string(),
-                    ],
-                },
-                PortfolioProject {
-                    id: "apex-earthworks".to_string(),
-                    slug: "apex-earthworks".to_string(),
-                    title: "APEX Earthworks".to_string(),
-                    project_type: "Website + Lead Generation".to_string(),
-                    description: "Business website for an earthwork and excavation company. Professional presentation with automated customer lead generation to capture and follow up with potential clients.".to_string(),
-                    long_description: r#"## The Challenge
-
-APEX Earthworks is an earthwork and excavation company that needed an online presence to attract commercial and residential clients.
-
-## What We Did
-
-### Professional Website
-Built a business website that showcases APEX's services, equipment, and past projects. The design conveys professionalism and capability.
-
-### Lead Generation
-Implemented a lead capture system with automated follow-up to ensure no potential client falls through the cracks.
-
-### Mobile Optimization
-Ensured the site works perfectly on mobile devices, since many potential clients search for services on their phones.
-
-## Results
-
-- Professional online presence
-- Automated lead capture and follow-up
-- Mobile-friendly experience"#.to_string(),
-                    external_url: "https://apexearthwork.com".to_string(),
-                    before_url: None,
-                    logo: Some("assets/portfolio/apex-earthworks-logo.png".to_string()),
-                    screenshot: None,
-                    video: None,
-                    tech_tags: vec!["Lead Gen Automation".to_string(), "Mobile".to_string()],
-                    scope: vec![
-                        "Automated lead capture and follow-up".to_string(),
-                        "Professional company showcase".to_string(),
-                    ],
-                },
-                PortfolioProject {
-                    id: "missouri-jiu-jitsu".to_string(),
-                    slug: "missouri-jiu-jitsu".to_string(),
-                    title: "Missouri Jiu Jitsu".to_string(),
-                    project_type: "Website Development".to_string(),
-                    description: "Demo website with a mock jiu-jitsu academy featuring class information, instructor bios, and signup flow automation.".to_string(),
-                    long_description: r#"## The Project
-
-Missouri Jiu Jitsu is a demo website showcasing our capabilities for martial arts academies. It demonstrates a complete solution including class schedules, instructor profiles, and member sign-up flows.
-
-## Features
-
-### Class Schedules
-Easy-to-read class schedule showing times, instructors, and skill levels for each class.
-
-### Instructor Bios
-Professional profiles for each instructor, highlighting their background and expertise.
-
-### Membership Inquiry
-Lead capture forms for prospective students to request information or schedule a trial class.
-
-## Technical Details
-
-- Responsive design for all devices
-- Clean, modern aesthetic
-- Fast loading times
-- Easy content management"#.to_string(),
-                    external_url: "https://missourijiujitsu.com".to_string(),
-                    before_url: None,
-                    logo: Some("assets/portfolio/missouri-jiu-jitsu-logo.png".to_string()),
-                    screenshot: None,
-                    video: None,
-                    tech_tags: vec!["Responsive".to_string(), "Forms".to_string()],
-                    scope: vec![
-                        "Class schedules and instructor bios".to_string(),
-                        "Membership inquiry forms".to_string(),
-                    ],
-                },
-                PortfolioProject {
-                    id: "delaware-krav-maga".to_string(),
-                    slug: "delaware-krav-maga".to_string(),
-                    title: "Delaware Krav Maga".to_string(),
-                    project_type: "Landing Page + Lead Capture".to_string(),
-                    description: "Simple landing page with automated lead capture for a Krav Maga instructor's self-defense training service.".to_string(),
-                    long_description: r#"## The Challenge
-
-A Krav Maga instructor needed a simple, effective way to capture leads for his self-defense training service. No complex website needed, just a clean landing page that converts visitors into inquiries.
-
-## What We Did
-
-### Landing Page
-Built a focused, single-page site that communicates the value of the training and drives visitors to take action.
-
-### Automated Lead Capture
-Set up an automated form that captures prospect information and delivers it directly to the instructor, no manual follow-up required.
-
-## Results
-
-- Clean, professional landing page
-- Automated lead capture and delivery
-- Simple, low-maintenance solution"#.to_string(),
-                    external_url: "https://delawarekravmaga.com".to_string(),
-                    before_url: None,
-                    logo: Some("assets/portfolio/delaware-krav-maga-logo.png".to_string()),
-                    screenshot: None,
-                    video: None,
-                    tech_tags: vec!["Landing Page".to_string(), "Lead Capture".to_string(), "Automation".to_string()],
-                    scope: vec![
-                        "Landing page design".to_string(),
-                        "Automated lead capture form".to_string(),
-                    ],
-                },
-                PortfolioProject {
-                    id: "silo-wellness".to_string(),
-                    slug: "silo-wellness".to_string(),
-                    title: "Silo Wellness".to_string(),
-                    project_type: "Website Redesign".to_string(),
-                    description: "Website redesign for a wellness company offering healing retreats in Jamaica. Created an inviting, professional presence that communicates trust and tranquility to prospective guests.".to_string(),
-                    long_description: r#"## The Challenge
-
-Silo Wellness offers healing retreats in Jamaica, providing transformative wellness experiences. Their website needed to communicate trust, tranquility, and professionalism to prospective guests considering this significant investment in their wellbeing.
-
-## What We Did
-
-### Complete Redesign
-Redesigned the website from the ground up to create an inviting, professional experience that reflects the quality of the retreats.
-
-### Trust Building
-Incorporated elements that build trust: testimonials, clear information about what to expect, and professional photography showcasing the retreat experience.
-
-### Booking Flow
-Streamlined the inquiry and booking process to make it easy for interested visitors to take the next step.
-
-## Results
-
-- Professional, inviting web presence
-- Clear communication of retreat offerings
-- Streamlined booking inquiries
-- Improved trust signals throughout"#.to_string(),
-                    external_url: "https://silowellness.com".to_string(),
-                    before_url: None,
-                    logo: Some("assets/portfolio/silo-wellness-logo.png".to_string()),
-                    screenshot: None,
-                    video: None,
-                    tech_tags: vec!["Redesign".to_string(), "UX".to_string(), "Wellness".to_string()],
-                    scope: vec![
-                        "Complete website redesign".to_string(),
-                        "Healing retreat showcase".to_string(),
-                    ],
-                },
-                PortfolioProject {
-                    id: "toledo-aa".to_string(),
-                    slug: "toledo-aa".to_string(),
-                    title: "Toledo Area AA".to_string(),
-                    project_type: "Website Redesign".to_string(),
-                    description: "Website redesign for the Alcoholics Anonymous organization serving the Toledo region in Ohio. Built with accessibility and ease of use as top priorities to help those seeking support.".to_string(),
-                    long_description: r#"## The Challenge
-
-Toledo Area AA serves people seeking help with alcohol addiction in the Toledo, Ohio region. The website needed to be accessible, easy to use, and welcoming to people who may be in crisis.
-
-## What We Did
-
-### Accessibility Focus
-Built the site with accessibility as a top priority. Clear fonts, high contrast, and simple navigation ensure everyone can use the site regardless of ability.
-
-### Meeting Finder
-Created an easy-to-use meeting finder so visitors can quickly locate meetings near them by day, time, or location.
-
-### Resource Hub
-Organized resources and information in a clear, non-overwhelming way to help newcomers understand what to expect.
-
-## Results
-
-- Fully accessible website
-- Easy meeting finder functionality
-- Clear, welcoming design
-- Resources organized for newcomers"#.to_string(),
-                    external_url: "https://toledoaa.com".to_string(),
-                    before_url: None,
-                    logo: None,
-                    screenshot: None,
-                    video: None,
-                    tech_tags: vec!["Redesign".to_string(), "Accessibility".to_string(), "Community".to_string()],
-                    scope: vec![
-                        "Complete website redesign".to_string(),
-                        "Meeting finder and resources".to_string(),
-                    ],
-                },
-                PortfolioProject {
-                    id: "habiducer".to_string(),
-                    slug: "habiducer".to_string(),
-                    title: "Habiducer".to_string(),
-                    project_type: "Mobile App".to_string(),
-                    description: "Habit and goal tracking mobile app designed to help users build consistent routines and achieve their objectives through simple, effective tracking.".to_string(),
-                    long_description: r#"## The Project
-
-Habiducer is a habit and goal tracking application built to help people build better routines and achieve their personal objectives.
-
-## The Problem
-
-Most habit trackers overwhelm users with features they don't need. Complex analytics, social features, and gamification often distract from the core purpose: building consistent habits.
-
-## The Solution
-
-Habiducer takes a different approach. Clean interface. Simple tracking. Focus on what matters: showing up every day.
-
-### Key Features
-- Straightforward habit tracking without the clutter
-- Goal setting with progress visualization
-- Daily reminders to keep you accountable
-- Clean, distraction-free design
-
-## Why It Works
-
-The best habit tracker is the one you actually use. By removing unnecessary complexity, Habiducer helps users focus on building habits rather than managing an app."#.to_string(),
-                    external_url: "https://habiducer.com".to_string(),
-                    before_url: None,
-                    logo: None,
-                    screenshot: None,
-                    video: None,
-                    tech_tags: vec!["Mobile App".to_string(), "Habit Tracking".to_string(), "Goal Setting".to_string()],
-                    scope: vec![
-                        "Mobile app development".to_string(),
-                        "Habit tracking system".to_string(),
-                        "Goal progress visualization".to_string(),
-                    ],
-                },
-                PortfolioProject {
-                    id: "pounds-consulting".to_string(),
-                    slug: "pounds-consulting".to_string(),
-                    title: "Pounds Consulting".to_string(),
-                    project_type: "Open Source Website".to_string(),
-                    description: "This very website. Built with Rust and WebAssembly using the Dioxus framework. Open source, over-engineered with pride, and a template for anyone to use.".to_string(),
-                    long_description: r#"## The Project
-
-Yes, this is a case study about the website you're currently viewing. A consulting website built with Rust and WebAssembly. Because WordPress was too easy.
-
-## Why WebAssembly?
-
-Three reasons:
-
-1. **It's blazingly fast.** Near-native performance on every device.
-2. **It proves we can.** If we'll over-engineer our own website, imagine what we'll do for your actual problems.
-3. **It's a conversation starter.** You're reading this, aren't you?
-
-## Technical Details
-
-### Framework
-Built with Dioxus 0.7, a Rust-based framework that compiles to WebAssembly. The same technology that powers Figma, Google Earth, and AAA game engines.
-
-### Features
-- Full admin panel for managing articles and settings
-- Data-driven portfolio with individual case study pages
-- SEO optimization (sitemap, robots.txt, schema.org markup)
-- SPA routing on GitHub Pages with custom 404 handling
-- Dark theme with gold accents and glassmorphism design
-
-### Open Source
-The entire codebase is available on GitHub. Fork it, clone it, make it yours. The architecture is clean and the stack is modern.
-
-## Results
-
-- Sub-second load times
-- Perfect Lighthouse scores
-- A website that doubles as a portfolio piece
-- Open source template for the community"#.to_string(),
-                    external_url: "https://github.com/collinpounds/pounds-consulting".to_string(),
-                    before_url: None,
-                    logo: Some("assets/portfolio/Pounds-Consulting-Black-logo.png".to_string()),
-                    screenshot: None,
-                    video: None,
-                    tech_tags: vec!["Rust".to_string(), "WebAssembly".to_string(), "Dioxus".to_string(), "Open Source".to_string()],
-                    scope: vec![
-                        "Full-stack Rust/WASM website".to_string(),
-                        "Admin panel with article management".to_string(),
-                        "SEO optimization and SPA routing".to_string(),
-                        "Open source on GitHub".to_string(),
-                    ],
-                },
-            ],
+            id: generate_id(),
+            quote: String::new(),
+            author: String::new(),
+            role: None,
+            company: None,
+            photo: None,
+            project_slug: None,
+            service_slug: None,
         }
     }
-}
 
-impl Default for ServicesData {
-    fn default() -> Self {
-        Self {
-            services: vec![
-                Service {
-                    id: "ai-consulting".to_string(),
-                    slug: "ai-consulting".to_string(),
-                    title: "AI Consulting".to_string(),
-                    icon: "brain".to_string(),
-                    tagline: "Turn AI potential into measurable business results".to_string(),
-                    description: "According to McKinsey, companies using AI effectively see a 20% increase in cash flow. But most AI projects fail because they start with technology instead of business problems. With a Computer Science degree and hands-on experience implementing solutions with Claude, Llama, and custom models, I help businesses cut through the noise and deploy AI that actually delivers ROI.".to_string(),
-                    long_description: r#"## The $15.7 Trillion Opportunity
-
-According to PwC, AI will contribute $15.7 trillion to the global economy by 2030. But here's what the headlines don't tell you: 85% of AI projects fail to deliver expected results. The difference between success and failure isn't the technology. It's the strategy.
-
-## How We Can Help
-
-### AI Strategy & Assessment
-Before writing a single line of code, we map your business processes to identify where AI creates genuine value versus expensive novelty. Using frameworks developed by leading consulting firms like <a href="https://ey.com" target="_blank" rel="noopener noreferrer">Ernst & Young</a>, we build business cases that your stakeholders can actually approve.
-
-### LLM Implementation
-Large Language Models have matured rapidly. <a href="https://anthropic.com" target="_blank" rel="noopener noreferrer">Anthropic's Claude</a> excels at nuanced reasoning. Open-source models from <a href="https://huggingface.co" target="_blank" rel="noopener noreferrer">Hugging Face</a> offer flexibility and cost control. <a href="https://ai.google" target="_blank" rel="noopener noreferrer">Google's Gemini</a> integrates seamlessly with existing Google infrastructure. We help you choose the right model for your specific use case and budget.
-
-### Small Language Models (SLMs)
-Sometimes smaller is better. SLMs can run on your own infrastructure, keeping sensitive data in-house while reducing API costs by 90% or more. Companies like <a href="https://onefootprint.com" target="_blank" rel="noopener noreferrer">Footprint</a> are pioneering privacy-first AI approaches that we can help you implement.
-
-### Custom AI Solutions
-When off-the-shelf doesn't cut it, we build custom solutions. Fine-tuned models. RAG systems for your proprietary data. AI-powered applications that give you a competitive edge. Research from <a href="https://hai.stanford.edu" target="_blank" rel="noopener noreferrer">Stanford HAI</a> informs our approach to responsible AI development.
-
-## Why Work With Us
-
-I've spent years at the intersection of computer science theory and practical business application. You get someone who can explain transformer architectures to your engineering team and ROI projections to your board. No translation needed."#.to_string(),
-                    features: vec![
-                        "AI strategy aligned with business objectives".to_string(),
-                        "LLM integration (Claude, Llama, Gemini, Mistral)".to_string(),
-                        "Small Language Model deployment for cost control".to_string(),
-                        "Custom AI application development".to_string(),
-                        "RAG systems for proprietary data".to_string(),
-                        "Model selection and total cost analysis".to_string(),
-                        "Prompt engineering and optimization".to_string(),
-                        "AI security, privacy, and compliance consulting".to_string(),
-                    ],
-                    personas: vec![
-                        Persona {
-                            title: "Business Owner".to_string(),
-                            icon: "briefcase".to_string(),
-                            description: "You want AI that delivers ROI, not just impressive demos that go nowhere".to_string(),
-                        },
-                        Persona {
-                            title: "Tech Leader".to_string(),
-                            icon: "code".to_string(),
-                            description: "You need to implement AI features and want guidance on architecture, model selection, and scaling".to_string(),
-                        },
-                        Persona {
-                            title: "Operations Manager".to_string(),
-                            icon: "settings".to_string(),
-                            description: "You have repetitive processes costing thousands in labor that AI could handle in seconds".to_string(),
-                        },
-                    ],
-                    accent_color: "#8B5CF6".to_string(), // Purple for AI
-                },
-                Service {
-                    id: "web-development".to_string(),
-                    slug: "web-development".to_string(),
-                    title: "Web Development".to_string(),
-                    icon: "globe".to_string(),
-                    tagline: "Websites that load fast, rank high, and convert visitors into customers".to_string(),
-                    description: "Google research shows 53% of mobile users abandon sites that take over 3 seconds to load. Your website isn't just a digital brochure. It's a 24/7 sales machine that should be working while you sleep. We build sites that meet Google's Core Web Vitals standards, rank in search results, and turn visitors into paying customers.".to_string(),
-                    long_description: r#"## Every Second Costs You Money
-
-According to Google/SOASTA research, a 1-second delay in page load decreases conversions by 7%. If your site generates $100,000 annually, a slow site could be costing you $7,000 or more. And that's before considering the visitors who never found you because slow sites rank lower in search results.
-
-## How We Can Help
-
-### Custom Websites
-
-Every site we build starts with your business goals, not a template. We follow web standards from the <a href="https://w3.org" target="_blank" rel="noopener noreferrer">W3C</a> and build with performance best practices from <a href="https://web.dev" target="_blank" rel="noopener noreferrer">web.dev</a>. The result is a site that's fast, accessible, and built to last.
-
-### E-commerce Solutions
-
-Sell products online with a checkout experience optimized for conversion. We integrate with <a href="https://stripe.com" target="_blank" rel="noopener noreferrer">Stripe</a> for secure payment processing and build inventory systems that scale with your business. No 15% marketplace fees. No platform limitations.
-
-### Web Applications
-
-When you need your website to DO something. Customer portals that reduce support tickets. Booking systems that fill your calendar. Dashboards that surface insights from your data. We build tools that make your business more efficient.
-
-### Performance Optimization
-
-Already have a site that's slow? We audit against <a href="https://developers.google.com/search/docs/appearance/core-web-vitals" target="_blank" rel="noopener noreferrer">Google's Core Web Vitals</a>, identify bottlenecks, and implement fixes. Most sites see 40-60% improvement in load times.
-
-## Built to Web Standards
-
-We build according to specifications from <a href="https://developer.mozilla.org" target="_blank" rel="noopener noreferrer">MDN Web Docs</a> and W3C standards. This means your site works across browsers, meets accessibility requirements, and won't break when the next browser update drops."#.to_string(),
-                    features: vec![
-                        "Custom design tailored to your brand and goals".to_string(),
-                        "Mobile-first responsive development".to_string(),
-                        "SEO built-in from day one, not bolted on later".to_string(),
-                        "Core Web Vitals optimization for Google ranking".to_string(),
-                        "E-commerce with Stripe payment integration".to_string(),
-                        "Content management you can actually use".to_string(),
-                        "Ongoing support and maintenance plans".to_string(),
-                        "Analytics and conversion tracking setup".to_string(),
-                    ],
-                    personas: vec![
-                        Persona {
-                            title: "New Business".to_string(),
-                            icon: "rocket".to_string(),
-                            description: "You're launching and need a professional web presence that makes you look established from day one".to_string(),
-                        },
-                        Persona {
-                            title: "Growing Company".to_string(),
-                            icon: "trending-up".to_string(),
-                            description: "Your current site doesn't reflect how far you've come, and it's costing you credibility with prospects".to_string(),
-                        },
-                        Persona {
-                            title: "E-commerce Seller".to_string(),
-                            icon: "shopping-cart".to_string(),
-                            description: "You want to own your customer relationships instead of paying 15% to marketplaces".to_string(),
-                        },
-                    ],
-                    accent_color: "#3B82F6".to_string(), // Blue for Web
-                },
-                Service {
-                    id: "mobile-app-development".to_string(),
-                    slug: "mobile-app-development".to_string(),
-                    title: "Mobile App Development".to_string(),
-                    icon: "smartphone".to_string(),
-                    tagline: "Native and cross-platform apps built for performance and user retention".to_string(),
-                    description: "Mobile apps have 3x higher conversion rates than mobile web, according to Criteo research. But building an app that users actually keep on their phones requires more than code. It requires understanding platform guidelines, user expectations, and the economics of app store distribution. We build apps that get downloaded, used, and recommended.".to_string(),
-                    long_description: r#"## Mobile is Where Your Customers Are
-
-Statista reports mobile devices account for over 60% of web traffic. But here's what matters more: users spend 90% of their mobile time in apps, not browsers. If your competitors have an app and you don't, you're invisible during most of your customers' screen time.
-
-## How We Can Help
-
-### Native iOS & Android
-
-Following <a href="https://developer.apple.com/design/human-interface-guidelines" target="_blank" rel="noopener noreferrer">Apple's Human Interface Guidelines</a> and <a href="https://developer.android.com" target="_blank" rel="noopener noreferrer">Google's Material Design</a> principles, we build apps that feel right on each platform. Native apps access device features, perform faster, and create better user experiences.
-
-### Cross-Platform Apps
-
-Why build twice when you can build once? <a href="https://reactnative.dev" target="_blank" rel="noopener noreferrer">React Native</a> and <a href="https://flutter.dev" target="_blank" rel="noopener noreferrer">Flutter</a> let us deploy to both iOS and Android from a single codebase, cutting development time by 30-40% without sacrificing quality.
-
-### Progressive Web Apps (PWAs)
-
-Not every app needs the App Store. PWAs install from the browser, work offline, and avoid the 15-30% app store commission. For the right use case, PWAs deliver 80% of native functionality at 20% of the cost.
-
-### App Modernization
-
-Have an existing app built on outdated technology? We modernize apps to meet current platform requirements, improve performance, and prepare for future updates.
-
-## The Honest Conversation
-
-Before we build anything, we help you answer the hard questions: Do you actually need an app? What problem does it solve that a website can't? How will users discover it? Many businesses discover they need something simpler. We'd rather tell you that upfront than take your money for something that won't succeed."#.to_string(),
-                    features: vec![
-                        "iOS development following Apple HIG standards".to_string(),
-                        "Android development with Material Design".to_string(),
-                        "React Native and Flutter cross-platform apps".to_string(),
-                        "App Store optimization and submission".to_string(),
-                        "Push notifications for user engagement".to_string(),
-                        "Offline-first architecture".to_string(),
-                        "Backend API development and integration".to_string(),
-                        "Analytics, crash reporting, and user insights".to_string(),
-                    ],
-                    personas: vec![
-                        Persona {
-                            title: "Startup Founder".to_string(),
-                            icon: "lightbulb".to_string(),
-                            description: "You have an app idea and need to build an MVP that validates the concept without burning your runway".to_string(),
-                        },
-                        Persona {
-                            title: "Enterprise Leader".to_string(),
-                            icon: "building".to_string(),
-                            description: "Your field teams need mobile tools that work offline and sync when connected".to_string(),
-                        },
-                        Persona {
-                            title: "Service Business".to_string(),
-                            icon: "wrench".to_string(),
-                            description: "Your customers expect to book, pay, and communicate with you from their phones".to_string(),
-                        },
-                    ],
-                    accent_color: "#10B981".to_string(), // Green for Mobile
-                },
-                Service {
-                    id: "product-management".to_string(),
-                    slug: "product-management".to_string(),
-                    title: "Product Management".to_string(),
-                    icon: "target".to_string(),
-                    tagline: "Build products people actually want, on time and on budget".to_string(),
-                    description: "According to Gartner, 45% of product launches miss their target dates, and 80% of new products fail within their first year. The difference between success and failure isn't luck or even engineering talent. It's disciplined product management: knowing what to build, when to ship, and how to iterate based on real user feedback.".to_string(),
-                    long_description: r#"## The $10 Million Decision Problem
-
-Every feature you build costs money. Every feature you don't build has opportunity cost. Without clear product direction, teams waste months building things nobody asked for while ignoring the features that would actually move the needle. The best companies in the world understand this.
-
-## How We Can Help
-
-### Product Strategy
-
-<a href="https://tesla.com" target="_blank" rel="noopener noreferrer">Tesla</a> didn't become the world's most valuable automaker by building better cars. They redefined what a car could be: software-defined, constantly improving, directly sold. <a href="https://spacex.com" target="_blank" rel="noopener noreferrer">SpaceX</a> revolutionized aerospace not through incremental improvements but by questioning every assumption about rocket design. We bring that same first-principles thinking to your product strategy.
-
-### Roadmap Development
-
-<a href="https://boringcompany.com" target="_blank" rel="noopener noreferrer">The Boring Company</a> started with the goal of reducing tunnel costs by 10x. Every decision flows from that single metric. Your roadmap needs the same clarity. We help you identify your "one metric that matters" and ruthlessly prioritize everything against it.
-
-### User Research
-
-The best product teams at companies like <a href="https://gartner.com" target="_blank" rel="noopener noreferrer">Gartner</a> and <a href="https://forrester.com" target="_blank" rel="noopener noreferrer">Forrester</a> don't guess what users want. They watch users struggle. They count support tickets. They measure where people drop off. We set up the systems to capture this intelligence and turn it into roadmap decisions.
-
-### Feature Definition
-
-Vague requirements lead to vague products. We write specifications that answer every question before engineering starts. What does this feature do? What doesn't it do? How do we know if it worked? Clear specs mean faster builds and fewer rewrites.
-
-### Launch Planning
-
-Shipping isn't the finish line. It's the starting line. We plan launches that include measurement, iteration, and clear success criteria so you know within weeks (not months) whether a feature is working.
-
-## Why This Matters
-
-Good product management pays for itself many times over. Skip features that won't move metrics. Ship faster by eliminating ambiguity. Build confidence with stakeholders through clear communication. The best engineering team in the world can't save a product nobody wants."#.to_string(),
-                    features: vec![
-                        "Product strategy and vision development".to_string(),
-                        "Roadmap planning and ruthless prioritization".to_string(),
-                        "User research, interviews, and feedback loops".to_string(),
-                        "Feature specifications that engineers love".to_string(),
-                        "Sprint planning and backlog management".to_string(),
-                        "Cross-functional stakeholder alignment".to_string(),
-                        "Go-to-market planning and launch coordination".to_string(),
-                        "Metrics definition, tracking, and iteration".to_string(),
-                    ],
-                    personas: vec![
-                        Persona {
-                            title: "Startup Without PM".to_string(),
-                            icon: "seedling".to_string(),
-                            description: "You have engineers shipping code, but nobody dedicated to making sure it's the right code".to_string(),
-                        },
-                        Persona {
-                            title: "Overwhelmed Founder".to_string(),
-                            icon: "layers".to_string(),
-                            description: "You're doing product, sales, ops, and everything else, and product is the thing that keeps slipping".to_string(),
-                        },
-                        Persona {
-                            title: "Scaling Team".to_string(),
-                            icon: "bar-chart".to_string(),
-                            description: "The informal processes that worked with 5 people are failing with 20, and features keep missing targets".to_string(),
-                        },
-                    ],
-                    accent_color: "#F59E0B".to_string(), // Amber for Product
-                },
-                Service {
-                    id: "digital-marketing".to_string(),
-                    slug: "digital-marketing".to_string(),
-                    title: "Digital Marketing & Customer Systems".to_string(),
-                    icon: "megaphone".to_string(),
-                    tagline: "Turn website visitors into customers while you sleep".to_string(),
-                    description: "According to HubSpot, companies that automate lead management see a 10% or greater increase in revenue within 6-9 months. But most small businesses are still doing marketing manually, if at all. We build systems that capture leads, nurture relationships, and convert interest into revenue automatically.".to_string(),
-                    long_description: r#"## The Leaky Bucket Problem
-
-You're paying for traffic. Visitors come to your site, look around, and leave. Forever. Without a system to capture their information and follow up, you're filling a bucket with a hole in the bottom. The math is brutal: most websites convert less than 3% of visitors. That means 97% of your marketing spend walks out the door.
-
-## How We Can Help
-
-### Email Marketing Systems
-
-Email marketing delivers $42 for every $1 spent, according to DMA research. But only if you actually do it. We set up systems using platforms like <a href="https://mailchimp.com" target="_blank" rel="noopener noreferrer">Mailchimp</a> for growing businesses or <a href="https://klaviyo.com" target="_blank" rel="noopener noreferrer">Klaviyo</a> for e-commerce that run automatically. Welcome sequences. Abandoned cart reminders. Re-engagement campaigns. Set it up once, let it work forever.
-
-### SMS & Phone Outreach
-
-Text messages have a 98% open rate (vs 20% for email). For time-sensitive offers or appointment reminders, nothing beats SMS. We integrate with <a href="https://twilio.com" target="_blank" rel="noopener noreferrer">Twilio</a> to build automated text campaigns that feel personal.
-
-### Customer Intake & Lead Capture
-
-Every form on your site should capture exactly what you need, route it to the right person, and trigger the right follow-up. No more leads disappearing into an unmonitored inbox. No more calling people back three days later.
-
-### Marketing Automation
-
-When someone downloads your pricing guide, they should get a sequence of helpful emails. When they visit your pricing page three times, your sales team should get notified. We build intelligent workflows using <a href="https://hubspot.com" target="_blank" rel="noopener noreferrer">HubSpot</a> and similar platforms that respond to customer behavior in real-time.
-
-### Analytics & Tracking
-
-If you can't measure it, you can't improve it. We set up <a href="https://analytics.google.com" target="_blank" rel="noopener noreferrer">Google Analytics</a> tracking that actually answers your questions: Where are leads coming from? What's working? Where are people dropping off? Data-driven decisions, not guesswork.
-
-## The Compounding Effect
-
-Marketing automation isn't a one-time win. It's a compounding asset. Every email sequence you build keeps working. Every lead capture form keeps collecting. Every automation keeps running. The businesses that build these systems early create an advantage that compounds over time."#.to_string(),
-                    features: vec![
-                        "Email marketing setup with Mailchimp or Klaviyo".to_string(),
-                        "SMS campaigns and automated text sequences".to_string(),
-                        "Lead capture forms that actually convert".to_string(),
-                        "Appointment scheduling with automated reminders".to_string(),
-                        "Membership and subscription management".to_string(),
-                        "Marketing automation workflows".to_string(),
-                        "CRM integration and lead routing".to_string(),
-                        "Google Analytics setup and conversion tracking".to_string(),
-                    ],
-                    personas: vec![
-                        Persona {
-                            title: "Growing Business".to_string(),
-                            icon: "seedling".to_string(),
-                            description: "You've been growing through word-of-mouth and referrals, but you're ready for predictable, scalable growth".to_string(),
-                        },
-                        Persona {
-                            title: "Service Provider".to_string(),
-                            icon: "calendar".to_string(),
-                            description: "No-shows and manual scheduling are costing you money and time every single week".to_string(),
-                        },
-                        Persona {
-                            title: "Membership Business".to_string(),
-                            icon: "ticket".to_string(),
-                            description: "You have recurring customers, but no system to reduce churn and increase lifetime value".to_string(),
-                        },
-                    ],
-                    accent_color: "#EC4899".to_string(), // Pink for Marketing
-                },
-                Service {
-                    id: "technical-strategy".to_string(),
-                    slug: "technical-strategy".to_string(),
-                    title: "Technical Strategy & Advisory".to_string(),
-                    icon: "compass".to_string(),
-                    tagline: "Get the decision right before you spend the money".to_string(),
-                    description: "According to Gartner, 83% of data migrations fail or exceed their budgets. The wrong technology decision can cost months of wasted effort and hundreds of thousands in sunk costs. Before you commit to a platform, sign with a vendor, or greenlight a build, you need someone in your corner who understands the technology and has no stake in the outcome.".to_string(),
-                    long_description: r#"## The $500,000 Mistake
-
-Every year, companies waste enormous budgets on technology that doesn't fit their needs. They choose vendors based on sales pitches instead of technical reality. They build custom when they should buy, or buy when they should build. These mistakes are expensive, and they're preventable.
-
-## How We Can Help
-
-### Technology Assessment
-
-Firms like <a href="https://gartner.com" target="_blank" rel="noopener noreferrer">Gartner</a> and <a href="https://forrester.com" target="_blank" rel="noopener noreferrer">Forrester</a> publish research on technology trends, but their reports are written for everyone. We translate that research to your specific situation. What do you actually need? What's overkill? What's the minimum viable solution that solves your problem?
-
-### Vendor Evaluation
-
-Sales teams are trained to tell you what you want to hear. Their technical demos are optimized to impress, not to reveal limitations. We help you ask the hard questions: What happens when this fails? What's the real total cost of ownership? What does the migration path look like?
-
-### Build vs Buy Analysis
-
-The <a href="https://thoughtworks.com/radar" target="_blank" rel="noopener noreferrer">ThoughtWorks Technology Radar</a> tracks which technologies are ready for adoption and which are still experimental. But even mature technology isn't always the right choice. Sometimes custom development delivers 10x value. Sometimes off-the-shelf at 20% of the cost is good enough. We help you make that call.
-
-### Architecture Review
-
-Before you commit hundreds of engineering hours, have someone review the plan. We've seen the patterns that work and the patterns that fail. A few hours of review can prevent months of rework. <a href="https://ey.com" target="_blank" rel="noopener noreferrer">Ernst & Young</a> estimates that fixing architectural problems after launch costs 10-100x more than fixing them during design.
-
-### Due Diligence
-
-Acquiring a company or making a major technology investment? <a href="https://a16z.com" target="_blank" rel="noopener noreferrer">a16z</a> and other top VCs do technical due diligence for a reason. We assess code quality, technical debt, team capabilities, and scalability. Know what you're buying before you buy it.
-
-## The ROI of Good Advice
-
-One avoided mistake pays for dozens of hours of advisory work. One right decision compounds for years. The companies that invest in getting decisions right upfront outperform those that figure it out as they go."#.to_string(),
-                    features: vec![
-                        "Technology assessments tailored to your business".to_string(),
-                        "Vendor evaluation and negotiation support".to_string(),
-                        "Build vs buy analysis with total cost modeling".to_string(),
-                        "Architecture reviews before you commit".to_string(),
-                        "Technical due diligence for acquisitions".to_string(),
-                        "Product roadmapping and prioritization".to_string(),
-                        "Team capability assessment".to_string(),
-                        "Documentation and knowledge transfer".to_string(),
-                    ],
-                    personas: vec![
-                        Persona {
-                            title: "Non-Technical Founder".to_string(),
-                            icon: "graduation-cap".to_string(),
-                            description: "You need someone who speaks tech and business fluently, without the cost of a full-time CTO".to_string(),
-                        },
-                        Persona {
-                            title: "Decision Maker".to_string(),
-                            icon: "scale".to_string(),
-                            description: "You're about to sign a six-figure contract and want an unbiased technical opinion first".to_string(),
-                        },
-                        Persona {
-                            title: "Acquiring Company".to_string(),
-                            icon: "handshake".to_string(),
-                            description: "You need to know what the technology is actually worth before you close the deal".to_string(),
-                        },
-                    ],
-                    accent_color: "#6366F1".to_string(), // Indigo for Strategy
-                },
-                Service {
-                    id: "business-solutions".to_string(),
-                    slug: "business-solutions".to_string(),
-                    title: "Business Solutions & Integration".to_string(),
-                    icon: "zap".to_string(),
-                    tagline: "Connect your systems, eliminate the busywork".to_string(),
-                    description: "According to Zapier's State of Business Automation report, small businesses using automation save over 20 hours per week on manual tasks. That's 1,000 hours per year spent on work that adds no value. We build integrations and automations that let your team focus on work that actually matters.".to_string(),
-                    long_description: r#"## The Hidden Cost of Manual Work
-
-Every time an employee copies data from one system to another, you're paying twice: once for their time, and again in the errors they inevitably make. Research shows manual data entry has error rates of 1-4%. At scale, that's hundreds of mistakes per month, each one requiring time to find and fix.
-
-## How We Can Help
-
-### System Integration
-
-<a href="https://zapier.com" target="_blank" rel="noopener noreferrer">Zapier</a> and <a href="https://make.com" target="_blank" rel="noopener noreferrer">Make</a> have made it possible to connect almost any business software without custom code. When a sale happens in your e-commerce platform, inventory updates, accounting records it, and shipping gets notified. Automatically. No copying, no pasting, no errors.
-
-### Payment Processing
-
-<a href="https://stripe.com" target="_blank" rel="noopener noreferrer">Stripe</a> has become the backbone of internet commerce. We integrate Stripe with your existing systems so payments flow smoothly, subscriptions manage themselves, and financial reporting happens automatically. From simple checkout to complex billing logic, we build payment workflows that work.
-
-### Communication Automation
-
-<a href="https://twilio.com" target="_blank" rel="noopener noreferrer">Twilio</a> powers communications for companies from startups to the Fortune 500. We use it to build automated SMS notifications, voice systems, and communication workflows that keep your customers informed without requiring your team to manually send messages.
-
-### Custom Internal Tools
-
-Sometimes the tool you need doesn't exist. A dashboard for your specific metrics. A calculator for your unique pricing model. An intake form that matches your exact workflow. We build internal tools that solve your specific problems without the overhead of enterprise software.
-
-### Data Migration & Cleanup
-
-Moving from one system to another is notoriously painful. We handle migrations so your data arrives clean, complete, and correctly mapped. No lost records. No broken relationships. No three-month cleanup project.
-
-## The Automation Advantage
-
-Every automation you build is an asset that keeps working forever. The hour spent building an automation that saves 5 minutes per day pays for itself in 12 days. After that, it's pure profit. The businesses that systematically eliminate manual work create compounding efficiency advantages."#.to_string(),
-                    features: vec![
-                        "Zapier and Make automation workflows".to_string(),
-                        "Stripe payment and subscription integration".to_string(),
-                        "Twilio SMS and communication automation".to_string(),
-                        "Custom API integrations between systems".to_string(),
-                        "Internal tools and dashboards".to_string(),
-                        "Data migrations and cleanup".to_string(),
-                        "Process optimization and documentation".to_string(),
-                        "Ongoing maintenance and support".to_string(),
-                    ],
-                    personas: vec![
-                        Persona {
-                            title: "Operations Manager".to_string(),
-                            icon: "refresh-cw".to_string(),
-                            description: "You're spending more time on manual data work than on strategic initiatives".to_string(),
-                        },
-                        Persona {
-                            title: "Growing Company".to_string(),
-                            icon: "trending-up".to_string(),
-                            description: "The spreadsheets and manual processes that worked at 5 employees are breaking at 20".to_string(),
-                        },
-                        Persona {
-                            title: "Data-Driven Business".to_string(),
-                            icon: "bar-chart".to_string(),
-                            description: "Your data lives in 5 different places and nobody trusts any of the numbers".to_string(),
-                        },
-                    ],
-                    accent_color: "#14B8A6".to_string(), // Teal for Integration
-                },
-            ],
+    /// Quote and author are required; links must point at real content
+    pub fn validate(
+        &self,
+        portfolio: &PortfolioData,
+        services: &ServicesData,
+    ) -> Result<(), String> {
+        if self.quote.trim().is_empty() {
+            return Err("Quote is required".to_string());
+        }
+        if self.author.trim().is_empty() {
+            return Err("Person is required".to_string());
         }
+        if let Some(slug) = &self.project_slug {
+            if !portfolio.projects.iter().any(|p| &p.slug == slug) {
+                return Err(format!("No portfolio project with slug \"{}\"", slug));
+            }
+        }
+        if let Some(slug) = &self.service_slug {
+            if !services.services.iter().any(|s| &s.slug == slug) {
+                return Err(format!("No service with slug \"{}\"", slug));
+            }
+        }
+        Ok(())
+    }
+
+    /// "Role, Company" line under the author, if either is set
+    pub fn attribution(&self) -> Option<String> {
+        let parts: Vec<&str> = [self.role.as_deref(), self.company.as_deref()]
+            .into_iter()
+            .flatten()
+            .filter(|s| !s.is_empty())
+            .collect();
+        (!parts.is_empty()).then(|| parts.join(", "))
     }
 }
 
-impl Article {
-    pub fn new() -> Self {
-        let id = generate_id();
-        Self {
-            id: id.clone(),
-            title: String::new(),
-            slug: String::new(),
-            date: chrono_today(),
-            category: "General".to_string(),
-            excerpt: String::new(),
-            content: String::new(),
-            status: ArticleStatus::Draft,
+impl TestimonialsData {
+    pub fn find(&self, id: &str) -> Option<&Testimonial> {
+        self.testimonials.iter().find(|t| t.id == id)
+    }
+
+    /// Replace the testimonial with the same id, or append it
+    pub fn upsert(&mut self, testimonial: Testimonial) {
+        match self
+            .testimonials
+            .iter_mut()
+            .find(|t| t.id == testimonial.id)
+        {
+            Some(existing) => *existing = testimonial,
+            None => self.testimonials.push(testimonial),
         }
     }
 
-    /// Generate a URL-friendly slug from the title
-    pub fn generate_slug(title: &str) -> String {
-        title
-            .to_lowercase()
-            .chars()
-            .map(|c| if c.is_alphanumeric() { c } else { '-' })
-            .collect::<String>()
-            .split('-')
-            .filter(|s| !s.is_empty())
-            .collect::<Vec<_>>()
-            .join("-")
+    pub fn remove(&mut self, id: &str) {
+        self.testimonials.retain(|t| t.id != id);
+    }
+
+    /// Quotes linked to a portfolio project
+    pub fn for_project(&self, slug: &str) -> Vec<Testimonial> {
+        self.testimonials
+            .iter()
+            .filter(|t| t.project_slug.as_deref() == Some(slug))
+            .cloned()
+            .collect()
+    }
+
+    /// Quotes linked to a service
+    pub fn for_service(&self, slug: &str) -> Vec<Testimonial> {
+        self.testimonials
+            .iter()
+            .filter(|t| t.service_slug.as_deref() == Some(slug))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Render a metric value without trailing ".0" and with its unit
+fn format_metric(value: f64, unit: &str) -> String {
+    let number = if value.fract() == 0.0 {
+        format!("{}", value as i64)
+    } else {
+        format!("{:.1}", value)
+    };
+    match unit {
+        "" => number,
+        "$" => format!("${}", number),
+        "%" | "x" | "s" | "ms" => format!("{}{}", number, unit),
+        _ => format!("{} {}", number, unit),
     }
 }
 
+/// URL slug for a tech tag, e.g. "Lead Capture" -> "lead-capture"
+pub fn tag_slug(tag: &str) -> String {
+    Article::generate_slug(tag)
+}
+
 /// Generate a simple unique ID
 fn generate_id() -> String {
     #[cfg(target_arch = "wasm32")]
@@ -1918,6 +1195,8 @@ mod tests {
             excerpt: "A test excerpt".to_string(),
             content: "Full content here".to_string(),
             status: ArticleStatus::Published,
+            pinned_articles: vec![],
+            translations: Translations::new(),
         };
 
         let json = serde_json::to_string(&article).unwrap();
@@ -1942,6 +1221,12 @@ mod tests {
             video: None,
             tech_tags: vec!["Rust".to_string(), "WASM".to_string()],
             scope: vec!["Design".to_string(), "Development".to_string()],
+            featured: false,
+            sort_order: 0,
+            case_study: CaseStudy::default(),
+            pinned_services: vec![],
+            status: ArticleStatus::Published,
+            translations: Translations::new(),
         };
 
         let json = serde_json::to_string(&project).unwrap();
@@ -1950,6 +1235,223 @@ mod tests {
         assert_eq!(project, deserialized);
     }
 
+    #[test]
+    fn test_portfolio_project_without_case_study_deserializes() {
+        let mut value = serde_json::to_value(project("legacy", "Website", &[], false, 0)).unwrap();
+        value.as_object_mut().unwrap().remove("case_study");
+
+        let project: PortfolioProject = serde_json::from_value(value).unwrap();
+        assert_eq!(project.case_study, CaseStudy::default());
+    }
+
+    #[test]
+    fn test_before_after_needs_both_screenshots() {
+        let mut p = project("p", "Website", &[], false, 0);
+        assert_eq!(p.before_after(), None);
+
+        p.case_study.before_screenshot = Some("before.png".to_string());
+        assert_eq!(p.before_after(), None);
+
+        p.screenshot = Some("after.png".to_string());
+        assert_eq!(p.before_after(), Some(("before.png", "after.png")));
+    }
+
+    fn result(before: f64, after: f64, unit: &str, lower_is_better: bool) -> ProjectResult {
+        ProjectResult {
+            metric: "Metric".to_string(),
+            before,
+            after,
+            unit: unit.to_string(),
+            lower_is_better,
+        }
+    }
+
+    #[test]
+    fn test_project_result_formatting() {
+        let load = result(4.2, 1.5, "s", true);
+        assert_eq!(load.format_before(), "4.2s");
+        assert_eq!(load.format_after(), "1.5s");
+        assert_eq!(load.format_change(), "-64%");
+        assert!(load.improved());
+
+        let leads = result(20.0, 29.0, "leads/mo", false);
+        assert_eq!(leads.format_before(), "20 leads/mo");
+        assert_eq!(leads.format_change(), "+45%");
+        assert!(leads.improved());
+
+        let revenue = result(0.0, 1500.0, "$", false);
+        assert_eq!(revenue.change_percent(), None);
+        assert_eq!(revenue.format_change(), "+$1500");
+    }
+
+    #[test]
+    fn test_testimonial_attribution() {
+        let mut testimonial = Testimonial {
+            quote: "Great work".to_string(),
+            author: "Sam".to_string(),
+            ..Testimonial::new()
+        };
+        assert_eq!(testimonial.attribution(), None);
+
+        testimonial.company = Some("Acme".to_string());
+        assert_eq!(testimonial.attribution().as_deref(), Some("Acme"));
+
+        testimonial.role = Some("Owner".to_string());
+        assert_eq!(testimonial.attribution().as_deref(), Some("Owner, Acme"));
+    }
+
+    #[test]
+    fn test_testimonial_validate() {
+        let portfolio = PortfolioData::default();
+        let services = ServicesData::default();
+        let mut testimonial = Testimonial::new();
+        assert!(testimonial.validate(&portfolio, &services).is_err());
+
+        testimonial.quote = "They shipped on time.".to_string();
+        testimonial.author = "Pat".to_string();
+        assert!(testimonial.validate(&portfolio, &services).is_ok());
+
+        testimonial.project_slug = Some("paytient".to_string());
+        assert!(testimonial.validate(&portfolio, &services).is_ok());
+
+        testimonial.service_slug = Some("no-such-service".to_string());
+        assert!(testimonial
+            .validate(&portfolio, &services)
+            .unwrap_err()
+            .contains("no-such-service"));
+    }
+
+    #[test]
+    fn test_testimonials_data_crud_and_lookup() {
+        let mut data = TestimonialsData::default();
+        let mut first = Testimonial {
+            id: "a".to_string(),
+            project_slug: Some("paytient".to_string()),
+            ..Testimonial::new()
+        };
+        data.upsert(first.clone());
+        data.upsert(Testimonial {
+            id: "b".to_string(),
+            service_slug: Some("web-development".to_string()),
+            ..Testimonial::new()
+        });
+        assert_eq!(data.testimonials.len(), 2);
+
+        first.author = "Updated".to_string();
+        data.upsert(first);
+        assert_eq!(data.testimonials.len(), 2);
+        assert_eq!(data.find("a").unwrap().author, "Updated");
+
+        assert_eq!(data.for_project("paytient").len(), 1);
+        assert_eq!(data.for_service("web-development")[0].id, "b");
+        assert!(data.for_project("club-car-wash").is_empty());
+
+        data.remove("a");
+        assert!(data.find("a").is_none());
+    }
+
+    #[test]
+    fn test_legacy_testimonial_deserializes() {
+        let json = r#"{"quote":"Great","author":"Sam","role":"Owner"}"#;
+        let testimonial: Testimonial = serde_json::from_str(json).unwrap();
+        assert!(testimonial.id.is_empty());
+        assert_eq!(testimonial.photo, None);
+        assert_eq!(testimonial.project_slug, None);
+    }
+
+    fn project(
+        id: &str,
+        project_type: &str,
+        tags: &[&str],
+        featured: bool,
+        order: u32,
+    ) -> PortfolioProject {
+        PortfolioProject {
+            id: id.to_string(),
+            slug: id.to_string(),
+            title: id.to_string(),
+            project_type: project_type.to_string(),
+            description: String::new(),
+            long_description: String::new(),
+            external_url: String::new(),
+            before_url: None,
+            logo: None,
+            screenshot: None,
+            video: None,
+            tech_tags: tags.iter().map(|t| t.to_string()).collect(),
+            scope: vec![],
+            featured,
+            sort_order: order,
+            case_study: CaseStudy::default(),
+            pinned_services: vec![],
+            status: ArticleStatus::Published,
+            translations: Translations::new(),
+        }
+    }
+
+    fn sample_portfolio() -> PortfolioData {
+        PortfolioData {
+            projects: vec![
+                project("c", "Website", &["Rust", "SEO"], false, 1),
+                project("a", "Mobile App", &["React"], false, 2),
+                project("b", "Website", &["React", "Lead Capture"], true, 3),
+            ],
+            redirects: SlugRedirects::default(),
+        }
+    }
+
+    #[test]
+    fn test_portfolio_sorted_featured_first() {
+        let data = sample_portfolio();
+        let ids: Vec<&str> = data.sorted().iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(ids, vec!["b", "c", "a"]);
+    }
+
+    #[test]
+    fn test_portfolio_filter() {
+        let data = sample_portfolio();
+        let ids = |projects: Vec<&PortfolioProject>| -> Vec<String> {
+            projects.iter().map(|p| p.id.clone()).collect()
+        };
+
+        assert_eq!(ids(data.filter(Some("Website"), None)), vec!["b", "c"]);
+        assert_eq!(ids(data.filter(None, Some("react"))), vec!["b", "a"]);
+        assert_eq!(ids(data.filter(Some("Website"), Some("react"))), vec!["b"]);
+        assert_eq!(ids(data.filter(None, Some("lead-capture"))), vec!["b"]);
+        assert!(data.filter(None, Some("cobol")).is_empty());
+        assert_eq!(data.filter(None, None).len(), 3);
+    }
+
+    #[test]
+    fn test_portfolio_project_types_and_tags() {
+        let data = sample_portfolio();
+        assert_eq!(data.project_types(), vec!["Website", "Mobile App"]);
+
+        let tags = data.tech_tags();
+        assert_eq!(tags[0], ("React", 2));
+        assert_eq!(tags.len(), 4);
+
+        assert_eq!(data.tag_name("lead-capture"), Some("Lead Capture"));
+        assert_eq!(data.tag_name("cobol"), None);
+    }
+
+    #[test]
+    fn test_tag_slug() {
+        assert_eq!(tag_slug("Rust"), "rust");
+        assert_eq!(tag_slug("Lead Capture"), "lead-capture");
+        assert_eq!(tag_slug("Next.js"), "next-js");
+    }
+
+    #[test]
+    fn test_default_portfolio_sort_orders_unique() {
+        let data = PortfolioData::default();
+        let mut orders: Vec<u32> = data.projects.iter().map(|p| p.sort_order).collect();
+        orders.sort();
+        orders.dedup();
+        assert_eq!(orders.len(), data.projects.len());
+        assert!(data.projects.iter().any(|p| p.featured));
+    }
+
     #[test]
     fn test_site_settings_roundtrip() {
         let settings = SiteSettings::default();
@@ -1988,6 +1490,23 @@ mod tests {
         assert!(default.first_responder_enabled);
     }
 
+    #[test]
+    fn test_compiled_content_order() {
+        let articles = ArticlesData::default().articles;
+        assert!(articles.windows(2).all(|w| w[0].date >= w[1].date));
+        assert!(articles
+            .iter()
+            .all(|a| a.id == a.slug && !a.content.is_empty()));
+
+        let services = ServicesData::default().services;
+        assert_eq!(services[0].slug, "ai-consulting");
+
+        let projects = PortfolioData::default().projects;
+        assert!(projects
+            .windows(2)
+            .all(|w| w[0].sort_order < w[1].sort_order));
+    }
+
     #[test]
     fn test_site_settings_default_has_all_pages() {
         let settings = SiteSettings::default();
@@ -2019,8 +1538,61 @@ mod tests {
 
         assert_eq!(settings.brand.name, "Pounds Consulting");
         assert!(!settings.brand.tagline.is_empty());
-        assert!(settings.brand.primary_color.starts_with('#'));
-        assert!(settings.brand.accent_color.starts_with('#'));
+        assert_eq!(settings.brand.theme, ThemeConfig::default_gold());
+        assert!(settings.brand.theme.meets_aa());
+        assert!(settings.features.visitor_themes);
+    }
+
+    #[test]
+    fn test_site_settings_without_theme_fields_deserialize() {
+        // Settings saved before the brand theme existed
+        let mut json = serde_json::to_value(SiteSettings::default()).unwrap();
+        json["brand"].as_object_mut().unwrap().remove("theme");
+        json["features"]
+            .as_object_mut()
+            .unwrap()
+            .remove("visitor_themes");
+
+        let settings: SiteSettings = serde_json::from_value(json).unwrap();
+        assert_eq!(settings.brand.theme, ThemeConfig::default_gold());
+        assert!(settings.features.visitor_themes);
+    }
+
+    #[test]
+    fn test_legacy_brand_colors_become_the_brand_theme() {
+        let brand = |primary: &str, accent: &str| -> BrandSettings {
+            serde_json::from_value(serde_json::json!({
+                "name": "Acme",
+                "tagline": "We make things",
+                "primary_color": primary,
+                "accent_color": accent,
+            }))
+            .unwrap()
+        };
+
+        // Colors an admin picked carry over
+        let custom = brand("#1E6FD9", "#3B82F6");
+        assert_eq!(custom.name, "Acme");
+        assert_eq!(custom.theme.secondary, Color::hex(0x1E6FD9));
+        assert_eq!(custom.theme.accent, Color::hex(0x3B82F6));
+        assert!(custom.theme.is_dark());
+
+        // The old defaults were never a choice, so keep the default theme
+        assert_eq!(
+            brand("#C9A227", "#d4af37").theme,
+            ThemeConfig::default_gold()
+        );
+        // Garbage falls back instead of failing the whole settings load
+        assert_eq!(
+            brand("not a color", "#D4AF37").theme,
+            ThemeConfig::default_gold()
+        );
+
+        // Saved again, only the theme is written
+        let json = serde_json::to_value(&custom).unwrap();
+        assert!(json.get("primary_color").is_none());
+        let reloaded: BrandSettings = serde_json::from_value(json).unwrap();
+        assert_eq!(reloaded, custom);
     }
 
     #[test]
@@ -2034,6 +1606,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_compiled_article_redirects_reach_published_articles() {
+        // `redirect_from` in an article's front matter
+        let articles = ArticlesData::default();
+        for entry in &articles.redirects.entries {
+            assert!(
+                articles
+                    .find_published(&entry.from)
+                    .is_some_and(|(_, moved)| moved),
+                "{} redirects to {}, which isn't published",
+                entry.from,
+                entry.to
+            );
+        }
+    }
+
     #[test]
     fn test_portfolio_data_default_has_projects() {
         let portfolio = PortfolioData::default();
@@ -2059,6 +1647,27 @@ mod tests {
         assert_eq!(orders, expected);
     }
 
+    #[test]
+    fn test_move_page_renumbers_order() {
+        let mut settings = SiteSettings::default();
+        // Move "contact" (last) to the front
+        settings.move_page(5, 0);
+
+        let pages = settings.pages_in_order();
+        assert_eq!(pages[0].id, "contact");
+        assert_eq!(pages[1].id, "home");
+        let orders: Vec<u32> = pages.iter().map(|p| p.order).collect();
+        assert_eq!(orders, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_move_page_out_of_bounds_is_noop() {
+        let mut settings = SiteSettings::default();
+        let before = settings.clone();
+        settings.move_page(0, 99);
+        assert_eq!(settings, before);
+    }
+
     // ==================== Article::new() Tests ====================
 
     #[test]
@@ -2102,6 +1711,12 @@ mod tests {
             video: None,
             tech_tags: vec![],
             scope: vec![],
+            featured: false,
+            sort_order: 0,
+            case_study: CaseStudy::default(),
+            pinned_services: vec![],
+            status: ArticleStatus::Published,
+            translations: Translations::new(),
         };
 
         // Should serialize and deserialize correctly with None values
@@ -2115,13 +1730,225 @@ mod tests {
 
     #[test]
     fn test_articles_data_empty() {
-        let empty = ArticlesData { articles: vec![] };
+        let empty = ArticlesData {
+            articles: vec![],
+            redirects: SlugRedirects::default(),
+        };
         let json = serde_json::to_string(&empty).unwrap();
         let deserialized: ArticlesData = serde_json::from_str(&json).unwrap();
 
         assert!(deserialized.articles.is_empty());
     }
 
+    // ==================== Slug Redirect Tests ====================
+
+    fn published(id: &str, slug: &str) -> Article {
+        Article {
+            id: id.to_string(),
+            slug: slug.to_string(),
+            status: ArticleStatus::Published,
+            pinned_articles: vec![],
+            ..Article::new()
+        }
+    }
+
+    #[test]
+    fn test_upsert_records_redirect_on_slug_change() {
+        let mut data = ArticlesData {
+            articles: vec![published("1", "old-title")],
+            redirects: SlugRedirects::default(),
+        };
+        data.upsert(published("1", "new-title"));
+
+        let (article, redirected) = data.find_published("old-title").unwrap();
+        assert_eq!(article.slug, "new-title");
+        assert!(redirected);
+        assert!(!data.find_published("new-title").unwrap().1);
+    }
+
+    #[test]
+    fn test_upsert_draft_slug_change_has_no_redirect() {
+        let mut draft = published("1", "draft-slug");
+        draft.status = ArticleStatus::Draft;
+        let mut data = ArticlesData {
+            articles: vec![draft.clone()],
+            redirects: SlugRedirects::default(),
+        };
+        draft.slug = "renamed".to_string();
+        data.upsert(draft);
+
+        assert!(data.redirects.entries.is_empty());
+    }
+
+    #[test]
+    fn test_slug_uniqueness() {
+        let data = ArticlesData {
+            articles: vec![published("1", "foo"), published("2", "foo-2")],
+            redirects: SlugRedirects::default(),
+        };
+
+        assert!(data.is_slug_available("1", "foo"));
+        assert!(!data.is_slug_available("3", "foo"));
+        assert_eq!(data.suggest_slug("3", "foo"), "foo-3");
+        assert_eq!(data.suggest_slug("3", "bar"), "bar");
+    }
+
+    #[test]
+    fn test_redirected_slug_is_reserved() {
+        let mut data = ArticlesData {
+            articles: vec![published("1", "old")],
+            redirects: SlugRedirects::default(),
+        };
+        data.upsert(published("1", "new"));
+
+        // Another article can't take over the old URL
+        assert!(!data.is_slug_available("2", "old"));
+        // But the original article can move back to it
+        assert!(data.is_slug_available("1", "old"));
+    }
+
+    #[test]
+    fn test_default_content_slugs_are_unique() {
+        let articles = ArticlesData::default();
+        for article in &articles.articles {
+            assert!(
+                articles.is_slug_available(&article.id, &article.slug),
+                "Duplicate article slug: {}",
+                article.slug
+            );
+        }
+    }
+
+    // ==================== Service & Project Editing Tests ====================
+
+    fn valid_service() -> Service {
+        Service {
+            title: "Site Audits".to_string(),
+            slug: "site-audits".to_string(),
+            description: "A full review of your site.".to_string(),
+            ..Service::new()
+        }
+    }
+
+    fn valid_project() -> PortfolioProject {
+        PortfolioProject {
+            title: "Acme Bakery".to_string(),
+            slug: "acme-bakery".to_string(),
+            project_type: "Website".to_string(),
+            description: "A new site for a local bakery.".to_string(),
+            external_url: "https://example.com".to_string(),
+            ..PortfolioProject::new()
+        }
+    }
+
+    #[test]
+    fn test_default_services_and_projects_validate() {
+        for service in &ServicesData::default().services {
+            assert_eq!(service.validate(), Ok(()), "{}", service.slug);
+        }
+        for project in &PortfolioData::default().projects {
+            assert_eq!(project.validate(), Ok(()), "{}", project.slug);
+        }
+    }
+
+    #[test]
+    fn test_service_validate() {
+        assert!(valid_service().validate().is_ok());
+
+        let mut service = valid_service();
+        service.icon = IconName::from_name("not-an-icon");
+        assert!(service.validate().unwrap_err().contains("not-an-icon"));
+
+        let mut service = valid_service();
+        service.accent_color = "gold-ish".to_string();
+        assert!(service.validate().unwrap_err().starts_with("Accent color"));
+
+        let mut service = valid_service();
+        service.slug = "Site Audits".to_string();
+        assert!(service.validate().is_err());
+
+        let mut service = valid_service();
+        service.personas.push(Persona {
+            title: "Owners".to_string(),
+            icon: IconName::from_name("nope"),
+            description: String::new(),
+        });
+        assert!(service.validate().unwrap_err().contains("Owners"));
+    }
+
+    #[test]
+    fn test_project_validate() {
+        assert!(valid_project().validate().is_ok());
+
+        let mut project = valid_project();
+        project.external_url = "example.com".to_string();
+        assert!(project.validate().unwrap_err().contains("Site URL"));
+
+        let mut project = valid_project();
+        project.before_url = Some("ftp://old.example.com".to_string());
+        assert!(project.validate().unwrap_err().contains("Before URL"));
+
+        let mut project = valid_project();
+        project.project_type = " ".to_string();
+        assert!(project.validate().is_err());
+    }
+
+    #[test]
+    fn test_legacy_content_defaults_to_published() {
+        let mut json = serde_json::to_value(valid_service()).unwrap();
+        json.as_object_mut().unwrap().remove("status");
+        let service: Service = serde_json::from_value(json).unwrap();
+        assert_eq!(service.status, ArticleStatus::Published);
+
+        let mut json = serde_json::to_value(valid_project()).unwrap();
+        json.as_object_mut().unwrap().remove("status");
+        let project: PortfolioProject = serde_json::from_value(json).unwrap();
+        assert_eq!(project.status, ArticleStatus::Published);
+    }
+
+    #[test]
+    fn test_published_filters_drafts_and_trash() {
+        let mut data = ServicesData::default();
+        let total = data.services.len();
+        data.services[0].status = ArticleStatus::Draft;
+        data.services[1].status = ArticleStatus::Trashed;
+        assert_eq!(data.published().services.len(), total - 2);
+
+        let mut data = PortfolioData::default();
+        let total = data.projects.len();
+        data.projects[0].status = ArticleStatus::Draft;
+        assert_eq!(data.published().projects.len(), total - 1);
+    }
+
+    #[test]
+    fn test_service_and_project_upsert_redirect() {
+        let mut services = ServicesData {
+            services: vec![],
+            redirects: SlugRedirects::default(),
+        };
+        let mut service = valid_service();
+        service.status = ArticleStatus::Published;
+        services.upsert(service.clone());
+        service.slug = "website-audits".to_string();
+        services.upsert(service);
+        let (found, redirected) = services.find("site-audits").unwrap();
+        assert_eq!(found.slug, "website-audits");
+        assert!(redirected);
+        assert!(!services.is_slug_available("other", "site-audits"));
+
+        let mut portfolio = PortfolioData {
+            projects: vec![],
+            redirects: SlugRedirects::default(),
+        };
+        let mut project = valid_project();
+        portfolio.upsert(project.clone());
+        // Drafts can be renamed freely
+        project.slug = "acme".to_string();
+        portfolio.upsert(project);
+        assert!(portfolio.find("acme-bakery").is_none());
+        assert_eq!(portfolio.suggest_slug("other", "acme"), "acme-2");
+    }
+
     // ==================== Sitemap Verification Tests ====================
 
     #[test]
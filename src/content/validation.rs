@@ -0,0 +1,397 @@
+//! Checks on `SiteSettings` before admin saves them.
+//!
+//! Each part of the settings validates its own fields; `SiteSettings`
+//! adds the rules that compare pages with each other. Every error names the
+//! field it belongs to, so the settings form can show it next to the input.
+
+use super::contrast::WCAG_AA;
+use super::storage::{ThemeColor, ThemeConfig};
+use super::types::{BrandSettings, PageConfig, PromoDiscount, SiteSettings};
+use crate::navigation::{page_route, route_section};
+
+/// Longest brand name that still fits the header and launcher labels
+pub const BRAND_NAME_MAX: usize = 60;
+pub const TAGLINE_MAX: usize = 160;
+/// Allowed promotional discount, in percent
+pub const PROMO_PERCENTAGE_RANGE: std::ops::RangeInclusive<u8> = 1..=50;
+/// Longest promo label that fits the pricing badge
+pub const PROMO_LABEL_MAX: usize = 40;
+
+/// A settings input that can fail validation
+#[derive(Debug, Clone, PartialEq)]
+pub enum SettingsField {
+    BrandName,
+    Tagline,
+    ThemeColor(ThemeColor),
+    /// Navigation label of the page with this id
+    PageLabel(String),
+    /// Path of the page with this id
+    PagePath(String),
+    PromoPercentage,
+    PromoLabel,
+    AdminPassword,
+}
+
+/// Why a field can't be saved
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldError {
+    pub field: SettingsField,
+    pub message: String,
+}
+
+impl FieldError {
+    fn new(field: SettingsField, message: impl Into<String>) -> Self {
+        Self {
+            field,
+            message: message.into(),
+        }
+    }
+}
+
+/// The message for `field`, if it has one
+pub fn error_for<'a>(errors: &'a [FieldError], field: &SettingsField) -> Option<&'a str> {
+    errors
+        .iter()
+        .find(|e| &e.field == field)
+        .map(|e| e.message.as_str())
+}
+
+impl ThemeConfig {
+    /// Both text colors need AA contrast against both backgrounds. Each
+    /// text color reports its worst pairing.
+    pub fn contrast_errors(&self) -> Vec<FieldError> {
+        let backgrounds = [ThemeColor::Background, ThemeColor::Surface];
+        [ThemeColor::TextPrimary, ThemeColor::TextSecondary]
+            .into_iter()
+            .filter_map(|text| {
+                let (background, ratio) = backgrounds
+                    .into_iter()
+                    .map(|bg| (bg, self.color(text).contrast_ratio(self.color(bg))))
+                    .min_by(|a, b| a.1.total_cmp(&b.1))?;
+                (ratio < WCAG_AA).then(|| {
+                    FieldError::new(
+                        SettingsField::ThemeColor(text),
+                        format!(
+                            "Only {:.1}:1 against {}; text needs at least {}:1 (WCAG AA)",
+                            ratio,
+                            background.label(),
+                            WCAG_AA
+                        ),
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+impl BrandSettings {
+    /// Name is required; name and tagline have to fit where they're shown
+    pub fn validate(&self) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        let name = self.name.trim();
+        if name.is_empty() {
+            errors.push(FieldError::new(
+                SettingsField::BrandName,
+                "Site name is required",
+            ));
+        } else if name.chars().count() > BRAND_NAME_MAX {
+            errors.push(FieldError::new(
+                SettingsField::BrandName,
+                format!(
+                    "Keep the site name to {} characters or fewer",
+                    BRAND_NAME_MAX
+                ),
+            ));
+        }
+        if self.tagline.trim().chars().count() > TAGLINE_MAX {
+            errors.push(FieldError::new(
+                SettingsField::Tagline,
+                format!("Keep the tagline to {} characters or fewer", TAGLINE_MAX),
+            ));
+        }
+        errors.extend(self.theme.contrast_errors());
+        errors
+    }
+}
+
+impl PageConfig {
+    /// A label, and a path that opens one of the site's public pages in
+    /// English; other languages get their paths from it
+    pub fn validate(&self) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        if self.label.trim().is_empty() {
+            errors.push(FieldError::new(
+                SettingsField::PageLabel(self.id.clone()),
+                "Navigation label is required",
+            ));
+        }
+        let path_error = match page_route(self) {
+            None => Some(format!("\"{}\" doesn't match any page", self.path)),
+            Some(route) if route_section(&route).is_none() => Some(format!(
+                "\"{}\" isn't a public page, so it can't be in the navigation",
+                self.path
+            )),
+            Some(route) if route != route.unlocalized() => Some(format!(
+                "Use the English path \"{}\"; other languages get theirs automatically",
+                route.unlocalized()
+            )),
+            Some(_) => None,
+        };
+        if let Some(message) = path_error {
+            errors.push(FieldError::new(
+                SettingsField::PagePath(self.id.clone()),
+                message,
+            ));
+        }
+        errors
+    }
+}
+
+impl PromoDiscount {
+    /// Only checked while the promotion is on; a disabled one isn't shown
+    pub fn validate(&self) -> Vec<FieldError> {
+        let mut errors = Vec::new();
+        if !self.enabled {
+            return errors;
+        }
+        if !PROMO_PERCENTAGE_RANGE.contains(&self.percentage) {
+            errors.push(promo_percentage_range_error());
+        }
+        if let Some(label) = &self.label {
+            if label.trim().chars().count() > PROMO_LABEL_MAX {
+                errors.push(FieldError::new(
+                    SettingsField::PromoLabel,
+                    format!("Keep the label to {} characters or fewer", PROMO_LABEL_MAX),
+                ));
+            }
+        }
+        errors
+    }
+}
+
+fn promo_percentage_range_error() -> FieldError {
+    FieldError::new(
+        SettingsField::PromoPercentage,
+        format!(
+            "Discount must be between {}% and {}%",
+            PROMO_PERCENTAGE_RANGE.start(),
+            PROMO_PERCENTAGE_RANGE.end()
+        ),
+    )
+}
+
+/// The promo percentage as typed. Text that isn't a whole number is an
+/// error of its own rather than a 0 that happens to fail the range check.
+pub fn parse_promo_percentage(text: &str) -> Result<u8, FieldError> {
+    let percentage: u32 = text.trim().parse().map_err(|_| {
+        FieldError::new(
+            SettingsField::PromoPercentage,
+            "Enter the discount as a whole number, like 15",
+        )
+    })?;
+    u8::try_from(percentage).map_err(|_| promo_percentage_range_error())
+}
+
+impl SiteSettings {
+    /// Every problem that should block saving, in form order
+    pub fn validate(&self) -> Result<(), Vec<FieldError>> {
+        let mut errors = self.brand.validate();
+        errors.extend(self.discount.promo_discount.validate());
+
+        let pages = self.pages_in_order();
+        for (i, page) in pages.iter().enumerate() {
+            errors.extend(page.validate());
+            let route = page_route(page);
+            if let Some(first) = pages[..i]
+                .iter()
+                .find(|other| route.is_some() && page_route(other) == route)
+            {
+                errors.push(FieldError::new(
+                    SettingsField::PagePath(page.id.clone()),
+                    format!("Same path as \"{}\"", first.label),
+                ));
+            }
+        }
+
+        if self.admin_password_hash.trim().is_empty() {
+            errors.push(FieldError::new(
+                SettingsField::AdminPassword,
+                "Admin password is required",
+            ));
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::Color;
+
+    fn errors(settings: &SiteSettings) -> Vec<FieldError> {
+        settings.validate().err().unwrap_or_default()
+    }
+
+    fn fields(settings: &SiteSettings) -> Vec<SettingsField> {
+        errors(settings).into_iter().map(|e| e.field).collect()
+    }
+
+    fn page_index(settings: &SiteSettings, id: &str) -> usize {
+        settings.pages.iter().position(|p| p.id == id).unwrap()
+    }
+
+    #[test]
+    fn test_default_settings_are_valid() {
+        assert_eq!(SiteSettings::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_every_theme_preset_is_valid() {
+        for preset in ThemeConfig::all_presets() {
+            assert!(preset.contrast_errors().is_empty(), "{} fails", preset.name);
+        }
+    }
+
+    #[test]
+    fn test_brand_name_is_required() {
+        let mut settings = SiteSettings::default();
+        settings.brand.name = "   ".to_string();
+        assert_eq!(fields(&settings), vec![SettingsField::BrandName]);
+    }
+
+    #[test]
+    fn test_brand_name_and_tagline_length() {
+        let mut settings = SiteSettings::default();
+        settings.brand.name = "x".repeat(BRAND_NAME_MAX);
+        settings.brand.tagline = "x".repeat(TAGLINE_MAX);
+        assert_eq!(settings.validate(), Ok(()));
+
+        settings.brand.name.push('x');
+        settings.brand.tagline.push('x');
+        assert_eq!(
+            fields(&settings),
+            vec![SettingsField::BrandName, SettingsField::Tagline]
+        );
+    }
+
+    #[test]
+    fn test_low_contrast_text_color_is_reported() {
+        let mut settings = SiteSettings::default();
+        settings.brand.theme.text_secondary = Color::hex(0x444444);
+        let errors = errors(&settings);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].field,
+            SettingsField::ThemeColor(ThemeColor::TextSecondary)
+        );
+        // Surface is the lighter background, so the worse pairing
+        assert!(errors[0].message.contains("against Surface"));
+    }
+
+    #[test]
+    fn test_page_label_is_required() {
+        let mut settings = SiteSettings::default();
+        let i = page_index(&settings, "about");
+        settings.pages[i].label = String::new();
+        assert_eq!(
+            fields(&settings),
+            vec![SettingsField::PageLabel("about".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_page_path_must_match_a_public_route() {
+        let mut settings = SiteSettings::default();
+        let i = page_index(&settings, "about");
+        let about = SettingsField::PagePath("about".to_string());
+
+        for path in ["/no-such-page", "about", "/admin/settings", "/es/about"] {
+            settings.pages[i].path = path.to_string();
+            assert_eq!(fields(&settings), vec![about.clone()], "{}", path);
+        }
+
+        settings.pages[i].path = "/articles/build-vs-buy-software".to_string();
+        assert_eq!(settings.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_duplicate_page_path_is_reported_on_the_later_page() {
+        let mut settings = SiteSettings::default();
+        let i = page_index(&settings, "contact");
+        settings.pages[i].path = "/about".to_string();
+        let errors = errors(&settings);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].field,
+            SettingsField::PagePath("contact".to_string())
+        );
+        assert_eq!(errors[0].message, "Same path as \"About\"");
+    }
+
+    #[test]
+    fn test_promo_percentage_range_only_when_enabled() {
+        let mut settings = SiteSettings::default();
+        settings.discount.promo_discount.percentage = 0;
+        assert_eq!(settings.validate(), Ok(()));
+
+        settings.discount.promo_discount.enabled = true;
+        assert_eq!(fields(&settings), vec![SettingsField::PromoPercentage]);
+        settings.discount.promo_discount.percentage = 51;
+        assert_eq!(fields(&settings), vec![SettingsField::PromoPercentage]);
+        for percentage in [1, 50] {
+            settings.discount.promo_discount.percentage = percentage;
+            assert_eq!(settings.validate(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_promo_percentage_text_must_be_a_number() {
+        assert_eq!(parse_promo_percentage(" 15 "), Ok(15));
+        for text in ["", "abc", "12.5", "-3"] {
+            let error = parse_promo_percentage(text).unwrap_err();
+            assert_eq!(error.field, SettingsField::PromoPercentage);
+            assert!(error.message.contains("whole number"), "{}", text);
+        }
+        assert!(parse_promo_percentage("300")
+            .unwrap_err()
+            .message
+            .contains("between"));
+    }
+
+    #[test]
+    fn test_promo_label_length() {
+        let mut settings = SiteSettings::default();
+        settings.discount.promo_discount.enabled = true;
+        settings.discount.promo_discount.label = Some("x".repeat(PROMO_LABEL_MAX));
+        assert_eq!(settings.validate(), Ok(()));
+        settings.discount.promo_discount.label = Some("x".repeat(PROMO_LABEL_MAX + 1));
+        assert_eq!(fields(&settings), vec![SettingsField::PromoLabel]);
+    }
+
+    #[test]
+    fn test_admin_password_is_required() {
+        let settings = SiteSettings {
+            admin_password_hash: " ".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(fields(&settings), vec![SettingsField::AdminPassword]);
+    }
+
+    #[test]
+    fn test_error_for_finds_the_field_message() {
+        let mut settings = SiteSettings::default();
+        settings.brand.name = String::new();
+        settings.admin_password_hash = String::new();
+        let errors = errors(&settings);
+        assert_eq!(
+            error_for(&errors, &SettingsField::AdminPassword),
+            Some("Admin password is required")
+        );
+        assert_eq!(error_for(&errors, &SettingsField::Tagline), None);
+    }
+}
@@ -0,0 +1,10 @@
+//! Escaping for markup the site writes out as text, like email bodies and
+//! preview image SVGs.
+
+/// `text` made safe for HTML or SVG text and quoted attribute values
+pub fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,368 @@
+//! Translated UI strings and the locale a page is shown in.
+//!
+//! Messages live in `locales/<code>.ftl`, a small subset of Fluent: one
+//! `id = text` per message, indented lines continue it, and `{ $name }`
+//! is filled from the arguments. build.rs checks the catalogs and compiles
+//! them in. A message missing from a translation falls back to English.
+//!
+//! English pages keep their unprefixed URLs; every other locale is a first
+//! path segment, so `/services` in Spanish is `/es/services`.
+
+use crate::Route;
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::cell::OnceCell;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::str::FromStr;
+
+/// A language the site is translated into
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    #[default]
+    En,
+    Es,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::En, Locale::Es];
+
+    /// The source language, served without a URL prefix
+    pub const DEFAULT: Locale = Locale::En;
+
+    /// BCP 47 tag, used in URLs, `lang` and `hreflang`
+    pub fn code(self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Es => "es",
+        }
+    }
+
+    /// The language's name in that language, for the switcher
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Locale::En => "English",
+            Locale::Es => "Español",
+        }
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl FromStr for Locale {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Locale::ALL
+            .into_iter()
+            .find(|l| l.code() == s)
+            .ok_or_else(|| format!("\"{}\" is not a supported locale", s))
+    }
+}
+
+type Catalog = HashMap<String, String>;
+
+thread_local! {
+    static CATALOGS: OnceCell<BTreeMap<Locale, Catalog>> = const { OnceCell::new() };
+}
+
+fn with_catalogs<R>(f: impl FnOnce(&BTreeMap<Locale, Catalog>) -> R) -> R {
+    CATALOGS.with(|cell| {
+        f(cell.get_or_init(|| {
+            serde_json::from_str(include_str!(concat!(env!("OUT_DIR"), "/locales.json")))
+                .expect("build.rs emits one id to message map per locale")
+        }))
+    })
+}
+
+/// Look up `id` in `locale`, falling back to English and then to the id
+/// itself so a typo shows up on the page instead of crashing it
+pub fn message(locale: Locale, id: &str, args: &[(&str, &str)]) -> String {
+    with_catalogs(|catalogs| {
+        let text = [locale, Locale::DEFAULT]
+            .iter()
+            .find_map(|l| catalogs.get(l)?.get(id))
+            .map_or(id, String::as_str);
+        format_message(text, args)
+    })
+}
+
+/// Fill `{ $name }` placeables; unknown names are left as written
+fn format_message(text: &str, args: &[(&str, &str)]) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('{') {
+        let Some(len) = rest[start..].find('}') else {
+            break;
+        };
+        out.push_str(&rest[..start]);
+        let placeable = &rest[start..start + len + 1];
+        let name = placeable[1..len].trim().trim_start_matches('$');
+        match args.iter().find(|(n, _)| *n == name) {
+            Some((_, value)) => out.push_str(value),
+            None => out.push_str(placeable),
+        }
+        rest = &rest[start + len + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Message lookup for one locale
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct I18n {
+    pub locale: Locale,
+}
+
+impl I18n {
+    pub fn new(locale: Locale) -> Self {
+        I18n { locale }
+    }
+
+    pub fn t(&self, id: &str) -> String {
+        message(self.locale, id, &[])
+    }
+
+    pub fn t_args(&self, id: &str, args: &[(&str, &str)]) -> String {
+        message(self.locale, id, args)
+    }
+
+    /// `id` only if this locale's own catalog has it, without falling back
+    pub fn translated(&self, id: &str) -> Option<String> {
+        with_catalogs(|catalogs| catalogs.get(&self.locale)?.get(id).cloned())
+    }
+
+    /// `route` in this locale, for links
+    pub fn route(&self, route: Route) -> Route {
+        route.localized(self.locale)
+    }
+}
+
+/// Where the site is published, for absolute `hreflang` links
+pub const SITE_URL: &str = "https://poundsconsulting.net";
+
+/// The page's path in every locale, for `hreflang` alternates. The root of a
+/// prefixed locale is written `/es`, without the router's trailing slash.
+pub fn alternate_paths(route: &Route) -> Vec<(Locale, String)> {
+    Locale::ALL
+        .into_iter()
+        .map(|locale| {
+            let path = route.localized(locale).to_string();
+            let path = match path.strip_suffix('/') {
+                Some(trimmed) if !trimmed.is_empty() => trimmed.to_string(),
+                _ => path,
+            };
+            (locale, path)
+        })
+        .collect()
+}
+
+/// Locale of the current page, English outside the router
+pub fn use_locale() -> Locale {
+    dioxus::router::try_router()
+        .map_or(Locale::DEFAULT, |router| router.current::<Route>().locale())
+}
+
+/// Messages in the current page's locale
+pub fn use_i18n() -> I18n {
+    I18n::new(use_locale())
+}
+
+impl Route {
+    /// Language the route is shown in
+    pub fn locale(&self) -> Locale {
+        match self {
+            Route::LocalizedHome { locale }
+            | Route::LocalizedAbout { locale }
+            | Route::LocalizedServices { locale }
+            | Route::LocalizedServiceDetail { locale, .. }
+            | Route::LocalizedPortfolio { locale }
+            | Route::LocalizedPortfolioDetail { locale, .. }
+            | Route::LocalizedPortfolioTag { locale, .. }
+            | Route::LocalizedContact { locale }
+            | Route::LocalizedArticles { locale }
+            | Route::LocalizedArticleDetail { locale, .. }
+            | Route::LocalizedNewsletterConfirm { locale, .. }
+            | Route::LocalizedNewsletterUnsubscribe { locale, .. }
+            | Route::LocalizedNotFound { locale, .. } => *locale,
+            _ => Locale::DEFAULT,
+        }
+    }
+
+    /// The same page in English, which is how navigation and feature
+    /// toggles identify it
+    pub fn unlocalized(&self) -> Route {
+        match self.clone() {
+            Route::LocalizedHome { .. } => Route::Home {},
+            Route::LocalizedAbout { .. } => Route::About {},
+            Route::LocalizedServices { .. } => Route::Services {},
+            Route::LocalizedServiceDetail { slug, .. } => Route::ServiceDetail { slug },
+            Route::LocalizedPortfolio { .. } => Route::Portfolio {},
+            Route::LocalizedPortfolioDetail { slug, .. } => Route::PortfolioDetail { slug },
+            Route::LocalizedPortfolioTag { tag, .. } => Route::PortfolioTag { tag },
+            Route::LocalizedContact { .. } => Route::Contact {},
+            Route::LocalizedArticles { .. } => Route::Articles {},
+            Route::LocalizedArticleDetail { slug, .. } => Route::ArticleDetail { slug },
+            Route::LocalizedNewsletterConfirm { token, .. } => Route::NewsletterConfirm { token },
+            Route::LocalizedNewsletterUnsubscribe { token, .. } => {
+                Route::NewsletterUnsubscribe { token }
+            }
+            Route::LocalizedNotFound { segments, .. } => Route::NotFound { segments },
+            route => route,
+        }
+    }
+
+    /// The same page in `locale`. Admin routes have no translations and
+    /// come back unchanged.
+    pub fn localized(&self, locale: Locale) -> Route {
+        let route = self.unlocalized();
+        if locale == Locale::DEFAULT {
+            return route;
+        }
+        match route {
+            Route::Home {} => Route::LocalizedHome { locale },
+            Route::About {} => Route::LocalizedAbout { locale },
+            Route::Services {} => Route::LocalizedServices { locale },
+            Route::ServiceDetail { slug } => Route::LocalizedServiceDetail { locale, slug },
+            Route::Portfolio {} => Route::LocalizedPortfolio { locale },
+            Route::PortfolioDetail { slug } => Route::LocalizedPortfolioDetail { locale, slug },
+            Route::PortfolioTag { tag } => Route::LocalizedPortfolioTag { locale, tag },
+            Route::Contact {} => Route::LocalizedContact { locale },
+            Route::Articles {} => Route::LocalizedArticles { locale },
+            Route::ArticleDetail { slug } => Route::LocalizedArticleDetail { locale, slug },
+            Route::NewsletterConfirm { token } => {
+                Route::LocalizedNewsletterConfirm { locale, token }
+            }
+            Route::NewsletterUnsubscribe { token } => {
+                Route::LocalizedNewsletterUnsubscribe { locale, token }
+            }
+            Route::NotFound { segments } => Route::LocalizedNotFound { locale, segments },
+            route => route,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    /// Every `t("…")` and `t_args("…", …)` in the source, with its file
+    fn ids_in_source(dir: &Path, found: &mut Vec<(String, String)>) {
+        for entry in std::fs::read_dir(dir).unwrap().flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                ids_in_source(&path, found);
+            } else if path.extension().is_some_and(|e| e == "rs") && !path.ends_with("i18n.rs") {
+                let text = std::fs::read_to_string(&path).unwrap();
+                for call in [".t(\"", ".t_args(\""] {
+                    for rest in text.split(call).skip(1) {
+                        if let Some((id, _)) = rest.split_once('"') {
+                            found.push((path.display().to_string(), id.to_string()));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_every_message_used_is_in_english() {
+        let mut used = Vec::new();
+        ids_in_source(
+            &Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+            &mut used,
+        );
+        assert!(used.len() > 100, "found only {} messages", used.len());
+        for (file, id) in used {
+            assert!(
+                I18n::new(Locale::En).translated(&id).is_some(),
+                "{} uses `{}`, which isn't in locales/en.ftl",
+                file,
+                id
+            );
+        }
+    }
+
+    #[test]
+    fn test_every_locale_has_a_catalog() {
+        with_catalogs(|catalogs| {
+            assert_eq!(catalogs.keys().copied().collect::<Vec<_>>(), Locale::ALL);
+        });
+    }
+
+    #[test]
+    fn test_missing_messages_fall_back_to_english_then_id() {
+        let es = I18n::new(Locale::Es);
+        assert_eq!(es.t("nav-services"), "Servicios");
+        assert_eq!(es.t("no-such-message"), "no-such-message");
+        assert_eq!(es.translated("no-such-message"), None);
+    }
+
+    #[test]
+    fn test_format_message_fills_placeables() {
+        assert_eq!(
+            format_message(
+                "{ $count } projects in { $tag }",
+                &[("count", "3"), ("tag", "Rust")]
+            ),
+            "3 projects in Rust"
+        );
+        assert_eq!(
+            format_message("{$name} logo", &[("name", "APEX")]),
+            "APEX logo"
+        );
+        assert_eq!(format_message("Hi { $who }", &[]), "Hi { $who }");
+        assert_eq!(format_message("Open { brace", &[]), "Open { brace");
+    }
+
+    #[test]
+    fn test_locale_codes_round_trip() {
+        for locale in Locale::ALL {
+            assert_eq!(locale.code().parse::<Locale>(), Ok(locale));
+        }
+        assert!("fr".parse::<Locale>().is_err());
+        assert!("EN".parse::<Locale>().is_err());
+    }
+
+    #[test]
+    fn test_routes_move_between_locales() {
+        let english = Route::ServiceDetail {
+            slug: "web-development".to_string(),
+        };
+        let spanish = english.localized(Locale::Es);
+        assert_eq!(spanish.to_string(), "/es/services/web-development");
+        assert_eq!(spanish.locale(), Locale::Es);
+        assert_eq!(spanish.unlocalized(), english);
+        assert_eq!(spanish.localized(Locale::En), english);
+        assert_eq!(
+            Route::AdminDashboard {}.localized(Locale::Es),
+            Route::AdminDashboard {}
+        );
+    }
+
+    #[test]
+    fn test_alternate_paths_trim_the_prefixed_root() {
+        assert_eq!(
+            alternate_paths(&Route::LocalizedHome { locale: Locale::Es }),
+            vec![
+                (Locale::En, "/".to_string()),
+                (Locale::Es, "/es".to_string())
+            ]
+        );
+        assert_eq!(
+            alternate_paths(&Route::Contact {}),
+            vec![
+                (Locale::En, "/contact".to_string()),
+                (Locale::Es, "/es/contact".to_string())
+            ]
+        );
+    }
+}
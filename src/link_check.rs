@@ -0,0 +1,580 @@
+//! Link and asset integrity: every URL and path stored in content is
+//! checked against the routes, the published slugs and `assets/` on disk.
+//!
+//! `cargo test link_check -- --nocapture` prints the report. External URLs
+//! are only checked for shape unless `CHECK_EXTERNAL_LINKS=1` is set, which
+//! also requests each one (with `curl`) and reports the ones that fail.
+
+use crate::content::redirects::SlugRedirects;
+use crate::content::{
+    load_all_portfolio, load_all_services, load_articles, load_testimonials, tag_slug,
+    ArticleStatus, PortfolioProject, Service, Testimonial,
+};
+use crate::Route;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::path::Path;
+use std::process::Command;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LinkKind {
+    /// A site path such as `/portfolio/apex-earthworks`
+    Internal,
+    /// A file path such as `assets/portfolio/logo.png`
+    Asset,
+    External,
+}
+
+/// One URL or path found in content, and where it came from
+#[derive(Debug, Clone, PartialEq)]
+struct Link {
+    source: String,
+    target: String,
+    kind: LinkKind,
+}
+
+impl Link {
+    fn new(source: String, target: &str, kind: LinkKind) -> Self {
+        Link {
+            source,
+            target: target.trim().to_string(),
+            kind,
+        }
+    }
+
+    /// Classifies a link written in markup, where any kind can appear
+    fn from_markup(source: String, target: &str) -> Self {
+        let target = target.trim();
+        let kind = if target.starts_with('/') && !target.starts_with("/assets/") {
+            LinkKind::Internal
+        } else if target.starts_with("assets/") || target.starts_with("/assets/") {
+            LinkKind::Asset
+        } else {
+            LinkKind::External
+        };
+        Link::new(source, target, kind)
+    }
+
+    /// A field holding an asset path, which may also point off-site
+    fn from_asset_field(source: String, target: &str) -> Self {
+        if target.starts_with("http://") || target.starts_with("https://") {
+            Link::new(source, target, LinkKind::External)
+        } else {
+            Link::new(source, target, LinkKind::Asset)
+        }
+    }
+}
+
+/// `href="…"` values in HTML written into content fields
+fn html_hrefs(text: &str) -> Vec<&str> {
+    let mut hrefs = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("href=\"") {
+        rest = &rest[start + 6..];
+        let Some(end) = rest.find('"') else {
+            break;
+        };
+        hrefs.push(&rest[..end]);
+        rest = &rest[end..];
+    }
+    hrefs
+}
+
+/// Targets of Markdown links and images, `[text](target)` / `![alt](target)`
+fn markdown_targets(text: &str) -> Vec<&str> {
+    let mut targets = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("](") {
+        rest = &rest[start + 2..];
+        let Some(end) = rest.find(')') else {
+            break;
+        };
+        targets.push(&rest[..end]);
+        rest = &rest[end..];
+    }
+    targets
+}
+
+/// Links written inline in a prose field
+fn prose_links(source: &str, field: &str, text: &str) -> Vec<Link> {
+    html_hrefs(text)
+        .into_iter()
+        .chain(markdown_targets(text))
+        .map(|target| Link::from_markup(format!("{} `{}`", source, field), target))
+        .collect()
+}
+
+fn service_links(service: &Service) -> Vec<Link> {
+    let source = format!("services/{}", service.slug);
+    let mut links = Vec::new();
+    for (field, text) in [
+        ("tagline", &service.tagline),
+        ("description", &service.description),
+        ("long_description", &service.long_description),
+    ] {
+        links.extend(prose_links(&source, field, text));
+    }
+    for (i, feature) in service.features.iter().enumerate() {
+        links.extend(prose_links(&source, &format!("features[{}]", i), feature));
+    }
+    for (i, persona) in service.personas.iter().enumerate() {
+        let field = format!("personas[{}].description", i);
+        links.extend(prose_links(&source, &field, &persona.description));
+    }
+    links
+}
+
+fn testimonial_links(source: &str, prefix: &str, testimonial: &Testimonial) -> Vec<Link> {
+    let mut links = prose_links(source, &format!("{}quote", prefix), &testimonial.quote);
+    let field = |name: &str| format!("{} `{}{}`", source, prefix, name);
+    if let Some(photo) = &testimonial.photo {
+        links.push(Link::from_asset_field(field("photo"), photo));
+    }
+    if let Some(slug) = &testimonial.project_slug {
+        let path = Route::PortfolioDetail { slug: slug.clone() }.to_string();
+        links.push(Link::new(field("project_slug"), &path, LinkKind::Internal));
+    }
+    if let Some(slug) = &testimonial.service_slug {
+        let path = Route::ServiceDetail { slug: slug.clone() }.to_string();
+        links.push(Link::new(field("service_slug"), &path, LinkKind::Internal));
+    }
+    links
+}
+
+fn project_links(project: &PortfolioProject) -> Vec<Link> {
+    let source = format!("portfolio/{}", project.slug);
+    let field = |name: &str| format!("{} `{}`", source, name);
+    let mut links = Vec::new();
+    if !project.external_url.is_empty() {
+        links.push(Link::new(
+            field("external_url"),
+            &project.external_url,
+            LinkKind::External,
+        ));
+    }
+    if let Some(url) = &project.before_url {
+        links.push(Link::new(field("before_url"), url, LinkKind::External));
+    }
+    for (name, path) in [
+        ("logo", &project.logo),
+        ("screenshot", &project.screenshot),
+        ("video", &project.video),
+        (
+            "case_study.before_screenshot",
+            &project.case_study.before_screenshot,
+        ),
+    ] {
+        if let Some(path) = path {
+            links.push(Link::from_asset_field(field(name), path));
+        }
+    }
+    for (i, image) in project.case_study.gallery.iter().enumerate() {
+        let name = format!("case_study.gallery[{}].src", i);
+        links.push(Link::from_asset_field(field(&name), &image.src));
+    }
+    for (name, text) in [
+        ("description", &project.description),
+        ("long_description", &project.long_description),
+    ] {
+        links.extend(prose_links(&source, name, text));
+    }
+    if let Some(testimonial) = &project.case_study.testimonial {
+        links.extend(testimonial_links(
+            &source,
+            "case_study.testimonial.",
+            testimonial,
+        ));
+    }
+    links
+}
+
+/// Everything a page can link to, from the content being checked
+struct Site {
+    services: BTreeSet<String>,
+    projects: BTreeSet<String>,
+    articles: BTreeSet<String>,
+    tags: BTreeSet<String>,
+    service_redirects: SlugRedirects,
+    project_redirects: SlugRedirects,
+    article_redirects: SlugRedirects,
+}
+
+impl Site {
+    fn load() -> Self {
+        let services = load_all_services();
+        let portfolio = load_all_portfolio();
+        let articles = load_articles();
+        let published = |status: &ArticleStatus| *status == ArticleStatus::Published;
+        Site {
+            services: services
+                .services
+                .iter()
+                .filter(|s| published(&s.status))
+                .map(|s| s.slug.clone())
+                .collect(),
+            projects: portfolio
+                .projects
+                .iter()
+                .filter(|p| published(&p.status))
+                .map(|p| p.slug.clone())
+                .collect(),
+            articles: articles
+                .articles
+                .iter()
+                .filter(|a| published(&a.status))
+                .map(|a| a.slug.clone())
+                .collect(),
+            tags: portfolio
+                .projects
+                .iter()
+                .filter(|p| published(&p.status))
+                .flat_map(|p| p.tech_tags.iter().map(|t| tag_slug(t)))
+                .collect(),
+            service_redirects: services.redirects,
+            project_redirects: portfolio.redirects,
+            article_redirects: articles.redirects,
+        }
+    }
+
+    /// The path matches a route, and any slug in it is published
+    fn check_internal(&self, href: &str) -> Result<(), String> {
+        let path = href.split(['#', '?']).next().unwrap_or_default();
+        let slug_in = |slugs: &BTreeSet<String>, slug: &str, redirects: Option<&SlugRedirects>| {
+            if slugs.contains(slug) {
+                return Ok(());
+            }
+            match redirects.and_then(|r| r.resolve(slug)) {
+                Some(to) => Err(format!("{} was renamed to {}; link the new slug", slug, to)),
+                None => Err(format!("nothing is published at {}", path)),
+            }
+        };
+        match path.parse::<Route>() {
+            Err(_) | Ok(Route::NotFound { .. }) => Err(format!("no route matches {}", path)),
+            Ok(Route::ServiceDetail { slug }) => {
+                slug_in(&self.services, &slug, Some(&self.service_redirects))
+            }
+            Ok(Route::PortfolioDetail { slug }) => {
+                slug_in(&self.projects, &slug, Some(&self.project_redirects))
+            }
+            Ok(Route::ArticleDetail { slug }) => {
+                slug_in(&self.articles, &slug, Some(&self.article_redirects))
+            }
+            Ok(Route::PortfolioTag { tag }) => slug_in(&self.tags, &tag, None),
+            Ok(_) => Ok(()),
+        }
+    }
+}
+
+/// The path is inside `assets/` and the file exists
+fn check_asset(root: &Path, path: &str) -> Result<(), String> {
+    let relative = path.trim_start_matches('/');
+    if !relative.starts_with("assets/") || relative.split('/').any(|part| part == "..") {
+        return Err(format!("{} is outside assets/", path));
+    }
+    if root.join(relative).is_file() {
+        Ok(())
+    } else {
+        Err(format!("{} is not in assets/", path))
+    }
+}
+
+/// An absolute http(s) URL with a plausible host, or a mailto:/tel: link
+fn check_external(url: &str) -> Result<(), String> {
+    if url.is_empty() {
+        return Err("URL is empty".to_string());
+    }
+    if url.contains(char::is_whitespace) {
+        return Err(format!("{} contains whitespace", url));
+    }
+    if let Some(address) = url.strip_prefix("mailto:") {
+        return match address.split_once('@') {
+            Some((user, domain)) if !user.is_empty() && domain.contains('.') => Ok(()),
+            _ => Err(format!("{} is not an email address", url)),
+        };
+    }
+    if let Some(number) = url.strip_prefix("tel:") {
+        let digits = number.chars().filter(char::is_ascii_digit).count();
+        return if digits >= 7 {
+            Ok(())
+        } else {
+            Err(format!("{} is not a phone number", url))
+        };
+    }
+    let Some(rest) = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+    else {
+        return Err(format!("{} is not an http(s) URL", url));
+    };
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host = match authority.rsplit_once(':') {
+        Some((host, port)) if port.chars().all(|c| c.is_ascii_digit()) => host,
+        _ => authority,
+    };
+    let valid_label = |label: &str| {
+        !label.is_empty()
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    };
+    if host.contains('.') && host.split('.').all(valid_label) {
+        Ok(())
+    } else {
+        Err(format!("{} has no valid host", url))
+    }
+}
+
+/// Requests the URL and expects a 2xx or 3xx answer. Some servers refuse
+/// HEAD, so a failed HEAD is retried as a GET before it counts.
+fn check_online(url: &str) -> Result<(), String> {
+    let request = |head: bool| {
+        let mut curl = Command::new("curl");
+        curl.args(["--silent", "--show-error", "--location", "--max-time", "20"])
+            .args(["--output", "/dev/null", "--write-out", "%{http_code}"]);
+        if head {
+            curl.arg("--head");
+        }
+        let output = curl
+            .arg(url)
+            .output()
+            .map_err(|e| format!("could not run curl: {}", e))?;
+        let code = String::from_utf8_lossy(&output.stdout)
+            .trim()
+            .parse::<u16>()
+            .unwrap_or(0);
+        let error = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        Ok::<_, String>((code, error))
+    };
+    let (code, error) = match request(true)? {
+        (200..=399, _) => return Ok(()),
+        _ => request(false)?,
+    };
+    match code {
+        200..=399 => Ok(()),
+        0 => Err(format!("{} did not respond ({})", url, error)),
+        code => Err(format!("{} answered {}", url, code)),
+    }
+}
+
+/// A link that failed a check
+struct Problem {
+    source: String,
+    message: String,
+}
+
+/// What was checked and what failed
+#[derive(Default)]
+struct Report {
+    internal: usize,
+    assets: usize,
+    external: usize,
+    online: bool,
+    problems: Vec<Problem>,
+}
+
+impl Report {
+    fn run(links: &[Link], site: &Site, root: &Path, online: bool) -> Self {
+        let mut report = Report {
+            online,
+            ..Report::default()
+        };
+        let mut requested = BTreeSet::new();
+        for link in links {
+            let result = match link.kind {
+                LinkKind::Internal => {
+                    report.internal += 1;
+                    site.check_internal(&link.target)
+                }
+                LinkKind::Asset => {
+                    report.assets += 1;
+                    check_asset(root, &link.target)
+                }
+                LinkKind::External => {
+                    report.external += 1;
+                    check_external(&link.target).and_then(|()| {
+                        let web = link.target.starts_with("http");
+                        if online && web && requested.insert(link.target.clone()) {
+                            check_online(&link.target)
+                        } else {
+                            Ok(())
+                        }
+                    })
+                }
+            };
+            if let Err(message) = result {
+                report.problems.push(Problem {
+                    source: link.source.clone(),
+                    message,
+                });
+            }
+        }
+        report
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "Checked {} internal links, {} asset paths and {} external URLs{}",
+            self.internal,
+            self.assets,
+            self.external,
+            if self.online {
+                " (online)"
+            } else {
+                " (format only; set CHECK_EXTERNAL_LINKS=1 to request them)"
+            }
+        )?;
+        if self.problems.is_empty() {
+            return writeln!(f, "No problems found");
+        }
+        writeln!(f, "{} problem(s):", self.problems.len())?;
+        for problem in &self.problems {
+            writeln!(f, "  {}: {}", problem.source, problem.message)?;
+        }
+        Ok(())
+    }
+}
+
+/// Every link in the site's content, trashed items excepted
+fn content_links() -> Vec<Link> {
+    let mut links = Vec::new();
+    for service in load_all_services().services {
+        if service.status != ArticleStatus::Trashed {
+            links.extend(service_links(&service));
+            for (locale, text) in &service.translations {
+                let source = format!("services/{}.{}", service.slug, locale);
+                for (field, text) in [
+                    ("tagline", &text.tagline),
+                    ("description", &text.description),
+                    ("long_description", &text.long_description),
+                ] {
+                    links.extend(prose_links(&source, field, text));
+                }
+            }
+        }
+    }
+    for project in load_all_portfolio().projects {
+        if project.status != ArticleStatus::Trashed {
+            links.extend(project_links(&project));
+            for (locale, text) in &project.translations {
+                let source = format!("portfolio/{}.{}", project.slug, locale);
+                links.extend(prose_links(&source, "description", &text.description));
+                links.extend(prose_links(
+                    &source,
+                    "long_description",
+                    &text.long_description,
+                ));
+            }
+        }
+    }
+    for article in load_articles().articles {
+        if article.status != ArticleStatus::Trashed {
+            let source = format!("articles/{}", article.slug);
+            links.extend(prose_links(&source, "excerpt", &article.excerpt));
+            links.extend(prose_links(&source, "content", &article.content));
+            for (locale, text) in &article.translations {
+                let source = format!("articles/{}.{}", article.slug, locale);
+                links.extend(prose_links(&source, "excerpt", &text.excerpt));
+                links.extend(prose_links(&source, "content", &text.content));
+            }
+        }
+    }
+    for testimonial in load_testimonials().testimonials {
+        let source = format!("testimonials/{}", testimonial.id);
+        links.extend(testimonial_links(&source, "", &testimonial));
+    }
+    links
+}
+
+#[test]
+fn test_content_links_and_assets_resolve() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let online = std::env::var_os("CHECK_EXTERNAL_LINKS").is_some();
+    let links = content_links();
+    assert!(links.iter().any(|l| l.kind == LinkKind::Asset));
+    assert!(links.iter().any(|l| l.kind == LinkKind::External));
+
+    let report = Report::run(&links, &Site::load(), root, online);
+    println!("{}", report);
+    assert!(report.problems.is_empty(), "\n{}", report);
+}
+
+#[test]
+fn test_prose_links_find_html_and_markdown() {
+    let text = r#"See <a href="https://w3.org" target="_blank">the spec</a>, [our work](/portfolio) and
+
+![Chart](assets/chart.png)"#;
+    let links = prose_links("articles/a", "content", text);
+    let found = links
+        .iter()
+        .map(|l| (l.target.as_str(), l.kind))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        found,
+        [
+            ("https://w3.org", LinkKind::External),
+            ("/portfolio", LinkKind::Internal),
+            ("assets/chart.png", LinkKind::Asset),
+        ]
+    );
+    assert_eq!(links[0].source, "articles/a `content`");
+}
+
+#[test]
+fn test_check_internal_requires_published_slugs() {
+    let mut site = Site {
+        services: BTreeSet::from(["web".to_string()]),
+        projects: BTreeSet::new(),
+        articles: BTreeSet::from(["hello".to_string()]),
+        tags: BTreeSet::from(["rust".to_string()]),
+        service_redirects: SlugRedirects::default(),
+        project_redirects: SlugRedirects::default(),
+        article_redirects: SlugRedirects::default(),
+    };
+    site.service_redirects.record("old-web", "web");
+
+    assert!(site.check_internal("/").is_ok());
+    assert!(site.check_internal("/services/web#pricing").is_ok());
+    assert!(site.check_internal("/articles/hello").is_ok());
+    assert!(site.check_internal("/portfolio/tech/rust").is_ok());
+    assert!(site.check_internal("/admin/dashboard").is_ok());
+    assert!(site.check_internal("/portfolio/missing").is_err());
+    assert!(site.check_internal("/articles/draft").is_err());
+    assert!(site.check_internal("/no/such/page").is_err());
+    let renamed = site.check_internal("/services/old-web").unwrap_err();
+    assert!(renamed.contains("renamed to web"), "{}", renamed);
+}
+
+#[test]
+fn test_check_asset_and_external() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    assert!(check_asset(root, "assets/main.css").is_ok());
+    assert!(check_asset(root, "/assets/main.css").is_ok());
+    assert!(check_asset(root, "assets/missing.png").is_err());
+    assert!(check_asset(root, "src/main.rs").is_err());
+    assert!(check_asset(root, "assets/../Cargo.toml").is_err());
+
+    for ok in [
+        "https://example.com",
+        "http://sub.example.co.uk:8080/path?q=1#top",
+        "https://web.archive.org/web/2020/http://www.example.com/",
+        "mailto:hello@example.com",
+        "tel:+15551234567",
+    ] {
+        assert!(check_external(ok).is_ok(), "{}", ok);
+    }
+    for bad in [
+        "",
+        "example.com",
+        "ftp://example.com",
+        "https://",
+        "https://localhost",
+        "https://exa mple.com",
+        "https://-bad-.com",
+        "mailto:nobody",
+    ] {
+        assert!(check_external(bad).is_err(), "{:?}", bad);
+    }
+}
@@ -1,13 +1,36 @@
 use dioxus::prelude::*;
 
+mod analytics;
 mod components;
 mod content;
+mod html;
+mod i18n;
+#[cfg(test)]
+mod link_check;
+mod navigation;
+mod newsletter;
+#[cfg(test)]
+mod og_image;
 mod pages;
+#[cfg(test)]
+mod pwa;
+#[cfg(test)]
+mod render_tests;
+#[cfg(test)]
+mod sitemap;
+mod suggestions;
 
-use components::{Footer, Header};
+use components::{Footer, Header, PageErrorBoundary, SkipLink, MAIN_CONTENT_ID};
+use i18n::Locale;
 use pages::{
-    About, AdminArticleEdit, AdminArticleNew, AdminArticles, AdminDashboard, AdminLogin,
-    AdminSettings, ArticleDetail, Articles, Contact, Home, Portfolio, PortfolioDetail,
+    About, AdminArticleEdit, AdminArticleNew, AdminArticles, AdminDashboard, AdminIcons,
+    AdminLogin, AdminPortfolio, AdminProjectEdit, AdminProjectNew, AdminServiceEdit,
+    AdminServiceNew, AdminServices, AdminSettings, AdminSubscribers, AdminTestimonials,
+    ArticleDetail, Articles, Contact, Home, LocalizedAbout, LocalizedArticleDetail,
+    LocalizedArticles, LocalizedContact, LocalizedHome, LocalizedNewsletterConfirm,
+    LocalizedNewsletterUnsubscribe, LocalizedNotFound, LocalizedPortfolio,
+    LocalizedPortfolioDetail, LocalizedPortfolioTag, LocalizedServiceDetail, LocalizedServices,
+    NewsletterConfirm, NewsletterUnsubscribe, NotFound, Portfolio, PortfolioDetail, PortfolioTag,
     ServiceDetail, Services,
 };
 
@@ -30,12 +53,51 @@ pub enum Route {
     Portfolio {},
     #[route("/portfolio/:slug")]
     PortfolioDetail { slug: String },
+    #[route("/portfolio/tech/:tag")]
+    PortfolioTag { tag: String },
     #[route("/contact")]
     Contact {},
     #[route("/articles")]
     Articles {},
     #[route("/articles/:slug")]
     ArticleDetail { slug: String },
+    #[route("/newsletter/confirm/:token")]
+    NewsletterConfirm { token: String },
+    #[route("/newsletter/unsubscribe/:token")]
+    NewsletterUnsubscribe { token: String },
+
+    // The same pages in another language, e.g. /es/services
+    #[nest("/:locale")]
+        #[route("/")]
+        LocalizedHome { locale: Locale },
+        #[route("/about")]
+        LocalizedAbout { locale: Locale },
+        #[route("/services")]
+        LocalizedServices { locale: Locale },
+        #[route("/services/:slug")]
+        LocalizedServiceDetail { locale: Locale, slug: String },
+        #[route("/portfolio")]
+        LocalizedPortfolio { locale: Locale },
+        #[route("/portfolio/:slug")]
+        LocalizedPortfolioDetail { locale: Locale, slug: String },
+        #[route("/portfolio/tech/:tag")]
+        LocalizedPortfolioTag { locale: Locale, tag: String },
+        #[route("/contact")]
+        LocalizedContact { locale: Locale },
+        #[route("/articles")]
+        LocalizedArticles { locale: Locale },
+        #[route("/articles/:slug")]
+        LocalizedArticleDetail { locale: Locale, slug: String },
+        #[route("/newsletter/confirm/:token")]
+        LocalizedNewsletterConfirm { locale: Locale, token: String },
+        #[route("/newsletter/unsubscribe/:token")]
+        LocalizedNewsletterUnsubscribe { locale: Locale, token: String },
+        #[route("/:..segments")]
+        LocalizedNotFound { locale: Locale, segments: Vec<String> },
+    #[end_nest]
+
+    #[route("/:..segments")]
+    NotFound { segments: Vec<String> },
     #[end_layout]
 
     // Admin routes (no public layout)
@@ -51,13 +113,49 @@ pub enum Route {
     AdminArticleNew {},
     #[route("/admin/articles/:id")]
     AdminArticleEdit { id: String },
+    #[route("/admin/services")]
+    AdminServices {},
+    #[route("/admin/services/new")]
+    AdminServiceNew {},
+    #[route("/admin/services/:id")]
+    AdminServiceEdit { id: String },
+    #[route("/admin/portfolio")]
+    AdminPortfolio {},
+    #[route("/admin/portfolio/new")]
+    AdminProjectNew {},
+    #[route("/admin/portfolio/:id")]
+    AdminProjectEdit { id: String },
+    #[route("/admin/testimonials")]
+    AdminTestimonials {},
+    #[route("/admin/icons")]
+    AdminIcons {},
+    #[route("/admin/subscribers")]
+    AdminSubscribers {},
 }
 
 fn main() {
+    #[cfg(target_arch = "wasm32")]
+    components::install_panic_hook();
     // Initialize storage with defaults
     content::init_storage();
     // Apply saved theme on startup
     content::apply_theme_to_dom(&content::load_theme());
+    content::watch_system_theme();
+    components::register_bundled_icons();
+    // Install an `AnalyticsSink` backed by a server here to count visits;
+    // without one nothing is recorded
+    if let Some(sink) = analytics::configured_sink() {
+        analytics::install(sink);
+    }
+    // Install a `SubscriberStore` and `MailTransport` backed by a server
+    // here to take newsletter sign-ups; without them the form says sign-up
+    // is unavailable
+    if let Some(store) = newsletter::configured_store() {
+        newsletter::install_store(store);
+    }
+    if let Some(transport) = newsletter::configured_transport() {
+        newsletter::install(transport);
+    }
     dioxus::launch(App);
 }
 
@@ -111,6 +209,95 @@ mod tests {
 
     // ==================== Dynamic Route Tests ====================
 
+    #[test]
+    fn test_admin_content_editor_routes() {
+        assert_eq!(
+            "/admin/services/new".parse::<Route>().ok(),
+            Some(Route::AdminServiceNew {})
+        );
+        assert_eq!(
+            "/admin/portfolio/abc123".parse::<Route>().ok(),
+            Some(Route::AdminProjectEdit {
+                id: "abc123".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_localized_routes() {
+        assert_eq!(
+            "/es/services".parse::<Route>().ok(),
+            Some(Route::LocalizedServices { locale: Locale::Es })
+        );
+        assert_eq!(
+            "/es/portfolio/tech/rust".parse::<Route>().ok(),
+            Some(Route::LocalizedPortfolioTag {
+                locale: Locale::Es,
+                tag: "rust".to_string()
+            })
+        );
+        assert_eq!(
+            "/es".parse::<Route>().ok(),
+            Some(Route::LocalizedHome { locale: Locale::Es })
+        );
+        assert_eq!(
+            Route::LocalizedArticles { locale: Locale::Es }.to_string(),
+            "/es/articles"
+        );
+    }
+
+    #[test]
+    fn test_unknown_locale_is_not_found() {
+        assert!(matches!(
+            "/fr/services".parse::<Route>(),
+            Ok(Route::NotFound { .. })
+        ));
+        // A page that doesn't exist still shows in the visitor's language
+        assert!(matches!(
+            "/es/no-such-page".parse::<Route>(),
+            Ok(Route::LocalizedNotFound {
+                locale: Locale::Es,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_newsletter_routes() {
+        assert_eq!(
+            "/newsletter/confirm/abc123".parse::<Route>().ok(),
+            Some(Route::NewsletterConfirm {
+                token: "abc123".to_string()
+            })
+        );
+        assert_eq!(
+            "/es/newsletter/unsubscribe/abc123".parse::<Route>().ok(),
+            Some(Route::LocalizedNewsletterUnsubscribe {
+                locale: Locale::Es,
+                token: "abc123".to_string()
+            })
+        );
+        assert_eq!(
+            "/admin/subscribers".parse::<Route>().ok(),
+            Some(Route::AdminSubscribers {})
+        );
+    }
+
+    #[test]
+    fn test_admin_icons_route() {
+        assert_eq!(
+            "/admin/icons".parse::<Route>().ok(),
+            Some(Route::AdminIcons {})
+        );
+        assert_eq!(Route::AdminIcons {}.to_string(), "/admin/icons");
+    }
+
+    #[test]
+    fn test_admin_testimonials_route_parsing() {
+        let route: Result<Route, _> = "/admin/testimonials".parse();
+        assert_eq!(route.ok(), Some(Route::AdminTestimonials {}));
+    }
+
     #[test]
     fn test_service_detail_route_parsing() {
         let route: Result<Route, _> = "/services/ai-consulting".parse();
@@ -147,6 +334,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_portfolio_tag_route_parsing() {
+        let route: Result<Route, _> = "/portfolio/tech/lead-capture".parse();
+
+        if let Ok(Route::PortfolioTag { tag }) = route {
+            assert_eq!(tag, "lead-capture");
+        } else {
+            panic!("Expected PortfolioTag route, got {:?}", route);
+        }
+    }
+
     #[test]
     fn test_article_detail_route_parsing() {
         let route: Result<Route, _> = "/articles/do-you-need-custom-website".parse();
@@ -208,22 +406,48 @@ mod tests {
         ];
     }
 
-    // ==================== Route Negative Tests ====================
+    // ==================== Not Found Route Tests ====================
 
     #[test]
-    fn test_invalid_routes() {
-        let invalid_routes = vec![
-            "/nonexistent",
-            "/admin/invalid",
-            "/portfolio/detail/extra", // Too many segments
+    fn test_unknown_routes_fall_through_to_not_found() {
+        let unknown_routes = vec![
+            ("/nonexistent", vec!["nonexistent"]),
+            ("/admin/invalid", vec!["admin", "invalid"]),
+            (
+                "/portfolio/detail/extra",
+                vec!["portfolio", "detail", "extra"],
+            ), // Too many segments
         ];
 
-        for path in &invalid_routes {
+        for (path, expected) in &unknown_routes {
             let result: Result<Route, _> = path.parse();
-            assert!(result.is_err(), "Route '{}' should not be valid", path);
+            match result {
+                Ok(Route::NotFound { segments }) => assert_eq!(&segments, expected),
+                other => panic!("Route '{}' should be NotFound, got {:?}", path, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_known_routes_are_not_caught_by_not_found() {
+        for path in ["/", "/about", "/services/ai-consulting", "/admin/dashboard"] {
+            let result: Route = path.parse().unwrap();
+            assert!(
+                !matches!(result, Route::NotFound { .. }),
+                "Route '{}' should not be NotFound",
+                path
+            );
         }
     }
 
+    #[test]
+    fn test_not_found_route_display() {
+        let route = Route::NotFound {
+            segments: vec!["missing".to_string(), "page".to_string()],
+        };
+        assert_eq!(route.to_string(), "/missing/page");
+    }
+
     // ==================== Route Display/ToString Tests ====================
 
     #[test]
@@ -244,6 +468,11 @@ mod tests {
         };
         assert_eq!(portfolio.to_string(), "/portfolio/test-project");
 
+        let tag = Route::PortfolioTag {
+            tag: "rust".to_string(),
+        };
+        assert_eq!(tag.to_string(), "/portfolio/tech/rust");
+
         let article = Route::ArticleDetail {
             slug: "my-article".to_string(),
         };
@@ -291,31 +520,73 @@ fn App() -> Element {
         });
     }
 
+    let settings = content::load_settings();
+
     rsx! {
         document::Link { rel: "stylesheet", href: CSS }
-        document::Link {
-            rel: "preconnect",
-            href: "https://fonts.googleapis.com"
-        }
-        document::Link {
-            rel: "preconnect",
-            href: "https://fonts.gstatic.com",
-            crossorigin: "anonymous"
+        document::Meta {
+            name: "theme-color",
+            content: settings.brand.theme.background.to_string()
         }
-        document::Link {
-            rel: "stylesheet",
-            href: "https://fonts.googleapis.com/css2?family=Inter:wght@500;600&family=Montserrat:wght@700&family=Open+Sans:wght@400;500&display=swap"
-        }
-        Router::<Route> {}
+        PageErrorBoundary { Router::<Route> {} }
     }
 }
 
 #[component]
 fn Layout() -> Element {
+    let route = use_route::<Route>();
+    let settings = content::load_settings();
+    let segments: Vec<String> = route
+        .to_string()
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+        .collect();
+    let alternates = i18n::alternate_paths(&route);
+    let default_path = alternates
+        .iter()
+        .find(|(locale, _)| *locale == Locale::DEFAULT)
+        .map_or("/", |(_, path)| path.as_str());
+
+    use_effect(use_reactive!(|route| analytics::track_page_view(&route)));
+
+    // Screen readers and hyphenation follow the page's language
+    #[cfg(target_arch = "wasm32")]
+    use_effect(use_reactive!(|route| {
+        if let Some(root) = web_sys::window()
+            .and_then(|w| w.document())
+            .and_then(|d| d.document_element())
+        {
+            let _ = root.set_attribute("lang", route.locale().code());
+        }
+    }));
+
     rsx! {
+        for (locale, path) in alternates.iter() {
+            document::Link {
+                key: "{locale}",
+                rel: "alternate",
+                hreflang: locale.code(),
+                href: "{i18n::SITE_URL}{path}"
+            }
+        }
+        document::Link {
+            rel: "alternate",
+            hreflang: "x-default",
+            href: "{i18n::SITE_URL}{default_path}"
+        }
+        SkipLink {}
         Header {}
-        main {
-            Outlet::<Route> {}
+        main { id: MAIN_CONTENT_ID, tabindex: "-1",
+            // Keyed by route so navigating away clears a previous error
+            PageErrorBoundary { key: "{route}",
+                // Pages disabled in admin settings answer as not found
+                if navigation::is_route_enabled(&settings, &route) {
+                    Outlet::<Route> {}
+                } else {
+                    NotFound { segments }
+                }
+            }
         }
         Footer {}
     }
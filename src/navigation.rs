@@ -0,0 +1,348 @@
+use crate::content::{PageConfig, SiteSettings};
+use crate::i18n::{I18n, Locale};
+use crate::Route;
+
+/// A single entry in the public header/footer navigation
+#[derive(Debug, Clone, PartialEq)]
+pub struct NavItem {
+    pub id: String,
+    pub label: String,
+    pub route: Route,
+}
+
+impl NavItem {
+    /// The entry for a page shown in another language. Labels set in admin
+    /// are English, so other locales use the catalog's `nav-<id>` message
+    /// when there is one.
+    pub fn localized(self, i18n: I18n) -> NavItem {
+        let label = match i18n.locale {
+            Locale::DEFAULT => None,
+            _ => i18n.translated(&format!("nav-{}", self.id)),
+        };
+        NavItem {
+            label: label.unwrap_or(self.label),
+            route: i18n.route(self.route),
+            id: self.id,
+        }
+    }
+}
+
+/// Map a page's configured path to the router's `Route`.
+///
+/// Paths that only match the catch-all `NotFound` route don't count.
+pub fn page_route(page: &PageConfig) -> Option<Route> {
+    match page.path.parse::<Route>() {
+        Ok(Route::NotFound { .. }) | Err(_) => None,
+        Ok(route) => Some(route),
+    }
+}
+
+/// The top-level section a route belongs to.
+///
+/// Detail routes share the section of their index page, so disabling
+/// "Services" also hides every `/services/:slug` page, in every language.
+pub fn route_section(route: &Route) -> Option<&'static str> {
+    match route.unlocalized() {
+        Route::Home {} => Some("home"),
+        Route::About {} => Some("about"),
+        Route::Services {} | Route::ServiceDetail { .. } => Some("services"),
+        Route::Portfolio {} | Route::PortfolioDetail { .. } | Route::PortfolioTag { .. } => {
+            Some("portfolio")
+        }
+        Route::Articles {} | Route::ArticleDetail { .. } => Some("articles"),
+        Route::Contact {} => Some("contact"),
+        _ => None,
+    }
+}
+
+/// Whether the feature toggle for a section is on
+fn feature_enabled(settings: &SiteSettings, section: &str) -> bool {
+    match section {
+        "services" => settings.features.services,
+        "portfolio" => settings.features.portfolio,
+        "articles" => settings.features.articles,
+        "contact" => settings.features.contact,
+        _ => true,
+    }
+}
+
+/// Whether a page should be shown in navigation
+fn page_visible(settings: &SiteSettings, page: &PageConfig) -> bool {
+    if !page.enabled {
+        return false;
+    }
+    match page_route(page).as_ref().and_then(route_section) {
+        Some(section) => feature_enabled(settings, section),
+        None => true,
+    }
+}
+
+/// Navigation entries built from `settings.pages`, sorted by `order`.
+///
+/// Pages that are disabled, whose feature toggle is off, or whose path
+/// doesn't match a route are left out.
+pub fn nav_items(settings: &SiteSettings) -> Vec<NavItem> {
+    let mut pages: Vec<&PageConfig> = settings
+        .pages
+        .iter()
+        .filter(|p| page_visible(settings, p))
+        .collect();
+    pages.sort_by_key(|p| p.order);
+
+    pages
+        .into_iter()
+        .filter_map(|page| {
+            page_route(page).map(|route| NavItem {
+                id: page.id.clone(),
+                label: page.label.clone(),
+                route,
+            })
+        })
+        .collect()
+}
+
+/// Whether a route may be rendered under the current settings.
+///
+/// Admin routes are always reachable. The home page is never blocked so the
+/// site always has a landing page, even when it's hidden from navigation.
+pub fn is_route_enabled(settings: &SiteSettings, route: &Route) -> bool {
+    let Some(section) = route_section(route) else {
+        return true;
+    };
+    if section == "home" {
+        return true;
+    }
+    if !feature_enabled(settings, section) {
+        return false;
+    }
+
+    // Sections without a page entry stay reachable
+    settings
+        .pages
+        .iter()
+        .find(|p| page_route(p).as_ref().and_then(route_section) == Some(section))
+        .is_none_or(|p| p.enabled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(id: &str, path: &str, order: u32) -> PageConfig {
+        PageConfig {
+            id: id.to_string(),
+            label: id.to_string(),
+            path: path.to_string(),
+            enabled: true,
+            order,
+        }
+    }
+
+    // ==================== PageConfig.path -> Route ====================
+
+    #[test]
+    fn test_default_pages_map_to_routes() {
+        let settings = SiteSettings::default();
+        for page in &settings.pages {
+            assert!(
+                page_route(page).is_some(),
+                "Page '{}' has path '{}' which is not a route",
+                page.id,
+                page.path
+            );
+        }
+    }
+
+    #[test]
+    fn test_page_route_mapping() {
+        assert_eq!(page_route(&page("home", "/", 1)), Some(Route::Home {}));
+        assert_eq!(
+            page_route(&page("about", "/about", 1)),
+            Some(Route::About {})
+        );
+        assert_eq!(
+            page_route(&page("services", "/services", 1)),
+            Some(Route::Services {})
+        );
+        assert_eq!(
+            page_route(&page("portfolio", "/portfolio", 1)),
+            Some(Route::Portfolio {})
+        );
+        assert_eq!(
+            page_route(&page("articles", "/articles", 1)),
+            Some(Route::Articles {})
+        );
+        assert_eq!(
+            page_route(&page("contact", "/contact", 1)),
+            Some(Route::Contact {})
+        );
+    }
+
+    #[test]
+    fn test_page_route_unknown_path() {
+        assert_eq!(page_route(&page("blog", "/blog", 1)), None);
+    }
+
+    #[test]
+    fn test_route_section_groups_detail_routes() {
+        let detail = Route::ServiceDetail {
+            slug: "ai-consulting".to_string(),
+        };
+        assert_eq!(route_section(&detail), Some("services"));
+        let tag = Route::PortfolioTag {
+            tag: "rust".to_string(),
+        };
+        assert_eq!(route_section(&tag), Some("portfolio"));
+        assert_eq!(route_section(&Route::AdminDashboard {}), None);
+    }
+
+    // ==================== Navigation Items ====================
+
+    #[test]
+    fn test_nav_items_default_order() {
+        let items = nav_items(&SiteSettings::default());
+        let ids: Vec<&str> = items.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            vec![
+                "home",
+                "about",
+                "services",
+                "portfolio",
+                "articles",
+                "contact"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_nav_items_sorted_by_order() {
+        let settings = SiteSettings {
+            pages: vec![
+                page("contact", "/contact", 1),
+                page("home", "/", 3),
+                page("about", "/about", 2),
+            ],
+            ..Default::default()
+        };
+
+        let ids: Vec<String> = nav_items(&settings).into_iter().map(|i| i.id).collect();
+        assert_eq!(ids, vec!["contact", "about", "home"]);
+    }
+
+    #[test]
+    fn test_nav_items_hide_disabled_pages() {
+        let mut settings = SiteSettings::default();
+        settings
+            .pages
+            .iter_mut()
+            .find(|p| p.id == "about")
+            .unwrap()
+            .enabled = false;
+
+        let items = nav_items(&settings);
+        assert!(items.iter().all(|i| i.id != "about"));
+    }
+
+    #[test]
+    fn test_nav_items_respect_feature_toggles() {
+        let mut settings = SiteSettings::default();
+        settings.features.portfolio = false;
+
+        let items = nav_items(&settings);
+        assert!(items.iter().all(|i| i.route != Route::Portfolio {}));
+    }
+
+    #[test]
+    fn test_nav_items_localized() {
+        let mut settings = SiteSettings::default();
+        settings.pages.push(page("blog", "/articles", 7));
+        let items: Vec<_> = nav_items(&settings)
+            .into_iter()
+            .map(|item| item.localized(I18n::new(Locale::Es)))
+            .collect();
+
+        let services = items.iter().find(|i| i.id == "services").unwrap();
+        assert_eq!(services.label, "Servicios");
+        assert_eq!(services.route.to_string(), "/es/services");
+        // Custom pages have no message, so keep their configured label
+        let blog = items.iter().find(|i| i.id == "blog").unwrap();
+        assert_eq!(blog.label, "blog");
+
+        let english = nav_items(&settings)[2]
+            .clone()
+            .localized(I18n::new(Locale::En));
+        assert_eq!(english, nav_items(&settings)[2]);
+    }
+
+    #[test]
+    fn test_route_section_ignores_locale() {
+        let route = Route::LocalizedServiceDetail {
+            locale: Locale::Es,
+            slug: "web-development".to_string(),
+        };
+        assert_eq!(route_section(&route), Some("services"));
+    }
+
+    #[test]
+    fn test_nav_items_skip_unknown_paths() {
+        let mut settings = SiteSettings::default();
+        settings.pages.push(page("blog", "/blog", 7));
+
+        let items = nav_items(&settings);
+        assert!(items.iter().all(|i| i.id != "blog"));
+    }
+
+    // ==================== Route Guard ====================
+
+    #[test]
+    fn test_all_routes_enabled_by_default() {
+        let settings = SiteSettings::default();
+        assert!(is_route_enabled(&settings, &Route::Home {}));
+        assert!(is_route_enabled(&settings, &Route::Services {}));
+        assert!(is_route_enabled(
+            &settings,
+            &Route::ArticleDetail {
+                slug: "anything".to_string()
+            }
+        ));
+    }
+
+    #[test]
+    fn test_disabled_page_blocks_detail_routes() {
+        let mut settings = SiteSettings::default();
+        settings
+            .pages
+            .iter_mut()
+            .find(|p| p.id == "services")
+            .unwrap()
+            .enabled = false;
+
+        assert!(!is_route_enabled(&settings, &Route::Services {}));
+        assert!(!is_route_enabled(
+            &settings,
+            &Route::ServiceDetail {
+                slug: "ai-consulting".to_string()
+            }
+        ));
+    }
+
+    #[test]
+    fn test_feature_toggle_blocks_route() {
+        let mut settings = SiteSettings::default();
+        settings.features.articles = false;
+
+        assert!(!is_route_enabled(&settings, &Route::Articles {}));
+    }
+
+    #[test]
+    fn test_home_and_admin_always_enabled() {
+        let mut settings = SiteSettings::default();
+        for page in settings.pages.iter_mut() {
+            page.enabled = false;
+        }
+
+        assert!(is_route_enabled(&settings, &Route::Home {}));
+        assert!(is_route_enabled(&settings, &Route::AdminSettings {}));
+    }
+}
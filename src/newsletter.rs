@@ -0,0 +1,1008 @@
+//! Newsletter subscriptions and the article digest.
+//!
+//! Subscribing is double opt-in: the form adds a pending subscriber and
+//! emails them a confirmation link, and only confirmed subscribers get
+//! digests. Every email links to a page that unsubscribes in one click.
+//!
+//! Subscribers are kept by the installed `SubscriberStore` and mail goes
+//! through the installed `MailTransport`. A static site has neither, since
+//! each visitor's browser storage is their own, so until both are installed
+//! the form tells visitors sign-up is unavailable. Off the web, setting
+//! `NEWSLETTER_SUBSCRIBERS` to a file and `NEWSLETTER_OUTBOX` to a directory
+//! keeps subscribers in that file and writes each email there as a `.eml`.
+
+use crate::analytics::today;
+use crate::content::{load_articles, load_settings, Article, ArticleStatus, ArticlesData};
+use crate::html::escape_html;
+use crate::i18n::{I18n, Locale};
+use crate::Route;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Address emails are sent from
+pub const FROM_ADDRESS: &str = "collin@poundsconsulting.net";
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SubscriberStatus {
+    /// Signed up but hasn't followed the confirmation link yet
+    Pending,
+    Confirmed,
+    Unsubscribed,
+}
+
+impl SubscriberStatus {
+    pub fn label(self) -> &'static str {
+        match self {
+            SubscriberStatus::Pending => "Pending",
+            SubscriberStatus::Confirmed => "Confirmed",
+            SubscriberStatus::Unsubscribed => "Unsubscribed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Subscriber {
+    pub email: String,
+    pub status: SubscriberStatus,
+    /// Secret in this subscriber's confirm and unsubscribe links
+    pub token: String,
+    /// Language their emails are written in
+    #[serde(default)]
+    pub locale: Locale,
+    /// Day they last signed up, as YYYY-MM-DD
+    pub subscribed_on: String,
+    #[serde(default)]
+    pub confirmed_on: Option<String>,
+}
+
+/// Everyone who has signed up, kept after they unsubscribe so their
+/// unsubscribe link keeps working
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SubscribersData {
+    pub subscribers: Vec<Subscriber>,
+    /// Date of the newest article in the last digest sent; the next digest
+    /// has the articles published after it
+    #[serde(default)]
+    pub last_digest: Option<String>,
+}
+
+impl SubscribersData {
+    /// Add `email` as pending, or make it pending again with a new token if
+    /// it had unsubscribed. Someone already confirmed stays confirmed.
+    pub fn subscribe(
+        &mut self,
+        email: &str,
+        locale: Locale,
+        today: &str,
+    ) -> Result<Subscriber, String> {
+        let email = normalize_email(email)
+            .ok_or_else(|| format!("{} is not an email address", email.trim()))?;
+
+        let Some(subscriber) = self.subscribers.iter_mut().find(|s| s.email == email) else {
+            let subscriber = Subscriber {
+                email,
+                status: SubscriberStatus::Pending,
+                token: new_token()?,
+                locale,
+                subscribed_on: today.to_string(),
+                confirmed_on: None,
+            };
+            self.subscribers.push(subscriber.clone());
+            return Ok(subscriber);
+        };
+
+        if subscriber.status == SubscriberStatus::Unsubscribed {
+            subscriber.status = SubscriberStatus::Pending;
+            subscriber.token = new_token()?;
+            subscriber.subscribed_on = today.to_string();
+            subscriber.confirmed_on = None;
+        }
+        if subscriber.status == SubscriberStatus::Pending {
+            subscriber.locale = locale;
+        }
+        Ok(subscriber.clone())
+    }
+
+    /// Confirm the subscriber with `token`. Following the link twice is
+    /// harmless; a link from before they unsubscribed does nothing.
+    pub fn confirm(&mut self, token: &str, today: &str) -> Option<Subscriber> {
+        let subscriber = self.subscribers.iter_mut().find(|s| s.token == token)?;
+        match subscriber.status {
+            SubscriberStatus::Pending => {
+                subscriber.status = SubscriberStatus::Confirmed;
+                subscriber.confirmed_on = Some(today.to_string());
+            }
+            SubscriberStatus::Confirmed => {}
+            SubscriberStatus::Unsubscribed => return None,
+        }
+        Some(subscriber.clone())
+    }
+
+    pub fn unsubscribe(&mut self, token: &str) -> Option<Subscriber> {
+        let subscriber = self.subscribers.iter_mut().find(|s| s.token == token)?;
+        subscriber.status = SubscriberStatus::Unsubscribed;
+        Some(subscriber.clone())
+    }
+
+    pub fn remove(&mut self, email: &str) {
+        self.subscribers.retain(|s| s.email != email);
+    }
+
+    pub fn count(&self, status: SubscriberStatus) -> usize {
+        self.subscribers
+            .iter()
+            .filter(|s| s.status == status)
+            .count()
+    }
+
+    /// Subscribers who get digests
+    pub fn confirmed(&self) -> impl Iterator<Item = &Subscriber> {
+        self.subscribers
+            .iter()
+            .filter(|s| s.status == SubscriberStatus::Confirmed)
+    }
+}
+
+/// `input` trimmed and lowercased, if it looks like an email address
+pub fn normalize_email(input: &str) -> Option<String> {
+    let email = input.trim().to_lowercase();
+    let (local, domain) = email.split_once('@')?;
+    let valid = !local.is_empty()
+        && !domain.contains('@')
+        && domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && !email.contains(|c: char| c.is_whitespace() || matches!(c, '<' | '>' | ',' | ';'));
+    valid.then_some(email)
+}
+
+/// 128 random bits as hex, so links carrying it can't be guessed
+fn new_token() -> Result<String, String> {
+    let mut bytes = [0u8; 16];
+    fill_random(&mut bytes)?;
+    Ok(bytes.iter().map(|b| format!("{:02x}", b)).collect())
+}
+
+/// Without `crypto.getRandomValues` there's no source a token could be
+/// trusted from, so signing up fails rather than handing out guessable links
+#[cfg(target_arch = "wasm32")]
+fn fill_random(bytes: &mut [u8]) -> Result<(), String> {
+    web_sys::window()
+        .and_then(|w| w.crypto().ok())
+        .and_then(|crypto| crypto.get_random_values_with_u8_array(bytes).ok())
+        .map(|_| ())
+        .ok_or_else(|| "This browser can't generate secure random tokens".to_string())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn fill_random(bytes: &mut [u8]) -> Result<(), String> {
+    use std::hash::{BuildHasher, Hasher};
+    for chunk in bytes.chunks_mut(8) {
+        // Every RandomState gets fresh keys, so each chunk differs
+        let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+        hasher.write_u128(
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_or(0, |d| d.as_nanos()),
+        );
+        chunk.copy_from_slice(&hasher.finish().to_le_bytes()[..chunk.len()]);
+    }
+    Ok(())
+}
+
+// ==================== Emails ====================
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Email {
+    pub from: String,
+    pub to: String,
+    pub subject: String,
+    pub text: String,
+    pub html: String,
+    /// Also sent as the `List-Unsubscribe` header, which mail apps show as
+    /// their own unsubscribe button
+    pub unsubscribe_url: String,
+}
+
+/// Separates the text and HTML parts of a `.eml` file
+const BOUNDARY: &str = "=_newsletter_part";
+
+impl Email {
+    /// The email as a `.eml` file with a text and an HTML part, ready to
+    /// open in a mail app or hand to a mail server
+    pub fn to_eml(&self) -> String {
+        [
+            format!("From: {}", single_line(&self.from)),
+            format!("To: {}", single_line(&self.to)),
+            format!("Subject: {}", encode_header(&self.subject)),
+            "MIME-Version: 1.0".to_string(),
+            format!("List-Unsubscribe: <{}>", single_line(&self.unsubscribe_url)),
+            format!(
+                "Content-Type: multipart/alternative; boundary=\"{}\"",
+                BOUNDARY
+            ),
+            String::new(),
+            format!("--{}", BOUNDARY),
+            "Content-Type: text/plain; charset=utf-8".to_string(),
+            "Content-Transfer-Encoding: 8bit".to_string(),
+            String::new(),
+            self.text.clone(),
+            format!("--{}", BOUNDARY),
+            "Content-Type: text/html; charset=utf-8".to_string(),
+            "Content-Transfer-Encoding: 8bit".to_string(),
+            String::new(),
+            self.html.clone(),
+            format!("--{}--", BOUNDARY),
+            String::new(),
+        ]
+        .join("\r\n")
+    }
+}
+
+/// `value` with line breaks turned into spaces. A header ends at a line
+/// break, so one inside a site name or title would start a header of its own.
+fn single_line(value: &str) -> String {
+    value.replace(['\r', '\n'], " ")
+}
+
+/// Headers must be ASCII; anything else is sent as an RFC 2047 encoded word
+fn encode_header(value: &str) -> String {
+    let value = single_line(value);
+    if value.is_ascii() {
+        return value;
+    }
+    let encoded: String = value
+        .bytes()
+        .map(|b| match b {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' => (b as char).to_string(),
+            b' ' => "_".to_string(),
+            _ => format!("={:02X}", b),
+        })
+        .collect();
+    format!("=?UTF-8?Q?{}?=", encoded)
+}
+
+/// Where the app is served from: the page's origin in the browser,
+/// `SITE_URL` anywhere else
+fn origin() -> String {
+    #[cfg(target_arch = "wasm32")]
+    if let Some(origin) = web_sys::window().and_then(|w| w.location().origin().ok()) {
+        return origin;
+    }
+    crate::i18n::SITE_URL.to_string()
+}
+
+/// Who emails come from and where their links point
+#[derive(Debug, Clone, PartialEq)]
+pub struct Site {
+    pub name: String,
+    pub origin: String,
+}
+
+impl Site {
+    /// The brand name from settings, at the address the app is served from
+    pub fn current() -> Self {
+        Self {
+            name: load_settings().brand.name,
+            origin: origin(),
+        }
+    }
+
+    fn url(&self, route: Route) -> String {
+        format!("{}{}", self.origin.trim_end_matches('/'), route)
+    }
+
+    fn from(&self) -> String {
+        format!("{} <{}>", self.name, FROM_ADDRESS)
+    }
+}
+
+pub fn confirm_url(subscriber: &Subscriber, site: &Site) -> String {
+    site.url(
+        Route::NewsletterConfirm {
+            token: subscriber.token.clone(),
+        }
+        .localized(subscriber.locale),
+    )
+}
+
+pub fn unsubscribe_url(subscriber: &Subscriber, site: &Site) -> String {
+    site.url(
+        Route::NewsletterUnsubscribe {
+            token: subscriber.token.clone(),
+        }
+        .localized(subscriber.locale),
+    )
+}
+
+/// Wrap `body` in the page every HTML email shares, with the unsubscribe
+/// footer under it
+fn html_layout(i18n: I18n, site: &Site, unsubscribe: &str, body: &str) -> String {
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="{lang}">
+<head><meta charset="utf-8"><meta name="viewport" content="width=device-width, initial-scale=1"></head>
+<body style="margin:0;padding:24px;background:#f4f4f4;font-family:Arial,Helvetica,sans-serif;color:#1a1a1a;">
+<div style="max-width:560px;margin:0 auto;background:#ffffff;border-radius:8px;padding:32px;">
+<p style="margin:0 0 24px;font-weight:bold;letter-spacing:1px;">{name}</p>
+{body}
+<hr style="border:none;border-top:1px solid #dddddd;margin:32px 0 16px;">
+<p style="font-size:12px;color:#666666;">{note} <a href="{unsubscribe}" style="color:#666666;">{unsubscribe_label}</a></p>
+</div>
+</body>
+</html>
+"#,
+        lang = i18n.locale.code(),
+        name = escape_html(&site.name),
+        body = body,
+        note = escape_html(&i18n.t_args("newsletter-email-reason", &[("site", &site.name)])),
+        unsubscribe = escape_html(unsubscribe),
+        unsubscribe_label = escape_html(&i18n.t("newsletter-email-unsubscribe")),
+    )
+}
+
+fn text_footer(i18n: I18n, site: &Site, unsubscribe: &str) -> String {
+    format!(
+        "--\n{}\n{}: {}\n",
+        i18n.t_args("newsletter-email-reason", &[("site", &site.name)]),
+        i18n.t("newsletter-email-unsubscribe"),
+        unsubscribe
+    )
+}
+
+/// The email asking a new subscriber to confirm their address
+pub fn confirmation_email(subscriber: &Subscriber, site: &Site) -> Email {
+    let i18n = I18n::new(subscriber.locale);
+    let confirm = confirm_url(subscriber, site);
+    let unsubscribe = unsubscribe_url(subscriber, site);
+    let intro = i18n.t_args("newsletter-confirm-intro", &[("site", &site.name)]);
+    let action = i18n.t("newsletter-confirm-action");
+    let ignore = i18n.t("newsletter-confirm-ignore");
+
+    let body = format!(
+        r#"<p>{intro}</p>
+<p style="margin:24px 0;"><a href="{confirm}" style="display:inline-block;padding:12px 24px;background:#d4a017;color:#1a1a1a;border-radius:6px;text-decoration:none;font-weight:bold;">{action}</a></p>
+<p style="color:#666666;">{ignore}</p>"#,
+        intro = escape_html(&intro),
+        confirm = escape_html(&confirm),
+        action = escape_html(&action),
+        ignore = escape_html(&ignore),
+    );
+
+    Email {
+        from: site.from(),
+        to: subscriber.email.clone(),
+        subject: i18n.t_args("newsletter-confirm-subject", &[("site", &site.name)]),
+        text: format!(
+            "{}\n\n{}: {}\n\n{}\n\n{}",
+            intro,
+            action,
+            confirm,
+            ignore,
+            text_footer(i18n, site, &unsubscribe)
+        ),
+        html: html_layout(i18n, site, &unsubscribe, &body),
+        unsubscribe_url: unsubscribe,
+    }
+}
+
+/// Published articles dated after `since`, newest first
+pub fn new_articles(articles: &ArticlesData, since: Option<&str>) -> Vec<Article> {
+    let mut new: Vec<Article> = articles
+        .articles
+        .iter()
+        .filter(|a| a.status == ArticleStatus::Published)
+        .filter(|a| since.is_none_or(|since| a.date.as_str() > since))
+        .cloned()
+        .collect();
+    new.sort_by(|a, b| b.date.cmp(&a.date));
+    new
+}
+
+/// The digest of `articles` for `subscriber`, in their language. There's
+/// no digest without articles.
+pub fn digest_email(subscriber: &Subscriber, articles: &[Article], site: &Site) -> Option<Email> {
+    if articles.is_empty() {
+        return None;
+    }
+    let i18n = I18n::new(subscriber.locale);
+    let articles: Vec<Article> = articles
+        .iter()
+        .map(|a| a.localized(subscriber.locale))
+        .collect();
+    let unsubscribe = unsubscribe_url(subscriber, site);
+    let intro = i18n.t("newsletter-digest-intro");
+    let read_more = i18n.t("newsletter-digest-read");
+    let subject = match articles.as_slice() {
+        [only] => i18n.t_args(
+            "newsletter-digest-subject-one",
+            &[("site", &site.name), ("title", &only.title)],
+        ),
+        _ => i18n.t_args(
+            "newsletter-digest-subject",
+            &[("site", &site.name), ("count", &articles.len().to_string())],
+        ),
+    };
+
+    let mut text = format!("{}\n\n", intro);
+    let mut body = format!("<p>{}</p>\n", escape_html(&intro));
+    for article in &articles {
+        let url = site.url(
+            Route::ArticleDetail {
+                slug: article.slug.clone(),
+            }
+            .localized(subscriber.locale),
+        );
+        text.push_str(&format!(
+            "{}\n{} | {}\n{}\n{}: {}\n\n",
+            article.title, article.category, article.date, article.excerpt, read_more, url
+        ));
+        body.push_str(&format!(
+            r#"<div style="margin:24px 0;">
+<p style="margin:0;font-size:12px;color:#666666;text-transform:uppercase;">{category} · {date}</p>
+<h2 style="margin:4px 0 8px;font-size:20px;"><a href="{url}" style="color:#1a1a1a;">{title}</a></h2>
+<p style="margin:0 0 8px;">{excerpt}</p>
+<a href="{url}" style="color:#b8860b;font-weight:bold;">{read_more}</a>
+</div>
+"#,
+            category = escape_html(&article.category),
+            date = escape_html(&article.date),
+            url = escape_html(&url),
+            title = escape_html(&article.title),
+            excerpt = escape_html(&article.excerpt),
+            read_more = escape_html(&read_more),
+        ));
+    }
+    text.push_str(&text_footer(i18n, site, &unsubscribe));
+
+    Some(Email {
+        from: site.from(),
+        to: subscriber.email.clone(),
+        subject,
+        text,
+        html: html_layout(i18n, site, &unsubscribe, &body),
+        unsubscribe_url: unsubscribe,
+    })
+}
+
+// ==================== Backends ====================
+
+/// Where subscribers are kept. Every visitor signs up into the same list
+/// that admin reads, so on the web this has to be backed by a server.
+pub trait SubscriberStore {
+    fn load(&self) -> SubscribersData;
+    fn save(&self, data: &SubscribersData) -> Result<(), String>;
+}
+
+/// Keeps subscribers in a JSON file, for running off the web
+#[cfg(not(target_arch = "wasm32"))]
+pub struct FileStore {
+    pub path: std::path::PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl SubscriberStore for FileStore {
+    fn load(&self) -> SubscribersData {
+        std::fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self, data: &SubscribersData) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(data).map_err(|e| e.to_string())?;
+        std::fs::write(&self.path, json).map_err(|e| format!("{}: {}", self.path.display(), e))
+    }
+}
+
+/// Delivers emails. Install another to send through a mail service.
+pub trait MailTransport {
+    fn send(&self, email: &Email) -> Result<(), String>;
+}
+
+/// Writes each email to a numbered `.eml` file in `dir`, for running off
+/// the web and for tests
+#[cfg(not(target_arch = "wasm32"))]
+pub struct FileTransport {
+    pub dir: std::path::PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl MailTransport for FileTransport {
+    fn send(&self, email: &Email) -> Result<(), String> {
+        let error = |e: std::io::Error| format!("{}: {}", self.dir.display(), e);
+        std::fs::create_dir_all(&self.dir).map_err(error)?;
+        let count = std::fs::read_dir(&self.dir).map_err(error)?.count();
+        let name: String = email
+            .to
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+            .collect();
+        std::fs::write(
+            self.dir.join(format!("{:04}-{}.eml", count + 1, name)),
+            email.to_eml(),
+        )
+        .map_err(error)
+    }
+}
+
+/// The `NEWSLETTER_SUBSCRIBERS` file when set off the web; on the web
+/// there's nowhere to keep subscribers until a backend is written and
+/// installed
+pub fn configured_store() -> Option<Rc<dyn SubscriberStore>> {
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(path) = std::env::var_os("NEWSLETTER_SUBSCRIBERS") {
+        return Some(Rc::new(FileStore { path: path.into() }));
+    }
+    None
+}
+
+/// The `NEWSLETTER_OUTBOX` directory when set off the web; on the web
+/// there's no way to send mail until a transport is written and installed
+pub fn configured_transport() -> Option<Rc<dyn MailTransport>> {
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(dir) = std::env::var_os("NEWSLETTER_OUTBOX") {
+        return Some(Rc::new(FileTransport { dir: dir.into() }));
+    }
+    None
+}
+
+thread_local! {
+    static STORE: RefCell<Option<Rc<dyn SubscriberStore>>> = const { RefCell::new(None) };
+    static TRANSPORT: RefCell<Option<Rc<dyn MailTransport>>> = const { RefCell::new(None) };
+}
+
+/// Keep subscribers in `store` from now on
+pub fn install_store(store: Rc<dyn SubscriberStore>) {
+    STORE.with(|s| *s.borrow_mut() = Some(store));
+}
+
+/// Send emails through `transport` from now on
+pub fn install(transport: Rc<dyn MailTransport>) {
+    TRANSPORT.with(|t| *t.borrow_mut() = Some(transport));
+}
+
+/// Whether visitors can sign up: both a store and a transport are installed
+pub fn is_configured() -> bool {
+    STORE.with(|s| s.borrow().is_some()) && TRANSPORT.with(|t| t.borrow().is_some())
+}
+
+fn store() -> Result<Rc<dyn SubscriberStore>, String> {
+    STORE
+        .with(|s| s.borrow().clone())
+        .ok_or_else(|| "No subscriber store is configured".to_string())
+}
+
+fn send(email: &Email) -> Result<(), String> {
+    let transport = TRANSPORT
+        .with(|t| t.borrow().clone())
+        .ok_or_else(|| "No mail transport is configured".to_string())?;
+    transport.send(email)
+}
+
+// ==================== Subscribing ====================
+
+/// Sign `email` up and send them the confirmation email. The subscriber's
+/// status says whether they had already confirmed, in which case nothing
+/// is sent. If the email can't be sent nothing is saved, so they can try
+/// again.
+pub fn subscribe(email: &str, locale: Locale) -> Result<Subscriber, String> {
+    let store = store()?;
+    let mut data = store.load();
+    let subscriber = data.subscribe(email, locale, &today())?;
+    if subscriber.status == SubscriberStatus::Pending {
+        send(&confirmation_email(&subscriber, &Site::current()))?;
+    }
+    store.save(&data)?;
+    Ok(subscriber)
+}
+
+/// Confirm from the link in the confirmation email
+pub fn confirm(token: &str) -> Option<Subscriber> {
+    let store = store().ok()?;
+    let mut data = store.load();
+    let subscriber = data.confirm(token, &today())?;
+    store.save(&data).ok()?;
+    Some(subscriber)
+}
+
+/// Unsubscribe from the link in any email
+pub fn unsubscribe(token: &str) -> Option<Subscriber> {
+    let store = store().ok()?;
+    let mut data = store.load();
+    let subscriber = data.unsubscribe(token)?;
+    store.save(&data).ok()?;
+    Some(subscriber)
+}
+
+/// Everyone in the installed store, or `None` when there isn't one
+pub fn subscribers() -> Option<SubscribersData> {
+    store().ok().map(|store| store.load())
+}
+
+/// Drop `email` from the list for good
+pub fn remove_subscriber(email: &str) -> Result<(), String> {
+    let store = store()?;
+    let mut data = store.load();
+    data.remove(email);
+    store.save(&data)
+}
+
+/// Articles the next digest would include
+pub fn pending_digest() -> Vec<Article> {
+    new_articles(
+        &load_articles(),
+        subscribers().unwrap_or_default().last_digest.as_deref(),
+    )
+}
+
+/// Email the articles published since the last digest to every confirmed
+/// subscriber, returning how many were sent. If sending fails part way the
+/// digest isn't marked sent, so it can be tried again.
+pub fn send_digest() -> Result<usize, String> {
+    let store = store()?;
+    let mut data = store.load();
+    let articles = new_articles(&load_articles(), data.last_digest.as_deref());
+    let Some(newest) = articles.first() else {
+        return Err("There are no new articles since the last digest".to_string());
+    };
+    if data.confirmed().next().is_none() {
+        return Err("There are no confirmed subscribers yet".to_string());
+    }
+
+    let site = Site::current();
+    let mut sent = 0;
+    for subscriber in data.confirmed() {
+        if let Some(email) = digest_email(subscriber, &articles, &site) {
+            send(&email)?;
+            sent += 1;
+        }
+    }
+    data.last_digest = Some(newest.date.clone());
+    store.save(&data)?;
+    Ok(sent)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    /// Keeps subscribers in memory, standing in for a real backend in tests
+    #[derive(Default)]
+    pub(crate) struct MemoryStore(pub RefCell<SubscribersData>);
+
+    impl SubscriberStore for MemoryStore {
+        fn load(&self) -> SubscribersData {
+            self.0.borrow().clone()
+        }
+
+        fn save(&self, data: &SubscribersData) -> Result<(), String> {
+            *self.0.borrow_mut() = data.clone();
+            Ok(())
+        }
+    }
+
+    /// Collects sent emails in memory, standing in for a mail service in
+    /// tests
+    #[derive(Default)]
+    pub(crate) struct MemoryOutbox(pub RefCell<Vec<Email>>);
+
+    impl MailTransport for MemoryOutbox {
+        fn send(&self, email: &Email) -> Result<(), String> {
+            self.0.borrow_mut().push(email.clone());
+            Ok(())
+        }
+    }
+
+    fn site() -> Site {
+        Site {
+            name: "Acme".to_string(),
+            origin: "https://acme.example".to_string(),
+        }
+    }
+
+    fn article(slug: &str, date: &str, status: ArticleStatus) -> Article {
+        let mut article = load_articles().articles[0].clone();
+        article.slug = slug.to_string();
+        article.title = format!("Title of {}", slug);
+        article.date = date.to_string();
+        article.status = status;
+        article
+    }
+
+    /// A fresh directory for a `FileTransport`
+    fn outbox_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "newsletter-{}-{}-{}",
+            name,
+            std::process::id(),
+            new_token().unwrap()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn sent_emails(dir: &PathBuf) -> Vec<String> {
+        let mut files: Vec<_> = std::fs::read_dir(dir)
+            .map(|entries| entries.flatten().map(|e| e.path()).collect())
+            .unwrap_or_default();
+        files.sort();
+        files
+            .iter()
+            .map(|path| std::fs::read_to_string(path).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_normalize_email() {
+        assert_eq!(
+            normalize_email("  Jane@Example.COM "),
+            Some("jane@example.com".to_string())
+        );
+        for bad in [
+            "",
+            "jane",
+            "jane@",
+            "@example.com",
+            "jane@example",
+            "jane@@example.com",
+            "jane doe@example.com",
+            "jane@example.com.",
+            "a@b.com, c@d.com",
+        ] {
+            assert_eq!(normalize_email(bad), None, "{:?}", bad);
+        }
+    }
+
+    #[test]
+    fn test_double_opt_in() {
+        let mut data = SubscribersData::default();
+        let pending = data
+            .subscribe("jane@example.com", Locale::Es, "2026-01-01")
+            .unwrap();
+        assert_eq!(pending.status, SubscriberStatus::Pending);
+        assert_eq!(pending.token.len(), 32);
+        assert_eq!(data.confirmed().count(), 0);
+
+        assert_eq!(data.confirm("not-a-token", "2026-01-02"), None);
+        let confirmed = data.confirm(&pending.token, "2026-01-02").unwrap();
+        assert_eq!(confirmed.status, SubscriberStatus::Confirmed);
+        assert_eq!(confirmed.confirmed_on.as_deref(), Some("2026-01-02"));
+        // Following the link again changes nothing
+        assert_eq!(data.confirm(&pending.token, "2026-01-05"), Some(confirmed));
+        assert_eq!(data.confirmed().count(), 1);
+    }
+
+    #[test]
+    fn test_subscribing_twice_keeps_one_entry() {
+        let mut data = SubscribersData::default();
+        let first = data
+            .subscribe("jane@example.com", Locale::En, "2026-01-01")
+            .unwrap();
+        let again = data
+            .subscribe("JANE@example.com", Locale::Es, "2026-01-02")
+            .unwrap();
+        assert_eq!(data.subscribers.len(), 1);
+        assert_eq!(again.token, first.token);
+        assert_eq!(again.locale, Locale::Es);
+
+        data.confirm(&first.token, "2026-01-02");
+        let confirmed = data
+            .subscribe("jane@example.com", Locale::En, "2026-01-03")
+            .unwrap();
+        assert_eq!(confirmed.status, SubscriberStatus::Confirmed);
+        assert_eq!(confirmed.locale, Locale::Es);
+
+        assert!(data
+            .subscribe("not an email", Locale::En, "2026-01-03")
+            .is_err());
+    }
+
+    #[test]
+    fn test_unsubscribe_and_resubscribe() {
+        let mut data = SubscribersData::default();
+        let subscriber = data
+            .subscribe("jane@example.com", Locale::En, "2026-01-01")
+            .unwrap();
+        data.confirm(&subscriber.token, "2026-01-01");
+        let gone = data.unsubscribe(&subscriber.token).unwrap();
+        assert_eq!(gone.status, SubscriberStatus::Unsubscribed);
+        assert_eq!(data.confirmed().count(), 0);
+        // The old confirmation link can't sign them back up
+        assert_eq!(data.confirm(&subscriber.token, "2026-01-02"), None);
+
+        let back = data
+            .subscribe("jane@example.com", Locale::En, "2026-02-01")
+            .unwrap();
+        assert_eq!(back.status, SubscriberStatus::Pending);
+        assert_ne!(back.token, subscriber.token);
+        assert_eq!(back.subscribed_on, "2026-02-01");
+        assert_eq!(back.confirmed_on, None);
+        assert_eq!(data.subscribers.len(), 1);
+    }
+
+    #[test]
+    fn test_new_articles_are_published_after_the_last_digest() {
+        let articles = ArticlesData {
+            articles: vec![
+                article("old", "2026-01-01", ArticleStatus::Published),
+                article("newest", "2026-03-01", ArticleStatus::Published),
+                article("draft", "2026-03-02", ArticleStatus::Draft),
+                article("newer", "2026-02-01", ArticleStatus::Published),
+            ],
+            redirects: Default::default(),
+        };
+        let slugs = |since| {
+            new_articles(&articles, since)
+                .into_iter()
+                .map(|a| a.slug)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(slugs(None), ["newest", "newer", "old"]);
+        assert_eq!(slugs(Some("2026-01-01")), ["newest", "newer"]);
+        assert!(slugs(Some("2026-03-01")).is_empty());
+    }
+
+    #[test]
+    fn test_confirmation_email_links() {
+        let mut data = SubscribersData::default();
+        let subscriber = data
+            .subscribe("jane@example.com", Locale::En, "2026-01-01")
+            .unwrap();
+        let email = confirmation_email(&subscriber, &site());
+        let confirm = format!(
+            "https://acme.example/newsletter/confirm/{}",
+            subscriber.token
+        );
+        let unsubscribe = format!(
+            "https://acme.example/newsletter/unsubscribe/{}",
+            subscriber.token
+        );
+        assert_eq!(email.to, "jane@example.com");
+        assert_eq!(email.from, format!("Acme <{}>", FROM_ADDRESS));
+        assert!(email.subject.contains("Acme"));
+        assert!(email.text.contains(&confirm));
+        assert!(email.html.contains(&confirm));
+        assert_eq!(email.unsubscribe_url, unsubscribe);
+        assert!(email.text.contains(&unsubscribe));
+        assert!(email.html.contains(&unsubscribe));
+    }
+
+    #[test]
+    fn test_digest_email_renders_articles_in_the_subscribers_language() {
+        let mut data = SubscribersData::default();
+        let subscriber = data
+            .subscribe("ana@example.com", Locale::Es, "2026-01-01")
+            .unwrap();
+        assert_eq!(digest_email(&subscriber, &[], &site()), None);
+
+        let mut translated = article("ai", "2026-02-01", ArticleStatus::Published);
+        translated.title = "Fish & <Chips>".to_string();
+        translated.translations = Default::default();
+        let articles = vec![
+            translated,
+            article("other", "2026-01-15", ArticleStatus::Published),
+        ];
+        let email = digest_email(&subscriber, &articles, &site()).unwrap();
+
+        assert!(email.subject.contains('2'), "{}", email.subject);
+        assert!(email.html.contains("<html lang=\"es\">"));
+        assert!(email.html.contains("https://acme.example/es/articles/ai\""));
+        assert!(email
+            .text
+            .contains("https://acme.example/es/articles/other"));
+        // Titles are escaped in HTML and left alone in text
+        assert!(email.html.contains("Fish &amp; &lt;Chips&gt;"));
+        assert!(email.text.contains("Fish & <Chips>"));
+        let unsubscribe = format!(
+            "https://acme.example/es/newsletter/unsubscribe/{}",
+            subscriber.token
+        );
+        assert!(email.html.contains(&unsubscribe));
+        assert!(email.text.contains(&unsubscribe));
+    }
+
+    #[test]
+    fn test_eml_has_both_parts_and_an_unsubscribe_header() {
+        let email = Email {
+            from: "Acme <news@acme.example>".to_string(),
+            to: "jane@example.com".to_string(),
+            subject: "Artículos nuevos".to_string(),
+            text: "Hello".to_string(),
+            html: "<p>Hello</p>".to_string(),
+            unsubscribe_url: "https://acme.example/newsletter/unsubscribe/abc".to_string(),
+        };
+        let eml = email.to_eml();
+        assert!(eml.contains("\r\nTo: jane@example.com\r\n"));
+        assert!(eml.contains("Subject: =?UTF-8?Q?Art=C3=ADculos_nuevos?=\r\n"));
+        assert!(eml.contains("List-Unsubscribe: <https://acme.example/newsletter/unsubscribe/abc>"));
+        assert!(eml.contains(
+            "text/plain; charset=utf-8\r\nContent-Transfer-Encoding: 8bit\r\n\r\nHello\r\n"
+        ));
+        assert!(eml.contains("\r\n\r\n<p>Hello</p>\r\n"));
+        assert!(eml.ends_with(&format!("--{}--\r\n", BOUNDARY)));
+    }
+
+    #[test]
+    fn test_eml_headers_cant_be_split_by_line_breaks() {
+        let email = Email {
+            from: "Acme\r\nBcc: all@example.com <news@acme.example>".to_string(),
+            to: "jane@example.com".to_string(),
+            subject: "New\nBcc: all@example.com".to_string(),
+            text: "Hello".to_string(),
+            html: "<p>Hello</p>".to_string(),
+            unsubscribe_url: "https://acme.example/newsletter/unsubscribe/abc".to_string(),
+        };
+        let eml = email.to_eml();
+        assert!(!eml.contains("\nBcc:"));
+        assert!(eml.contains("From: Acme  Bcc: all@example.com <news@acme.example>\r\n"));
+        assert!(eml.contains("Subject: New Bcc: all@example.com\r\n"));
+    }
+
+    #[test]
+    fn test_subscribing_sends_a_confirmation_through_the_transport() {
+        let dir = outbox_dir("confirm");
+        install_store(Rc::new(MemoryStore::default()));
+        install(Rc::new(FileTransport { dir: dir.clone() }));
+
+        let subscriber = subscribe("jane@example.com", Locale::En).unwrap();
+        let emails = sent_emails(&dir);
+        assert_eq!(emails.len(), 1);
+        assert!(emails[0].contains("To: jane@example.com"));
+        assert!(emails[0].contains(&format!("/newsletter/confirm/{}", subscriber.token)));
+
+        confirm(&subscriber.token).unwrap();
+        // Already confirmed, so there's nothing to send
+        subscribe("jane@example.com", Locale::En).unwrap();
+        assert_eq!(sent_emails(&dir).len(), 1);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_send_digest_only_to_confirmed_subscribers() {
+        let dir = outbox_dir("digest");
+        install_store(Rc::new(MemoryStore::default()));
+        install(Rc::new(MemoryOutbox::default()));
+        let confirmed = subscribe("jane@example.com", Locale::En).unwrap();
+        confirm(&confirmed.token).unwrap();
+        let gone = subscribe("gone@example.com", Locale::En).unwrap();
+        confirm(&gone.token).unwrap();
+        unsubscribe(&gone.token).unwrap();
+        subscribe("pending@example.com", Locale::En).unwrap();
+        install(Rc::new(FileTransport { dir: dir.clone() }));
+
+        let published = pending_digest();
+        assert!(!published.is_empty());
+        assert_eq!(send_digest(), Ok(1));
+        let emails = sent_emails(&dir);
+        assert_eq!(emails.len(), 1);
+        assert!(emails[0].contains("To: jane@example.com"));
+        assert!(emails[0].contains(&published[0].slug));
+        assert!(emails[0].contains(&format!("/newsletter/unsubscribe/{}", confirmed.token)));
+
+        // Nothing new until another article is published
+        assert!(pending_digest().is_empty());
+        assert!(send_digest().is_err());
+        assert_eq!(sent_emails(&dir).len(), 1);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_sign_up_is_unavailable_without_a_backend() {
+        assert!(!is_configured());
+        assert!(subscribe("jane@example.com", Locale::En).is_err());
+        assert_eq!(subscribers(), None);
+
+        // A store alone isn't enough: the confirmation can't be sent, so
+        // nobody is left pending
+        install_store(Rc::new(MemoryStore::default()));
+        assert!(!is_configured());
+        assert!(subscribe("jane@example.com", Locale::En).is_err());
+        assert_eq!(subscribers(), Some(SubscribersData::default()));
+    }
+}
@@ -0,0 +1,282 @@
+//! Open Graph preview images, in `assets/og/`.
+//!
+//! Every published article and case study gets a 1200×630 SVG card in each
+//! language: the title, its category and the brand name, in the colors of the
+//! brand theme. `og.sh` converts them to the PNGs the page meta tags point
+//! at, since most sites that unfurl links don't accept SVG.
+//!
+//! The test fails when a card is missing or out of date, e.g. after editing
+//! an article or changing the brand theme; run
+//! `UPDATE_SNAPSHOTS=1 cargo test og_image` to rewrite them.
+
+use crate::components::{og_image_path, OgKind, OG_IMAGE_HEIGHT, OG_IMAGE_WIDTH};
+use crate::content::{load_settings, ArticleStatus, ArticlesData, PortfolioData, ThemeConfig};
+use crate::html::escape_html;
+use crate::i18n::{Locale, SITE_URL};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+const DIR: &str = "assets/og";
+
+/// Left and right margin of the text
+const MARGIN: u32 = 80;
+
+/// Title sizes to try, largest first, with how many characters fit a line
+/// at that size. Bold sans-serif averages a little over half an em per
+/// character.
+const TITLE_SIZES: [(u32, usize); 3] = [(68, 28), (58, 33), (50, 38)];
+
+/// Most lines a title wraps onto before it's cut short
+const TITLE_MAX_LINES: usize = 3;
+
+const FONT_FAMILY: &str = "Montserrat, 'DejaVu Sans', Arial, sans-serif";
+
+/// What a card shows
+#[derive(Debug, Clone, PartialEq)]
+struct Card {
+    title: String,
+    /// The article category or project type
+    category: String,
+    brand: String,
+}
+
+/// Words of `text` packed greedily into lines of at most `width` characters.
+/// A word longer than a line gets a line to itself.
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > width {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// The title's font size and lines: the largest size it fits at, or the
+/// smallest with the last line ending in an ellipsis
+fn layout_title(title: &str) -> (u32, Vec<String>) {
+    for (size, width) in TITLE_SIZES {
+        let lines = wrap(title, width);
+        if lines.len() <= TITLE_MAX_LINES {
+            return (size, lines);
+        }
+    }
+
+    let (size, width) = TITLE_SIZES[TITLE_SIZES.len() - 1];
+    let mut lines = wrap(title, width);
+    lines.truncate(TITLE_MAX_LINES);
+    let last = lines.last_mut().expect("a title that overflows has lines");
+    while last.chars().count() + 1 > width {
+        match last.rfind(' ') {
+            Some(space) => last.truncate(space),
+            None => {
+                *last = last.chars().take(width - 1).collect();
+                break;
+            }
+        }
+    }
+    last.push('…');
+    (size, lines)
+}
+
+/// The card as an SVG document
+fn render(card: &Card, theme: &ThemeConfig) -> String {
+    let (size, lines) = layout_title(&card.title);
+    let line_height = size * 6 / 5;
+    // Title block sits centred between the category and the footer
+    let block = line_height * lines.len() as u32;
+    let first_baseline = 150 + (330 - block) / 2 + size;
+    let domain = SITE_URL.trim_start_matches("https://");
+
+    let title: String = lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            format!(
+                "    <tspan x=\"{MARGIN}\" y=\"{}\">{}</tspan>\n",
+                first_baseline + line_height * i as u32,
+                escape_html(line)
+            )
+        })
+        .collect();
+
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">
+  <rect width="{w}" height="{h}" fill="{background}"/>
+  <circle cx="1080" cy="90" r="260" fill="{surface}"/>
+  <circle cx="1080" cy="90" r="160" fill="{background}" fill-opacity="0.5"/>
+  <rect width="16" height="{h}" fill="{secondary}"/>
+  <text x="{MARGIN}" y="130" font-family="{FONT_FAMILY}" font-size="28" font-weight="600" letter-spacing="4" fill="{secondary}">{category}</text>
+  <text font-family="{FONT_FAMILY}" font-size="{size}" font-weight="700" fill="{text}">
+{title}  </text>
+  <rect x="{MARGIN}" y="510" width="{rule}" height="2" fill="{border}"/>
+  <text x="{MARGIN}" y="568" font-family="{FONT_FAMILY}" font-size="30" font-weight="700" fill="{text}">{brand}</text>
+  <text x="{right}" y="568" font-family="{FONT_FAMILY}" font-size="26" text-anchor="end" fill="{muted}">{domain}</text>
+</svg>
+"##,
+        w = OG_IMAGE_WIDTH,
+        h = OG_IMAGE_HEIGHT,
+        background = theme.background,
+        surface = theme.surface,
+        secondary = theme.secondary,
+        text = theme.text_primary,
+        muted = theme.text_secondary,
+        border = theme.border,
+        category = escape_html(&card.category.to_uppercase()),
+        brand = escape_html(&card.brand),
+        rule = OG_IMAGE_WIDTH - 2 * MARGIN,
+        right = OG_IMAGE_WIDTH - MARGIN,
+    )
+}
+
+/// Every card that should exist, by its path on the site
+fn cards() -> BTreeMap<String, Card> {
+    let settings = load_settings();
+    let brand = settings.brand.name;
+    let articles = ArticlesData::default();
+    let portfolio = PortfolioData::default().published();
+
+    let mut cards = BTreeMap::new();
+    for locale in Locale::ALL {
+        for article in &articles.articles {
+            if article.status != ArticleStatus::Published {
+                continue;
+            }
+            let article = article.localized(locale);
+            cards.insert(
+                og_image_path(OgKind::Article, locale, &article.slug, "svg"),
+                Card {
+                    title: article.title,
+                    category: article.category,
+                    brand: brand.clone(),
+                },
+            );
+        }
+        for project in &portfolio.projects {
+            let project = project.localized(locale);
+            cards.insert(
+                og_image_path(OgKind::CaseStudy, locale, &project.slug, "svg"),
+                Card {
+                    title: project.title,
+                    category: project.project_type,
+                    brand: brand.clone(),
+                },
+            );
+        }
+    }
+    cards
+}
+
+/// Every SVG under `dir`, by its path on the site
+fn existing(dir: &Path, found: &mut Vec<String>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            existing(&path, found);
+        } else if path.extension().is_some_and(|ext| ext == "svg") {
+            let path = path.to_string_lossy().replace('\\', "/");
+            found.push(format!("/{}", path));
+        }
+    }
+}
+
+#[test]
+fn test_og_images_are_up_to_date() {
+    let theme = load_settings().brand.theme;
+    let cards = cards();
+    assert!(!cards.is_empty(), "no published articles or case studies");
+
+    let mut found = Vec::new();
+    existing(Path::new(DIR), &mut found);
+    let stale: Vec<&String> = found.iter().filter(|p| !cards.contains_key(*p)).collect();
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        for path in stale {
+            std::fs::remove_file(path.trim_start_matches('/')).unwrap();
+        }
+        for (path, card) in &cards {
+            let path = Path::new(path.trim_start_matches('/'));
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, render(card, &theme)).unwrap();
+        }
+        return;
+    }
+
+    assert!(
+        stale.is_empty(),
+        "{:?} no longer match a published page; \
+         rerun with UPDATE_SNAPSHOTS=1 to remove them",
+        stale
+    );
+    for (path, card) in &cards {
+        let svg = std::fs::read_to_string(path.trim_start_matches('/')).unwrap_or_default();
+        assert!(
+            svg == render(card, &theme),
+            "{} is missing or out of date; rerun with UPDATE_SNAPSHOTS=1 to regenerate it",
+            path
+        );
+    }
+}
+
+#[test]
+fn test_wrap_packs_words_into_lines() {
+    assert_eq!(
+        wrap("Build vs Buy: When Custom Software Makes Sense", 20),
+        vec!["Build vs Buy: When", "Custom Software", "Makes Sense"]
+    );
+    assert_eq!(
+        wrap("Supercalifragilistic word", 10),
+        vec!["Supercalifragilistic", "word"]
+    );
+    assert!(wrap("   ", 10).is_empty());
+}
+
+#[test]
+fn test_long_titles_shrink_then_truncate() {
+    let (size, lines) = layout_title("A Short Title");
+    assert_eq!((size, lines.len()), (TITLE_SIZES[0].0, 1));
+
+    let medium = "Seven Questions Every Small Business Owner Should Ask Before They Pay Anyone to Build an App";
+    let (size, lines) = layout_title(medium);
+    assert!(size < TITLE_SIZES[0].0);
+    assert!(lines.len() <= TITLE_MAX_LINES);
+    assert!(!lines.concat().contains('…'));
+
+    let long = "word ".repeat(60);
+    let (size, lines) = layout_title(&long);
+    let (smallest, width) = TITLE_SIZES[TITLE_SIZES.len() - 1];
+    assert_eq!(size, smallest);
+    assert_eq!(lines.len(), TITLE_MAX_LINES);
+    assert!(lines.iter().all(|line| line.chars().count() <= width));
+    assert!(lines[TITLE_MAX_LINES - 1].ends_with('…'));
+}
+
+#[test]
+fn test_card_uses_theme_colors_and_escapes_text() {
+    let theme = ThemeConfig::default();
+    let svg = render(
+        &Card {
+            title: "Fast <and> \"cheap\" & good".to_string(),
+            category: "Tips & Tricks".to_string(),
+            brand: "Pounds Consulting".to_string(),
+        },
+        &theme,
+    );
+    assert!(svg.contains(&format!("fill=\"{}\"", theme.background)));
+    assert!(svg.contains(&format!("fill=\"{}\"", theme.secondary)));
+    assert!(svg.contains(&format!("fill=\"{}\"", theme.text_primary)));
+    assert!(svg.contains("Fast &lt;and&gt; &quot;cheap&quot; &amp; good"));
+    assert!(svg.contains(">TIPS &amp; TRICKS<"));
+    assert!(svg.contains(&format!("width=\"{}\"", OG_IMAGE_WIDTH)));
+}
@@ -1,15 +1,18 @@
 use crate::components::CtaSection;
+use crate::i18n::use_i18n;
 use dioxus::prelude::*;
 
 #[component]
 pub fn About() -> Element {
+    let i18n = use_i18n();
+
     rsx! {
         // Hero Section
         section { class: "hero hero-short",
             div { class: "hero-content",
-                h1 { class: "hero-title", "We Make Tech Less Painful" }
+                h1 { class: "hero-title", {i18n.t("about-hero-title")} }
                 p { class: "hero-subtitle",
-                    "You shouldn't need a computer science degree to run your business. We handle the technical stuff so you can focus on what you're good at."
+                    {i18n.t("about-hero-subtitle")}
                 }
             }
         }
@@ -19,29 +22,16 @@ pub fn About() -> Element {
             div { class: "container",
                 div { class: "personal-content glass-card",
                     div { class: "personal-text",
-                        h2 { class: "personal-title", "Hi, I'm Collin" }
-                        p {
-                            "I started Pounds Consulting because too many businesses get overcharged for confusing technology. "
-                            "Whether it's your first website, a system to capture leads, or figuring out which tools you actually need, "
-                            "I'm here to help you make sense of it all."
-                        }
-                        p {
-                            "My background includes building products at Paytient (a healthcare fintech startup serving hundreds of thousands of users), "
-                            "running digital marketing for Club Car Wash during their rapid expansion, and implementing enterprise integrations "
-                            "for clients like Hyatt and Commerce Bank. I have a Computer Science degree and stay current with AI/ML developments "
-                            "by building with tools like Claude and Llama in my spare time."
-                        }
-                        p {
-                            "But what I enjoy most is working directly with people in my community. There's something rewarding about "
-                            "helping a local gym owner get their scheduling system working, or building a website for a business "
-                            "that's been growing through word-of-mouth for years and is finally ready to scale."
-                        }
+                        h2 { class: "personal-title", {i18n.t("about-intro-title")} }
+                        p { {i18n.t("about-intro-1")} }
+                        p { {i18n.t("about-intro-2")} }
+                        p { {i18n.t("about-intro-3")} }
                         a {
                             href: "https://github.com/collinpounds",
                             class: "personal-github-link",
                             target: "_blank",
                             rel: "noopener noreferrer",
-                            "See my work on GitHub →"
+                            {i18n.t("about-github-link")}
                         }
                     }
                 }
@@ -52,16 +42,16 @@ pub fn About() -> Element {
         section { class: "section clients-section",
             div { class: "container",
                 div { class: "clients-banner glass-card",
-                    h3 { class: "clients-title", "Experience Across Industries" }
-                    p { class: "clients-subtitle", "From local businesses to venture-backed startups" }
+                    h3 { class: "clients-title", {i18n.t("about-clients-title")} }
+                    p { class: "clients-subtitle", {i18n.t("about-clients-subtitle")} }
                     div { class: "clients-logos",
-                        span { class: "client-name", "Healthcare Fintech" }
+                        span { class: "client-name", {i18n.t("about-client-fintech")} }
                         span { class: "client-divider", "•" }
-                        span { class: "client-name", "Regional Retail" }
+                        span { class: "client-name", {i18n.t("about-client-retail")} }
                         span { class: "client-divider", "•" }
-                        span { class: "client-name", "Professional Services" }
+                        span { class: "client-name", {i18n.t("about-client-services")} }
                         span { class: "client-divider", "•" }
-                        span { class: "client-name", "Local Small Business" }
+                        span { class: "client-name", {i18n.t("about-client-local")} }
                     }
                 }
             }
@@ -70,50 +60,50 @@ pub fn About() -> Element {
         // What We Can Help With Section
         section { class: "section skills-section",
             div { class: "container",
-                h2 { class: "section-title", "What We Can Help With" }
+                h2 { class: "section-title", {i18n.t("about-skills-title")} }
                 div { class: "skills-grid",
                     // Websites
                     div { class: "skill-category glass-card",
-                        h3 { class: "skill-category-title", "Websites" }
+                        h3 { class: "skill-category-title", {i18n.t("about-skills-websites")} }
                         div { class: "skill-pills",
-                            span { class: "skill-pill", "Custom Websites" }
-                            span { class: "skill-pill", "Mobile-Friendly" }
-                            span { class: "skill-pill", "Fast Loading" }
-                            span { class: "skill-pill", "Easy to Update" }
-                            span { class: "skill-pill", "SEO Built-In" }
+                            span { class: "skill-pill", {i18n.t("about-skill-custom-websites")} }
+                            span { class: "skill-pill", {i18n.t("about-skill-mobile")} }
+                            span { class: "skill-pill", {i18n.t("about-skill-fast")} }
+                            span { class: "skill-pill", {i18n.t("about-skill-easy-update")} }
+                            span { class: "skill-pill", {i18n.t("about-skill-seo")} }
                         }
                     }
                     // Marketing
                     div { class: "skill-category glass-card",
-                        h3 { class: "skill-category-title", "Marketing Systems" }
+                        h3 { class: "skill-category-title", {i18n.t("about-skills-marketing")} }
                         div { class: "skill-pills",
-                            span { class: "skill-pill", "Email Campaigns" }
-                            span { class: "skill-pill", "Text Messaging" }
-                            span { class: "skill-pill", "Online Scheduling" }
-                            span { class: "skill-pill", "Lead Capture" }
-                            span { class: "skill-pill", "Customer Follow-up" }
+                            span { class: "skill-pill", {i18n.t("about-skill-email")} }
+                            span { class: "skill-pill", {i18n.t("about-skill-text")} }
+                            span { class: "skill-pill", {i18n.t("about-skill-scheduling")} }
+                            span { class: "skill-pill", {i18n.t("about-skill-leads")} }
+                            span { class: "skill-pill", {i18n.t("about-skill-follow-up")} }
                         }
                     }
                     // Business Tools
                     div { class: "skill-category glass-card",
-                        h3 { class: "skill-category-title", "Business Tools" }
+                        h3 { class: "skill-category-title", {i18n.t("about-skills-tools")} }
                         div { class: "skill-pills",
-                            span { class: "skill-pill", "Connect Your Apps" }
-                            span { class: "skill-pill", "Automate Tasks" }
-                            span { class: "skill-pill", "Customer Portals" }
-                            span { class: "skill-pill", "Online Payments" }
-                            span { class: "skill-pill", "Custom Software" }
+                            span { class: "skill-pill", {i18n.t("about-skill-connect")} }
+                            span { class: "skill-pill", {i18n.t("about-skill-automate")} }
+                            span { class: "skill-pill", {i18n.t("about-skill-portals")} }
+                            span { class: "skill-pill", {i18n.t("about-skill-payments")} }
+                            span { class: "skill-pill", {i18n.t("about-skill-software")} }
                         }
                     }
                     // Strategy
                     div { class: "skill-category glass-card",
-                        h3 { class: "skill-category-title", "Guidance" }
+                        h3 { class: "skill-category-title", {i18n.t("about-skills-guidance")} }
                         div { class: "skill-pills",
-                            span { class: "skill-pill", "What Do I Need?" }
-                            span { class: "skill-pill", "Vendor Selection" }
-                            span { class: "skill-pill", "Tech Strategy" }
-                            span { class: "skill-pill", "Honest Advice" }
-                            span { class: "skill-pill", "No Jargon" }
+                            span { class: "skill-pill", {i18n.t("about-skill-what-do-i-need")} }
+                            span { class: "skill-pill", {i18n.t("about-skill-vendors")} }
+                            span { class: "skill-pill", {i18n.t("about-skill-strategy")} }
+                            span { class: "skill-pill", {i18n.t("about-skill-honest")} }
+                            span { class: "skill-pill", {i18n.t("about-skill-no-jargon")} }
                         }
                     }
                 }
@@ -123,22 +113,22 @@ pub fn About() -> Element {
         // Background Section
         section { class: "section timeline-section",
             div { class: "container",
-                h2 { class: "section-title", "Background" }
+                h2 { class: "section-title", {i18n.t("about-background-title")} }
                 div { class: "timeline",
                     // Current
                     div { class: "timeline-item",
                         div { class: "timeline-marker" }
                         div { class: "timeline-content glass-card",
                             div { class: "timeline-header",
-                                h3 { class: "timeline-title", "Independent Consulting" }
+                                h3 { class: "timeline-title", {i18n.t("about-now-title")} }
                                 span { class: "timeline-company", "Pounds Consulting" }
-                                span { class: "timeline-period", "Now" }
+                                span { class: "timeline-period", {i18n.t("about-now-period")} }
                             }
                             ul { class: "timeline-list",
-                                li { "Building websites for local businesses who need something better than a template" }
-                                li { "Helping business owners figure out what technology they actually need" }
-                                li { "Setting up marketing systems that run on autopilot" }
-                                li { "Connecting software so you don't have to copy-paste between apps" }
+                                li { {i18n.t("about-now-1")} }
+                                li { {i18n.t("about-now-2")} }
+                                li { {i18n.t("about-now-3")} }
+                                li { {i18n.t("about-now-4")} }
                             }
                         }
                     }
@@ -148,14 +138,14 @@ pub fn About() -> Element {
                         div { class: "timeline-marker" }
                         div { class: "timeline-content glass-card",
                             div { class: "timeline-header",
-                                h3 { class: "timeline-title", "Before Going Independent" }
-                                span { class: "timeline-company", "5+ Years Building Software" }
+                                h3 { class: "timeline-title", {i18n.t("about-before-title")} }
+                                span { class: "timeline-company", {i18n.t("about-before-years")} }
                             }
                             ul { class: "timeline-list",
-                                li { "Paytient: Healthcare fintech serving 300,000+ users. Led MFA implementation, redesigned member portal" }
-                                li { "Club Car Wash: Built website and employee portal, ran Google Ads for ~3 store openings/month" }
-                                li { "Enterprise clients: Integrations for Hyatt, Commerce Bank, and others" }
-                                li { "Learned what works at scale, and how to keep things simple" }
+                                li { {i18n.t("about-before-1")} }
+                                li { {i18n.t("about-before-2")} }
+                                li { {i18n.t("about-before-3")} }
+                                li { {i18n.t("about-before-4")} }
                             }
                         }
                     }
@@ -165,13 +155,13 @@ pub fn About() -> Element {
                         div { class: "timeline-marker" }
                         div { class: "timeline-content glass-card",
                             div { class: "timeline-header",
-                                h3 { class: "timeline-title", "Why We Do This" }
+                                h3 { class: "timeline-title", {i18n.t("about-why-title")} }
                             }
                             ul { class: "timeline-list",
-                                li { "Too many businesses get overcharged for confusing technology" }
-                                li { "We like solving problems and explaining things in plain English" }
-                                li { "Small businesses deserve the same quality work as big companies" }
-                                li { "It's satisfying to build something that actually helps people" }
+                                li { {i18n.t("about-why-1")} }
+                                li { {i18n.t("about-why-2")} }
+                                li { {i18n.t("about-why-3")} }
+                                li { {i18n.t("about-why-4")} }
                             }
                         }
                     }
@@ -183,27 +173,27 @@ pub fn About() -> Element {
         section { class: "section philosophy-section",
             div { class: "container",
                 div { class: "philosophy-content glass-card",
-                    h2 { class: "philosophy-title", "How We Work" }
+                    h2 { class: "philosophy-title", {i18n.t("about-how-title")} }
                     div { class: "philosophy-grid",
                         div { class: "philosophy-item",
                             span { class: "philosophy-number", "01" }
-                            h3 { "We Listen" }
-                            p { "Every project starts with a conversation. What's working? What's not? What would make your life easier? We go from there." }
+                            h3 { {i18n.t("about-how-listen-title")} }
+                            p { {i18n.t("about-how-listen")} }
                         }
                         div { class: "philosophy-item",
                             span { class: "philosophy-number", "02" }
-                            h3 { "We Speak Your Language" }
-                            p { "No buzzwords. No alphabet soup of acronyms. Just clear explanations of what we're doing and why it matters." }
+                            h3 { {i18n.t("about-how-language-title")} }
+                            p { {i18n.t("about-how-language")} }
                         }
                         div { class: "philosophy-item",
                             span { class: "philosophy-number", "03" }
-                            h3 { "We Don't Overdo It" }
-                            p { "The goal is to solve your problem, not to build the fanciest thing possible. We recommend what actually makes sense for your situation." }
+                            h3 { {i18n.t("about-how-simple-title")} }
+                            p { {i18n.t("about-how-simple")} }
                         }
                         div { class: "philosophy-item",
                             span { class: "philosophy-number", "04" }
-                            h3 { "We've Got Backup" }
-                            p { "Some problems need specialists. We work with designers, security experts, and other talented pros. You deal with one person, but you get a whole network when you need it." }
+                            h3 { {i18n.t("about-how-backup-title")} }
+                            p { {i18n.t("about-how-backup")} }
                         }
                     }
                 }
@@ -212,9 +202,9 @@ pub fn About() -> Element {
 
         // CTA Section
         CtaSection {
-            title: "Not Sure What You Need?".to_string(),
-            description: "That's fine. Most people aren't when they first reach out. Let's have a conversation and figure it out.".to_string(),
-            button_text: "Schedule a Free Call".to_string(),
+            title: i18n.t("cta-not-sure-title"),
+            description: i18n.t("about-cta-description"),
+            button_text: i18n.t("about-cta-button"),
             use_calendar_link: true
         }
     }
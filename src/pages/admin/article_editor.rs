@@ -1,6 +1,8 @@
-use crate::content::{
-    is_authenticated, load_articles, save_articles, set_authenticated, Article, ArticleStatus,
-};
+use super::media_picker::MediaPicker;
+use super::sidebar::{AdminSection, AdminSidebar};
+use crate::components::MAIN_CONTENT_ID;
+use crate::content::media::MediaKind;
+use crate::content::{is_authenticated, load_articles, save_articles, Article, ArticleStatus};
 use crate::Route;
 use dioxus::prelude::*;
 
@@ -47,6 +49,9 @@ fn ArticleEditorInner(article_id: Option<String>) -> Element {
 
     let mut article = use_signal(|| initial_article);
     let mut saved_message = use_signal(|| Option::<String>::None);
+    // Free alternative offered when the chosen slug is already taken
+    let mut slug_suggestion = use_signal(|| Option::<String>::None);
+    let mut picking_image = use_signal(|| false);
 
     let mut do_save = move || {
         let mut articles_data = load_articles();
@@ -58,16 +63,16 @@ fn ArticleEditorInner(article_id: Option<String>) -> Element {
             });
         }
 
-        // Find and update or add
-        if let Some(pos) = articles_data
-            .articles
-            .iter()
-            .position(|a| a.id == article().id)
-        {
-            articles_data.articles[pos] = article();
-        } else {
-            articles_data.articles.push(article());
+        // Slugs must be unique, including old slugs that still redirect
+        let current = article();
+        if !articles_data.is_slug_available(&current.id, &current.slug) {
+            slug_suggestion.set(Some(articles_data.suggest_slug(&current.id, &current.slug)));
+            return;
         }
+        slug_suggestion.set(None);
+
+        // Update or add, recording a redirect if a published slug changed
+        articles_data.upsert(current);
 
         save_articles(&articles_data);
         saved_message.set(Some("Article saved!".to_string()));
@@ -88,11 +93,6 @@ fn ArticleEditorInner(article_id: Option<String>) -> Element {
         do_save();
     };
 
-    let handle_logout = move |_| {
-        set_authenticated(false);
-        navigator.push(Route::AdminLogin {});
-    };
-
     let update_title = move |evt: FormEvent| {
         let title = evt.value();
         article.with_mut(|a| {
@@ -106,36 +106,10 @@ fn ArticleEditorInner(article_id: Option<String>) -> Element {
 
     rsx! {
         div { class: "admin-layout",
-            // Sidebar
-            nav { class: "admin-sidebar",
-                div { class: "admin-sidebar-header",
-                    h2 { class: "admin-logo", "Admin" }
-                }
-
-                ul { class: "admin-nav",
-                    li { class: "admin-nav-item",
-                        Link { to: Route::AdminDashboard {}, class: "admin-nav-link", "📊 Dashboard" }
-                    }
-                    li { class: "admin-nav-item",
-                        Link { to: Route::AdminSettings {}, class: "admin-nav-link", "⚙️ Settings" }
-                    }
-                    li { class: "admin-nav-item active",
-                        Link { to: Route::AdminArticles {}, class: "admin-nav-link", "📝 Articles" }
-                    }
-                }
-
-                div { class: "admin-sidebar-footer",
-                    a { href: "/", class: "admin-nav-link", "🏠 View Site" }
-                    button {
-                        class: "btn btn-secondary btn-full",
-                        onclick: handle_logout,
-                        "Logout"
-                    }
-                }
-            }
+            AdminSidebar { active: AdminSection::Articles }
 
             // Main Content
-            main { class: "admin-main",
+            main { id: MAIN_CONTENT_ID, class: "admin-main", tabindex: "-1",
                 div { class: "admin-header",
                     div { class: "admin-header-back",
                         Link { to: Route::AdminArticles {}, class: "admin-back-btn", "← Back to Articles" }
@@ -163,8 +137,9 @@ fn ArticleEditorInner(article_id: Option<String>) -> Element {
                     div { class: "admin-editor-main",
                         div { class: "admin-form-card glass-card",
                             div { class: "form-group",
-                                label { class: "form-label", "Title" }
+                                label { class: "form-label", r#for: "article-title", "Title" }
                                 input {
+                                    id: "article-title",
                                     class: "form-input form-input-large",
                                     r#type: "text",
                                     placeholder: "Article title",
@@ -174,8 +149,17 @@ fn ArticleEditorInner(article_id: Option<String>) -> Element {
                             }
 
                             div { class: "form-group",
-                                label { class: "form-label", "Content" }
+                                div { class: "form-label-row",
+                                    label { class: "form-label", r#for: "article-content", "Content" }
+                                    button {
+                                        class: "btn btn-secondary btn-sm",
+                                        r#type: "button",
+                                        onclick: move |_| picking_image.set(true),
+                                        "Insert Image"
+                                    }
+                                }
                                 textarea {
+                                    id: "article-content",
                                     class: "form-textarea form-textarea-large",
                                     placeholder: "Write your article content here...\n\nYou can use plain text or basic formatting.",
                                     rows: "20",
@@ -184,6 +168,9 @@ fn ArticleEditorInner(article_id: Option<String>) -> Element {
                                         article.with_mut(|a| a.content = evt.value());
                                     }
                                 }
+                                p { class: "form-hint",
+                                    "Images sit on their own line as ![alt text](assets/path.png)."
+                                }
                             }
                         }
                     }
@@ -191,24 +178,45 @@ fn ArticleEditorInner(article_id: Option<String>) -> Element {
                     // Sidebar Meta
                     div { class: "admin-editor-sidebar",
                         div { class: "admin-form-card glass-card",
-                            h3 { "Article Details" }
+                            h2 { "Article Details" }
 
                             div { class: "form-group",
-                                label { class: "form-label", "Slug" }
+                                label { class: "form-label", r#for: "article-slug", "Slug" }
                                 input {
+                                    id: "article-slug",
                                     class: "form-input",
                                     r#type: "text",
                                     placeholder: "article-url-slug",
                                     value: "{article().slug}",
                                     oninput: move |evt: FormEvent| {
                                         article.with_mut(|a| a.slug = evt.value());
+                                        slug_suggestion.set(None);
+                                    }
+                                }
+                                if let Some(suggestion) = slug_suggestion() {
+                                    div { class: "form-error",
+                                        "This slug is already in use. "
+                                        button {
+                                            class: "btn-link",
+                                            onclick: move |_| {
+                                                article.with_mut(|a| a.slug = suggestion.clone());
+                                                slug_suggestion.set(None);
+                                            },
+                                            "Use \"{suggestion}\""
+                                        }
+                                    }
+                                }
+                                if !is_new {
+                                    p { class: "form-hint",
+                                        "Renaming a published article's slug redirects the old URL to the new one."
                                     }
                                 }
                             }
 
                             div { class: "form-group",
-                                label { class: "form-label", "Date" }
+                                label { class: "form-label", r#for: "article-date", "Date" }
                                 input {
+                                    id: "article-date",
                                     class: "form-input",
                                     r#type: "date",
                                     value: "{article().date}",
@@ -219,8 +227,9 @@ fn ArticleEditorInner(article_id: Option<String>) -> Element {
                             }
 
                             div { class: "form-group",
-                                label { class: "form-label", "Category" }
+                                label { class: "form-label", r#for: "article-category", "Category" }
                                 input {
+                                    id: "article-category",
                                     class: "form-input",
                                     r#type: "text",
                                     placeholder: "e.g., Technology, News",
@@ -232,8 +241,9 @@ fn ArticleEditorInner(article_id: Option<String>) -> Element {
                             }
 
                             div { class: "form-group",
-                                label { class: "form-label", "Excerpt" }
+                                label { class: "form-label", r#for: "article-excerpt", "Excerpt" }
                                 textarea {
+                                    id: "article-excerpt",
                                     class: "form-textarea",
                                     placeholder: "Brief description for article previews",
                                     rows: "3",
@@ -276,5 +286,27 @@ fn ArticleEditorInner(article_id: Option<String>) -> Element {
                 }
             }
         }
+
+        if picking_image() {
+            MediaPicker {
+                kinds: vec![MediaKind::Image, MediaKind::Vector],
+                on_select: move |path: String| {
+                    article.with_mut(|a| a.content = insert_image(&a.content, &path));
+                    picking_image.set(false);
+                },
+                on_close: move |_| picking_image.set(false)
+            }
+        }
+    }
+}
+
+/// Append an image paragraph for `path`, with alt text for the author to fill in
+fn insert_image(content: &str, path: &str) -> String {
+    let image = format!("![Describe the image]({})", path);
+    let content = content.trim_end();
+    if content.is_empty() {
+        image
+    } else {
+        format!("{}\n\n{}", content, image)
     }
 }
@@ -1,6 +1,6 @@
-use crate::content::{
-    is_authenticated, load_articles, save_articles, set_authenticated, Article, ArticleStatus,
-};
+use super::sidebar::{AdminSection, AdminSidebar};
+use crate::components::MAIN_CONTENT_ID;
+use crate::content::{is_authenticated, load_articles, save_articles, Article, ArticleStatus};
 use crate::Route;
 use dioxus::prelude::*;
 
@@ -93,11 +93,6 @@ pub fn AdminArticles() -> Element {
         selected_article_id.set(None);
     };
 
-    let handle_logout = move |_: MouseEvent| {
-        set_authenticated(false);
-        navigator.push(Route::AdminLogin {});
-    };
-
     let trash_count = articles_data()
         .articles
         .iter()
@@ -106,33 +101,7 @@ pub fn AdminArticles() -> Element {
 
     rsx! {
         div { class: "admin-layout",
-            // Main Sidebar (Navigation)
-            nav { class: "admin-sidebar",
-                div { class: "admin-sidebar-header",
-                    h2 { class: "admin-logo", "Admin" }
-                }
-
-                ul { class: "admin-nav",
-                    li { class: "admin-nav-item",
-                        Link { to: Route::AdminDashboard {}, class: "admin-nav-link", "Dashboard" }
-                    }
-                    li { class: "admin-nav-item",
-                        Link { to: Route::AdminSettings {}, class: "admin-nav-link", "Settings" }
-                    }
-                    li { class: "admin-nav-item active",
-                        Link { to: Route::AdminArticles {}, class: "admin-nav-link", "Articles" }
-                    }
-                }
-
-                div { class: "admin-sidebar-footer",
-                    a { href: "/", class: "admin-nav-link", "View Site" }
-                    button {
-                        class: "btn btn-secondary btn-full",
-                        onclick: handle_logout,
-                        "Logout"
-                    }
-                }
-            }
+            AdminSidebar { active: AdminSection::Articles }
 
             // Articles List Sidebar
             aside { class: "articles-list-sidebar",
@@ -232,7 +201,7 @@ pub fn AdminArticles() -> Element {
             }
 
             // Main Content - Article Preview/Actions
-            main { class: "admin-main articles-main",
+            main { id: MAIN_CONTENT_ID, class: "admin-main articles-main", tabindex: "-1",
                 if let Some(article) = selected_article {
                     div { class: "article-preview-panel",
                         div { class: "article-preview-header",
@@ -338,7 +307,7 @@ pub fn AdminArticles() -> Element {
                 } else {
                     div { class: "article-no-selection",
                         div { class: "no-selection-content",
-                            div { class: "no-selection-icon", "📝" }
+                            div { class: "no-selection-icon", "aria-hidden": "true", "📝" }
                             h3 { "Select an article" }
                             p { "Choose an article from the list to view details and actions" }
                             Link {
@@ -359,8 +328,8 @@ pub fn AdminArticles() -> Element {
                 div {
                     class: "admin-modal glass-card",
                     onclick: move |e| e.stop_propagation(),
-                    div { class: "modal-icon warning", "🗑️" }
-                    h3 { "Move to Trash?" }
+                    div { class: "modal-icon warning", "aria-hidden": "true", "🗑️" }
+                    h2 { "Move to Trash?" }
                     p { "This article will be moved to the trash. You can restore it later." }
                     div { class: "admin-modal-actions",
                         button {
@@ -385,8 +354,8 @@ pub fn AdminArticles() -> Element {
                 div {
                     class: "admin-modal glass-card",
                     onclick: move |e| e.stop_propagation(),
-                    div { class: "modal-icon danger", "⚠️" }
-                    h3 { "Delete Permanently?" }
+                    div { class: "modal-icon danger", "aria-hidden": "true", "⚠️" }
+                    h2 { "Delete Permanently?" }
                     p { "This action cannot be undone. The article will be permanently deleted." }
                     div { class: "admin-modal-actions",
                         button {
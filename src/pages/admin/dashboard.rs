@@ -1,6 +1,7 @@
-use crate::content::{
-    is_authenticated, load_articles, load_settings, set_authenticated, ArticleStatus,
-};
+use super::sidebar::{AdminSection, AdminSidebar};
+use super::traffic::TrafficPanel;
+use crate::components::MAIN_CONTENT_ID;
+use crate::content::{is_authenticated, load_articles, load_settings, ArticleStatus};
 use crate::Route;
 use dioxus::prelude::*;
 
@@ -30,43 +31,12 @@ pub fn AdminDashboard() -> Element {
         .count();
     let enabled_pages = settings.pages.iter().filter(|p| p.enabled).count();
 
-    let handle_logout = move |_| {
-        set_authenticated(false);
-        navigator.push(Route::AdminLogin {});
-    };
-
     rsx! {
         div { class: "admin-layout",
-            // Sidebar
-            nav { class: "admin-sidebar",
-                div { class: "admin-sidebar-header",
-                    h2 { class: "admin-logo", "Admin" }
-                }
-
-                ul { class: "admin-nav",
-                    li { class: "admin-nav-item active",
-                        Link { to: Route::AdminDashboard {}, class: "admin-nav-link", "📊 Dashboard" }
-                    }
-                    li { class: "admin-nav-item",
-                        Link { to: Route::AdminSettings {}, class: "admin-nav-link", "⚙️ Settings" }
-                    }
-                    li { class: "admin-nav-item",
-                        Link { to: Route::AdminArticles {}, class: "admin-nav-link", "📝 Articles" }
-                    }
-                }
-
-                div { class: "admin-sidebar-footer",
-                    a { href: "/", class: "admin-nav-link", "🏠 View Site" }
-                    button {
-                        class: "btn btn-secondary btn-full",
-                        onclick: handle_logout,
-                        "Logout"
-                    }
-                }
-            }
+            AdminSidebar { active: AdminSection::Dashboard }
 
             // Main Content
-            main { class: "admin-main",
+            main { id: MAIN_CONTENT_ID, class: "admin-main", tabindex: "-1",
                 div { class: "admin-header",
                     h1 { "Dashboard" }
                     p { class: "admin-subtitle", "Welcome back, {settings.brand.name}" }
@@ -75,38 +45,40 @@ pub fn AdminDashboard() -> Element {
                 div { class: "admin-stats-grid",
                     // Articles Stats
                     div { class: "admin-stat-card glass-card",
-                        div { class: "admin-stat-icon", "📝" }
+                        div { class: "admin-stat-icon", "aria-hidden": "true", "📝" }
                         div { class: "admin-stat-content",
-                            h3 { class: "admin-stat-value", "{articles.articles.len()}" }
+                            p { class: "admin-stat-value", "{articles.articles.len()}" }
                             p { class: "admin-stat-label", "Total Articles" }
                         }
                     }
 
                     div { class: "admin-stat-card glass-card",
-                        div { class: "admin-stat-icon", "✅" }
+                        div { class: "admin-stat-icon", "aria-hidden": "true", "✅" }
                         div { class: "admin-stat-content",
-                            h3 { class: "admin-stat-value", "{published_count}" }
+                            p { class: "admin-stat-value", "{published_count}" }
                             p { class: "admin-stat-label", "Published" }
                         }
                     }
 
                     div { class: "admin-stat-card glass-card",
-                        div { class: "admin-stat-icon", "📋" }
+                        div { class: "admin-stat-icon", "aria-hidden": "true", "📋" }
                         div { class: "admin-stat-content",
-                            h3 { class: "admin-stat-value", "{draft_count}" }
+                            p { class: "admin-stat-value", "{draft_count}" }
                             p { class: "admin-stat-label", "Drafts" }
                         }
                     }
 
                     div { class: "admin-stat-card glass-card",
-                        div { class: "admin-stat-icon", "📄" }
+                        div { class: "admin-stat-icon", "aria-hidden": "true", "📄" }
                         div { class: "admin-stat-content",
-                            h3 { class: "admin-stat-value", "{enabled_pages}" }
+                            p { class: "admin-stat-value", "{enabled_pages}" }
                             p { class: "admin-stat-label", "Active Pages" }
                         }
                     }
                 }
 
+                TrafficPanel {}
+
                 // Quick Actions
                 div { class: "admin-section",
                     h2 { "Quick Actions" }
@@ -114,19 +86,19 @@ pub fn AdminDashboard() -> Element {
                         Link {
                             to: Route::AdminArticleNew {},
                             class: "admin-action-card glass-card",
-                            div { class: "admin-action-icon", "✏️" }
+                            div { class: "admin-action-icon", "aria-hidden": "true", "✏️" }
                             span { "New Article" }
                         }
                         Link {
                             to: Route::AdminSettings {},
                             class: "admin-action-card glass-card",
-                            div { class: "admin-action-icon", "🎨" }
+                            div { class: "admin-action-icon", "aria-hidden": "true", "🎨" }
                             span { "Edit Theme" }
                         }
                         Link {
                             to: Route::AdminArticles {},
                             class: "admin-action-card glass-card",
-                            div { class: "admin-action-icon", "📚" }
+                            div { class: "admin-action-icon", "aria-hidden": "true", "📚" }
                             span { "Manage Articles" }
                         }
                     }
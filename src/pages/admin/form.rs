@@ -0,0 +1,64 @@
+//! Conversions between admin form inputs and content fields
+
+/// Blank input clears an optional field
+pub fn optional(value: String) -> Option<String> {
+    let trimmed = value.trim();
+    (!trimmed.is_empty()).then(|| trimmed.to_string())
+}
+
+/// One entry per non-blank line, e.g. features or scope items
+pub fn lines(value: &str) -> Vec<String> {
+    value
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Comma-separated entries, e.g. tech tags
+pub fn comma_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Add `slug` to a pinned list, or take it out
+pub fn toggle_pin(pins: &mut Vec<String>, slug: &str, pinned: bool) {
+    pins.retain(|p| p != slug);
+    if pinned {
+        pins.push(slug.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_optional() {
+        assert_eq!(optional("  ".to_string()), None);
+        assert_eq!(
+            optional(" logo.png ".to_string()),
+            Some("logo.png".to_string())
+        );
+    }
+
+    #[test]
+    fn test_lines_and_comma_list() {
+        assert_eq!(lines("One\n\n  Two  \n"), vec!["One", "Two"]);
+        assert_eq!(comma_list("Rust, WASM,, SEO "), vec!["Rust", "WASM", "SEO"]);
+    }
+
+    #[test]
+    fn test_toggle_pin_keeps_order_without_duplicates() {
+        let mut pins = vec!["a".to_string(), "b".to_string()];
+        toggle_pin(&mut pins, "a", true);
+        assert_eq!(pins, vec!["b", "a"]);
+        toggle_pin(&mut pins, "b", false);
+        assert_eq!(pins, vec!["a"]);
+    }
+}
@@ -0,0 +1,162 @@
+use super::sidebar::{AdminSection, AdminSidebar};
+use crate::components::{custom_icon_names, Icon, IconName, MAIN_CONTENT_ID};
+use crate::content::{is_authenticated, load_all_services, Service};
+use crate::Route;
+use dioxus::prelude::*;
+
+/// A service or persona whose icon doesn't resolve
+struct UnknownIcon {
+    service_id: String,
+    owner: String,
+    name: String,
+}
+
+/// Every icon in services that will render as the fallback
+fn unknown_icons(services: &[Service]) -> Vec<UnknownIcon> {
+    let mut unknown = Vec::new();
+    for service in services {
+        let mut check = |owner: String, icon: &IconName| {
+            if !icon.is_known() {
+                unknown.push(UnknownIcon {
+                    service_id: service.id.clone(),
+                    owner,
+                    name: icon.to_string(),
+                });
+            }
+        };
+        check(service.title.clone(), &service.icon);
+        for persona in &service.personas {
+            check(
+                format!("{} → {}", service.title, persona.title),
+                &persona.icon,
+            );
+        }
+    }
+    unknown
+}
+
+#[component]
+pub fn AdminIcons() -> Element {
+    let navigator = use_navigator();
+
+    // Redirect if not authenticated
+    use_effect(move || {
+        if !is_authenticated() {
+            navigator.push(Route::AdminLogin {});
+        }
+    });
+
+    let mut filter = use_signal(String::new);
+    let query = filter().trim().to_lowercase();
+    let builtin = IconName::ALL
+        .iter()
+        .filter(|icon| icon.name().contains(&query))
+        .cloned()
+        .collect::<Vec<_>>();
+    let custom = custom_icon_names()
+        .into_iter()
+        .filter(|name| name.contains(&query))
+        .collect::<Vec<_>>();
+    let unknown = unknown_icons(&load_all_services().services);
+
+    rsx! {
+        div { class: "admin-layout",
+            AdminSidebar { active: AdminSection::Icons }
+
+            main { id: MAIN_CONTENT_ID, class: "admin-main", tabindex: "-1",
+                div { class: "admin-header",
+                    h1 { "Icons" }
+                }
+
+                div { class: "admin-form-card glass-card",
+                    h2 { "Icons in Content" }
+                    if unknown.is_empty() {
+                        p { class: "form-hint", "Every service and persona icon resolves." }
+                    } else {
+                        p { class: "form-error",
+                            "These names aren't built in or registered, so they show the fallback icon."
+                        }
+                        for item in unknown {
+                            div { key: "{item.owner}", class: "admin-content-row",
+                                span { class: "admin-content-icon",
+                                    Icon { name: IconName::from_name(&item.name), size: 24 }
+                                }
+                                div { class: "admin-content-info",
+                                    strong { "{item.owner}" }
+                                    code { class: "admin-content-path", "{item.name}" }
+                                }
+                                div { class: "admin-content-actions",
+                                    Link {
+                                        to: Route::AdminServiceEdit { id: item.service_id.clone() },
+                                        class: "btn btn-primary btn-sm",
+                                        "Fix"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                div { class: "admin-form-card glass-card",
+                    input {
+                        class: "form-input",
+                        r#type: "search",
+                        "aria-label": "Filter icons",
+                        placeholder: "Filter icons by name",
+                        value: "{filter}",
+                        oninput: move |evt: FormEvent| filter.set(evt.value())
+                    }
+
+                    h2 { "Built In ({builtin.len()})" }
+                    div { class: "icon-gallery",
+                        for icon in builtin {
+                            figure { key: "{icon}", class: "icon-gallery-item",
+                                Icon { name: icon.clone(), size: 32, color: "var(--color-secondary)" }
+                                figcaption { code { "{icon}" } }
+                            }
+                        }
+                    }
+
+                    h2 { "Custom ({custom.len()})" }
+                    if custom.is_empty() {
+                        p { class: "form-hint",
+                            "Add an SVG to assets/icons/ and it's registered under its file name, e.g. assets/icons/tractor.svg is \"tractor\". Draw on a 24×24 view box with strokes in currentColor to match the built-ins."
+                        }
+                    }
+                    div { class: "icon-gallery",
+                        for name in custom {
+                            figure { key: "{name}", class: "icon-gallery-item",
+                                Icon { name: IconName::Custom(name.clone()), size: 32, color: "var(--color-secondary)" }
+                                figcaption { code { "{name}" } }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::Persona;
+
+    #[test]
+    fn test_unknown_icons_lists_services_and_personas() {
+        let mut service = Service::new();
+        service.title = "Web".to_string();
+        service.personas.push(Persona {
+            title: "Owner".to_string(),
+            icon: IconName::from_name("not-registered"),
+            description: String::new(),
+        });
+        let unknown = unknown_icons(std::slice::from_ref(&service));
+        assert_eq!(unknown.len(), 1);
+        assert_eq!(unknown[0].owner, "Web → Owner");
+        assert_eq!(unknown[0].name, "not-registered");
+
+        service.icon = IconName::from_name("also-missing");
+        assert_eq!(unknown_icons(&[service]).len(), 2);
+    }
+}
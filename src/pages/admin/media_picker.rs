@@ -0,0 +1,85 @@
+use crate::content::media::{load_media, MediaAsset, MediaKind};
+use dioxus::prelude::*;
+
+/// Modal grid of the files in `assets/` for filling in a media path
+#[component]
+pub fn MediaPicker(
+    /// Kinds of file that make sense for the field being filled
+    kinds: Vec<MediaKind>,
+    on_select: EventHandler<String>,
+    on_close: EventHandler<()>,
+) -> Element {
+    let mut filter = use_signal(String::new);
+    let query = filter().to_lowercase();
+    let assets = load_media()
+        .assets
+        .into_iter()
+        .filter(|a| kinds.contains(&a.kind) && a.path.to_lowercase().contains(&query))
+        .collect::<Vec<_>>();
+
+    rsx! {
+        div { class: "admin-modal-overlay",
+            onclick: move |_| on_close.call(()),
+            div {
+                class: "admin-modal media-picker glass-card",
+                onclick: move |e| e.stop_propagation(),
+                h2 { "Media Library" }
+                input {
+                    class: "form-input",
+                    r#type: "search",
+                    "aria-label": "Filter files",
+                    placeholder: "Filter by file name",
+                    value: "{filter}",
+                    oninput: move |evt: FormEvent| filter.set(evt.value())
+                }
+                if assets.is_empty() {
+                    p { class: "admin-content-empty", "No matching files in assets/." }
+                }
+                div { class: "media-grid",
+                    for asset in assets {
+                        button {
+                            key: "{asset.path}",
+                            class: "media-tile",
+                            r#type: "button",
+                            title: "{asset.path}",
+                            onclick: {
+                                let path = asset.path.clone();
+                                move |_| on_select.call(path.clone())
+                            },
+                            if asset.kind == MediaKind::Video {
+                                span { class: "media-tile-preview media-tile-video", "▶" }
+                            } else {
+                                img { class: "media-tile-preview", src: "/{asset.path}", alt: "", loading: "lazy" }
+                            }
+                            span { class: "media-tile-name", "{asset.name()}" }
+                            span { class: "media-tile-meta", {tile_meta(&asset)} }
+                        }
+                    }
+                }
+                p { class: "form-hint",
+                    "New files go in assets/; responsive variants are made by ./media.sh, which deploys run. The library updates on the next build."
+                }
+                div { class: "admin-modal-actions",
+                    button {
+                        class: "btn btn-secondary",
+                        onclick: move |_| on_close.call(()),
+                        "Cancel"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Size, weight and variant count under a tile
+fn tile_meta(asset: &MediaAsset) -> String {
+    let mut parts = Vec::new();
+    if let (Some(w), Some(h)) = (asset.width, asset.height) {
+        parts.push(format!("{}×{}", w, h));
+    }
+    parts.push(asset.size_label());
+    if !asset.variants.is_empty() {
+        parts.push(format!("{} variants", asset.variants.len()));
+    }
+    parts.join(" · ")
+}
@@ -1,11 +1,29 @@
 mod article_editor;
 mod articles;
 mod dashboard;
+mod form;
+mod icons;
 mod login;
+mod media_picker;
+mod portfolio;
+mod project_editor;
+mod service_editor;
+mod services;
 mod settings;
+mod sidebar;
+mod subscribers;
+mod testimonials;
+mod traffic;
 
 pub use article_editor::{AdminArticleEdit, AdminArticleNew};
 pub use articles::AdminArticles;
 pub use dashboard::AdminDashboard;
+pub use icons::AdminIcons;
 pub use login::AdminLogin;
+pub use portfolio::AdminPortfolio;
+pub use project_editor::{AdminProjectEdit, AdminProjectNew};
+pub use service_editor::{AdminServiceEdit, AdminServiceNew};
+pub use services::AdminServices;
 pub use settings::AdminSettings;
+pub use subscribers::AdminSubscribers;
+pub use testimonials::AdminTestimonials;
@@ -0,0 +1,193 @@
+use super::services::ContentStatusBadge;
+use super::sidebar::{AdminSection, AdminSidebar};
+use crate::components::MAIN_CONTENT_ID;
+use crate::content::overrides::deployed_version;
+use crate::content::{
+    is_authenticated, load_all_portfolio, save_portfolio, ArticleStatus, PortfolioData,
+    PortfolioProject,
+};
+use crate::Route;
+use dioxus::prelude::*;
+
+#[component]
+pub fn AdminPortfolio() -> Element {
+    let navigator = use_navigator();
+
+    // Redirect if not authenticated
+    use_effect(move || {
+        if !is_authenticated() {
+            navigator.push(Route::AdminLogin {});
+        }
+    });
+
+    let mut portfolio_data = use_signal(load_all_portfolio);
+    // What's deployed from content/, to spot and undo edits made here
+    let compiled = use_hook(PortfolioData::default);
+    let mut show_trash = use_signal(|| false);
+    let mut delete_confirm = use_signal(|| Option::<String>::None);
+
+    let mut set_status = move |id: String, status: ArticleStatus| {
+        portfolio_data.with_mut(|data| {
+            if let Some(project) = data.projects.iter_mut().find(|p| p.id == id) {
+                project.status = status;
+            }
+        });
+        save_portfolio(&portfolio_data());
+    };
+
+    // Put back the deployed version, redirecting from a renamed slug
+    let mut revert = move |project: PortfolioProject| {
+        portfolio_data.with_mut(|data| data.upsert(project));
+        save_portfolio(&portfolio_data());
+    };
+
+    let mut permanent_delete = move |id: String| {
+        portfolio_data.with_mut(|data| data.projects.retain(|p| p.id != id));
+        save_portfolio(&portfolio_data());
+        delete_confirm.set(None);
+    };
+
+    let trash_count = portfolio_data()
+        .projects
+        .iter()
+        .filter(|p| matches!(p.status, ArticleStatus::Trashed))
+        .count();
+
+    // Listed in the same order as the public portfolio page
+    // Each with the deployed version when admin's copy differs from it
+    let visible = portfolio_data()
+        .sorted()
+        .into_iter()
+        .filter(|p| matches!(p.status, ArticleStatus::Trashed) == show_trash())
+        .map(|p| (p.clone(), deployed_version(&compiled.projects, p).cloned()))
+        .collect::<Vec<_>>();
+
+    rsx! {
+        div { class: "admin-layout",
+            AdminSidebar { active: AdminSection::Portfolio }
+
+            main { id: MAIN_CONTENT_ID, class: "admin-main", tabindex: "-1",
+                div { class: "admin-header",
+                    h1 { "Portfolio" }
+                    div { class: "admin-header-actions",
+                        Link { to: Route::AdminProjectNew {}, class: "btn btn-primary", "+ New Project" }
+                    }
+                }
+
+                div { class: "articles-view-toggle",
+                    button {
+                        class: if !show_trash() { "view-toggle-btn active" } else { "view-toggle-btn" },
+                        onclick: move |_| show_trash.set(false),
+                        "All Projects"
+                    }
+                    button {
+                        class: if show_trash() { "view-toggle-btn active" } else { "view-toggle-btn" },
+                        onclick: move |_| show_trash.set(true),
+                        "Trash ({trash_count})"
+                    }
+                }
+
+                div { class: "admin-content-list glass-card",
+                    if visible.is_empty() {
+                        p { class: "admin-content-empty",
+                            if show_trash() { "Trash is empty" } else { "No projects yet" }
+                        }
+                    }
+                    for (project, deployed) in visible {
+                        div { key: "{project.id}", class: "admin-content-row",
+                            span { class: "admin-content-icon",
+                                if let Some(logo) = &project.logo {
+                                    img { src: "/{logo}", alt: "", width: "32", height: "32" }
+                                }
+                            }
+                            div { class: "admin-content-info",
+                                strong {
+                                    "{project.title}"
+                                    if project.featured {
+                                        span { class: "admin-content-featured", " ★ Featured" }
+                                    }
+                                }
+                                span { class: "admin-content-path", "/portfolio/{project.slug} · {project.project_type}" }
+                            }
+                            ContentStatusBadge { status: project.status.clone() }
+                            if deployed.is_some() {
+                                span {
+                                    class: "article-list-status edited",
+                                    title: "Saved in this browser only; differs from what's deployed",
+                                    "Edited here"
+                                }
+                            }
+                            div { class: "admin-content-actions",
+                                if matches!(project.status, ArticleStatus::Trashed) {
+                                    button {
+                                        class: "btn btn-secondary btn-sm",
+                                        onclick: {
+                                            let id = project.id.clone();
+                                            move |_| set_status(id.clone(), ArticleStatus::Draft)
+                                        },
+                                        "Restore"
+                                    }
+                                    button {
+                                        class: "btn btn-danger btn-sm",
+                                        onclick: {
+                                            let id = project.id.clone();
+                                            move |_| delete_confirm.set(Some(id.clone()))
+                                        },
+                                        "Delete Permanently"
+                                    }
+                                } else {
+                                    Link {
+                                        to: Route::AdminProjectEdit { id: project.id.clone() },
+                                        class: "btn btn-primary btn-sm",
+                                        "Edit"
+                                    }
+                                    if let Some(deployed) = deployed {
+                                        button {
+                                            class: "btn btn-secondary btn-sm",
+                                            title: "Replace this copy with content/portfolio/{deployed.slug}.toml",
+                                            onclick: move |_| revert(deployed.clone()),
+                                            "Revert to Deployed"
+                                        }
+                                    }
+                                    button {
+                                        class: "btn btn-danger-outline btn-sm",
+                                        onclick: {
+                                            let id = project.id.clone();
+                                            move |_| set_status(id.clone(), ArticleStatus::Trashed)
+                                        },
+                                        "Move to Trash"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(id) = delete_confirm() {
+            div { class: "admin-modal-overlay",
+                onclick: move |_| delete_confirm.set(None),
+                div {
+                    class: "admin-modal glass-card",
+                    onclick: move |e| e.stop_propagation(),
+                    div { class: "modal-icon danger", "aria-hidden": "true", "⚠️" }
+                    h2 { "Delete Permanently?" }
+                    p { "This action cannot be undone. The project will be permanently deleted." }
+                    div { class: "admin-modal-actions",
+                        button {
+                            class: "btn btn-secondary",
+                            onclick: move |_| delete_confirm.set(None),
+                            "Cancel"
+                        }
+                        button {
+                            class: "btn btn-danger",
+                            onclick: move |_| permanent_delete(id.clone()),
+                            "Delete Forever"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
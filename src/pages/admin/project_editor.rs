@@ -0,0 +1,438 @@
+use super::form::{comma_list, lines, optional, toggle_pin};
+use super::media_picker::MediaPicker;
+use super::sidebar::{AdminSection, AdminSidebar};
+use crate::components::MAIN_CONTENT_ID;
+use crate::content::media::{load_media, MediaKind};
+use crate::content::{
+    is_authenticated, load_all_portfolio, load_all_services, save_portfolio, Article,
+    ArticleStatus, PortfolioProject,
+};
+use crate::Route;
+use dioxus::prelude::*;
+
+#[component]
+pub fn AdminProjectNew() -> Element {
+    rsx! {
+        ProjectEditorInner { project_id: None }
+    }
+}
+
+#[component]
+pub fn AdminProjectEdit(id: String) -> Element {
+    rsx! {
+        ProjectEditorInner { project_id: Some(id) }
+    }
+}
+
+/// Text input bound to an optional media path on the project, with a
+/// picker over the media library
+#[component]
+fn MediaInput(
+    label: &'static str,
+    placeholder: &'static str,
+    kinds: Vec<MediaKind>,
+    value: Option<String>,
+    on_change: EventHandler<Option<String>>,
+) -> Element {
+    let mut picking = use_signal(|| false);
+    let missing = value
+        .as_deref()
+        .is_some_and(|path| !path.contains("://") && load_media().find(path).is_none());
+    let id = format!("project-{}", label.to_lowercase().replace(' ', "-"));
+
+    rsx! {
+        div { class: "form-group",
+            label { class: "form-label", r#for: "{id}", "{label}" }
+            div { class: "media-input-row",
+                input {
+                    id: "{id}",
+                    class: "form-input",
+                    r#type: "text",
+                    placeholder: "{placeholder}",
+                    value: value.unwrap_or_default(),
+                    oninput: move |evt: FormEvent| on_change.call(optional(evt.value()))
+                }
+                button {
+                    class: "btn btn-secondary btn-sm",
+                    r#type: "button",
+                    onclick: move |_| picking.set(true),
+                    "Browse"
+                }
+            }
+            if missing {
+                p { class: "form-error", "Not in the media library; the build will reject this path." }
+            }
+        }
+        if picking() {
+            MediaPicker {
+                kinds,
+                on_select: move |path| {
+                    on_change.call(Some(path));
+                    picking.set(false);
+                },
+                on_close: move |_| picking.set(false)
+            }
+        }
+    }
+}
+
+#[component]
+fn ProjectEditorInner(project_id: Option<String>) -> Element {
+    let navigator = use_navigator();
+    let is_new = project_id.is_none();
+
+    // Redirect if not authenticated
+    use_effect(move || {
+        if !is_authenticated() {
+            navigator.push(Route::AdminLogin {});
+        }
+    });
+
+    let initial_project = project_id
+        .as_ref()
+        .and_then(|id| {
+            load_all_portfolio()
+                .projects
+                .into_iter()
+                .find(|p| &p.id == id)
+        })
+        .unwrap_or_else(PortfolioProject::new);
+
+    let mut project = use_signal(|| initial_project);
+    let mut saved_message = use_signal(|| Option::<String>::None);
+    let mut save_error = use_signal(|| Option::<String>::None);
+    let mut slug_suggestion = use_signal(|| Option::<String>::None);
+    // Textareas keep what was typed; the project gets the parsed entries
+    let mut tags_text = use_signal(|| project().tech_tags.join(", "));
+    let mut scope_text = use_signal(|| project().scope.join("\n"));
+    let services = load_all_services().services;
+
+    let mut do_save = move || {
+        let mut portfolio_data = load_all_portfolio();
+
+        if project().slug.is_empty() {
+            project.with_mut(|p| p.slug = Article::generate_slug(&p.title));
+        }
+
+        let current = project();
+        if let Err(err) = current.validate() {
+            save_error.set(Some(err));
+            return;
+        }
+        if !portfolio_data.is_slug_available(&current.id, &current.slug) {
+            slug_suggestion.set(Some(
+                portfolio_data.suggest_slug(&current.id, &current.slug),
+            ));
+            save_error.set(None);
+            return;
+        }
+        save_error.set(None);
+        slug_suggestion.set(None);
+
+        portfolio_data.upsert(current);
+        save_portfolio(&portfolio_data);
+        saved_message.set(Some("Project saved!".to_string()));
+
+        spawn(async move {
+            gloo_timers::future::TimeoutFuture::new(1500).await;
+            saved_message.set(None);
+        });
+    };
+
+    let handle_save = move |_: MouseEvent| {
+        do_save();
+    };
+
+    let handle_publish = move |_: MouseEvent| {
+        project.with_mut(|p| p.status = ArticleStatus::Published);
+        do_save();
+    };
+
+    let update_title = move |evt: FormEvent| {
+        let title = evt.value();
+        project.with_mut(|p| {
+            // Keep the slug in step with the title until it's edited by hand
+            if p.slug.is_empty() || p.slug == Article::generate_slug(&p.title) {
+                p.slug = Article::generate_slug(&title);
+            }
+            p.title = title;
+        });
+    };
+
+    rsx! {
+        div { class: "admin-layout",
+            AdminSidebar { active: AdminSection::Portfolio }
+
+            main { id: MAIN_CONTENT_ID, class: "admin-main", tabindex: "-1",
+                div { class: "admin-header",
+                    div { class: "admin-header-back",
+                        Link { to: Route::AdminPortfolio {}, class: "admin-back-btn", "← Back to Portfolio" }
+                    }
+                    h1 { {if is_new { "New Project" } else { "Edit Project" }} }
+                    div { class: "admin-header-actions",
+                        if let Some(msg) = saved_message() {
+                            span { class: "admin-success-message", "{msg}" }
+                        }
+                        if let Some(err) = save_error() {
+                            span { class: "form-error", "{err}" }
+                        }
+                        button {
+                            class: "btn btn-secondary",
+                            onclick: handle_save,
+                            "Save Draft"
+                        }
+                        button {
+                            class: "btn btn-primary",
+                            onclick: handle_publish,
+                            "Publish"
+                        }
+                    }
+                }
+
+                div { class: "admin-editor-layout",
+                    div { class: "admin-editor-main",
+                        div { class: "admin-form-card glass-card",
+                            div { class: "form-group",
+                                label { class: "form-label", r#for: "project-title", "Title" }
+                                input {
+                                    id: "project-title",
+                                    class: "form-input form-input-large",
+                                    r#type: "text",
+                                    placeholder: "Client or project name",
+                                    value: "{project().title}",
+                                    oninput: update_title
+                                }
+                            }
+
+                            div { class: "form-group",
+                                label { class: "form-label", r#for: "project-description", "Description" }
+                                textarea {
+                                    id: "project-description",
+                                    class: "form-textarea",
+                                    rows: "3",
+                                    placeholder: "One or two sentences for the portfolio card",
+                                    value: "{project().description}",
+                                    oninput: move |evt: FormEvent| project.with_mut(|p| p.description = evt.value())
+                                }
+                            }
+
+                            div { class: "form-group",
+                                label { class: "form-label", r#for: "project-case-study", "Case Study" }
+                                textarea {
+                                    id: "project-case-study",
+                                    class: "form-textarea form-textarea-large",
+                                    rows: "16",
+                                    placeholder: "## Headings, - lists and paragraphs separated by blank lines",
+                                    value: "{project().long_description}",
+                                    oninput: move |evt: FormEvent| project.with_mut(|p| p.long_description = evt.value())
+                                }
+                            }
+
+                            div { class: "form-group",
+                                label { class: "form-label", r#for: "project-scope-of-work", "Scope of Work" }
+                                textarea {
+                                    id: "project-scope-of-work",
+                                    class: "form-textarea",
+                                    rows: "6",
+                                    placeholder: "One item per line",
+                                    value: "{scope_text}",
+                                    oninput: move |evt: FormEvent| {
+                                        let text = evt.value();
+                                        project.with_mut(|p| p.scope = lines(&text));
+                                        scope_text.set(text);
+                                    }
+                                }
+                            }
+                        }
+
+                        div { class: "admin-form-card glass-card",
+                            h2 { "Media" }
+                            MediaInput {
+                                label: "Logo",
+                                placeholder: "assets/portfolio/client-logo.png",
+                                kinds: vec![MediaKind::Image, MediaKind::Vector],
+                                value: project().logo,
+                                on_change: move |v| project.with_mut(|p| p.logo = v)
+                            }
+                            MediaInput {
+                                label: "Screenshot",
+                                placeholder: "assets/portfolio/client-after.png",
+                                kinds: vec![MediaKind::Image],
+                                value: project().screenshot,
+                                on_change: move |v| project.with_mut(|p| p.screenshot = v)
+                            }
+                            MediaInput {
+                                label: "Before Screenshot",
+                                placeholder: "Shown in a before/after slider with the screenshot",
+                                kinds: vec![MediaKind::Image],
+                                value: project().case_study.before_screenshot,
+                                on_change: move |v| project.with_mut(|p| p.case_study.before_screenshot = v)
+                            }
+                            MediaInput {
+                                label: "Video",
+                                placeholder: "assets/portfolio/client-demo.mp4",
+                                kinds: vec![MediaKind::Video],
+                                value: project().video,
+                                on_change: move |v| project.with_mut(|p| p.video = v)
+                            }
+                        }
+                    }
+
+                    div { class: "admin-editor-sidebar",
+                        div { class: "admin-form-card glass-card",
+                            h2 { "Project Details" }
+
+                            div { class: "form-group",
+                                label { class: "form-label", r#for: "project-slug", "Slug" }
+                                input {
+                                    id: "project-slug",
+                                    class: "form-input",
+                                    r#type: "text",
+                                    placeholder: "project-url-slug",
+                                    value: "{project().slug}",
+                                    oninput: move |evt: FormEvent| {
+                                        project.with_mut(|p| p.slug = evt.value());
+                                        slug_suggestion.set(None);
+                                    }
+                                }
+                                if let Some(suggestion) = slug_suggestion() {
+                                    div { class: "form-error",
+                                        "This slug is already in use. "
+                                        button {
+                                            class: "btn-link",
+                                            onclick: move |_| {
+                                                project.with_mut(|p| p.slug = suggestion.clone());
+                                                slug_suggestion.set(None);
+                                            },
+                                            "Use \"{suggestion}\""
+                                        }
+                                    }
+                                }
+                            }
+
+                            div { class: "form-group",
+                                label { class: "form-label", r#for: "project-project-type", "Project Type" }
+                                input {
+                                    id: "project-project-type",
+                                    class: "form-input",
+                                    r#type: "text",
+                                    placeholder: "e.g., Website, Web App",
+                                    value: "{project().project_type}",
+                                    oninput: move |evt: FormEvent| project.with_mut(|p| p.project_type = evt.value())
+                                }
+                            }
+
+                            div { class: "form-group",
+                                label { class: "form-label", r#for: "project-tech-tags", "Tech Tags" }
+                                input {
+                                    id: "project-tech-tags",
+                                    class: "form-input",
+                                    r#type: "text",
+                                    placeholder: "Comma separated, e.g., Rust, SEO",
+                                    value: "{tags_text}",
+                                    oninput: move |evt: FormEvent| {
+                                        let text = evt.value();
+                                        project.with_mut(|p| p.tech_tags = comma_list(&text));
+                                        tags_text.set(text);
+                                    }
+                                }
+                            }
+
+                            div { class: "form-group",
+                                label { class: "form-label", r#for: "project-site-url", "Site URL" }
+                                input {
+                                    id: "project-site-url",
+                                    class: "form-input",
+                                    r#type: "url",
+                                    placeholder: "https://",
+                                    value: "{project().external_url}",
+                                    oninput: move |evt: FormEvent| project.with_mut(|p| p.external_url = evt.value())
+                                }
+                            }
+
+                            div { class: "form-group",
+                                label { class: "form-label", r#for: "project-before-url", "Before URL" }
+                                input {
+                                    id: "project-before-url",
+                                    class: "form-input",
+                                    r#type: "url",
+                                    placeholder: "Archived copy of the old site (optional)",
+                                    value: project().before_url.unwrap_or_default(),
+                                    oninput: move |evt: FormEvent| project.with_mut(|p| p.before_url = optional(evt.value()))
+                                }
+                            }
+
+                            div { class: "form-group",
+                                label { class: "toggle-label",
+                                    input {
+                                        r#type: "checkbox",
+                                        class: "toggle-input",
+                                        checked: project().featured,
+                                        onchange: move |evt: FormEvent| project.with_mut(|p| p.featured = evt.checked())
+                                    }
+                                    span { class: "toggle-switch" }
+                                    span { class: "toggle-text", "Featured" }
+                                }
+                            }
+
+                            div { class: "form-group",
+                                label { class: "form-label", r#for: "project-sort-order", "Sort Order" }
+                                input {
+                                    id: "project-sort-order",
+                                    class: "form-input",
+                                    r#type: "number",
+                                    min: "0",
+                                    value: "{project().sort_order}",
+                                    oninput: move |evt: FormEvent| {
+                                        if let Ok(order) = evt.value().parse() {
+                                            project.with_mut(|p| p.sort_order = order);
+                                        }
+                                    }
+                                }
+                                p { class: "form-hint", "Lowest first, after featured projects." }
+                            }
+
+                            div { class: "form-group",
+                                label { class: "form-label", "Status" }
+                                div { class: "status-toggle",
+                                    button {
+                                        class: if matches!(project().status, ArticleStatus::Draft) { "status-btn status-btn-active" } else { "status-btn" },
+                                        onclick: move |_| project.with_mut(|p| p.status = ArticleStatus::Draft),
+                                        "Draft"
+                                    }
+                                    button {
+                                        class: if matches!(project().status, ArticleStatus::Published) { "status-btn status-btn-active" } else { "status-btn" },
+                                        onclick: move |_| project.with_mut(|p| p.status = ArticleStatus::Published),
+                                        "Published"
+                                    }
+                                }
+                            }
+                        }
+
+                        div { class: "admin-form-card glass-card",
+                            h2 { "Services Used" }
+                            p { class: "form-hint",
+                                "Checked services lead \"Services Used\" on the case study and link it from those service pages."
+                            }
+                            for service in services.iter() {
+                                label { key: "{service.id}", class: "pin-option",
+                                    input {
+                                        r#type: "checkbox",
+                                        checked: project().pinned_services.contains(&service.slug),
+                                        onchange: {
+                                            let slug = service.slug.clone();
+                                            move |evt: FormEvent| {
+                                                project.with_mut(|p| toggle_pin(&mut p.pinned_services, &slug, evt.checked()))
+                                            }
+                                        }
+                                    }
+                                    span { "{service.title}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,421 @@
+use super::form::{lines, toggle_pin};
+use super::sidebar::{AdminSection, AdminSidebar};
+use crate::components::{custom_icon_names, Icon, IconName, MAIN_CONTENT_ID};
+use crate::content::{
+    is_authenticated, load_all_portfolio, load_all_services, save_services, Article, ArticleStatus,
+    Color, Persona, Service,
+};
+use crate::Route;
+use dioxus::prelude::*;
+
+#[component]
+pub fn AdminServiceNew() -> Element {
+    rsx! {
+        ServiceEditorInner { service_id: None }
+    }
+}
+
+#[component]
+pub fn AdminServiceEdit(id: String) -> Element {
+    rsx! {
+        ServiceEditorInner { service_id: Some(id) }
+    }
+}
+
+/// Dropdown of every built-in and custom icon, with a preview. An unknown
+/// name from older content stays selected (and flagged) until it's changed.
+#[component]
+fn IconSelect(
+    value: IconName,
+    color: String,
+    #[props(default)] class: String,
+    /// For a `<label for>` pointing at the dropdown
+    id: Option<String>,
+    /// Accessible name when there's no visible label
+    label: Option<String>,
+    on_change: EventHandler<IconName>,
+) -> Element {
+    let custom = custom_icon_names();
+    let known = value.is_known();
+
+    rsx! {
+        select {
+            id,
+            "aria-label": label,
+            class: "form-select {class}",
+            value: "{value}",
+            onchange: move |evt: FormEvent| on_change.call(IconName::from_name(&evt.value())),
+            if !known {
+                option { value: "{value}", "{value} (unknown)" }
+            }
+            for icon in IconName::ALL {
+                option { key: "{icon}", value: "{icon}", "{icon}" }
+            }
+            for name in custom {
+                option { key: "custom-{name}", value: "{name}", "{name} (custom)" }
+            }
+        }
+        span { class: "icon-preview",
+            Icon { name: value.clone(), size: 28, color }
+        }
+        if !known {
+            span { class: "form-error", "Unknown icon" }
+        }
+    }
+}
+
+#[component]
+fn ServiceEditorInner(service_id: Option<String>) -> Element {
+    let navigator = use_navigator();
+    let is_new = service_id.is_none();
+
+    // Redirect if not authenticated
+    use_effect(move || {
+        if !is_authenticated() {
+            navigator.push(Route::AdminLogin {});
+        }
+    });
+
+    let initial_service = service_id
+        .as_ref()
+        .and_then(|id| {
+            load_all_services()
+                .services
+                .into_iter()
+                .find(|s| &s.id == id)
+        })
+        .unwrap_or_else(Service::new);
+
+    let mut service = use_signal(|| initial_service);
+    let mut saved_message = use_signal(|| Option::<String>::None);
+    let mut save_error = use_signal(|| Option::<String>::None);
+    let mut slug_suggestion = use_signal(|| Option::<String>::None);
+    // Textareas keep what was typed; the service gets the parsed lines
+    let mut features_text = use_signal(|| service().features.join("\n"));
+    let projects = load_all_portfolio()
+        .sorted()
+        .into_iter()
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let mut do_save = move || {
+        let mut services_data = load_all_services();
+
+        if service().slug.is_empty() {
+            service.with_mut(|s| s.slug = Article::generate_slug(&s.title));
+        }
+
+        let current = service();
+        if let Err(err) = current.validate() {
+            save_error.set(Some(err));
+            return;
+        }
+        if !services_data.is_slug_available(&current.id, &current.slug) {
+            slug_suggestion.set(Some(services_data.suggest_slug(&current.id, &current.slug)));
+            save_error.set(None);
+            return;
+        }
+        save_error.set(None);
+        slug_suggestion.set(None);
+
+        services_data.upsert(current);
+        save_services(&services_data);
+        saved_message.set(Some("Service saved!".to_string()));
+
+        spawn(async move {
+            gloo_timers::future::TimeoutFuture::new(1500).await;
+            saved_message.set(None);
+        });
+    };
+
+    let handle_save = move |_: MouseEvent| {
+        do_save();
+    };
+
+    let handle_publish = move |_: MouseEvent| {
+        service.with_mut(|s| s.status = ArticleStatus::Published);
+        do_save();
+    };
+
+    let update_title = move |evt: FormEvent| {
+        let title = evt.value();
+        service.with_mut(|s| {
+            // Keep the slug in step with the title until it's edited by hand
+            if s.slug.is_empty() || s.slug == Article::generate_slug(&s.title) {
+                s.slug = Article::generate_slug(&title);
+            }
+            s.title = title;
+        });
+    };
+
+    let accent_valid = service().accent_color.parse::<Color>().is_ok();
+    let accent_hex = service()
+        .accent_color
+        .parse::<Color>()
+        .map(|c| c.to_string())
+        .unwrap_or_else(|_| "#000000".to_string());
+
+    rsx! {
+        div { class: "admin-layout",
+            AdminSidebar { active: AdminSection::Services }
+
+            main { id: MAIN_CONTENT_ID, class: "admin-main", tabindex: "-1",
+                div { class: "admin-header",
+                    div { class: "admin-header-back",
+                        Link { to: Route::AdminServices {}, class: "admin-back-btn", "← Back to Services" }
+                    }
+                    h1 { {if is_new { "New Service" } else { "Edit Service" }} }
+                    div { class: "admin-header-actions",
+                        if let Some(msg) = saved_message() {
+                            span { class: "admin-success-message", "{msg}" }
+                        }
+                        if let Some(err) = save_error() {
+                            span { class: "form-error", "{err}" }
+                        }
+                        button {
+                            class: "btn btn-secondary",
+                            onclick: handle_save,
+                            "Save Draft"
+                        }
+                        button {
+                            class: "btn btn-primary",
+                            onclick: handle_publish,
+                            "Publish"
+                        }
+                    }
+                }
+
+                div { class: "admin-editor-layout",
+                    div { class: "admin-editor-main",
+                        div { class: "admin-form-card glass-card",
+                            div { class: "form-group",
+                                label { class: "form-label", r#for: "service-title", "Title" }
+                                input {
+                                    id: "service-title",
+                                    class: "form-input form-input-large",
+                                    r#type: "text",
+                                    placeholder: "Service name",
+                                    value: "{service().title}",
+                                    oninput: update_title
+                                }
+                            }
+
+                            div { class: "form-group",
+                                label { class: "form-label", r#for: "service-tagline", "Tagline" }
+                                input {
+                                    id: "service-tagline",
+                                    class: "form-input",
+                                    r#type: "text",
+                                    value: "{service().tagline}",
+                                    oninput: move |evt: FormEvent| service.with_mut(|s| s.tagline = evt.value())
+                                }
+                            }
+
+                            div { class: "form-group",
+                                label { class: "form-label", r#for: "service-description", "Description" }
+                                textarea {
+                                    id: "service-description",
+                                    class: "form-textarea",
+                                    rows: "3",
+                                    value: "{service().description}",
+                                    oninput: move |evt: FormEvent| service.with_mut(|s| s.description = evt.value())
+                                }
+                            }
+
+                            div { class: "form-group",
+                                label { class: "form-label", r#for: "service-long-description", "Long Description" }
+                                textarea {
+                                    id: "service-long-description",
+                                    class: "form-textarea form-textarea-large",
+                                    rows: "14",
+                                    placeholder: "## Headings, - lists and paragraphs separated by blank lines",
+                                    value: "{service().long_description}",
+                                    oninput: move |evt: FormEvent| service.with_mut(|s| s.long_description = evt.value())
+                                }
+                            }
+
+                            div { class: "form-group",
+                                label { class: "form-label", r#for: "service-features", "Features" }
+                                textarea {
+                                    id: "service-features",
+                                    class: "form-textarea",
+                                    rows: "6",
+                                    placeholder: "One feature per line",
+                                    value: "{features_text}",
+                                    oninput: move |evt: FormEvent| {
+                                        let text = evt.value();
+                                        service.with_mut(|s| s.features = lines(&text));
+                                        features_text.set(text);
+                                    }
+                                }
+                            }
+                        }
+
+                        div { class: "admin-form-card glass-card",
+                            h2 { "Who This Is For" }
+                            for (index, persona) in service().personas.into_iter().enumerate() {
+                                div { key: "{index}", class: "persona-editor",
+                                    div { class: "persona-editor-row",
+                                        input {
+                                            class: "form-input",
+                                            r#type: "text",
+                                            "aria-label": "Persona {index + 1} title",
+                                            placeholder: "Persona title",
+                                            value: "{persona.title}",
+                                            oninput: move |evt: FormEvent| service.with_mut(|s| s.personas[index].title = evt.value())
+                                        }
+                                        IconSelect {
+                                            class: "persona-icon-input",
+                                            label: format!("Persona {} icon", index + 1),
+                                            value: persona.icon.clone(),
+                                            color: "var(--color-secondary)",
+                                            on_change: move |icon| service.with_mut(|s| s.personas[index].icon = icon)
+                                        }
+                                        button {
+                                            class: "btn btn-danger-outline btn-sm",
+                                            onclick: move |_| {
+                                                service.with_mut(|s| {
+                                                    s.personas.remove(index);
+                                                });
+                                            },
+                                            "Remove"
+                                        }
+                                    }
+                                    textarea {
+                                        class: "form-textarea",
+                                        rows: "2",
+                                        "aria-label": "Persona {index + 1} description",
+                                        placeholder: "What they're struggling with",
+                                        value: "{persona.description}",
+                                        oninput: move |evt: FormEvent| service.with_mut(|s| s.personas[index].description = evt.value())
+                                    }
+                                }
+                            }
+                            button {
+                                class: "btn btn-secondary btn-sm",
+                                onclick: move |_| {
+                                    service.with_mut(|s| {
+                                        s.personas.push(Persona {
+                                            title: String::new(),
+                                            icon: IconName::Briefcase,
+                                            description: String::new(),
+                                        })
+                                    });
+                                },
+                                "+ Add Persona"
+                            }
+                        }
+                    }
+
+                    div { class: "admin-editor-sidebar",
+                        div { class: "admin-form-card glass-card",
+                            h2 { "Service Details" }
+
+                            div { class: "form-group",
+                                label { class: "form-label", r#for: "service-slug", "Slug" }
+                                input {
+                                    id: "service-slug",
+                                    class: "form-input",
+                                    r#type: "text",
+                                    placeholder: "service-url-slug",
+                                    value: "{service().slug}",
+                                    oninput: move |evt: FormEvent| {
+                                        service.with_mut(|s| s.slug = evt.value());
+                                        slug_suggestion.set(None);
+                                    }
+                                }
+                                if let Some(suggestion) = slug_suggestion() {
+                                    div { class: "form-error",
+                                        "This slug is already in use. "
+                                        button {
+                                            class: "btn-link",
+                                            onclick: move |_| {
+                                                service.with_mut(|s| s.slug = suggestion.clone());
+                                                slug_suggestion.set(None);
+                                            },
+                                            "Use \"{suggestion}\""
+                                        }
+                                    }
+                                }
+                            }
+
+                            div { class: "form-group",
+                                label { class: "form-label", r#for: "service-icon", "Icon" }
+                                div { class: "icon-input-row",
+                                    IconSelect {
+                                        id: "service-icon",
+                                        value: service().icon,
+                                        color: service().accent_color,
+                                        on_change: move |icon| service.with_mut(|s| s.icon = icon)
+                                    }
+                                }
+                                Link { to: Route::AdminIcons {}, class: "btn-link", "Browse all icons" }
+                            }
+
+                            div { class: "form-group",
+                                label { class: "form-label", r#for: "service-accent-color", "Accent Color" }
+                                div { class: "accent-color-inputs",
+                                    input {
+                                        id: "service-accent-color",
+                                        r#type: "color",
+                                        class: "accent-color-picker",
+                                        value: "{accent_hex}",
+                                        oninput: move |evt: FormEvent| service.with_mut(|s| s.accent_color = evt.value().to_uppercase())
+                                    }
+                                    input {
+                                        class: "form-input",
+                                        r#type: "text",
+                                        "aria-label": "Accent color value",
+                                        value: "{service().accent_color}",
+                                        oninput: move |evt: FormEvent| service.with_mut(|s| s.accent_color = evt.value())
+                                    }
+                                }
+                                if !accent_valid {
+                                    p { class: "form-error", "Use #RRGGBB, rgb() or hsl()" }
+                                }
+                            }
+
+                            div { class: "form-group",
+                                label { class: "form-label", "Status" }
+                                div { class: "status-toggle",
+                                    button {
+                                        class: if matches!(service().status, ArticleStatus::Draft) { "status-btn status-btn-active" } else { "status-btn" },
+                                        onclick: move |_| service.with_mut(|s| s.status = ArticleStatus::Draft),
+                                        "Draft"
+                                    }
+                                    button {
+                                        class: if matches!(service().status, ArticleStatus::Published) { "status-btn status-btn-active" } else { "status-btn" },
+                                        onclick: move |_| service.with_mut(|s| s.status = ArticleStatus::Published),
+                                        "Published"
+                                    }
+                                }
+                            }
+                        }
+
+                        div { class: "admin-form-card glass-card",
+                            h2 { "Pinned Case Studies" }
+                            p { class: "form-hint",
+                                "Pinned projects lead \"Related Case Studies\" on this page, in the order you pin them."
+                            }
+                            for project in projects.iter() {
+                                label { key: "{project.id}", class: "pin-option",
+                                    input {
+                                        r#type: "checkbox",
+                                        checked: service().pinned_projects.contains(&project.slug),
+                                        onchange: {
+                                            let slug = project.slug.clone();
+                                            move |evt: FormEvent| {
+                                                service.with_mut(|s| toggle_pin(&mut s.pinned_projects, &slug, evt.checked()))
+                                            }
+                                        }
+                                    }
+                                    span { "{project.title}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use super::sidebar::{AdminSection, AdminSidebar};
+use crate::components::{Icon, MAIN_CONTENT_ID};
+use crate::content::overrides::deployed_version;
+use crate::content::{
+    is_authenticated, load_all_services, save_services, ArticleStatus, Service, ServicesData,
+};
+use crate::Route;
+use dioxus::prelude::*;
+
+#[component]
+pub fn AdminServices() -> Element {
+    let navigator = use_navigator();
+
+    // Redirect if not authenticated
+    use_effect(move || {
+        if !is_authenticated() {
+            navigator.push(Route::AdminLogin {});
+        }
+    });
+
+    let mut services_data = use_signal(load_all_services);
+    // What's deployed from content/, to spot and undo edits made here
+    let compiled = use_hook(ServicesData::default);
+    let mut show_trash = use_signal(|| false);
+    let mut delete_confirm = use_signal(|| Option::<String>::None);
+
+    let mut set_status = move |id: String, status: ArticleStatus| {
+        services_data.with_mut(|data| {
+            if let Some(service) = data.services.iter_mut().find(|s| s.id == id) {
+                service.status = status;
+            }
+        });
+        save_services(&services_data());
+    };
+
+    // Put back the deployed version, redirecting from a renamed slug
+    let mut revert = move |service: Service| {
+        services_data.with_mut(|data| data.upsert(service));
+        save_services(&services_data());
+    };
+
+    let mut permanent_delete = move |id: String| {
+        services_data.with_mut(|data| data.services.retain(|s| s.id != id));
+        save_services(&services_data());
+        delete_confirm.set(None);
+    };
+
+    let trash_count = services_data()
+        .services
+        .iter()
+        .filter(|s| matches!(s.status, ArticleStatus::Trashed))
+        .count();
+
+    // Each with the deployed version when admin's copy differs from it
+    let visible = services_data()
+        .services
+        .into_iter()
+        .filter(|s| matches!(s.status, ArticleStatus::Trashed) == show_trash())
+        .map(|s| {
+            let deployed = deployed_version(&compiled.services, &s).cloned();
+            (s, deployed)
+        })
+        .collect::<Vec<_>>();
+
+    rsx! {
+        div { class: "admin-layout",
+            AdminSidebar { active: AdminSection::Services }
+
+            main { id: MAIN_CONTENT_ID, class: "admin-main", tabindex: "-1",
+                div { class: "admin-header",
+                    h1 { "Services" }
+                    div { class: "admin-header-actions",
+                        Link { to: Route::AdminServiceNew {}, class: "btn btn-primary", "+ New Service" }
+                    }
+                }
+
+                div { class: "articles-view-toggle",
+                    button {
+                        class: if !show_trash() { "view-toggle-btn active" } else { "view-toggle-btn" },
+                        onclick: move |_| show_trash.set(false),
+                        "All Services"
+                    }
+                    button {
+                        class: if show_trash() { "view-toggle-btn active" } else { "view-toggle-btn" },
+                        onclick: move |_| show_trash.set(true),
+                        "Trash ({trash_count})"
+                    }
+                }
+
+                div { class: "admin-content-list glass-card",
+                    if visible.is_empty() {
+                        p { class: "admin-content-empty",
+                            if show_trash() { "Trash is empty" } else { "No services yet" }
+                        }
+                    }
+                    for (service, deployed) in visible {
+                        div { key: "{service.id}", class: "admin-content-row",
+                            span { class: "admin-content-icon",
+                                Icon { name: service.icon.clone(), size: 24, color: service.accent_color.clone() }
+                            }
+                            div { class: "admin-content-info",
+                                strong { "{service.title}" }
+                                span { class: "admin-content-path", "/services/{service.slug}" }
+                            }
+                            ContentStatusBadge { status: service.status.clone() }
+                            if deployed.is_some() {
+                                span {
+                                    class: "article-list-status edited",
+                                    title: "Saved in this browser only; differs from what's deployed",
+                                    "Edited here"
+                                }
+                            }
+                            div { class: "admin-content-actions",
+                                if matches!(service.status, ArticleStatus::Trashed) {
+                                    button {
+                                        class: "btn btn-secondary btn-sm",
+                                        onclick: {
+                                            let id = service.id.clone();
+                                            move |_| set_status(id.clone(), ArticleStatus::Draft)
+                                        },
+                                        "Restore"
+                                    }
+                                    button {
+                                        class: "btn btn-danger btn-sm",
+                                        onclick: {
+                                            let id = service.id.clone();
+                                            move |_| delete_confirm.set(Some(id.clone()))
+                                        },
+                                        "Delete Permanently"
+                                    }
+                                } else {
+                                    Link {
+                                        to: Route::AdminServiceEdit { id: service.id.clone() },
+                                        class: "btn btn-primary btn-sm",
+                                        "Edit"
+                                    }
+                                    if let Some(deployed) = deployed {
+                                        button {
+                                            class: "btn btn-secondary btn-sm",
+                                            title: "Replace this copy with content/services/{deployed.slug}.toml",
+                                            onclick: move |_| revert(deployed.clone()),
+                                            "Revert to Deployed"
+                                        }
+                                    }
+                                    button {
+                                        class: "btn btn-danger-outline btn-sm",
+                                        onclick: {
+                                            let id = service.id.clone();
+                                            move |_| set_status(id.clone(), ArticleStatus::Trashed)
+                                        },
+                                        "Move to Trash"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(id) = delete_confirm() {
+            div { class: "admin-modal-overlay",
+                onclick: move |_| delete_confirm.set(None),
+                div {
+                    class: "admin-modal glass-card",
+                    onclick: move |e| e.stop_propagation(),
+                    div { class: "modal-icon danger", "aria-hidden": "true", "⚠️" }
+                    h2 { "Delete Permanently?" }
+                    p { "This action cannot be undone. The service will be permanently deleted." }
+                    div { class: "admin-modal-actions",
+                        button {
+                            class: "btn btn-secondary",
+                            onclick: move |_| delete_confirm.set(None),
+                            "Cancel"
+                        }
+                        button {
+                            class: "btn btn-danger",
+                            onclick: move |_| permanent_delete(id.clone()),
+                            "Delete Forever"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Draft / Published / Trashed pill used by the content lists
+#[component]
+pub fn ContentStatusBadge(status: ArticleStatus) -> Element {
+    let (class, label) = match status {
+        ArticleStatus::Published => ("article-list-status published", "Published"),
+        ArticleStatus::Draft => ("article-list-status draft", "Draft"),
+        ArticleStatus::Trashed => ("article-list-status trashed", "Trashed"),
+    };
+    rsx! {
+        span { class: "{class}", "{label}" }
+    }
+}
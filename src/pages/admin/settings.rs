@@ -1,7 +1,24 @@
-use crate::content::{is_authenticated, load_settings, save_settings, set_authenticated};
+use super::sidebar::{AdminSection, AdminSidebar};
+use crate::components::MAIN_CONTENT_ID;
+use crate::content::validation::{error_for, parse_promo_percentage, FieldError, SettingsField};
+use crate::content::{
+    apply_theme_to_dom, is_authenticated, load_settings, load_theme, save_settings, Color,
+    ThemeColor, ThemeConfig,
+};
 use crate::Route;
 use dioxus::prelude::*;
 
+/// A validation message under a settings input, which points at it with
+/// `aria-describedby="{input_id}-error"`
+#[component]
+fn FieldMessage(input_id: String, message: Option<String>) -> Element {
+    rsx! {
+        if let Some(message) = message {
+            p { id: "{input_id}-error", class: "form-error", "{message}" }
+        }
+    }
+}
+
 #[component]
 pub fn AdminSettings() -> Element {
     let navigator = use_navigator();
@@ -15,9 +32,33 @@ pub fn AdminSettings() -> Element {
 
     let mut settings = use_signal(load_settings);
     let mut saved_message = use_signal(|| Option::<String>::None);
+    // Set by a save blocked on invalid fields, to show the summary
+    let mut save_blocked = use_signal(|| false);
+    // Color text that doesn't parse yet, kept so it can be shown and fixed
+    let mut color_drafts = use_signal(Vec::<(ThemeColor, String)>::new);
+    // Percentage text that isn't a number, with why
+    let mut percentage_draft = use_signal(|| Option::<(String, FieldError)>::None);
+    // Page being dragged and the position it's hovering over
+    let mut dragging = use_signal(|| Option::<usize>::None);
+    let mut drag_over = use_signal(|| Option::<usize>::None);
+
+    let errors = settings().validate().err().unwrap_or_default();
+    let error_count =
+        errors.len() + color_drafts().len() + usize::from(percentage_draft().is_some());
+    let message = |field: SettingsField| error_for(&errors, &field).map(str::to_string);
 
     let handle_save = move |_| {
+        if !color_drafts().is_empty()
+            || percentage_draft().is_some()
+            || settings().validate().is_err()
+        {
+            save_blocked.set(true);
+            return;
+        }
+        save_blocked.set(false);
         save_settings(&settings());
+        // Visitors without their own theme see the new brand theme right away
+        apply_theme_to_dom(&load_theme());
         saved_message.set(Some("Settings saved successfully!".to_string()));
 
         // Clear message after 3 seconds
@@ -27,11 +68,6 @@ pub fn AdminSettings() -> Element {
         });
     };
 
-    let handle_logout = move |_| {
-        set_authenticated(false);
-        navigator.push(Route::AdminLogin {});
-    };
-
     let update_brand_name = move |evt: FormEvent| {
         settings.with_mut(|s| s.brand.name = evt.value());
     };
@@ -40,56 +76,86 @@ pub fn AdminSettings() -> Element {
         settings.with_mut(|s| s.brand.tagline = evt.value());
     };
 
-    let update_primary_color = move |evt: FormEvent| {
-        settings.with_mut(|s| s.brand.primary_color = evt.value());
+    let select_brand_preset = move |evt: FormEvent| {
+        if let Some(preset) = ThemeConfig::all_presets()
+            .into_iter()
+            .find(|p| p.name == evt.value())
+        {
+            settings.with_mut(|s| s.brand.theme = preset);
+            color_drafts.set(Vec::new());
+        }
     };
 
-    let update_accent_color = move |evt: FormEvent| {
-        settings.with_mut(|s| s.brand.accent_color = evt.value());
+    let mut update_brand_color = move |field: ThemeColor, value: String| {
+        color_drafts.with_mut(|drafts| drafts.retain(|(f, _)| *f != field));
+        match value.parse::<Color>() {
+            Ok(color) => settings.with_mut(|s| {
+                s.brand.theme.set_color(field, color);
+                s.brand.theme.name = "Custom".to_string();
+            }),
+            Err(_) => color_drafts.with_mut(|drafts| drafts.push((field, value))),
+        }
+    };
+    let color_draft = move |field: ThemeColor| {
+        color_drafts()
+            .into_iter()
+            .find(|(f, _)| *f == field)
+            .map(|(_, text)| text)
     };
 
     let update_password = move |evt: FormEvent| {
         settings.with_mut(|s| s.admin_password_hash = evt.value());
     };
 
-    rsx! {
-        div { class: "admin-layout",
-            // Sidebar
-            nav { class: "admin-sidebar",
-                div { class: "admin-sidebar-header",
-                    h2 { class: "admin-logo", "Admin" }
-                }
+    // Unparsed text first: the saved color it would replace is fine
+    let color_errors: Vec<(ThemeColor, Option<String>)> = ThemeColor::ALL
+        .into_iter()
+        .map(|field| {
+            let error = color_draft(field)
+                .map(|_| "Use #RRGGBB, rgb() or hsl()".to_string())
+                .or_else(|| message(SettingsField::ThemeColor(field)));
+            (field, error)
+        })
+        .collect();
 
-                ul { class: "admin-nav",
-                    li { class: "admin-nav-item",
-                        Link { to: Route::AdminDashboard {}, class: "admin-nav-link", "📊 Dashboard" }
-                    }
-                    li { class: "admin-nav-item active",
-                        Link { to: Route::AdminSettings {}, class: "admin-nav-link", "⚙️ Settings" }
-                    }
-                    li { class: "admin-nav-item",
-                        Link { to: Route::AdminArticles {}, class: "admin-nav-link", "📝 Articles" }
-                    }
-                }
+    // Unparsed text first, as with colors
+    let percentage_error = percentage_draft()
+        .map(|(_, error)| error.message)
+        .or_else(|| message(SettingsField::PromoPercentage));
 
-                div { class: "admin-sidebar-footer",
-                    a { href: "/", class: "admin-nav-link", "🏠 View Site" }
-                    button {
-                        class: "btn btn-secondary btn-full",
-                        onclick: handle_logout,
-                        "Logout"
-                    }
-                }
-            }
+    // Each page in navigation order, with its label and path errors
+    let pages: Vec<_> = settings()
+        .pages_in_order()
+        .into_iter()
+        .map(|page| {
+            let label_error = message(SettingsField::PageLabel(page.id.clone()));
+            let path_error = message(SettingsField::PagePath(page.id.clone()));
+            (page, label_error, path_error)
+        })
+        .collect();
+    let last_page = pages.len().saturating_sub(1);
+
+    rsx! {
+        div { class: "admin-layout",
+            AdminSidebar { active: AdminSection::Settings }
 
             // Main Content
-            main { class: "admin-main",
+            main { id: MAIN_CONTENT_ID, class: "admin-main", tabindex: "-1",
                 div { class: "admin-header",
                     h1 { "Settings" }
                     div { class: "admin-header-actions",
                         if let Some(msg) = saved_message() {
                             span { class: "admin-success-message", "{msg}" }
                         }
+                        if save_blocked() && error_count > 0 {
+                            span { class: "form-error", role: "alert",
+                                if error_count == 1 {
+                                    "Fix the highlighted field before saving."
+                                } else {
+                                    "Fix the {error_count} highlighted fields before saving."
+                                }
+                            }
+                        }
                         button {
                             class: "btn btn-primary",
                             onclick: handle_save,
@@ -103,62 +169,98 @@ pub fn AdminSettings() -> Element {
                     h2 { "Brand Settings" }
                     div { class: "admin-form-card glass-card",
                         div { class: "form-group",
-                            label { class: "form-label", "Site Name" }
+                            label { class: "form-label", r#for: "settings-site-name", "Site Name" }
                             input {
+                                id: "settings-site-name",
                                 class: "form-input",
                                 r#type: "text",
                                 value: "{settings().brand.name}",
+                                "aria-invalid": "{message(SettingsField::BrandName).is_some()}",
+                                "aria-describedby": if message(SettingsField::BrandName).is_some() { "settings-site-name-error" },
                                 oninput: update_brand_name
                             }
+                            FieldMessage { input_id: "settings-site-name", message: message(SettingsField::BrandName) }
                         }
 
                         div { class: "form-group",
-                            label { class: "form-label", "Tagline" }
+                            label { class: "form-label", r#for: "settings-tagline", "Tagline" }
                             input {
+                                id: "settings-tagline",
                                 class: "form-input",
                                 r#type: "text",
                                 value: "{settings().brand.tagline}",
+                                "aria-invalid": "{message(SettingsField::Tagline).is_some()}",
+                                "aria-describedby": if message(SettingsField::Tagline).is_some() { "settings-tagline-error" },
                                 oninput: update_tagline
                             }
+                            FieldMessage { input_id: "settings-tagline", message: message(SettingsField::Tagline) }
                         }
 
-                        div { class: "form-row",
-                            div { class: "form-group",
-                                label { class: "form-label", "Primary Color" }
-                                div { class: "color-input-group",
-                                    input {
-                                        class: "form-input form-input-color",
-                                        r#type: "color",
-                                        value: "{settings().brand.primary_color}",
-                                        oninput: update_primary_color
+                        div { class: "form-group",
+                            label { class: "form-label", r#for: "settings-brand-theme", "Brand Theme" }
+                            select {
+                                id: "settings-brand-theme",
+                                class: "form-input",
+                                onchange: select_brand_preset,
+                                for preset in ThemeConfig::all_presets() {
+                                    option {
+                                        key: "{preset.name}",
+                                        value: "{preset.name}",
+                                        selected: settings().brand.theme.name == preset.name,
+                                        "{preset.name}"
                                     }
-                                    input {
-                                        class: "form-input",
-                                        r#type: "text",
-                                        value: "{settings().brand.primary_color}",
-                                        oninput: update_primary_color
+                                }
+                                if !ThemeConfig::all_presets().iter().any(|p| p.name == settings().brand.theme.name) {
+                                    option {
+                                        value: "{settings().brand.theme.name}",
+                                        selected: true,
+                                        "{settings().brand.theme.name}"
                                     }
                                 }
                             }
+                            p { class: "form-hint", "Every visitor starts from this theme. Pick a preset, then fine-tune the colors below." }
+                        }
 
-                            div { class: "form-group",
-                                label { class: "form-label", "Accent Color" }
-                                div { class: "color-input-group",
-                                    input {
-                                        class: "form-input form-input-color",
-                                        r#type: "color",
-                                        value: "{settings().brand.accent_color}",
-                                        oninput: update_accent_color
-                                    }
-                                    input {
-                                        class: "form-input",
-                                        r#type: "text",
-                                        value: "{settings().brand.accent_color}",
-                                        oninput: update_accent_color
+                        div { class: "brand-theme-colors",
+                            for (field, error) in color_errors {
+                                div { key: "{field.key()}", class: "form-group",
+                                    label { class: "form-label", r#for: "settings-color-{field.key()}", "{field.label()}" }
+                                    div { class: "color-input-group",
+                                        input {
+                                            id: "settings-color-{field.key()}",
+                                            class: "form-input form-input-color",
+                                            r#type: "color",
+                                            value: "{settings().brand.theme.color(field)}",
+                                            oninput: move |evt: FormEvent| update_brand_color(field, evt.value())
+                                        }
+                                        input {
+                                            class: "form-input",
+                                            r#type: "text",
+                                            "aria-label": "{field.label()} value",
+                                            placeholder: "#RRGGBB, rgb() or hsl()",
+                                            value: color_draft(field).unwrap_or_else(|| settings().brand.theme.color(field).to_string()),
+                                            "aria-invalid": "{error.is_some()}",
+                                            "aria-describedby": if error.is_some() { "settings-color-{field.key()}-error" },
+                                            oninput: move |evt: FormEvent| update_brand_color(field, evt.value())
+                                        }
                                     }
+                                    FieldMessage { input_id: "settings-color-{field.key()}", message: error.clone() }
                                 }
                             }
                         }
+
+                        button {
+                            class: "btn btn-secondary",
+                            onclick: move |_| {
+                                settings.with_mut(|s| {
+                                    let theme = &s.brand.theme;
+                                    s.brand.theme = ThemeConfig::from_brand_color("Custom", theme.secondary, theme.is_dark());
+                                });
+                                color_drafts.set(Vec::new());
+                            },
+                            "Generate Palette from Accent"
+                        }
+                        p { class: "form-hint", "Derives background, surface, border and text shades from the accent color." }
                     }
                 }
 
@@ -240,6 +342,21 @@ pub fn AdminSettings() -> Element {
                                 span { class: "toggle-text", "Testimonials" }
                             }
                         }
+
+                        div { class: "toggle-group",
+                            label { class: "toggle-label",
+                                input {
+                                    r#type: "checkbox",
+                                    class: "toggle-input",
+                                    checked: settings().features.visitor_themes,
+                                    onchange: move |evt: FormEvent| {
+                                        settings.with_mut(|s| s.features.visitor_themes = evt.checked());
+                                    }
+                                }
+                                span { class: "toggle-switch" }
+                                span { class: "toggle-text", "Visitor Theme Customizer" }
+                            }
+                        }
                     }
                 }
 
@@ -272,6 +389,8 @@ pub fn AdminSettings() -> Element {
                                     checked: settings().discount.promo_discount.enabled,
                                     onchange: move |evt: FormEvent| {
                                         settings.with_mut(|s| s.discount.promo_discount.enabled = evt.checked());
+                                        // A hidden field can't block saving
+                                        percentage_draft.set(None);
                                     }
                                 }
                                 span { class: "toggle-switch" }
@@ -283,28 +402,43 @@ pub fn AdminSettings() -> Element {
                         if settings().discount.promo_discount.enabled {
                             div { class: "form-row promo-settings",
                                 div { class: "form-group",
-                                    label { class: "form-label", "Discount Percentage" }
+                                    label { class: "form-label", r#for: "settings-discount-percentage", "Discount Percentage" }
                                     input {
+                                        id: "settings-discount-percentage",
                                         class: "form-input",
                                         r#type: "number",
                                         min: "1",
                                         max: "50",
-                                        value: "{settings().discount.promo_discount.percentage}",
+                                        value: percentage_draft().map_or_else(
+                                            || settings().discount.promo_discount.percentage.to_string(),
+                                            |(text, _)| text,
+                                        ),
+                                        "aria-invalid": "{percentage_error.is_some()}",
+                                        "aria-describedby": if percentage_error.is_some() { "settings-discount-percentage-error" },
                                         oninput: move |evt: FormEvent| {
-                                            if let Ok(val) = evt.value().parse::<u8>() {
-                                                settings.with_mut(|s| s.discount.promo_discount.percentage = val.clamp(1, 50));
+                                            let text = evt.value();
+                                            match parse_promo_percentage(&text) {
+                                                Ok(percentage) => {
+                                                    percentage_draft.set(None);
+                                                    settings.with_mut(|s| s.discount.promo_discount.percentage = percentage);
+                                                }
+                                                Err(error) => percentage_draft.set(Some((text, error))),
                                             }
                                         }
                                     }
                                     p { class: "form-hint", "1-50%" }
+                                    FieldMessage { input_id: "settings-discount-percentage", message: percentage_error.clone() }
                                 }
                                 div { class: "form-group",
-                                    label { class: "form-label", "Discount Label (optional)" }
+                                    label { class: "form-label", r#for: "settings-discount-label", "Discount Label (optional)" }
                                     input {
+                                        id: "settings-discount-label",
                                         class: "form-input",
                                         r#type: "text",
                                         placeholder: "e.g., Holiday Special, New Year Sale",
                                         value: "{settings().discount.promo_discount.label.clone().unwrap_or_default()}",
+                                        "aria-invalid": "{message(SettingsField::PromoLabel).is_some()}",
+                                        "aria-describedby": if message(SettingsField::PromoLabel).is_some() { "settings-discount-label-error" },
                                         oninput: move |evt: FormEvent| {
                                             let val = evt.value();
                                             settings.with_mut(|s| {
@@ -313,6 +447,7 @@ pub fn AdminSettings() -> Element {
                                         }
                                     }
                                     p { class: "form-hint", "Leave empty to show \"X% Off\"" }
+                                    FieldMessage { input_id: "settings-discount-label", message: message(SettingsField::PromoLabel) }
                                 }
                             }
                         }
@@ -322,25 +457,112 @@ pub fn AdminSettings() -> Element {
                 // Page Management
                 div { class: "admin-section",
                     h2 { "Page Visibility" }
+                    p { class: "form-hint", "Drag pages or use the arrow buttons to reorder the site navigation." }
                     div { class: "admin-form-card glass-card",
-                        for (idx, page) in settings().pages.iter().enumerate() {
-                            div { class: "toggle-group", key: "{page.id}",
+                        for (idx, (page, label_error, path_error)) in pages.into_iter().enumerate() {
+                            div {
+                                key: "{page.id}",
+                                class: if drag_over() == Some(idx) { "toggle-group page-order-item drag-over" } else { "toggle-group page-order-item" },
+                                draggable: "true",
+                                ondragstart: move |_| dragging.set(Some(idx)),
+                                ondragover: move |evt: DragEvent| {
+                                    evt.prevent_default();
+                                    drag_over.set(Some(idx));
+                                },
+                                ondragleave: move |_| drag_over.set(None),
+                                ondrop: move |evt: DragEvent| {
+                                    evt.prevent_default();
+                                    if let Some(from) = dragging() {
+                                        settings.with_mut(|s| s.move_page(from, idx));
+                                    }
+                                    dragging.set(None);
+                                    drag_over.set(None);
+                                },
+                                ondragend: move |_| {
+                                    dragging.set(None);
+                                    drag_over.set(None);
+                                },
+                                span { class: "drag-handle", "⋮⋮" }
                                 label { class: "toggle-label",
                                     input {
                                         r#type: "checkbox",
                                         class: "toggle-input",
                                         checked: page.enabled,
-                                        onchange: move |evt: FormEvent| {
+                                        onchange: {
+                                            let id = page.id.clone();
+                                            move |evt: FormEvent| {
+                                                settings.with_mut(|s| {
+                                                    if let Some(p) = s.page_mut(&id) {
+                                                        p.enabled = evt.checked();
+                                                    }
+                                                });
+                                            }
+                                        }
+                                    }
+                                    span { class: "toggle-switch" }
+                                    span { class: "sr-only", "Show {page.label} in the navigation" }
+                                }
+                                input {
+                                    id: "settings-page-{page.id}-label",
+                                    class: "form-input page-order-input",
+                                    r#type: "text",
+                                    "aria-label": "Navigation label of the {page.id} page",
+                                    value: "{page.label}",
+                                    "aria-invalid": "{label_error.is_some()}",
+                                    "aria-describedby": if label_error.is_some() { "settings-page-{page.id}-label-error" },
+                                    oninput: {
+                                        let id = page.id.clone();
+                                        move |evt: FormEvent| {
                                             settings.with_mut(|s| {
-                                                if let Some(p) = s.pages.get_mut(idx) {
-                                                    p.enabled = evt.checked();
+                                                if let Some(p) = s.page_mut(&id) {
+                                                    p.label = evt.value();
                                                 }
                                             });
                                         }
                                     }
-                                    span { class: "toggle-switch" }
-                                    span { class: "toggle-text", "{page.label}" }
-                                    span { class: "toggle-path", "{page.path}" }
+                                }
+                                input {
+                                    id: "settings-page-{page.id}-path",
+                                    class: "form-input page-order-input page-order-path",
+                                    r#type: "text",
+                                    "aria-label": "Path of the {page.id} page",
+                                    value: "{page.path}",
+                                    "aria-invalid": "{path_error.is_some()}",
+                                    "aria-describedby": if path_error.is_some() { "settings-page-{page.id}-path-error" },
+                                    oninput: {
+                                        let id = page.id.clone();
+                                        move |evt: FormEvent| {
+                                            settings.with_mut(|s| {
+                                                if let Some(p) = s.page_mut(&id) {
+                                                    p.path = evt.value();
+                                                }
+                                            });
+                                        }
+                                    }
+                                }
+                                div { class: "page-order-moves",
+                                    button {
+                                        r#type: "button",
+                                        class: "btn btn-secondary btn-sm",
+                                        disabled: idx == 0,
+                                        "aria-label": "Move {page.label} up",
+                                        onclick: move |_| settings.with_mut(|s| s.move_page(idx, idx.saturating_sub(1))),
+                                        "↑"
+                                    }
+                                    button {
+                                        r#type: "button",
+                                        class: "btn btn-secondary btn-sm",
+                                        disabled: idx == last_page,
+                                        "aria-label": "Move {page.label} down",
+                                        onclick: move |_| settings.with_mut(|s| s.move_page(idx, idx + 1)),
+                                        "↓"
+                                    }
+                                }
+                                if let Some(error) = &label_error {
+                                    p { id: "settings-page-{page.id}-label-error", class: "form-error page-order-error", "{error}" }
+                                }
+                                if let Some(error) = &path_error {
+                                    p { id: "settings-page-{page.id}-path-error", class: "form-error page-order-error", "{error}" }
                                 }
                             }
                         }
@@ -352,15 +574,19 @@ pub fn AdminSettings() -> Element {
                     h2 { "Security" }
                     div { class: "admin-form-card glass-card",
                         div { class: "form-group",
-                            label { class: "form-label", "Admin Password" }
+                            label { class: "form-label", r#for: "settings-admin-password", "Admin Password" }
                             input {
+                                id: "settings-admin-password",
                                 class: "form-input",
                                 r#type: "password",
                                 placeholder: "Enter new password",
                                 value: "{settings().admin_password_hash}",
+                                "aria-invalid": "{message(SettingsField::AdminPassword).is_some()}",
+                                "aria-describedby": if message(SettingsField::AdminPassword).is_some() { "settings-admin-password-error" },
                                 oninput: update_password
                             }
                             p { class: "form-hint", "Change the admin password here" }
+                            FieldMessage { input_id: "settings-admin-password", message: message(SettingsField::AdminPassword) }
                         }
                     }
                 }
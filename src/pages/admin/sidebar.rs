@@ -0,0 +1,115 @@
+use crate::components::SkipLink;
+use crate::content::set_authenticated;
+use crate::Route;
+use dioxus::prelude::*;
+
+/// Admin areas linked from the sidebar
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AdminSection {
+    Dashboard,
+    Settings,
+    Articles,
+    Services,
+    Portfolio,
+    Testimonials,
+    Subscribers,
+    Icons,
+}
+
+impl AdminSection {
+    const ALL: [AdminSection; 8] = [
+        AdminSection::Dashboard,
+        AdminSection::Settings,
+        AdminSection::Articles,
+        AdminSection::Services,
+        AdminSection::Portfolio,
+        AdminSection::Testimonials,
+        AdminSection::Subscribers,
+        AdminSection::Icons,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            AdminSection::Dashboard => "Dashboard",
+            AdminSection::Settings => "Settings",
+            AdminSection::Articles => "Articles",
+            AdminSection::Services => "Services",
+            AdminSection::Portfolio => "Portfolio",
+            AdminSection::Testimonials => "Testimonials",
+            AdminSection::Subscribers => "Subscribers",
+            AdminSection::Icons => "Icons",
+        }
+    }
+
+    /// Decoration only; hidden from screen readers, which read the label
+    fn emoji(self) -> &'static str {
+        match self {
+            AdminSection::Dashboard => "📊",
+            AdminSection::Settings => "⚙️",
+            AdminSection::Articles => "📝",
+            AdminSection::Services => "🧰",
+            AdminSection::Portfolio => "📁",
+            AdminSection::Testimonials => "💬",
+            AdminSection::Subscribers => "📬",
+            AdminSection::Icons => "✨",
+        }
+    }
+
+    fn route(self) -> Route {
+        match self {
+            AdminSection::Dashboard => Route::AdminDashboard {},
+            AdminSection::Settings => Route::AdminSettings {},
+            AdminSection::Articles => Route::AdminArticles {},
+            AdminSection::Services => Route::AdminServices {},
+            AdminSection::Portfolio => Route::AdminPortfolio {},
+            AdminSection::Testimonials => Route::AdminTestimonials {},
+            AdminSection::Subscribers => Route::AdminSubscribers {},
+            AdminSection::Icons => Route::AdminIcons {},
+        }
+    }
+}
+
+/// Navigation shared by every admin page, with the current section highlighted
+#[component]
+pub fn AdminSidebar(active: AdminSection) -> Element {
+    let navigator = use_navigator();
+
+    let handle_logout = move |_| {
+        set_authenticated(false);
+        navigator.push(Route::AdminLogin {});
+    };
+
+    rsx! {
+        SkipLink {}
+        nav { class: "admin-sidebar", "aria-label": "Admin",
+            div { class: "admin-sidebar-header",
+                h2 { class: "admin-logo", "Admin" }
+            }
+
+            ul { class: "admin-nav",
+                for section in AdminSection::ALL {
+                    li {
+                        key: "{section.label()}",
+                        class: if section == active { "admin-nav-item active" } else { "admin-nav-item" },
+                        Link { to: section.route(), class: "admin-nav-link",
+                            span { "aria-hidden": "true", "{section.emoji()} " }
+                            "{section.label()}"
+                        }
+                    }
+                }
+            }
+
+            div { class: "admin-sidebar-footer",
+                a { href: "/", class: "admin-nav-link",
+                    span { "aria-hidden": "true", "🏠 " }
+                    "View Site"
+                }
+                button {
+                    class: "btn btn-secondary btn-full",
+                    onclick: handle_logout,
+                    "Logout"
+                }
+            }
+        }
+    }
+}
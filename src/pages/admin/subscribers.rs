@@ -0,0 +1,233 @@
+use super::sidebar::{AdminSection, AdminSidebar};
+use crate::components::MAIN_CONTENT_ID;
+use crate::content::is_authenticated;
+use crate::newsletter::{self, Site, Subscriber, SubscriberStatus};
+use crate::Route;
+use dioxus::prelude::*;
+
+/// Newsletter subscribers and the next digest
+#[component]
+pub fn AdminSubscribers() -> Element {
+    let navigator = use_navigator();
+
+    // Redirect if not authenticated
+    use_effect(move || {
+        if !is_authenticated() {
+            navigator.push(Route::AdminLogin {});
+        }
+    });
+
+    let mut subscribers_data = use_signal(newsletter::subscribers);
+    let mut digest = use_signal(newsletter::pending_digest);
+    let mut show_preview = use_signal(|| false);
+    let mut digest_message = use_signal(|| Option::<Result<String, String>>::None);
+    let mut delete_confirm = use_signal(|| Option::<String>::None);
+
+    let configured = newsletter::is_configured();
+    let data = subscribers_data().unwrap_or_default();
+    let confirmed = data.count(SubscriberStatus::Confirmed);
+    let pending = data.count(SubscriberStatus::Pending);
+    // Previewed as the first confirmed subscriber would get it
+    let preview = data
+        .confirmed()
+        .next()
+        .cloned()
+        .unwrap_or_else(|| Subscriber {
+            email: "subscriber@example.com".to_string(),
+            status: SubscriberStatus::Confirmed,
+            token: "preview".to_string(),
+            locale: Default::default(),
+            subscribed_on: String::new(),
+            confirmed_on: None,
+        });
+    let preview_email = newsletter::digest_email(&preview, &digest(), &Site::current());
+
+    let handle_send = move |_| {
+        digest_message.set(Some(
+            newsletter::send_digest().map(|sent| format!("Digest sent to {} subscribers", sent)),
+        ));
+        subscribers_data.set(newsletter::subscribers());
+        digest.set(newsletter::pending_digest());
+        show_preview.set(false);
+    };
+
+    let mut remove_subscriber = move |email: String| {
+        if let Err(message) = newsletter::remove_subscriber(&email) {
+            digest_message.set(Some(Err(message)));
+        }
+        subscribers_data.set(newsletter::subscribers());
+        delete_confirm.set(None);
+    };
+
+    rsx! {
+        div { class: "admin-layout",
+            AdminSidebar { active: AdminSection::Subscribers }
+
+            main { id: MAIN_CONTENT_ID, class: "admin-main", tabindex: "-1",
+                div { class: "admin-header",
+                    h1 { "Subscribers" }
+                    p { class: "admin-subtitle",
+                        "{confirmed} confirmed, {pending} waiting to confirm"
+                    }
+                }
+
+                if !configured {
+                    div { class: "admin-section",
+                        div { class: "admin-form-card glass-card",
+                            p { class: "admin-empty",
+                                "No newsletter backend configured, so visitors are told sign-up is unavailable. "
+                                "Install a SubscriberStore and MailTransport in main.rs that talk to a server."
+                            }
+                        }
+                    }
+                }
+
+                // Digest
+                div { class: "admin-section",
+                    h2 { "Next Digest" }
+                    div { class: "admin-form-card glass-card",
+                        if digest().is_empty() {
+                            p { class: "admin-empty",
+                                {match &data.last_digest {
+                                    Some(date) => format!("No articles published since the last digest ({}).", date),
+                                    None => "No published articles yet.".to_string(),
+                                }}
+                            }
+                        } else {
+                            p { class: "form-hint",
+                                {match &data.last_digest {
+                                    Some(date) => format!("Published since the last digest ({}):", date),
+                                    None => "No digest has been sent yet, so the first one has every published article:".to_string(),
+                                }}
+                            }
+                            ul { class: "digest-articles",
+                                for article in digest() {
+                                    li { key: "{article.id}", "{article.title} ", span { class: "article-list-date", "({article.date})" } }
+                                }
+                            }
+                            div { class: "admin-header-actions",
+                                button {
+                                    class: "btn btn-secondary",
+                                    r#type: "button",
+                                    "aria-expanded": "{show_preview()}",
+                                    onclick: move |_| show_preview.toggle(),
+                                    if show_preview() { "Hide Preview" } else { "Preview" }
+                                }
+                                button {
+                                    class: "btn btn-primary",
+                                    r#type: "button",
+                                    disabled: confirmed == 0,
+                                    onclick: handle_send,
+                                    "Send to {confirmed} Subscribers"
+                                }
+                            }
+                        }
+                        match digest_message() {
+                            Some(Ok(message)) => rsx! { p { class: "admin-success-message", role: "status", "{message}" } },
+                            Some(Err(message)) => rsx! { p { class: "form-error", role: "alert", "{message}" } },
+                            None => rsx! {},
+                        }
+                        if let (true, Some(email)) = (show_preview(), preview_email) {
+                            div { class: "digest-preview",
+                                p { class: "form-hint", "To: {email.to}" }
+                                p { class: "form-hint", "Subject: {email.subject}" }
+                                iframe {
+                                    class: "digest-preview-frame",
+                                    title: "Digest email preview",
+                                    "sandbox": "",
+                                    srcdoc: "{email.html}",
+                                }
+                                details {
+                                    summary { "Plain text version" }
+                                    pre { class: "digest-preview-text", "{email.text}" }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Subscriber list
+                div { class: "admin-section",
+                    h2 { "Everyone Who Signed Up" }
+                    div { class: "admin-table-container glass-card",
+                        if data.subscribers.is_empty() {
+                            p { class: "admin-empty",
+                                "No subscribers yet. Visitors can sign up from the footer or any article."
+                            }
+                        } else {
+                            table { class: "admin-table",
+                                thead {
+                                    tr {
+                                        th { "Email" }
+                                        th { "Status" }
+                                        th { "Language" }
+                                        th { "Signed Up" }
+                                        th { "Confirmed" }
+                                        th { "Actions" }
+                                    }
+                                }
+                                tbody {
+                                    for subscriber in data.subscribers.iter() {
+                                        tr { key: "{subscriber.email}",
+                                            td { "{subscriber.email}" }
+                                            td {
+                                                span {
+                                                    class: match subscriber.status {
+                                                        SubscriberStatus::Confirmed => "status-badge status-published",
+                                                        _ => "status-badge status-draft",
+                                                    },
+                                                    "{subscriber.status.label()}"
+                                                }
+                                            }
+                                            td { "{subscriber.locale.native_name()}" }
+                                            td { "{subscriber.subscribed_on}" }
+                                            td { {subscriber.confirmed_on.clone().unwrap_or_else(|| "–".to_string())} }
+                                            td {
+                                                button {
+                                                    class: "admin-action-btn admin-action-delete",
+                                                    r#type: "button",
+                                                    "aria-label": "Remove {subscriber.email}",
+                                                    onclick: {
+                                                        let email = subscriber.email.clone();
+                                                        move |_| delete_confirm.set(Some(email.clone()))
+                                                    },
+                                                    "Remove"
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // Remove confirmation
+        if let Some(email) = delete_confirm() {
+            div { class: "admin-modal-overlay",
+                onclick: move |_| delete_confirm.set(None),
+                div {
+                    class: "admin-modal glass-card",
+                    onclick: move |e| e.stop_propagation(),
+                    div { class: "modal-icon danger", "aria-hidden": "true", "⚠️" }
+                    h2 { "Remove Subscriber?" }
+                    p { "{email} won't get any more emails, and the links in emails already sent will stop working." }
+                    div { class: "admin-modal-actions",
+                        button {
+                            class: "btn btn-secondary",
+                            onclick: move |_| delete_confirm.set(None),
+                            "Cancel"
+                        }
+                        button {
+                            class: "btn btn-danger",
+                            onclick: move |_| remove_subscriber(email.clone()),
+                            "Remove"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
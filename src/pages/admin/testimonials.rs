@@ -0,0 +1,378 @@
+use super::form::optional;
+use super::sidebar::{AdminSection, AdminSidebar};
+use crate::components::{TestimonialQuote, MAIN_CONTENT_ID};
+use crate::content::overrides::deployed_version;
+use crate::content::{
+    is_authenticated, load_all_portfolio, load_all_services, load_settings, load_testimonials,
+    save_testimonials, Testimonial, TestimonialsData,
+};
+use crate::Route;
+use dioxus::prelude::*;
+
+#[component]
+pub fn AdminTestimonials() -> Element {
+    let navigator = use_navigator();
+
+    // Redirect if not authenticated
+    use_effect(move || {
+        if !is_authenticated() {
+            navigator.push(Route::AdminLogin {});
+        }
+    });
+
+    let portfolio = load_all_portfolio();
+    let services = load_all_services();
+    let enabled = load_settings().features.testimonials;
+
+    let mut testimonials_data = use_signal(load_testimonials);
+    // What's deployed from content/, to spot and undo edits made here
+    let compiled = use_hook(TestimonialsData::default);
+    // Testimonial being edited; new ones aren't in the list until saved
+    let mut editing = use_signal(|| Option::<Testimonial>::None);
+    let mut save_error = use_signal(|| Option::<String>::None);
+    let mut saved_message = use_signal(|| Option::<String>::None);
+    let mut delete_confirm = use_signal(|| Option::<String>::None);
+
+    let handle_save = {
+        let portfolio = portfolio.clone();
+        let services = services.clone();
+        move |_| {
+            let Some(testimonial) = editing() else {
+                return;
+            };
+            if let Err(err) = testimonial.validate(&portfolio, &services) {
+                save_error.set(Some(err));
+                return;
+            }
+            save_error.set(None);
+            testimonials_data.with_mut(|data| data.upsert(testimonial));
+            save_testimonials(&testimonials_data());
+            saved_message.set(Some("Testimonial saved".to_string()));
+
+            spawn(async move {
+                gloo_timers::future::TimeoutFuture::new(3000).await;
+                saved_message.set(None);
+            });
+        }
+    };
+
+    let mut delete_testimonial = move |id: String| {
+        testimonials_data.with_mut(|data| data.remove(&id));
+        save_testimonials(&testimonials_data());
+        editing.set(None);
+        delete_confirm.set(None);
+    };
+
+    // Put back the deployed version
+    let mut revert = move |testimonial: Testimonial| {
+        testimonials_data.with_mut(|data| data.upsert(testimonial.clone()));
+        save_testimonials(&testimonials_data());
+        editing.set(Some(testimonial));
+        save_error.set(None);
+    };
+
+    let is_saved = editing()
+        .map(|t| testimonials_data().find(&t.id).is_some())
+        .unwrap_or(false);
+    // The deployed version of the saved copy being edited, if it differs
+    let deployed = editing()
+        .and_then(|t| testimonials_data().find(&t.id).cloned())
+        .and_then(|saved| deployed_version(&compiled.testimonials, &saved).cloned());
+
+    rsx! {
+        div { class: "admin-layout",
+            AdminSidebar { active: AdminSection::Testimonials }
+
+            aside { class: "articles-list-sidebar",
+                div { class: "articles-list-header",
+                    h3 { "Testimonials" }
+                    button {
+                        class: "btn btn-primary btn-sm",
+                        onclick: move |_| {
+                            editing.set(Some(Testimonial::new()));
+                            save_error.set(None);
+                        },
+                        "+ New"
+                    }
+                }
+
+                div { class: "articles-list",
+                    if testimonials_data().testimonials.is_empty() {
+                        div { class: "articles-list-empty",
+                            p { "No testimonials yet" }
+                        }
+                    } else {
+                        for testimonial in testimonials_data().testimonials {
+                            div {
+                                key: "{testimonial.id}",
+                                class: if editing().is_some_and(|t| t.id == testimonial.id) {
+                                    "article-list-item selected"
+                                } else {
+                                    "article-list-item"
+                                },
+                                onclick: {
+                                    let testimonial = testimonial.clone();
+                                    move |_| {
+                                        editing.set(Some(testimonial.clone()));
+                                        save_error.set(None);
+                                    }
+                                },
+                                div { class: "article-list-item-title", "{testimonial.author}" }
+                                div { class: "article-list-item-meta",
+                                    if let Some(attribution) = testimonial.attribution() {
+                                        span { class: "article-list-date", "{attribution}" }
+                                    }
+                                    if deployed_version(&compiled.testimonials, &testimonial).is_some() {
+                                        span {
+                                            class: "article-list-status edited",
+                                            title: "Saved in this browser only; differs from what's deployed",
+                                            "Edited here"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            main { id: MAIN_CONTENT_ID, class: "admin-main articles-main", tabindex: "-1",
+                if !enabled {
+                    div { class: "admin-notice",
+                        "Testimonials are hidden on the site. Turn them on under Settings → Features."
+                    }
+                }
+
+                if let Some(testimonial) = editing() {
+                    div { class: "admin-header",
+                        h1 { {if is_saved { "Edit Testimonial" } else { "New Testimonial" }} }
+                        div { class: "admin-header-actions",
+                            if let Some(msg) = saved_message() {
+                                span { class: "admin-success-message", "{msg}" }
+                            }
+                            if let Some(err) = save_error() {
+                                span { class: "form-error", "{err}" }
+                            }
+                            if let Some(deployed) = deployed {
+                                button {
+                                    class: "btn btn-secondary",
+                                    title: "Replace this copy with content/testimonials/{deployed.id}.toml",
+                                    onclick: move |_| revert(deployed.clone()),
+                                    "Revert to Deployed"
+                                }
+                            }
+                            if is_saved {
+                                button {
+                                    class: "btn btn-danger-outline",
+                                    onclick: {
+                                        let id = testimonial.id.clone();
+                                        move |_| delete_confirm.set(Some(id.clone()))
+                                    },
+                                    "Delete"
+                                }
+                            }
+                            button {
+                                class: "btn btn-primary",
+                                onclick: handle_save,
+                                "Save"
+                            }
+                        }
+                    }
+
+                    div { class: "admin-editor-layout",
+                        div { class: "admin-editor-main",
+                            div { class: "admin-form-card glass-card",
+                                div { class: "form-group",
+                                    label { class: "form-label", r#for: "testimonial-quote", "Quote" }
+                                    textarea {
+                                        id: "testimonial-quote",
+                                        class: "form-textarea",
+                                        rows: "5",
+                                        value: "{testimonial.quote}",
+                                        oninput: move |evt: FormEvent| {
+                                            editing.with_mut(|t| {
+                                                if let Some(t) = t {
+                                                    t.quote = evt.value();
+                                                }
+                                            });
+                                        }
+                                    }
+                                }
+
+                                div { class: "form-group",
+                                    label { class: "form-label", r#for: "testimonial-person", "Person" }
+                                    input {
+                                        id: "testimonial-person",
+                                        class: "form-input",
+                                        r#type: "text",
+                                        value: "{testimonial.author}",
+                                        oninput: move |evt: FormEvent| {
+                                            editing.with_mut(|t| {
+                                                if let Some(t) = t {
+                                                    t.author = evt.value();
+                                                }
+                                            });
+                                        }
+                                    }
+                                }
+
+                                div { class: "form-group",
+                                    label { class: "form-label", r#for: "testimonial-role", "Role" }
+                                    input {
+                                        id: "testimonial-role",
+                                        class: "form-input",
+                                        r#type: "text",
+                                        placeholder: "e.g., Owner",
+                                        value: testimonial.role.clone().unwrap_or_default(),
+                                        oninput: move |evt: FormEvent| {
+                                            editing.with_mut(|t| {
+                                                if let Some(t) = t {
+                                                    t.role = optional(evt.value());
+                                                }
+                                            });
+                                        }
+                                    }
+                                }
+
+                                div { class: "form-group",
+                                    label { class: "form-label", r#for: "testimonial-company", "Company" }
+                                    input {
+                                        id: "testimonial-company",
+                                        class: "form-input",
+                                        r#type: "text",
+                                        value: testimonial.company.clone().unwrap_or_default(),
+                                        oninput: move |evt: FormEvent| {
+                                            editing.with_mut(|t| {
+                                                if let Some(t) = t {
+                                                    t.company = optional(evt.value());
+                                                }
+                                            });
+                                        }
+                                    }
+                                }
+
+                                div { class: "form-group",
+                                    label { class: "form-label", r#for: "testimonial-photo", "Photo" }
+                                    input {
+                                        id: "testimonial-photo",
+                                        class: "form-input",
+                                        r#type: "text",
+                                        placeholder: "assets/testimonials/name.jpg",
+                                        value: testimonial.photo.clone().unwrap_or_default(),
+                                        oninput: move |evt: FormEvent| {
+                                            editing.with_mut(|t| {
+                                                if let Some(t) = t {
+                                                    t.photo = optional(evt.value());
+                                                }
+                                            });
+                                        }
+                                    }
+                                }
+                            }
+                        }
+
+                        div { class: "admin-editor-sidebar",
+                            div { class: "admin-form-card glass-card",
+                                h2 { "Show With" }
+
+                                div { class: "form-group",
+                                    label { class: "form-label", r#for: "testimonial-portfolio-project", "Portfolio Project" }
+                                    select {
+                                        id: "testimonial-portfolio-project",
+                                        class: "form-input",
+                                        onchange: move |evt: FormEvent| {
+                                            editing.with_mut(|t| {
+                                                if let Some(t) = t {
+                                                    t.project_slug = optional(evt.value());
+                                                }
+                                            });
+                                        },
+                                        option { value: "", selected: testimonial.project_slug.is_none(), "None" }
+                                        for project in portfolio.sorted() {
+                                            option {
+                                                key: "{project.slug}",
+                                                value: "{project.slug}",
+                                                selected: testimonial.project_slug.as_deref() == Some(project.slug.as_str()),
+                                                "{project.title}"
+                                            }
+                                        }
+                                    }
+                                }
+
+                                div { class: "form-group",
+                                    label { class: "form-label", r#for: "testimonial-service", "Service" }
+                                    select {
+                                        id: "testimonial-service",
+                                        class: "form-input",
+                                        onchange: move |evt: FormEvent| {
+                                            editing.with_mut(|t| {
+                                                if let Some(t) = t {
+                                                    t.service_slug = optional(evt.value());
+                                                }
+                                            });
+                                        },
+                                        option { value: "", selected: testimonial.service_slug.is_none(), "None" }
+                                        for service in services.services.iter() {
+                                            option {
+                                                key: "{service.slug}",
+                                                value: "{service.slug}",
+                                                selected: testimonial.service_slug.as_deref() == Some(service.slug.as_str()),
+                                                "{service.title}"
+                                            }
+                                        }
+                                    }
+                                }
+                                p { class: "form-hint",
+                                    "Every testimonial rotates on the home page. Linked ones also appear on that project or service."
+                                }
+                            }
+
+                            div { class: "admin-form-card glass-card",
+                                h2 { "Preview" }
+                                TestimonialQuote { testimonial: testimonial.clone() }
+                            }
+                        }
+                    }
+                } else {
+                    div { class: "article-no-selection",
+                        div { class: "no-selection-content",
+                            div { class: "no-selection-icon", "aria-hidden": "true", "💬" }
+                            h3 { "Select a testimonial" }
+                            p { "Choose a testimonial from the list, or add a new one" }
+                            button {
+                                class: "btn btn-primary",
+                                onclick: move |_| editing.set(Some(Testimonial::new())),
+                                "Add Testimonial"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(id) = delete_confirm() {
+            div { class: "admin-modal-overlay",
+                onclick: move |_| delete_confirm.set(None),
+                div {
+                    class: "admin-modal glass-card",
+                    onclick: move |e| e.stop_propagation(),
+                    div { class: "modal-icon danger", "aria-hidden": "true", "⚠️" }
+                    h2 { "Delete Testimonial?" }
+                    p { "This action cannot be undone." }
+                    div { class: "admin-modal-actions",
+                        button {
+                            class: "btn btn-secondary",
+                            onclick: move |_| delete_confirm.set(None),
+                            "Cancel"
+                        }
+                        button {
+                            class: "btn btn-danger",
+                            onclick: move |_| delete_testimonial(id.clone()),
+                            "Delete"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
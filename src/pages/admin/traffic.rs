@@ -0,0 +1,270 @@
+use crate::analytics::{self, DayStats, DIRECT};
+use crate::content::load_all_services;
+use dioxus::prelude::*;
+
+/// Ranges the dashboard can show, in days
+const RANGES: [usize; 3] = [7, 30, 90];
+
+/// Chart size in SVG units; it scales to the card's width
+const CHART_WIDTH: f64 = 600.0;
+const CHART_HEIGHT: f64 = 160.0;
+
+/// Rows in the top pages and referrers tables
+const TOP_ROWS: usize = 8;
+
+/// Traffic from `analytics`: daily views and bookings, top pages and
+/// referrers, and how often service pages lead to a booking
+#[component]
+pub fn TrafficPanel() -> Element {
+    let mut range = use_signal(|| 30);
+    let Some(stats) = analytics::stats() else {
+        return rsx! {
+            div { class: "admin-section",
+                h2 { "Traffic" }
+                div { class: "admin-traffic-chart glass-card",
+                    p { class: "admin-empty",
+                        "No analytics backend configured, so visits aren't being counted. "
+                        "Install an AnalyticsSink in main.rs that sends them to a server."
+                    }
+                }
+            }
+        };
+    };
+    let days = stats.last_days(&analytics::today(), range());
+    let total = analytics::sum(&days);
+    let services = load_all_services().services;
+    let conversions = analytics::service_conversions(&total);
+    let service_views: u32 = conversions.iter().map(|c| c.views).sum();
+    let service_bookings: u32 = conversions.iter().map(|c| c.bookings).sum();
+
+    rsx! {
+        div { class: "admin-section",
+            div { class: "admin-traffic-header",
+                h2 { "Traffic" }
+                div { class: "admin-traffic-ranges", role: "group", "aria-label": "Date range",
+                    for days in RANGES {
+                        button {
+                            key: "{days}",
+                            r#type: "button",
+                            class: if range() == days { "filter-btn filter-btn-active" } else { "filter-btn" },
+                            "aria-pressed": "{range() == days}",
+                            onclick: move |_| range.set(days),
+                            "{days} days"
+                        }
+                    }
+                }
+            }
+
+            div { class: "admin-stats-grid",
+                div { class: "admin-stat-card glass-card",
+                    div { class: "admin-stat-content",
+                        p { class: "admin-stat-value", "{total.total_views()}" }
+                        p { class: "admin-stat-label", "Page Views" }
+                    }
+                }
+                div { class: "admin-stat-card glass-card",
+                    div { class: "admin-stat-content",
+                        p { class: "admin-stat-value", "{total.total_bookings()}" }
+                        p { class: "admin-stat-label", "Booking Clicks" }
+                    }
+                }
+                div { class: "admin-stat-card glass-card",
+                    div { class: "admin-stat-content",
+                        p { class: "admin-stat-value", "{percent(service_bookings, service_views)}" }
+                        p { class: "admin-stat-label", "Service Page Conversion" }
+                    }
+                }
+            }
+
+            div { class: "admin-traffic-chart glass-card",
+                if total.total_views() == 0 && total.total_bookings() == 0 {
+                    p { class: "admin-empty", "No visits recorded in the last {range()} days." }
+                } else {
+                    TrafficChart { days: days.clone() }
+                }
+            }
+
+            div { class: "admin-traffic-tables",
+                CountTable {
+                    title: "Top Pages",
+                    label: "Page",
+                    rows: analytics::top(&total.views, TOP_ROWS),
+                }
+                CountTable {
+                    title: "Referrers",
+                    label: "Source",
+                    rows: analytics::top(&total.referrers, TOP_ROWS)
+                        .into_iter()
+                        .map(|(source, count)| {
+                            let source = if source == DIRECT { "Direct or unknown".to_string() } else { source };
+                            (source, count)
+                        })
+                        .collect::<Vec<_>>(),
+                }
+                CountTable {
+                    title: "Booking Buttons",
+                    label: "Button",
+                    rows: analytics::top(&total.bookings, TOP_ROWS)
+                        .into_iter()
+                        .map(|(placement, count)| (placement_label(&placement).to_string(), count))
+                        .collect::<Vec<_>>(),
+                }
+            }
+
+            h3 { class: "admin-traffic-subtitle", "Service Pages to Bookings" }
+            div { class: "admin-table-container glass-card",
+                if conversions.is_empty() {
+                    p { class: "admin-empty", "No service page visits yet." }
+                } else {
+                    table { class: "admin-table",
+                        thead {
+                            tr {
+                                th { "Service" }
+                                th { "Views" }
+                                th { "Bookings" }
+                                th { "Conversion" }
+                            }
+                        }
+                        tbody {
+                            for conversion in conversions.iter() {
+                                tr { key: "{conversion.slug}",
+                                    td {
+                                        {services
+                                            .iter()
+                                            .find(|s| s.slug == conversion.slug)
+                                            .map_or(conversion.slug.clone(), |s| s.title.clone())}
+                                    }
+                                    td { "{conversion.views}" }
+                                    td { "{conversion.bookings}" }
+                                    td { "{conversion.rate():.1}%" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Daily views as bars with bookings as a line over them
+#[component]
+fn TrafficChart(days: Vec<(String, DayStats)>) -> Element {
+    let views: Vec<u32> = days.iter().map(|(_, d)| d.total_views()).collect();
+    let bookings: Vec<u32> = days.iter().map(|(_, d)| d.total_bookings()).collect();
+    let max = views
+        .iter()
+        .chain(&bookings)
+        .copied()
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let slot = CHART_WIDTH / days.len().max(1) as f64;
+    let bookings_line = bookings
+        .iter()
+        .enumerate()
+        .map(|(i, count)| {
+            format!(
+                "{:.1},{:.1}",
+                slot * (i as f64 + 0.5),
+                CHART_HEIGHT - bar_height(*count, max)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    let first = days.first().map(|(d, _)| d.clone()).unwrap_or_default();
+    let last = days.last().map(|(d, _)| d.clone()).unwrap_or_default();
+    let summary = format!(
+        "Daily page views and booking clicks from {} to {}: {} views and {} bookings, at most {} in a day",
+        first,
+        last,
+        views.iter().sum::<u32>(),
+        bookings.iter().sum::<u32>(),
+        max
+    );
+
+    rsx! {
+        div { class: "admin-traffic-legend", "aria-hidden": "true",
+            span { class: "legend-views", "Page views" }
+            span { class: "legend-bookings", "Booking clicks" }
+            span { class: "legend-max", "Peak {max}" }
+        }
+        svg {
+            class: "admin-traffic-svg",
+            view_box: "0 0 {CHART_WIDTH} {CHART_HEIGHT}",
+            preserve_aspect_ratio: "none",
+            role: "img",
+            "aria-label": "{summary}",
+            for (i, ((day, _), count)) in days.iter().zip(&views).enumerate() {
+                rect {
+                    key: "{day}",
+                    class: "chart-bar",
+                    x: "{slot * i as f64 + slot * 0.15:.1}",
+                    y: "{CHART_HEIGHT - bar_height(*count, max):.1}",
+                    width: "{slot * 0.7:.1}",
+                    height: "{bar_height(*count, max):.1}",
+                    title { "{day}: {count} views" }
+                }
+            }
+            polyline { class: "chart-line", points: "{bookings_line}" }
+        }
+        div { class: "admin-traffic-axis", "aria-hidden": "true",
+            span { "{first}" }
+            span { "{last}" }
+        }
+    }
+}
+
+#[component]
+fn CountTable(title: String, label: String, rows: Vec<(String, u32)>) -> Element {
+    rsx! {
+        div { class: "admin-table-container glass-card",
+            h3 { class: "admin-traffic-subtitle", "{title}" }
+            if rows.is_empty() {
+                p { class: "admin-empty", "Nothing yet." }
+            } else {
+                table { class: "admin-table",
+                    thead {
+                        tr {
+                            th { "{label}" }
+                            th { "Count" }
+                        }
+                    }
+                    tbody {
+                        for (name, count) in rows.iter() {
+                            tr { key: "{name}",
+                                td { "{name}" }
+                                td { "{count}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Height of a bar for `count` when `max` fills the chart
+fn bar_height(count: u32, max: u32) -> f64 {
+    f64::from(count) / f64::from(max.max(1)) * CHART_HEIGHT
+}
+
+fn percent(part: u32, whole: u32) -> String {
+    if whole == 0 {
+        "–".to_string()
+    } else {
+        format!("{:.1}%", f64::from(part) * 100.0 / f64::from(whole))
+    }
+}
+
+/// What admin calls each booking button
+fn placement_label(placement: &str) -> &str {
+    match placement {
+        "hero" => "Home page hero",
+        "services" => "Services page",
+        "service" => "Service page",
+        "contact" => "Contact page",
+        "cta_section" => "Call-to-action banner",
+        other => other,
+    }
+}
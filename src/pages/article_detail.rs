@@ -1,6 +1,44 @@
-use crate::content::{load_articles, ArticleStatus};
+use crate::components::{
+    copy_to_clipboard, og_image_path, NotFoundPanel, OgKind, Redirect, ResponsiveImage,
+    ShareButtons, SocialMeta, SubscribeForm,
+};
+use crate::content::related::{category_neighbors, related_articles, RELATED_LIMIT};
+use crate::content::{load_articles, Article};
+use crate::i18n::{use_i18n, SITE_URL};
 use crate::Route;
 use dioxus::prelude::*;
+use dioxus::CapturedError;
+#[cfg(target_arch = "wasm32")]
+use std::{cell::RefCell, rc::Rc};
+#[cfg(target_arch = "wasm32")]
+use web_sys::wasm_bindgen::{closure::Closure, JsCast};
+
+/// A `scroll` listener on the window, removed when dropped so it neither
+/// leaks nor displaces other listeners
+#[cfg(target_arch = "wasm32")]
+struct ScrollListener(Closure<dyn FnMut()>);
+
+#[cfg(target_arch = "wasm32")]
+impl ScrollListener {
+    fn new(on_scroll: impl FnMut() + 'static) -> Option<Self> {
+        let window = web_sys::window()?;
+        let closure = Closure::<dyn FnMut()>::new(on_scroll);
+        window
+            .add_event_listener_with_callback("scroll", closure.as_ref().unchecked_ref())
+            .ok()?;
+        Some(Self(closure))
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Drop for ScrollListener {
+    fn drop(&mut self) {
+        if let Some(window) = web_sys::window() {
+            let _ = window
+                .remove_event_listener_with_callback("scroll", self.0.as_ref().unchecked_ref());
+        }
+    }
+}
 
 /// Convert **bold** markers to <strong> tags for HTML rendering
 fn process_bold_markers(text: &str) -> String {
@@ -21,13 +59,43 @@ fn process_bold_markers(text: &str) -> String {
     result
 }
 
+/// Average reading speed for non-fiction, in words per minute
+const WORDS_PER_MINUTE: usize = 200;
+
+/// How far below the top of the window a heading must scroll to count as
+/// the section being read, clearing the fixed header
+#[cfg(target_arch = "wasm32")]
+const SCROLL_SPY_OFFSET: f64 = 120.0;
+
+/// A section heading and the id its anchor and table of contents entry
+/// point to
+#[derive(Debug, Clone, PartialEq)]
+struct Heading {
+    html: String,
+    /// The heading without markup, for labels
+    text: String,
+    id: String,
+}
+
+impl Heading {
+    fn new(html: String) -> Self {
+        let text = strip_tags(&html);
+        Self {
+            html,
+            text,
+            id: String::new(),
+        }
+    }
+}
+
 /// Represents a content block in an article
 enum ContentBlock {
-    H2(String),
-    H3(String),
+    H2(Heading),
+    H3(Heading),
     Paragraph(String),
     List(Vec<String>),
     BoldLine(String),
+    Image { src: String, alt: String },
 }
 
 /// Parse article content into structured blocks
@@ -35,11 +103,20 @@ fn parse_content(content: &str) -> Vec<ContentBlock> {
     let mut blocks = Vec::new();
 
     for paragraph in content.split("\n\n") {
+        if let Some((alt, src)) = parse_image(paragraph.trim()) {
+            blocks.push(ContentBlock::Image {
+                src: src.to_string(),
+                alt: alt.to_string(),
+            });
+            continue;
+        }
         if paragraph.starts_with("### ") {
             // Handle h3 headings (may have body text on next line)
             let lines: Vec<&str> = paragraph.lines().collect();
             let heading = lines[0].trim_start_matches("### ");
-            blocks.push(ContentBlock::H3(process_bold_markers(heading)));
+            blocks.push(ContentBlock::H3(Heading::new(process_bold_markers(
+                heading,
+            ))));
             // Add remaining lines as paragraphs
             for line in lines.iter().skip(1) {
                 if !line.trim().is_empty() {
@@ -49,7 +126,9 @@ fn parse_content(content: &str) -> Vec<ContentBlock> {
         } else if paragraph.starts_with("## ") {
             let lines: Vec<&str> = paragraph.lines().collect();
             let heading = lines[0].trim_start_matches("## ");
-            blocks.push(ContentBlock::H2(process_bold_markers(heading)));
+            blocks.push(ContentBlock::H2(Heading::new(process_bold_markers(
+                heading,
+            ))));
             for line in lines.iter().skip(1) {
                 if !line.trim().is_empty() {
                     blocks.push(ContentBlock::Paragraph(process_bold_markers(line)));
@@ -58,7 +137,9 @@ fn parse_content(content: &str) -> Vec<ContentBlock> {
         } else if paragraph.starts_with("# ") {
             let lines: Vec<&str> = paragraph.lines().collect();
             let heading = lines[0].trim_start_matches("# ");
-            blocks.push(ContentBlock::H2(process_bold_markers(heading)));
+            blocks.push(ContentBlock::H2(Heading::new(process_bold_markers(
+                heading,
+            ))));
             for line in lines.iter().skip(1) {
                 if !line.trim().is_empty() {
                     blocks.push(ContentBlock::Paragraph(process_bold_markers(line)));
@@ -82,30 +163,235 @@ fn parse_content(content: &str) -> Vec<ContentBlock> {
         }
     }
 
+    assign_heading_ids(&mut blocks);
     blocks
 }
 
+/// Give every heading an id from its text, numbering repeats so each
+/// anchor is unique
+fn assign_heading_ids(blocks: &mut [ContentBlock]) {
+    let mut used = std::collections::HashSet::new();
+    for block in blocks {
+        let (ContentBlock::H2(heading) | ContentBlock::H3(heading)) = block else {
+            continue;
+        };
+        let base = match Article::generate_slug(&heading.text) {
+            slug if slug.is_empty() => "section".to_string(),
+            slug => slug,
+        };
+        let mut id = base.clone();
+        let mut n = 2;
+        while !used.insert(id.clone()) {
+            id = format!("{}-{}", base, n);
+            n += 1;
+        }
+        heading.id = id;
+    }
+}
+
+/// `html` with its tags removed
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// Table of contents entries: each heading and whether it's an h3
+fn table_of_contents(blocks: &[ContentBlock]) -> Vec<(&Heading, bool)> {
+    blocks
+        .iter()
+        .filter_map(|block| match block {
+            ContentBlock::H2(heading) => Some((heading, false)),
+            ContentBlock::H3(heading) => Some((heading, true)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Words in the article's text, leaving out images and Markdown markers
+fn word_count(content: &str) -> usize {
+    content
+        .split("\n\n")
+        .filter(|paragraph| parse_image(paragraph.trim()).is_none())
+        .flat_map(str::split_whitespace)
+        .filter(|word| word.chars().any(char::is_alphanumeric))
+        .count()
+}
+
+/// Whole minutes to read `words`, at least one
+fn reading_minutes(words: usize) -> usize {
+    words.div_ceil(WORDS_PER_MINUTE).max(1)
+}
+
+/// The heading the reader has scrolled to: the last one above the offset,
+/// or the first while they're still above all of them
+#[cfg(target_arch = "wasm32")]
+fn current_section(ids: &[String]) -> Option<String> {
+    let document = web_sys::window()?.document()?;
+    let mut current = ids.first()?.clone();
+    for id in ids {
+        match document.get_element_by_id(id) {
+            Some(el) if el.get_bounding_client_rect().top() <= SCROLL_SPY_OFFSET => {
+                current = id.clone();
+            }
+            Some(_) => break,
+            None => {}
+        }
+    }
+    Some(current)
+}
+
+/// `![alt](src)` on a paragraph of its own, as (alt, src)
+fn parse_image(paragraph: &str) -> Option<(&str, &str)> {
+    let rest = paragraph.strip_prefix("![")?;
+    let (alt, rest) = rest.split_once("](")?;
+    let src = rest.strip_suffix(')')?;
+    (!src.is_empty() && !src.contains(char::is_whitespace)).then_some((alt, src))
+}
+
 #[component]
 pub fn ArticleDetail(slug: String) -> Element {
-    let articles_data = load_articles();
-    let article = articles_data
-        .articles
-        .iter()
-        .find(|a| a.slug == slug && matches!(a.status, ArticleStatus::Published));
+    let i18n = use_i18n();
+    let all_articles = load_articles();
+    let articles_data = all_articles.clone().localized(i18n.locale);
+    let article = articles_data.find_published(&slug);
+    // Section being read, highlighted in the table of contents; only the
+    // browser scrolls, so only there is it set
+    #[cfg_attr(not(target_arch = "wasm32"), allow(unused_mut))]
+    let mut active_section = use_signal(|| Option::<String>::None);
+    // Section whose link was just copied
+    let mut copied_section = use_signal(|| Option::<String>::None);
+
+    let section_ids: Vec<String> = article
+        .map(|(article, _)| {
+            table_of_contents(&parse_content(&article.content))
+                .iter()
+                .map(|(heading, _)| heading.id.clone())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Follow the reader through the sections as they scroll. The listener
+    // lives in a hook so it's replaced when the article changes and removed
+    // when the page goes away.
+    #[cfg(target_arch = "wasm32")]
+    let scroll_listener = use_hook(|| Rc::new(RefCell::new(None::<ScrollListener>)));
+    #[cfg(target_arch = "wasm32")]
+    {
+        let scroll_listener = scroll_listener.clone();
+        use_drop(move || drop(scroll_listener.borrow_mut().take()));
+    }
+    use_effect(use_reactive!(|section_ids| {
+        #[cfg(target_arch = "wasm32")]
+        {
+            active_section.set(current_section(&section_ids));
+            *scroll_listener.borrow_mut() = ScrollListener::new(move || {
+                let current = current_section(&section_ids);
+                if *active_section.peek() != current {
+                    active_section.set(current);
+                }
+            });
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        let _ = section_ids;
+    }));
+
+    let copy_section_link = move |id: String| {
+        // Put the section in the address bar, then copy that address
+        #[cfg(target_arch = "wasm32")]
+        let url = web_sys::window().and_then(|window| {
+            let history = window.history().ok()?;
+            let state = history.state().ok()?;
+            history
+                .replace_state_with_url(&state, "", Some(&format!("#{}", id)))
+                .ok()?;
+            window.location().href().ok()
+        });
+        #[cfg(not(target_arch = "wasm32"))]
+        let url = Some(format!("#{}", id));
+        let Some(url) = url else {
+            return;
+        };
+        spawn(async move {
+            if copy_to_clipboard(&url).await {
+                copied_section.set(Some(id));
+            }
+        });
+    };
 
     match article {
-        Some(article) => {
+        // Old slug: send the visitor to the article's current URL
+        Some((article, true)) => {
+            rsx! {
+                Redirect { to: i18n.route(Route::ArticleDetail { slug: article.slug.clone() }) }
+            }
+        }
+        Some((article, false)) => {
             let content_blocks = parse_content(&article.content);
+            if content_blocks.is_empty() {
+                // Published with an empty body; the error page beats a blank one
+                return Err(CapturedError::from_display(format!(
+                    "article \"{}\" has no content",
+                    article.slug
+                ))
+                .into());
+            }
+            let toc = table_of_contents(&content_blocks);
+            let words = word_count(&article.content);
+            let related = related_articles(article, &articles_data, RELATED_LIMIT);
+            // Neighbors are found in English so categories match across
+            // translations, then shown in the page's language
+            let original = all_articles
+                .articles
+                .iter()
+                .find(|a| a.id == article.id)
+                .context("translated article has no original")?;
+            let (previous, next) = category_neighbors(original, &all_articles);
+            let previous = previous.map(|a| a.localized(i18n.locale));
+            let next = next.map(|a| a.localized(i18n.locale));
+            let url = format!(
+                "{}{}",
+                SITE_URL,
+                i18n.route(Route::ArticleDetail {
+                    slug: article.slug.clone()
+                })
+            );
+            let image = format!(
+                "{}{}",
+                SITE_URL,
+                og_image_path(OgKind::Article, i18n.locale, &article.slug, "png")
+            );
 
             rsx! {
+                SocialMeta {
+                    title: article.title.clone(),
+                    description: article.excerpt.clone(),
+                    url: url.clone(),
+                    image,
+                }
+
                 // Article Header
                 section { class: "article-hero",
                     div { class: "container",
-                        Link { to: Route::Articles {}, class: "article-back-link", "← Back to Articles" }
+                        Link { to: i18n.route(Route::Articles {}), class: "article-back-link", {i18n.t("article-back")} }
 
                         div { class: "article-meta",
                             span { class: "article-category-badge", "{article.category}" }
                             span { class: "article-date", "{article.date}" }
+                            span { class: "article-reading-time",
+                                {i18n.t_args("article-reading-time", &[("minutes", &reading_minutes(words).to_string())])}
+                            }
+                            span { class: "article-word-count",
+                                {i18n.t_args("article-word-count", &[("count", &words.to_string())])}
+                            }
                         }
 
                         h1 { class: "article-title", "{article.title}" }
@@ -118,91 +404,150 @@ pub fn ArticleDetail(slug: String) -> Element {
 
                 // Article Content
                 section { class: "article-content-section",
-                    div { class: "container",
-                        div { class: "article-body glass-card",
-                            for (i, block) in content_blocks.iter().enumerate() {
-                                match block {
-                                    ContentBlock::H2(text) => rsx! {
-                                        h2 { key: "{i}", class: "article-h2", dangerous_inner_html: "{text}" }
-                                    },
-                                    ContentBlock::H3(text) => rsx! {
-                                        h3 { key: "{i}", class: "article-h3", dangerous_inner_html: "{text}" }
-                                    },
-                                    ContentBlock::Paragraph(text) => rsx! {
-                                        p { key: "{i}", dangerous_inner_html: "{text}" }
-                                    },
-                                    ContentBlock::List(items) => rsx! {
-                                        ul { key: "{i}", class: "article-list",
-                                            for (j, item) in items.iter().enumerate() {
-                                                li { key: "{j}", dangerous_inner_html: "{item}" }
+                    div { class: if toc.len() > 1 { "container article-layout article-layout-toc" } else { "container article-layout" },
+                        // Only worth it with more than one section
+                        if toc.len() > 1 {
+                            nav { class: "article-toc", "aria-labelledby": "article-toc-title",
+                                p { id: "article-toc-title", class: "article-toc-title", {i18n.t("article-toc")} }
+                                ol { class: "article-toc-list",
+                                    for (heading, nested) in toc.iter() {
+                                        li {
+                                            key: "{heading.id}",
+                                            class: if *nested { "article-toc-item article-toc-item-nested" } else { "article-toc-item" },
+                                            a {
+                                                href: "#{heading.id}",
+                                                class: if active_section() == Some(heading.id.clone()) { "article-toc-link active" } else { "article-toc-link" },
+                                                "aria-current": if active_section() == Some(heading.id.clone()) { "location" },
+                                                "{heading.text}"
                                             }
                                         }
-                                    },
-                                    ContentBlock::BoldLine(text) => rsx! {
-                                        p { key: "{i}", class: "article-bold", dangerous_inner_html: "{text}" }
-                                    },
+                                    }
                                 }
                             }
                         }
 
-                        // Share Section
-                        div { class: "article-share",
-                            span { class: "share-label", "Share this article:" }
-                            div { class: "share-buttons",
-                                a {
-                                    href: "https://twitter.com/intent/tweet?text={article.title}&url=",
-                                    target: "_blank",
-                                    rel: "noopener noreferrer",
-                                    class: "share-btn",
-                                    "Twitter"
+                        div { class: "article-main",
+                            div { class: "article-body glass-card",
+                                for (i, block) in content_blocks.iter().enumerate() {
+                                    match block {
+                                        ContentBlock::H2(heading) | ContentBlock::H3(heading) => {
+                                            let copy_label = i18n.t_args("article-copy-section-link", &[("heading", &heading.text)]);
+                                            let copied = copied_section() == Some(heading.id.clone());
+                                            rsx! {
+                                                div { key: "{i}", class: "article-heading",
+                                                    if matches!(block, ContentBlock::H2(_)) {
+                                                        h2 { id: "{heading.id}", class: "article-h2", dangerous_inner_html: "{heading.html}" }
+                                                    } else {
+                                                        h3 { id: "{heading.id}", class: "article-h3", dangerous_inner_html: "{heading.html}" }
+                                                    }
+                                                    button {
+                                                        r#type: "button",
+                                                        class: if copied { "heading-copy-link copied" } else { "heading-copy-link" },
+                                                        title: "{copy_label}",
+                                                        "aria-label": "{copy_label}",
+                                                        onclick: {
+                                                            let id = heading.id.clone();
+                                                            move |_| copy_section_link(id.clone())
+                                                        },
+                                                        span { "aria-hidden": "true", if copied { "✓" } else { "#" } }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        ContentBlock::Paragraph(text) => rsx! {
+                                            p { key: "{i}", dangerous_inner_html: "{text}" }
+                                        },
+                                        ContentBlock::List(items) => rsx! {
+                                            ul { key: "{i}", class: "article-list",
+                                                for (j, item) in items.iter().enumerate() {
+                                                    li { key: "{j}", dangerous_inner_html: "{item}" }
+                                                }
+                                            }
+                                        },
+                                        ContentBlock::BoldLine(text) => rsx! {
+                                            p { key: "{i}", class: "article-bold", dangerous_inner_html: "{text}" }
+                                        },
+                                        ContentBlock::Image { src, alt } => rsx! {
+                                            figure { key: "{i}", class: "article-figure",
+                                                ResponsiveImage {
+                                                    src: src.clone(),
+                                                    alt: alt.clone(),
+                                                    sizes: "(max-width: 800px) 100vw, 800px"
+                                                }
+                                            }
+                                        },
+                                    }
                                 }
-                                a {
-                                    href: "https://www.linkedin.com/sharing/share-offsite/?url=",
-                                    target: "_blank",
-                                    rel: "noopener noreferrer",
-                                    class: "share-btn",
-                                    "LinkedIn"
+                            }
+                            p { class: "sr-only", role: "status",
+                                if copied_section().is_some() {
+                                    {i18n.t("article-link-copied")}
                                 }
-                                button {
-                                    class: "share-btn",
-                                    onclick: move |_| {
-                                        // Copy URL to clipboard using eval for simplicity
-                                        #[cfg(target_arch = "wasm32")]
-                                        {
-                                            let _ = js_sys::eval("navigator.clipboard.writeText(window.location.href)");
+                            }
+
+                            // Previous and next in the category
+                            if previous.is_some() || next.is_some() {
+                                nav {
+                                    class: "article-pagination",
+                                    "aria-label": i18n.t_args("article-category-nav", &[("category", &article.category)]),
+                                    if let Some(previous) = previous {
+                                        Link {
+                                            to: i18n.route(Route::ArticleDetail { slug: previous.slug.clone() }),
+                                            class: "article-pagination-link article-pagination-previous glass-card",
+                                            rel: "prev",
+                                            span { class: "article-pagination-label", {i18n.t("article-previous")} }
+                                            span { class: "article-pagination-title", "{previous.title}" }
+                                        }
+                                    }
+                                    if let Some(next) = next {
+                                        Link {
+                                            to: i18n.route(Route::ArticleDetail { slug: next.slug.clone() }),
+                                            class: "article-pagination-link article-pagination-next glass-card",
+                                            rel: "next",
+                                            span { class: "article-pagination-label", {i18n.t("article-next")} }
+                                            span { class: "article-pagination-title", "{next.title}" }
                                         }
-                                    },
-                                    "Copy Link"
+                                    }
                                 }
                             }
+                            ShareButtons {
+                                title: article.title.clone(),
+                                text: article.excerpt.clone(),
+                                url,
+                            }
+
+                            div { class: "article-subscribe glass-card",
+                                h2 { class: "article-subscribe-title", {i18n.t("article-subscribe-title")} }
+                                p { class: "article-subscribe-text", {i18n.t("subscribe-text")} }
+                                SubscribeForm { id: "article-subscribe" }
+                            }
                         }
                     }
                 }
 
-                // More Articles
-                section { class: "section related-articles",
-                    div { class: "container",
-                        h2 { class: "section-title", "More Articles" }
-                        div { class: "articles-grid",
-                            for other in articles_data.articles.iter()
-                                .filter(|a| a.slug != slug && matches!(a.status, ArticleStatus::Published))
-                                .take(3)
-                            {
-                                Link {
-                                    key: "{other.id}",
-                                    to: Route::ArticleDetail { slug: other.slug.clone() },
-                                    class: "article-card glass-card",
-
-                                    div { class: "article-card-header",
-                                        span { class: "article-category", "{other.category}" }
-                                        span { class: "article-date", "{other.date}" }
-                                    }
+                // Related Reading
+                if !related.is_empty() {
+                    section { class: "section related-articles",
+                        div { class: "container",
+                            h2 { class: "section-title", {i18n.t("article-related")} }
+                            div { class: "articles-grid",
+                                for other in related {
+                                    Link {
+                                        key: "{other.id}",
+                                        to: i18n.route(Route::ArticleDetail { slug: other.slug.clone() }),
+                                        class: "article-card glass-card",
+
+                                        div { class: "article-card-header",
+                                            span { class: "article-category", "{other.category}" }
+                                            span { class: "article-date", "{other.date}" }
+                                        }
 
-                                    h3 { class: "article-card-title", "{other.title}" }
+                                        h3 { class: "article-card-title", "{other.title}" }
 
-                                    p { class: "article-card-excerpt", "{other.excerpt}" }
+                                        p { class: "article-card-excerpt", "{other.excerpt}" }
 
-                                    span { class: "article-read-more", "Read more →" }
+                                        span { class: "article-read-more", {i18n.t("read-more")} }
+                                    }
                                 }
                             }
                         }
@@ -212,14 +557,75 @@ pub fn ArticleDetail(slug: String) -> Element {
         }
         None => {
             rsx! {
-                section { class: "hero hero-short",
-                    div { class: "hero-content",
-                        h1 { class: "hero-title", "Article Not Found" }
-                        p { class: "hero-subtitle", "The article you're looking for doesn't exist." }
-                        Link { to: Route::Articles {}, class: "btn btn-primary", "View All Articles" }
-                    }
+                NotFoundPanel {
+                    title: i18n.t("article-not-found-title"),
+                    message: i18n.t("article-not-found"),
+                    path: format!("/articles/{slug}"),
+                    back_to: Route::Articles {},
+                    back_label: i18n.t("article-view-all"),
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headings(blocks: &[ContentBlock]) -> Vec<(&str, &str, bool)> {
+        table_of_contents(blocks)
+            .into_iter()
+            .map(|(h, nested)| (h.id.as_str(), h.text.as_str(), nested))
+            .collect()
+    }
+
+    #[test]
+    fn test_headings_get_unique_anchor_ids() {
+        let blocks = parse_content(
+            "## Why **Speed** Matters\n\nIntro.\n\n### Step 1: Measure\n\n### Step 1: Measure\n\n## ¿Qué sigue?\n\n## !!!",
+        );
+        assert_eq!(
+            headings(&blocks),
+            [
+                ("why-speed-matters", "Why Speed Matters", false),
+                ("step-1-measure", "Step 1: Measure", true),
+                ("step-1-measure-2", "Step 1: Measure", true),
+                ("qué-sigue", "¿Qué sigue?", false),
+                ("section", "!!!", false),
+            ]
+        );
+        // The heading keeps its markup for display
+        let ContentBlock::H2(first) = &blocks[0] else {
+            panic!("expected an h2");
+        };
+        assert_eq!(first.html, "Why <strong>Speed</strong> Matters");
+    }
+
+    #[test]
+    fn test_word_count_skips_images_and_markers() {
+        let content = "## A Heading\n\nOne two three.\n\n![A chart](assets/chart.png)\n\n- four\n- **five** six\n\n---";
+        assert_eq!(word_count(content), 8);
+    }
+
+    #[test]
+    fn test_reading_minutes_round_up() {
+        assert_eq!(reading_minutes(0), 1);
+        assert_eq!(reading_minutes(200), 1);
+        assert_eq!(reading_minutes(201), 2);
+        assert_eq!(reading_minutes(1000), 5);
+    }
+
+    #[test]
+    fn test_every_article_heading_has_an_id() {
+        for article in load_articles().articles {
+            let blocks = parse_content(&article.content);
+            let toc = table_of_contents(&blocks);
+            let ids: std::collections::HashSet<_> = toc.iter().map(|(h, _)| &h.id).collect();
+            assert_eq!(ids.len(), toc.len(), "{} repeats an id", article.slug);
+            assert!(toc
+                .iter()
+                .all(|(h, _)| !h.id.is_empty() && !h.text.contains('<')));
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::components::CtaSection;
 use crate::content::{load_articles, ArticleStatus};
+use crate::i18n::use_i18n;
 use crate::Route;
 use dioxus::prelude::*;
 
@@ -7,7 +8,8 @@ const ARTICLES_PER_PAGE: usize = 10;
 
 #[component]
 pub fn Articles() -> Element {
-    let articles_data = load_articles();
+    let i18n = use_i18n();
+    let articles_data = load_articles().localized(i18n.locale);
     let published: Vec<_> = articles_data
         .articles
         .into_iter()
@@ -47,9 +49,9 @@ pub fn Articles() -> Element {
         // Hero Section
         section { class: "hero hero-short",
             div { class: "hero-content",
-                h1 { class: "hero-title", "Articles" }
+                h1 { class: "hero-title", {i18n.t("articles-hero-title")} }
                 p { class: "hero-subtitle",
-                    "Insights, updates, and perspectives on technology and business."
+                    {i18n.t("articles-hero-subtitle")}
                 }
             }
         }
@@ -66,7 +68,7 @@ pub fn Articles() -> Element {
                                 selected_category.set(None);
                                 current_page.set(1);
                             },
-                            "All"
+                            {i18n.t("filter-all")}
                         }
                         for cat in categories.iter() {
                             button {
@@ -88,15 +90,15 @@ pub fn Articles() -> Element {
                 // Articles Grid
                 if paginated_articles.is_empty() {
                     div { class: "articles-empty glass-card",
-                        h3 { "No articles yet" }
-                        p { "Check back soon for new content." }
+                        h3 { {i18n.t("articles-empty-title")} }
+                        p { {i18n.t("articles-empty")} }
                     }
                 } else {
                     div { class: "articles-grid",
                         for article in paginated_articles {
                             Link {
                                 key: "{article.id}",
-                                to: Route::ArticleDetail { slug: article.slug.clone() },
+                                to: i18n.route(Route::ArticleDetail { slug: article.slug.clone() }),
                                 class: "article-card glass-card",
 
                                 div { class: "article-card-header",
@@ -108,7 +110,7 @@ pub fn Articles() -> Element {
 
                                 p { class: "article-card-excerpt", "{article.excerpt}" }
 
-                                span { class: "article-read-more", "Read more →" }
+                                span { class: "article-read-more", {i18n.t("read-more")} }
                             }
                         }
                     }
@@ -124,10 +126,10 @@ pub fn Articles() -> Element {
                                         current_page.set(page - 1);
                                     }
                                 },
-                                "← Previous"
+                                {i18n.t("articles-previous")}
                             }
                             span { class: "pagination-info",
-                                "Page {page} of {total_pages}"
+                                {i18n.t_args("articles-page", &[("page", &page.to_string()), ("total", &total_pages.to_string())])}
                             }
                             button {
                                 class: "pagination-btn",
@@ -137,7 +139,7 @@ pub fn Articles() -> Element {
                                         current_page.set(page + 1);
                                     }
                                 },
-                                "Next →"
+                                {i18n.t("articles-next")}
                             }
                         }
                     }
@@ -147,9 +149,9 @@ pub fn Articles() -> Element {
 
         // CTA Section
         CtaSection {
-            title: "Have a Question?".to_string(),
-            description: "We're always happy to discuss technology, business, or potential projects.".to_string(),
-            button_text: "Get in Touch".to_string(),
+            title: i18n.t("articles-cta-title"),
+            description: i18n.t("articles-cta-description"),
+            button_text: i18n.t("articles-cta-button"),
             use_calendar_link: true
         }
     }
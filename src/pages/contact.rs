@@ -1,14 +1,18 @@
+use crate::analytics::use_booking_tracker;
+use crate::i18n::use_i18n;
 use dioxus::prelude::*;
 
 #[component]
 pub fn Contact() -> Element {
+    let i18n = use_i18n();
+    let track_booking = use_booking_tracker("contact");
     rsx! {
         // Hero Section
         section { class: "hero hero-short",
             div { class: "hero-content",
-                h1 { class: "hero-title", "Let's Talk" }
+                h1 { class: "hero-title", {i18n.t("contact-hero-title")} }
                 p { class: "hero-subtitle",
-                    "Have a project in mind? Questions? Just want to network? Book some time."
+                    {i18n.t("contact-hero-subtitle")}
                 }
             }
         }
@@ -19,24 +23,25 @@ pub fn Contact() -> Element {
                 div { class: "contact-centered",
                     // Schedule CTA
                     div { class: "schedule-card glass-card",
-                        h2 { class: "schedule-title", "Schedule a Call" }
+                        h2 { class: "schedule-title", {i18n.t("contact-schedule-title")} }
                         p { class: "schedule-description",
-                            "Book a free 30-minute discovery call. We'll discuss your project, answer questions, and figure out if we're a good fit."
+                            {i18n.t("contact-schedule")}
                         }
                         a {
                             href: "https://calendar.app.google/LNasBbmDr8LXNEuu5",
+                            onclick: move |evt| track_booking.call(evt),
                             target: "_blank",
                             rel: "noopener noreferrer",
                             class: "btn btn-primary btn-large",
-                            "Book a Time"
+                            {i18n.t("contact-book")}
                         }
                     }
 
                     // Contact Info
                     div { class: "contact-details glass-card",
-                        h3 { class: "contact-heading", "Other Ways to Reach Us" }
+                        h3 { class: "contact-heading", {i18n.t("contact-other-ways")} }
                         div { class: "contact-item",
-                            span { class: "contact-label", "Email" }
+                            span { class: "contact-label", {i18n.t("contact-email")} }
                             a {
                                 href: "mailto:collin@poundsconsulting.net",
                                 class: "contact-value contact-link",
@@ -44,8 +49,8 @@ pub fn Contact() -> Element {
                             }
                         }
                         div { class: "contact-item",
-                            span { class: "contact-label", "Location" }
-                            span { class: "contact-value", "Kansas City, Missouri" }
+                            span { class: "contact-label", {i18n.t("contact-location")} }
+                            span { class: "contact-value", {i18n.t("location")} }
                             span { class: "contact-note", }
                         }
                     }
@@ -53,30 +58,30 @@ pub fn Contact() -> Element {
 
                 // FAQ Section
                 div { class: "faq-section",
-                    h2 { class: "section-title", "Common Questions" }
+                    h2 { class: "section-title", {i18n.t("contact-faq-title")} }
                     div { class: "faq-grid",
                         div { class: "faq-item glass-card",
-                            h3 { class: "faq-question", "What's a typical project timeline?" }
+                            h3 { class: "faq-question", {i18n.t("contact-faq-timeline-question")} }
                             p { class: "faq-answer",
-                                "Simple websites can launch in 2-4 weeks. More complex projects vary based on scope, but we'll provide a realistic timeline during our initial conversation."
+                                {i18n.t("contact-faq-timeline")}
                             }
                         }
                         div { class: "faq-item glass-card",
-                            h3 { class: "faq-question", "Do you work with clients outside Missouri?" }
+                            h3 { class: "faq-question", {i18n.t("contact-faq-remote-question")} }
                             p { class: "faq-answer",
-                                "Absolutely. While we're based in Columbia, most of our work is done remotely. We work with clients across the country."
+                                {i18n.t("contact-faq-remote")}
                             }
                         }
                         div { class: "faq-item glass-card",
-                            h3 { class: "faq-question", "What if I'm not sure what I need?" }
+                            h3 { class: "faq-question", {i18n.t("contact-faq-unsure-question")} }
                             p { class: "faq-answer",
-                                "That's completely fine. Most conversations start with a problem, not a solution. Book a call and we'll help you figure out the right approach."
+                                {i18n.t("contact-faq-unsure")}
                             }
                         }
                         div { class: "faq-item glass-card",
-                            h3 { class: "faq-question", "How does billing work?" }
+                            h3 { class: "faq-question", {i18n.t("contact-faq-billing-question")} }
                             p { class: "faq-answer",
-                                "We bill at $71/hour for most work, invoiced monthly. For fixed-scope projects, we provide a complete quote upfront. We offer a 50% discount for military, veterans, law enforcement, and first responders."
+                                {i18n.t("contact-faq-billing")}
                             }
                         }
                     }
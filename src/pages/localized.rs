@@ -0,0 +1,123 @@
+//! Pages under a locale prefix, e.g. `/es/services`.
+//!
+//! Each one renders the same component as its English route; pages read
+//! the locale from the current route. `/en/...` redirects to the
+//! unprefixed URL so every English page has one address.
+
+use super::{
+    About, ArticleDetail, Articles, Contact, Home, NewsletterConfirm, NewsletterUnsubscribe,
+    NotFound, Portfolio, PortfolioDetail, PortfolioTag, ServiceDetail, Services,
+};
+use crate::components::Redirect;
+use crate::i18n::Locale;
+use crate::Route;
+use dioxus::prelude::*;
+
+fn localized(locale: Locale, english: Route, page: Element) -> Element {
+    if locale == Locale::DEFAULT {
+        rsx! {
+            Redirect { to: english }
+        }
+    } else {
+        page
+    }
+}
+
+#[component]
+pub fn LocalizedHome(locale: Locale) -> Element {
+    localized(locale, Route::Home {}, rsx! { Home {} })
+}
+
+#[component]
+pub fn LocalizedAbout(locale: Locale) -> Element {
+    localized(locale, Route::About {}, rsx! { About {} })
+}
+
+#[component]
+pub fn LocalizedServices(locale: Locale) -> Element {
+    localized(locale, Route::Services {}, rsx! { Services {} })
+}
+
+#[component]
+pub fn LocalizedServiceDetail(locale: Locale, slug: String) -> Element {
+    localized(
+        locale,
+        Route::ServiceDetail { slug: slug.clone() },
+        rsx! { ServiceDetail { slug } },
+    )
+}
+
+#[component]
+pub fn LocalizedPortfolio(locale: Locale) -> Element {
+    localized(locale, Route::Portfolio {}, rsx! { Portfolio {} })
+}
+
+#[component]
+pub fn LocalizedPortfolioDetail(locale: Locale, slug: String) -> Element {
+    localized(
+        locale,
+        Route::PortfolioDetail { slug: slug.clone() },
+        rsx! { PortfolioDetail { slug } },
+    )
+}
+
+#[component]
+pub fn LocalizedPortfolioTag(locale: Locale, tag: String) -> Element {
+    localized(
+        locale,
+        Route::PortfolioTag { tag: tag.clone() },
+        rsx! { PortfolioTag { tag } },
+    )
+}
+
+#[component]
+pub fn LocalizedContact(locale: Locale) -> Element {
+    localized(locale, Route::Contact {}, rsx! { Contact {} })
+}
+
+#[component]
+pub fn LocalizedArticles(locale: Locale) -> Element {
+    localized(locale, Route::Articles {}, rsx! { Articles {} })
+}
+
+#[component]
+pub fn LocalizedArticleDetail(locale: Locale, slug: String) -> Element {
+    localized(
+        locale,
+        Route::ArticleDetail { slug: slug.clone() },
+        rsx! { ArticleDetail { slug } },
+    )
+}
+
+#[component]
+pub fn LocalizedNewsletterConfirm(locale: Locale, token: String) -> Element {
+    localized(
+        locale,
+        Route::NewsletterConfirm {
+            token: token.clone(),
+        },
+        rsx! { NewsletterConfirm { token } },
+    )
+}
+
+#[component]
+pub fn LocalizedNewsletterUnsubscribe(locale: Locale, token: String) -> Element {
+    localized(
+        locale,
+        Route::NewsletterUnsubscribe {
+            token: token.clone(),
+        },
+        rsx! { NewsletterUnsubscribe { token } },
+    )
+}
+
+#[component]
+pub fn LocalizedNotFound(locale: Locale, segments: Vec<String>) -> Element {
+    localized(
+        locale,
+        Route::NotFound {
+            segments: segments.clone(),
+        },
+        rsx! { NotFound { segments } },
+    )
+}
@@ -4,8 +4,12 @@ mod article_detail;
 mod articles;
 mod contact;
 mod home;
+mod localized;
+mod newsletter;
+mod not_found;
 mod portfolio;
 mod portfolio_detail;
+mod portfolio_tag;
 mod service_detail;
 mod services;
 
@@ -15,7 +19,16 @@ pub use article_detail::ArticleDetail;
 pub use articles::Articles;
 pub use contact::Contact;
 pub use home::Home;
+pub use localized::{
+    LocalizedAbout, LocalizedArticleDetail, LocalizedArticles, LocalizedContact, LocalizedHome,
+    LocalizedNewsletterConfirm, LocalizedNewsletterUnsubscribe, LocalizedNotFound,
+    LocalizedPortfolio, LocalizedPortfolioDetail, LocalizedPortfolioTag, LocalizedServiceDetail,
+    LocalizedServices,
+};
+pub use newsletter::{NewsletterConfirm, NewsletterUnsubscribe};
+pub use not_found::NotFound;
 pub use portfolio::Portfolio;
 pub use portfolio_detail::PortfolioDetail;
+pub use portfolio_tag::PortfolioTag;
 pub use service_detail::ServiceDetail;
 pub use services::Services;
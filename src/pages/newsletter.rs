@@ -0,0 +1,64 @@
+//! Pages the links in newsletter emails open
+
+use crate::i18n::use_i18n;
+use crate::newsletter;
+use crate::Route;
+use dioxus::prelude::*;
+
+#[component]
+fn NewsletterMessage(title: String, message: String) -> Element {
+    let i18n = use_i18n();
+    rsx! {
+        section { class: "hero hero-short newsletter-result",
+            div { class: "hero-content",
+                h1 { class: "hero-title", "{title}" }
+                p { class: "hero-subtitle", "{message}" }
+                Link { to: i18n.route(Route::Articles {}), class: "btn btn-primary", {i18n.t("article-view-all")} }
+            }
+        }
+    }
+}
+
+/// Opened from the confirmation email to finish subscribing
+#[component]
+pub fn NewsletterConfirm(token: String) -> Element {
+    let i18n = use_i18n();
+    let confirmed = use_hook(|| newsletter::confirm(&token));
+
+    match confirmed {
+        Some(subscriber) => rsx! {
+            NewsletterMessage {
+                title: i18n.t("newsletter-confirmed-title"),
+                message: i18n.t_args("newsletter-confirmed", &[("email", &subscriber.email)]),
+            }
+        },
+        None => rsx! {
+            NewsletterMessage {
+                title: i18n.t("newsletter-link-invalid-title"),
+                message: i18n.t("newsletter-link-invalid"),
+            }
+        },
+    }
+}
+
+/// Opened from the unsubscribe link in any newsletter email
+#[component]
+pub fn NewsletterUnsubscribe(token: String) -> Element {
+    let i18n = use_i18n();
+    let unsubscribed = use_hook(|| newsletter::unsubscribe(&token));
+
+    match unsubscribed {
+        Some(subscriber) => rsx! {
+            NewsletterMessage {
+                title: i18n.t("newsletter-unsubscribed-title"),
+                message: i18n.t_args("newsletter-unsubscribed", &[("email", &subscriber.email)]),
+            }
+        },
+        None => rsx! {
+            NewsletterMessage {
+                title: i18n.t("newsletter-link-invalid-title"),
+                message: i18n.t("newsletter-link-invalid"),
+            }
+        },
+    }
+}
@@ -0,0 +1,21 @@
+use crate::components::NotFoundPanel;
+use crate::i18n::use_i18n;
+use crate::Route;
+use dioxus::prelude::*;
+
+/// Catch-all page for unknown paths and routes disabled in admin settings
+#[component]
+pub fn NotFound(segments: Vec<String>) -> Element {
+    let i18n = use_i18n();
+    let path = format!("/{}", segments.join("/"));
+
+    rsx! {
+        NotFoundPanel {
+            title: i18n.t("not-found-title"),
+            message: i18n.t("not-found"),
+            path,
+            back_to: Route::Home {},
+            back_label: i18n.t("back-to-home"),
+        }
+    }
+}
@@ -1,19 +1,28 @@
-use crate::components::CtaSection;
-use crate::content::load_portfolio;
+use crate::components::{CtaSection, PortfolioCard};
+use crate::content::{load_portfolio, tag_slug};
+use crate::i18n::use_i18n;
 use crate::Route;
 use dioxus::prelude::*;
 
 #[component]
 pub fn Portfolio() -> Element {
-    let portfolio_data = load_portfolio();
+    let i18n = use_i18n();
+    let portfolio_data = load_portfolio().localized(i18n.locale);
+    let mut type_filter = use_signal(|| Option::<String>::None);
+    // Slug of the selected tech tag
+    let mut tag_filter = use_signal(|| Option::<String>::None);
+
+    let projects = portfolio_data.filter(type_filter().as_deref(), tag_filter().as_deref());
+    let selected_tag_name =
+        tag_filter().and_then(|slug| portfolio_data.tag_name(&slug).map(str::to_string));
 
     rsx! {
         // Hero Section
         section { class: "hero hero-short",
             div { class: "hero-content",
-                h1 { class: "hero-title", "Work That Speaks for Itself" }
+                h1 { class: "hero-title", {i18n.t("portfolio-hero-title")} }
                 p { class: "hero-subtitle",
-                    "A selection of projects we've delivered. Real clients, real problems, real results."
+                    {i18n.t("portfolio-hero-subtitle")}
                 }
             }
         }
@@ -21,56 +30,85 @@ pub fn Portfolio() -> Element {
         // Projects Section
         section { class: "section portfolio-section",
             div { class: "container",
-                div { class: "portfolio-grid",
-                    for project in &portfolio_data.projects {
-                        Link {
-                            key: "{project.id}",
-                            to: Route::PortfolioDetail { slug: project.slug.clone() },
-                            class: "portfolio-card-link",
+                div { class: "portfolio-filters",
+                    div { class: "filter-group", role: "group", aria_label: i18n.t("portfolio-filter-type"),
+                        button {
+                            class: if type_filter().is_none() { "filter-chip active" } else { "filter-chip" },
+                            onclick: move |_| type_filter.set(None),
+                            {i18n.t("filter-all")}
+                        }
+                        for project_type in portfolio_data.project_types() {
+                            button {
+                                key: "{project_type}",
+                                class: if type_filter().as_deref() == Some(project_type) { "filter-chip active" } else { "filter-chip" },
+                                onclick: {
+                                    let project_type = project_type.to_string();
+                                    move |_| type_filter.set(Some(project_type.clone()))
+                                },
+                                "{project_type}"
+                            }
+                        }
+                    }
 
-                            div { class: "portfolio-card glass-card",
-                                div { class: "portfolio-header",
-                                    if let Some(logo) = &project.logo {
-                                        img {
-                                            class: "portfolio-card-logo",
-                                            src: "/{logo}",
-                                            alt: "{project.title} logo",
-                                            width: "180",
-                                            height: "180",
-                                            loading: "lazy"
+                    div { class: "filter-group", role: "group", aria_label: i18n.t("portfolio-filter-tech"),
+                        for (tag, count) in portfolio_data.tech_tags() {
+                            button {
+                                key: "{tag}",
+                                class: if tag_filter() == Some(tag_slug(tag)) { "filter-chip filter-chip-tag active" } else { "filter-chip filter-chip-tag" },
+                                onclick: {
+                                    let slug = tag_slug(tag);
+                                    move |_| {
+                                        if tag_filter() == Some(slug.clone()) {
+                                            tag_filter.set(None);
+                                        } else {
+                                            tag_filter.set(Some(slug.clone()));
                                         }
                                     }
-                                    div { class: "portfolio-header-text",
-                                        h3 { class: "portfolio-title", "{project.title}" }
-                                        span { class: "portfolio-type", "{project.project_type}" }
-                                    }
-                                }
-                                p { class: "portfolio-description", "{project.description}" }
-                                div { class: "portfolio-tech",
-                                    for tag in &project.tech_tags {
-                                        span { class: "tech-tag", "{tag}" }
-                                    }
-                                }
-                                ul { class: "portfolio-scope",
-                                    for item in &project.scope {
-                                        li { "{item}" }
-                                    }
-                                }
-                                span { class: "portfolio-link btn btn-primary",
-                                    "View Case Study →"
-                                }
+                                },
+                                "{tag} "
+                                span { class: "filter-count", "{count}" }
+                            }
+                        }
+                    }
+
+                    if let Some(tag_name) = selected_tag_name {
+                        p { class: "filter-summary",
+                            Link {
+                                to: i18n.route(Route::PortfolioTag { tag: tag_filter().unwrap_or_default() }),
+                                {i18n.t_args("portfolio-see-tag", &[("tag", &tag_name)])}
                             }
                         }
                     }
                 }
+
+                if projects.is_empty() {
+                    div { class: "portfolio-empty",
+                        p { {i18n.t("portfolio-no-match")} }
+                        button {
+                            class: "btn btn-secondary",
+                            onclick: move |_| {
+                                type_filter.set(None);
+                                tag_filter.set(None);
+                            },
+                            {i18n.t("portfolio-clear-filters")}
+                        }
+                    }
+                } else {
+                    h2 { class: "sr-only", {i18n.t("projects-heading")} }
+                    div { class: "portfolio-grid",
+                        for project in projects {
+                            PortfolioCard { key: "{project.id}", project: project.clone() }
+                        }
+                    }
+                }
             }
         }
 
         // CTA Section
         CtaSection {
-            title: "Ready to Join the List?".to_string(),
-            description: "Every project starts with a conversation. Let's talk about what you're building.".to_string(),
-            button_text: "Start Your Project".to_string(),
+            title: i18n.t("portfolio-cta-title"),
+            description: i18n.t("portfolio-cta-description"),
+            button_text: i18n.t("cta-start-project"),
             use_calendar_link: true
         }
     }
@@ -1,28 +1,83 @@
-use crate::content::load_portfolio;
+use crate::components::{
+    og_image_path, BeforeAfterSlider, Icon, NotFoundPanel, OgKind, ProjectGallery, ProjectTimeline,
+    Redirect, ResponsiveImage, ResultsGrid, SocialMeta, TestimonialList,
+};
+use crate::content::related::{related_services, RELATED_LIMIT};
+use crate::content::{load_portfolio, load_services, load_settings, load_testimonials, tag_slug};
+use crate::i18n::{use_i18n, SITE_URL};
 use crate::Route;
 use dioxus::prelude::*;
 
 #[component]
 pub fn PortfolioDetail(slug: String) -> Element {
-    let portfolio_data = load_portfolio();
-    let project = portfolio_data.projects.iter().find(|p| p.slug == slug);
+    let i18n = use_i18n();
+    let portfolio_data = load_portfolio().localized(i18n.locale);
+    let project = portfolio_data.find(&slug);
 
     match project {
-        Some(project) => {
+        Some((project, true)) => {
             rsx! {
+                Redirect { to: i18n.route(Route::PortfolioDetail { slug: project.slug.clone() }) }
+            }
+        }
+        Some((project, false)) => {
+            let settings = load_settings();
+            let services_data = load_services().localized(i18n.locale);
+            let services_used = if settings.features.services {
+                related_services(project, &services_data, RELATED_LIMIT)
+            } else {
+                Vec::new()
+            };
+
+            // The case study's own quote first, then any linked in admin
+            let testimonials = if settings.features.testimonials {
+                project
+                    .case_study
+                    .testimonial
+                    .iter()
+                    .cloned()
+                    .chain(load_testimonials().for_project(&project.slug))
+                    .collect()
+            } else {
+                Vec::new()
+            };
+
+            let url = format!(
+                "{}{}",
+                SITE_URL,
+                i18n.route(Route::PortfolioDetail {
+                    slug: project.slug.clone()
+                })
+            );
+            let image = format!(
+                "{}{}",
+                SITE_URL,
+                og_image_path(OgKind::CaseStudy, i18n.locale, &project.slug, "png")
+            );
+
+            rsx! {
+                SocialMeta {
+                    title: project.title.clone(),
+                    description: project.description.clone(),
+                    url,
+                    image,
+                }
+
                 // Case Study Hero
                 section { class: "case-study-hero",
                     div { class: "container",
-                        Link { to: Route::Portfolio {}, class: "case-study-back-link", "← Back to Portfolio" }
+                        Link { to: i18n.route(Route::Portfolio {}), class: "case-study-back-link", {i18n.t("project-back")} }
 
                         div { class: "case-study-header",
                             if let Some(logo) = &project.logo {
-                                img {
+                                ResponsiveImage {
                                     class: "case-study-logo",
-                                    src: "/{logo}",
-                                    alt: "{project.title} logo",
-                                    width: "250",
-                                    height: "250"
+                                    src: logo.clone(),
+                                    alt: i18n.t_args("logo-alt", &[("name", &project.title)]),
+                                    sizes: "250px",
+                                    width: 250,
+                                    height: 250,
+                                    lazy: false
                                 }
                             }
 
@@ -36,7 +91,12 @@ pub fn PortfolioDetail(slug: String) -> Element {
 
                         div { class: "case-study-tech",
                             for tag in &project.tech_tags {
-                                span { class: "tech-tag", "{tag}" }
+                                Link {
+                                    key: "{tag}",
+                                    to: i18n.route(Route::PortfolioTag { tag: tag_slug(tag) }),
+                                    class: "tech-tag tech-tag-link",
+                                    "{tag}"
+                                }
                             }
                         }
                     }
@@ -46,7 +106,13 @@ pub fn PortfolioDetail(slug: String) -> Element {
                 section { class: "case-study-media-section",
                     div { class: "container",
                         div { class: "case-study-media glass-card",
-                            if let Some(video) = &project.video {
+                            if let Some((before, after)) = project.before_after() {
+                                BeforeAfterSlider {
+                                    before: before.to_string(),
+                                    after: after.to_string(),
+                                    title: project.title.clone()
+                                }
+                            } else if let Some(video) = &project.video {
                                 video {
                                     class: "case-study-video",
                                     src: "/{video}",
@@ -58,18 +124,16 @@ pub fn PortfolioDetail(slug: String) -> Element {
                                     height: "675"
                                 }
                             } else if let Some(screenshot) = &project.screenshot {
-                                img {
+                                ResponsiveImage {
                                     class: "case-study-screenshot",
-                                    src: "/{screenshot}",
-                                    alt: "{project.title} screenshot",
-                                    width: "1200",
-                                    height: "675",
-                                    loading: "lazy"
+                                    src: screenshot.clone(),
+                                    alt: i18n.t_args("screenshot-alt", &[("name", &project.title)]),
+                                    sizes: "(max-width: 1200px) 100vw, 1200px"
                                 }
                             } else {
                                 div { class: "case-study-media-placeholder",
                                     span { class: "placeholder-icon", "🖼️" }
-                                    p { "Screenshot or video coming soon" }
+                                    p { {i18n.t("project-media-soon")} }
                                 }
                             }
                         }
@@ -100,9 +164,37 @@ pub fn PortfolioDetail(slug: String) -> Element {
                             }
                         }
 
+                        if !project.case_study.results.is_empty() {
+                            div { class: "case-study-section",
+                                h3 { {i18n.t("project-results")} }
+                                ResultsGrid { results: project.case_study.results.clone() }
+                            }
+                        }
+
+                        if !testimonials.is_empty() {
+                            div { class: "case-study-section",
+                                h3 { {i18n.t("project-client-says")} }
+                                TestimonialList { testimonials }
+                            }
+                        }
+
+                        if !project.case_study.timeline.is_empty() {
+                            div { class: "case-study-section glass-card",
+                                h3 { {i18n.t("project-timeline")} }
+                                ProjectTimeline { phases: project.case_study.timeline.clone() }
+                            }
+                        }
+
+                        if !project.case_study.gallery.is_empty() {
+                            div { class: "case-study-section",
+                                h3 { {i18n.t("project-gallery")} }
+                                ProjectGallery { images: project.case_study.gallery.clone() }
+                            }
+                        }
+
                         // Scope of Work
                         div { class: "case-study-scope glass-card",
-                            h3 { "Scope of Work" }
+                            h3 { {i18n.t("project-scope")} }
                             ul {
                                 for item in &project.scope {
                                     li { "{item}" }
@@ -117,7 +209,7 @@ pub fn PortfolioDetail(slug: String) -> Element {
                                 target: "_blank",
                                 rel: "noopener noreferrer",
                                 class: "btn btn-primary btn-large",
-                                "Visit Site →"
+                                {i18n.t("project-visit")}
                             }
                             if let Some(before_url) = &project.before_url {
                                 a {
@@ -125,7 +217,30 @@ pub fn PortfolioDetail(slug: String) -> Element {
                                     target: "_blank",
                                     rel: "noopener noreferrer",
                                     class: "btn btn-secondary btn-large",
-                                    "View Before →"
+                                    {i18n.t("project-view-before")}
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if !services_used.is_empty() {
+                    section { class: "section related-services",
+                        div { class: "container",
+                            h2 { class: "section-title", {i18n.t("project-services-used")} }
+                            div { class: "services-mini-grid centered-grid",
+                                for service in services_used {
+                                    Link {
+                                        key: "{service.id}",
+                                        to: i18n.route(Route::ServiceDetail { slug: service.slug.clone() }),
+                                        class: "service-mini-card glass-card",
+                                        style: "--service-accent: {service.accent_color}",
+
+                                        span { class: "service-mini-icon",
+                                            Icon { name: service.icon.clone(), size: 28, color: "var(--service-accent, var(--color-secondary))".to_string() }
+                                        }
+                                        h3 { class: "service-mini-title", "{service.title}" }
+                                    }
                                 }
                             }
                         }
@@ -135,7 +250,7 @@ pub fn PortfolioDetail(slug: String) -> Element {
                 // Related Projects
                 section { class: "section related-projects",
                     div { class: "container",
-                        h2 { class: "section-title", "More Projects" }
+                        h2 { class: "section-title", {i18n.t("project-more")} }
                         div { class: "portfolio-grid",
                             for other in portfolio_data.projects.iter()
                                 .filter(|p| p.slug != slug)
@@ -143,18 +258,18 @@ pub fn PortfolioDetail(slug: String) -> Element {
                             {
                                 Link {
                                     key: "{other.id}",
-                                    to: Route::PortfolioDetail { slug: other.slug.clone() },
+                                    to: i18n.route(Route::PortfolioDetail { slug: other.slug.clone() }),
                                     class: "portfolio-card glass-card",
 
                                     div { class: "portfolio-header",
                                         if let Some(logo) = &other.logo {
-                                            img {
+                                            ResponsiveImage {
                                                 class: "portfolio-card-logo",
-                                                src: "/{logo}",
-                                                alt: "{other.title} logo",
-                                                width: "180",
-                                                height: "180",
-                                                loading: "lazy"
+                                                src: logo.clone(),
+                                                alt: i18n.t_args("logo-alt", &[("name", &other.title)]),
+                                                sizes: "180px",
+                                                width: 180,
+                                                height: 180
                                             }
                                         }
                                         div { class: "portfolio-header-text",
@@ -171,7 +286,7 @@ pub fn PortfolioDetail(slug: String) -> Element {
                                         }
                                     }
 
-                                    span { class: "portfolio-read-more", "View Case Study →" }
+                                    span { class: "portfolio-read-more", {i18n.t("view-case-study")} }
                                 }
                             }
                         }
@@ -181,12 +296,12 @@ pub fn PortfolioDetail(slug: String) -> Element {
         }
         None => {
             rsx! {
-                section { class: "hero hero-short",
-                    div { class: "hero-content",
-                        h1 { class: "hero-title", "Project Not Found" }
-                        p { class: "hero-subtitle", "The project you're looking for doesn't exist." }
-                        Link { to: Route::Portfolio {}, class: "btn btn-primary", "View All Projects" }
-                    }
+                NotFoundPanel {
+                    title: i18n.t("project-not-found-title"),
+                    message: i18n.t("project-not-found"),
+                    path: format!("/portfolio/{slug}"),
+                    back_to: Route::Portfolio {},
+                    back_label: i18n.t("project-view-all"),
                 }
             }
         }
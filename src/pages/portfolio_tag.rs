@@ -0,0 +1,60 @@
+use crate::components::{CtaSection, NotFoundPanel, PortfolioCard};
+use crate::content::load_portfolio;
+use crate::i18n::use_i18n;
+use crate::Route;
+use dioxus::prelude::*;
+
+/// Every project built with one technology, e.g. `/portfolio/tech/rust`
+#[component]
+pub fn PortfolioTag(tag: String) -> Element {
+    let i18n = use_i18n();
+    let portfolio_data = load_portfolio().localized(i18n.locale);
+
+    let Some(tag_name) = portfolio_data.tag_name(&tag) else {
+        return rsx! {
+            NotFoundPanel {
+                title: i18n.t("tag-not-found-title"),
+                message: i18n.t("tag-not-found"),
+                path: format!("/portfolio/tech/{}", tag),
+                back_to: Route::Portfolio {},
+                back_label: i18n.t("tag-back"),
+            }
+        };
+    };
+    let projects = portfolio_data.filter(None, Some(&tag));
+    let count = projects.len().to_string();
+    let args = [("count", count.as_str()), ("tag", tag_name)];
+    let subtitle = if projects.len() == 1 {
+        i18n.t_args("tag-count-one", &args)
+    } else {
+        i18n.t_args("tag-count-other", &args)
+    };
+
+    rsx! {
+        section { class: "hero hero-short",
+            div { class: "hero-content",
+                Link { to: i18n.route(Route::Portfolio {}), class: "case-study-back-link", {i18n.t("tag-all-projects")} }
+                h1 { class: "hero-title", {i18n.t_args("tag-title", &args)} }
+                p { class: "hero-subtitle", "{subtitle}" }
+            }
+        }
+
+        section { class: "section portfolio-section",
+            div { class: "container",
+                h2 { class: "sr-only", {i18n.t("projects-heading")} }
+                div { class: "portfolio-grid",
+                    for project in projects {
+                        PortfolioCard { key: "{project.id}", project: project.clone() }
+                    }
+                }
+            }
+        }
+
+        CtaSection {
+            title: i18n.t_args("tag-cta-title", &args),
+            description: i18n.t("tag-cta-description"),
+            button_text: i18n.t("cta-start-project"),
+            use_calendar_link: true
+        }
+    }
+}
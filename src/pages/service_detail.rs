@@ -1,15 +1,39 @@
-use crate::components::{parse_icon_name, Icon, IconName};
-use crate::content::load_services;
+use crate::analytics::use_booking_tracker;
+use crate::components::{Icon, IconName, NotFoundPanel, PortfolioCard, Redirect, TestimonialList};
+use crate::content::related::{related_projects, RELATED_LIMIT};
+use crate::content::{load_portfolio, load_services, load_settings, load_testimonials};
+use crate::i18n::use_i18n;
 use crate::Route;
 use dioxus::prelude::*;
 
 #[component]
 pub fn ServiceDetail(slug: String) -> Element {
-    let services_data = load_services();
-    let service = services_data.services.iter().find(|s| s.slug == slug);
+    let i18n = use_i18n();
+    let track_booking = use_booking_tracker("service");
+    let services_data = load_services().localized(i18n.locale);
+    let service = services_data.find(&slug);
 
     match service {
-        Some(service) => {
+        Some((service, true)) => {
+            rsx! {
+                Redirect { to: i18n.route(Route::ServiceDetail { slug: service.slug.clone() }) }
+            }
+        }
+        Some((service, false)) => {
+            let settings = load_settings();
+            let portfolio_data = load_portfolio().localized(i18n.locale);
+            // Case studies follow the portfolio toggle like the portfolio pages
+            let case_studies = if settings.features.portfolio {
+                related_projects(service, &portfolio_data, RELATED_LIMIT)
+            } else {
+                Vec::new()
+            };
+            let testimonials = if settings.features.testimonials {
+                load_testimonials().for_service(&service.slug)
+            } else {
+                Vec::new()
+            };
+
             rsx! {
                 // Service Hero with accent color
                 section {
@@ -17,16 +41,15 @@ pub fn ServiceDetail(slug: String) -> Element {
                     style: "--service-accent: {service.accent_color}",
 
                     div { class: "container",
-                        Link { to: Route::Services {}, class: "service-back-link",
+                        Link { to: i18n.route(Route::Services {}), class: "service-back-link",
                             Icon { name: IconName::ArrowLeft, size: 16, color: "currentColor".to_string() }
-                            " All Services"
+                            " "
+                            {i18n.t("service-all-services")}
                         }
 
                         div { class: "service-hero-content",
                             span { class: "service-hero-icon",
-                                if let Some(icon_name) = parse_icon_name(&service.icon) {
-                                    Icon { name: icon_name, size: 64, color: "var(--service-accent, var(--color-secondary))".to_string() }
-                                }
+                                Icon { name: service.icon.clone(), size: 64, color: "var(--service-accent, var(--color-secondary))".to_string() }
                             }
                             h1 { class: "service-hero-title", "{service.title}" }
                             p { class: "service-hero-tagline", "{service.tagline}" }
@@ -46,7 +69,7 @@ pub fn ServiceDetail(slug: String) -> Element {
                 // Features Section
                 section { class: "section service-features-section",
                     div { class: "container",
-                        h2 { class: "section-title", "What's Included" }
+                        h2 { class: "section-title", {i18n.t("service-included")} }
                         div { class: "service-features-grid",
                             for (i, feature) in service.features.iter().enumerate() {
                                 div {
@@ -77,9 +100,9 @@ pub fn ServiceDetail(slug: String) -> Element {
                                     {
                                         let heading = paragraph.trim_start_matches("## ");
                                         let display_heading = if heading == "What We Do" {
-                                            "How We Can Help"
+                                            i18n.t("service-how-we-help")
                                         } else {
-                                            heading
+                                            heading.to_string()
                                         };
                                         rsx! { h2 { class: "service-h2", "{display_heading}" } }
                                     }
@@ -104,7 +127,7 @@ pub fn ServiceDetail(slug: String) -> Element {
                 // Persona Cards Section
                 section { class: "section service-personas-section",
                     div { class: "container",
-                        h2 { class: "section-title", "Who This Is For" }
+                        h2 { class: "section-title", {i18n.t("service-personas")} }
                         div { class: "persona-cards-grid centered-grid",
                             for (i, persona) in service.personas.iter().enumerate() {
                                 div {
@@ -112,9 +135,7 @@ pub fn ServiceDetail(slug: String) -> Element {
                                     class: "persona-card glass-card",
                                     style: "--animation-delay: {i * 150}ms; --service-accent: {service.accent_color}",
                                     div { class: "persona-icon",
-                                        if let Some(icon_name) = parse_icon_name(&persona.icon) {
-                                            Icon { name: icon_name, size: 48, color: "var(--service-accent, var(--color-secondary))".to_string() }
-                                        }
+                                        Icon { name: persona.icon.clone(), size: 48, color: "var(--service-accent, var(--color-secondary))".to_string() }
                                     }
                                     h3 { class: "persona-title", "{persona.title}" }
                                     p { class: "persona-description", "{persona.description}" }
@@ -124,20 +145,43 @@ pub fn ServiceDetail(slug: String) -> Element {
                     }
                 }
 
+                if !testimonials.is_empty() {
+                    section { class: "section service-testimonials-section",
+                        div { class: "container",
+                            h2 { class: "section-title", {i18n.t("testimonials-title")} }
+                            TestimonialList { testimonials }
+                        }
+                    }
+                }
+
                 // Book CTA
                 section { class: "section service-book-section",
                     div { class: "container",
                         div {
                             class: "service-book-card glass-card",
                             style: "--service-accent: {service.accent_color}",
-                            h2 { "Ready to get started?" }
-                            p { "Let's talk about how {service.title} can help your business." }
+                            h2 { {i18n.t("cta-ready-title")} }
+                            p { {i18n.t_args("service-cta-description", &[("service", &service.title)])} }
                             a {
                                 href: "https://calendar.app.google/NxuWY3RDGE5Miaan7",
+                                onclick: move |evt| track_booking.call(evt),
                                 target: "_blank",
                                 rel: "noopener noreferrer",
                                 class: "btn btn-primary btn-large service-cta-btn",
-                                "Book a Free Call"
+                                {i18n.t("cta-book-free-call")}
+                            }
+                        }
+                    }
+                }
+
+                if !case_studies.is_empty() {
+                    section { class: "section related-projects",
+                        div { class: "container",
+                            h2 { class: "section-title", {i18n.t("service-case-studies")} }
+                            div { class: "portfolio-grid",
+                                for project in case_studies {
+                                    PortfolioCard { key: "{project.id}", project: project.clone() }
+                                }
                             }
                         }
                     }
@@ -146,7 +190,7 @@ pub fn ServiceDetail(slug: String) -> Element {
                 // Other Services
                 section { class: "section related-services",
                     div { class: "container",
-                        h2 { class: "section-title", "Explore Other Services" }
+                        h2 { class: "section-title", {i18n.t("service-others")} }
                         div { class: "services-mini-grid centered-grid",
                             for other in services_data.services.iter()
                                 .filter(|s| s.slug != slug)
@@ -154,14 +198,12 @@ pub fn ServiceDetail(slug: String) -> Element {
                             {
                                 Link {
                                     key: "{other.id}",
-                                    to: Route::ServiceDetail { slug: other.slug.clone() },
+                                    to: i18n.route(Route::ServiceDetail { slug: other.slug.clone() }),
                                     class: "service-mini-card glass-card",
                                     style: "--service-accent: {other.accent_color}",
 
                                     span { class: "service-mini-icon",
-                                        if let Some(icon_name) = parse_icon_name(&other.icon) {
-                                            Icon { name: icon_name, size: 28, color: "var(--service-accent, var(--color-secondary))".to_string() }
-                                        }
+                                        Icon { name: other.icon.clone(), size: 28, color: "var(--service-accent, var(--color-secondary))".to_string() }
                                     }
                                     h3 { class: "service-mini-title", "{other.title}" }
                                     span { class: "service-mini-arrow",
@@ -176,12 +218,12 @@ pub fn ServiceDetail(slug: String) -> Element {
         }
         None => {
             rsx! {
-                section { class: "hero hero-short",
-                    div { class: "hero-content",
-                        h1 { class: "hero-title", "Service Not Found" }
-                        p { class: "hero-subtitle", "The service you're looking for doesn't exist." }
-                        Link { to: Route::Services {}, class: "btn btn-primary", "View All Services" }
-                    }
+                NotFoundPanel {
+                    title: i18n.t("service-not-found-title"),
+                    message: i18n.t("service-not-found"),
+                    path: format!("/services/{slug}"),
+                    back_to: Route::Services {},
+                    back_label: i18n.t("services-view-all"),
                 }
             }
         }
@@ -1,29 +1,34 @@
-use crate::components::{parse_icon_name, CtaSection, Icon, IconName};
+use crate::analytics::use_booking_tracker;
+use crate::components::{CtaSection, Icon, IconName};
 use crate::content::{load_services, load_settings};
+use crate::i18n::use_i18n;
 use crate::Route;
 use dioxus::prelude::*;
 
 #[component]
 pub fn Services() -> Element {
+    let i18n = use_i18n();
+    let track_booking = use_booking_tracker("services");
     let settings = load_settings();
     let discount = &settings.discount;
-    let services_data = load_services();
+    let services_data = load_services().localized(i18n.locale);
 
     rsx! {
         // Hero Section with animated background
         section { class: "hero hero-services",
             div { class: "hero-bg-animation" }
             div { class: "hero-content",
-                h1 { class: "hero-title hero-title-animated", "Technical Solutions for Every Challenge" }
+                h1 { class: "hero-title hero-title-animated", {i18n.t("services-hero-title")} }
                 p { class: "hero-subtitle",
-                    "From AI strategy to web development, mobile apps to business automation. Whatever technical problem you're facing, we can help solve it."
+                    {i18n.t("services-hero-subtitle")}
                 }
                 a {
                     href: "https://calendar.app.google/NxuWY3RDGE5Miaan7",
+                    onclick: move |evt| track_booking.call(evt),
                     target: "_blank",
                     rel: "noopener noreferrer",
                     class: "btn btn-primary btn-large btn-pulse",
-                    "Book a Free Discovery Call"
+                    {i18n.t("services-book-discovery")}
                 }
             }
         }
@@ -31,11 +36,12 @@ pub fn Services() -> Element {
         // Services Grid Section
         section { class: "section services-grid-section",
             div { class: "container",
+                h2 { class: "sr-only", {i18n.t("services-heading")} }
                 div { class: "services-grid",
                     for (i, service) in services_data.services.iter().enumerate() {
                         Link {
                             key: "{service.id}",
-                            to: Route::ServiceDetail { slug: service.slug.clone() },
+                            to: i18n.route(Route::ServiceDetail { slug: service.slug.clone() }),
                             class: "service-card-link",
                             style: "--animation-delay: {i * 100}ms",
 
@@ -47,9 +53,7 @@ pub fn Services() -> Element {
 
                                 div { class: "service-card-header",
                                     span { class: "service-card-icon",
-                                        if let Some(icon_name) = parse_icon_name(&service.icon) {
-                                            Icon { name: icon_name, size: 32, color: "var(--service-accent, var(--color-secondary))".to_string() }
-                                        }
+                                        Icon { name: service.icon.clone(), size: 32, color: "var(--service-accent, var(--color-secondary))".to_string() }
                                     }
                                     h3 { class: "service-card-title", "{service.title}" }
                                 }
@@ -62,7 +66,7 @@ pub fn Services() -> Element {
                                 }
 
                                 div { class: "service-card-footer",
-                                    span { class: "service-card-cta", "Learn More" }
+                                    span { class: "service-card-cta", {i18n.t("services-learn-more")} }
                                     span { class: "service-card-arrow",
                                         Icon { name: IconName::ArrowRight, size: 20, color: "var(--service-accent, var(--color-secondary))".to_string() }
                                     }
@@ -77,39 +81,39 @@ pub fn Services() -> Element {
         // Why Choose Us Section
         section { class: "section why-choose-section",
             div { class: "container",
-                h2 { class: "section-title", "Why Work With Us" }
+                h2 { class: "section-title", {i18n.t("why-work-with-us")} }
                 div { class: "why-choose-grid centered-grid",
                     div { class: "why-card glass-card",
                         style: "--animation-delay: 0ms",
                         div { class: "why-icon",
                             Icon { name: IconName::Target, size: 40, color: "var(--color-secondary)".to_string() }
                         }
-                        h3 { "One Point of Contact" }
-                        p { "No account managers or layers of bureaucracy. You work directly with me, someone who understands both the business and technical sides." }
+                        h3 { {i18n.t("services-why-contact-title")} }
+                        p { {i18n.t("services-why-contact")} }
                     }
                     div { class: "why-card glass-card",
                         style: "--animation-delay: 100ms",
                         div { class: "why-icon",
                             Icon { name: IconName::Users, size: 40, color: "var(--color-secondary)".to_string() }
                         }
-                        h3 { "Network of Experts" }
-                        p { "Need a designer? A security specialist? A data scientist? I have a network of brilliant people I can bring in for any challenge." }
+                        h3 { {i18n.t("services-why-network-title")} }
+                        p { {i18n.t("services-why-network")} }
                     }
                     div { class: "why-card glass-card",
                         style: "--animation-delay: 200ms",
                         div { class: "why-icon",
                             Icon { name: IconName::MessageCircle, size: 40, color: "var(--color-secondary)".to_string() }
                         }
-                        h3 { "Honest Advice" }
-                        p { "Sometimes the answer is 'you don't need this' or 'use a cheaper solution.' I'll tell you the truth, even when it means less work for me." }
+                        h3 { {i18n.t("services-why-honest-title")} }
+                        p { {i18n.t("services-why-honest")} }
                     }
                     div { class: "why-card glass-card",
                         style: "--animation-delay: 300ms",
                         div { class: "why-icon",
                             Icon { name: IconName::Zap, size: 40, color: "var(--color-secondary)".to_string() }
                         }
-                        h3 { "Fast Response" }
-                        p { "Emails answered within one business day. Usually faster. No waiting weeks for a callback or getting lost in a ticketing system." }
+                        h3 { {i18n.t("services-why-fast-title")} }
+                        p { {i18n.t("services-why-fast")} }
                     }
                 }
             }
@@ -118,65 +122,66 @@ pub fn Services() -> Element {
         // How We Work Section
         section { class: "section pricing-section",
             div { class: "container",
-                h2 { class: "section-title", "How We Work Together" }
+                h2 { class: "section-title", {i18n.t("services-process-title")} }
                 div { class: "pricing-content glass-card",
                     div { class: "discovery-highlight",
                         div { class: "discovery-badge",
                             Icon { name: IconName::Calendar, size: 32, color: "var(--color-secondary)".to_string() }
                         }
-                        h3 { class: "discovery-title", "Start With a Free Discovery Call" }
+                        h3 { class: "discovery-title", {i18n.t("services-discovery-title")} }
                         p { class: "discovery-description",
-                            "Every project starts with a conversation. We'll discuss your goals, challenges, and what success looks like for you."
+                            {i18n.t("services-discovery")}
                         }
                     }
 
                     div { class: "promise-box",
-                        h4 { class: "promise-title", "Our Promise" }
+                        h4 { class: "promise-title", {i18n.t("services-promise-title")} }
                         p { class: "promise-text",
-                            "You'll receive a complete quote with a clear delivery timeline before we ask for a single dollar. No surprises. No pressure. Just honest numbers so you can make the right decision for your business."
+                            {i18n.t("services-promise")}
                         }
                     }
 
                     div { class: "pricing-actions",
                         a {
                             href: "https://calendar.app.google/NxuWY3RDGE5Miaan7",
+                            onclick: move |evt| track_booking.call(evt),
                             target: "_blank",
                             rel: "noopener noreferrer",
                             class: "btn btn-primary btn-large btn-pulse",
-                            "Book Your Free Call"
+                            {i18n.t("services-book-call")}
                         }
-                        p { class: "no-obligation", "No credit card. No obligation. Just a conversation." }
+                        p { class: "no-obligation", {i18n.t("services-no-obligation")} }
                     }
 
                     div { class: "pricing-process",
-                        h3 { class: "process-title", "What to Expect" }
+                        h3 { class: "process-title", {i18n.t("services-expect-title")} }
                         div { class: "process-steps",
                             div { class: "process-step",
                                 span { class: "step-number", "1" }
                                 div { class: "step-content",
-                                    strong { "Discovery Call" }
-                                    p { "Free 30-minute conversation to understand your needs" }
+                                    strong { {i18n.t("services-step-discovery-title")} }
+                                    p { {i18n.t("services-step-discovery")} }
                                 }
                             }
                             div { class: "process-step",
                                 span { class: "step-number", "2" }
                                 div { class: "step-content",
-                                    strong { "Custom Quote" }
-                                    p { "Detailed proposal with exact pricing and timeline" }
+                                    strong { {i18n.t("services-step-quote-title")} }
+                                    p { {i18n.t("services-step-quote")} }
                                 }
                             }
                             div { class: "process-step",
                                 span { class: "step-number", "3" }
                                 div { class: "step-content",
-                                    strong { "You Decide" }
-                                    p { "Review everything, ask questions, no pressure" }
+                                    strong { {i18n.t("services-step-decide-title")} }
+                                    p { {i18n.t("services-step-decide")} }
                                 }
                             }
                             div { class: "process-step",
                                 span { class: "step-number", "4" }
                                 div { class: "step-content",
-                                    strong { "We Build" }
-                                    p { "Regular updates until delivery and handoff" }
+                                    strong { {i18n.t("services-step-build-title")} }
+                                    p { {i18n.t("services-step-build")} }
                                 }
                             }
                         }
@@ -193,19 +198,19 @@ pub fn Services() -> Element {
                         div { class: "first-responder-badge",
                             Icon { name: IconName::Award, size: 48, color: "var(--color-secondary)".to_string() }
                         }
-                        h3 { class: "first-responder-title", "50% Off for Those Who Serve" }
+                        h3 { class: "first-responder-title", {i18n.t("services-discount-title")} }
                         p { class: "first-responder-description",
-                            "We offer 50% off our hourly rate for those who serve our communities and country. Your service matters."
+                            {i18n.t("services-discount")}
                         }
                         ul { class: "first-responder-list",
-                            li { "Military (Active Duty & Reserves)" }
-                            li { "Veterans" }
-                            li { "Law Enforcement" }
-                            li { "Fire Fighters" }
-                            li { "EMTs & Paramedics" }
+                            li { {i18n.t("services-discount-military")} }
+                            li { {i18n.t("services-discount-veterans")} }
+                            li { {i18n.t("services-discount-police")} }
+                            li { {i18n.t("services-discount-fire")} }
+                            li { {i18n.t("services-discount-ems")} }
                         }
                         p { class: "first-responder-cta",
-                            "Mention your service when you book and we'll apply the discount."
+                            {i18n.t("services-discount-cta")}
                         }
                     }
                 }
@@ -214,9 +219,9 @@ pub fn Services() -> Element {
 
         // CTA Section
         CtaSection {
-            title: "Not Sure What You Need?".to_string(),
-            description: "That's okay. Most clients come to us with a problem, not a predefined solution. Let's talk through what you're trying to accomplish and figure out the right path forward together.".to_string(),
-            button_text: "Start a Conversation".to_string(),
+            title: i18n.t("cta-not-sure-title"),
+            description: i18n.t("services-cta-description"),
+            button_text: i18n.t("services-cta-button"),
             use_calendar_link: true
         }
     }
@@ -0,0 +1,124 @@
+//! Installing the site as an app.
+//!
+//! The web manifest, `manifest.webmanifest` at the site root, is generated
+//! from the compiled-in `BrandSettings`. It's a static file so every visitor
+//! installs the same app and the service worker can cache it; a name or
+//! theme changed in admin only applies to the running site. The service
+//! worker (`sw.js`) and its offline page are static files next to it; see
+//! the README's deployment notes.
+//!
+//! The test fails when the file is out of date, e.g. after changing the
+//! default brand; run `UPDATE_SNAPSHOTS=1 cargo test manifest` to rewrite it.
+
+use crate::content::SiteSettings;
+use serde_json::{json, Value};
+
+const PATH: &str = "manifest.webmanifest";
+
+/// Longest name launchers show without truncating
+const SHORT_NAME_MAX: usize = 12;
+
+/// A launcher label: the brand name, or its first word when that's too long
+fn short_name(name: &str) -> String {
+    if name.chars().count() <= SHORT_NAME_MAX {
+        return name.to_string();
+    }
+    let first = name.split_whitespace().next().unwrap_or(name);
+    first.chars().take(SHORT_NAME_MAX).collect()
+}
+
+/// The web app manifest for `settings`. URLs are relative to the site root,
+/// where the manifest is served.
+fn web_manifest(settings: &SiteSettings) -> Value {
+    let brand = &settings.brand;
+    json!({
+        "name": brand.name,
+        "short_name": short_name(&brand.name),
+        "description": brand.tagline,
+        "id": "/",
+        "start_url": "/",
+        "scope": "/",
+        "display": "standalone",
+        "background_color": brand.theme.background.to_string(),
+        "theme_color": brand.theme.background.to_string(),
+        "categories": ["business"],
+        "icons": [
+            {
+                "src": "/assets/pounds-consulting-icon.svg",
+                "sizes": "any",
+                "type": "image/svg+xml",
+                "purpose": "any"
+            },
+            {
+                "src": "/assets/PoundsConsultingBlackWhiteSiteIcon.png",
+                "sizes": "900x904",
+                "type": "image/png",
+                "purpose": "any"
+            }
+        ]
+    })
+}
+
+/// `web_manifest` as written to `PATH`
+fn render(settings: &SiteSettings) -> String {
+    let mut json = serde_json::to_string_pretty(&web_manifest(settings)).unwrap();
+    json.push('\n');
+    json
+}
+
+#[test]
+fn test_manifest_is_up_to_date() {
+    let expected = render(&SiteSettings::default());
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::write(PATH, &expected).unwrap();
+        return;
+    }
+    let actual = std::fs::read_to_string(PATH).unwrap_or_default();
+    assert!(
+        actual == expected,
+        "{} is missing or out of date; rerun with UPDATE_SNAPSHOTS=1 to regenerate it",
+        PATH
+    );
+}
+
+#[test]
+fn test_manifest_uses_brand_settings() {
+    use crate::content::Color;
+
+    let mut settings = SiteSettings::default();
+    settings.brand.name = "Acme".to_string();
+    settings.brand.tagline = "We make things".to_string();
+    settings.brand.theme.background = "#102030".parse::<Color>().unwrap();
+
+    let manifest = web_manifest(&settings);
+    assert_eq!(manifest["name"], "Acme");
+    assert_eq!(manifest["short_name"], "Acme");
+    assert_eq!(manifest["description"], "We make things");
+    assert_eq!(manifest["theme_color"], "#102030");
+    assert_eq!(manifest["start_url"], "/");
+    assert_eq!(manifest["display"], "standalone");
+}
+
+#[test]
+fn test_manifest_icons_exist() {
+    let manifest = web_manifest(&SiteSettings::default());
+    let icons = manifest["icons"].as_array().unwrap();
+    assert!(!icons.is_empty());
+    for icon in icons {
+        let file = icon["src"].as_str().unwrap().trim_start_matches('/');
+        assert!(
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join(file)
+                .is_file(),
+            "{} is missing",
+            file
+        );
+    }
+}
+
+#[test]
+fn test_short_name_fits_launchers() {
+    assert_eq!(short_name("Pounds Consulting"), "Pounds");
+    assert_eq!(short_name("Acme"), "Acme");
+    assert_eq!(short_name("Supercalifragilistic"), "Supercalifra");
+}
@@ -0,0 +1,924 @@
+//! Render tests: every route is server-side rendered and checked for the
+//! structure visitors and crawlers rely on.
+//!
+//! Each page's outline (the headings, links and images inside `<main>`) is
+//! compared with a snapshot in `src/snapshots/`. After an intended change,
+//! run `UPDATE_SNAPSHOTS=1 cargo test` and review the snapshot diff.
+
+use crate::analytics::{self, Event, Hit, Stats};
+use crate::components::{PageErrorBoundary, ThemeCustomizer, MAIN_CONTENT_ID};
+use crate::content::{
+    load_articles, load_portfolio, load_services, save_articles, tag_slug, Article, ArticleStatus,
+};
+use crate::i18n::Locale;
+use crate::newsletter;
+use crate::Route;
+use dioxus::history::MemoryHistory;
+use dioxus::prelude::*;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+/// The router at `path`, as the app mounts it
+#[component]
+fn TestRoot(path: String) -> Element {
+    use_context_provider(|| Rc::new(MemoryHistory::with_initial_path(path)) as Rc<dyn History>);
+    rsx! {
+        Router::<Route> {}
+    }
+}
+
+fn render(route: &Route) -> String {
+    let mut dom = VirtualDom::new_with_props(
+        TestRoot,
+        TestRootProps {
+            path: route.to_string(),
+        },
+    );
+    dom.rebuild_in_place();
+    dioxus_ssr::render(&dom)
+}
+
+/// Every page the site serves: the static routes plus one per content slug
+fn all_routes() -> Vec<Route> {
+    let mut routes = Route::static_routes();
+
+    let services = load_services().services;
+    let projects = load_portfolio().projects;
+    let articles = load_articles().articles;
+    let tags = projects
+        .iter()
+        .flat_map(|p| p.tech_tags.iter().map(|t| tag_slug(t)))
+        .collect::<BTreeSet<_>>();
+
+    routes.extend(services.iter().map(|s| Route::ServiceDetail {
+        slug: s.slug.clone(),
+    }));
+    routes.extend(projects.iter().map(|p| Route::PortfolioDetail {
+        slug: p.slug.clone(),
+    }));
+    routes.extend(tags.into_iter().map(|tag| Route::PortfolioTag { tag }));
+    routes.extend(articles.iter().map(|a| Route::ArticleDetail {
+        slug: a.slug.clone(),
+    }));
+    routes.push(Route::NotFound {
+        segments: vec!["no-such-page".to_string()],
+    });
+    routes.push(Route::NewsletterConfirm {
+        token: "no-such-token".to_string(),
+    });
+
+    // A Spanish page of each kind, including translated content
+    let es = Locale::Es;
+    routes.extend([
+        Route::LocalizedHome { locale: es },
+        Route::LocalizedServices { locale: es },
+        Route::LocalizedServiceDetail {
+            locale: es,
+            slug: "web-development".to_string(),
+        },
+        Route::LocalizedPortfolioDetail {
+            locale: es,
+            slug: "apex-earthworks".to_string(),
+        },
+        Route::LocalizedArticleDetail {
+            locale: es,
+            slug: "small-business-ai-mistakes".to_string(),
+        },
+        Route::LocalizedNotFound {
+            locale: es,
+            segments: vec!["no-such-page".to_string()],
+        },
+    ]);
+
+    routes.push(Route::AdminArticleEdit {
+        id: articles[0].id.clone(),
+    });
+    routes.push(Route::AdminServiceEdit {
+        id: services[0].id.clone(),
+    });
+    routes.push(Route::AdminProjectEdit {
+        id: projects[0].id.clone(),
+    });
+    routes
+}
+
+// ==================== HTML Scanning ====================
+
+/// Just enough of an HTML tokenizer for the markup dioxus-ssr writes
+#[derive(Debug, PartialEq)]
+enum Token {
+    Open {
+        name: String,
+        attrs: Vec<(String, String)>,
+    },
+    Close(String),
+    Text(String),
+}
+
+/// Elements with no closing tag
+const VOID_ELEMENTS: &[&str] = &["img", "input", "br", "hr", "meta", "link", "source"];
+
+fn tokenize(html: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+        } else if let Some(close) = rest.strip_prefix("</") {
+            let end = close.find('>').unwrap_or(close.len());
+            tokens.push(Token::Close(close[..end].trim().to_string()));
+            rest = close.get(end + 1..).unwrap_or("");
+        } else if let Some(open) = rest.strip_prefix('<') {
+            let (name, attrs, self_closing, after) = parse_tag(open);
+            let is_void = self_closing || VOID_ELEMENTS.contains(&name.as_str());
+            tokens.push(Token::Open {
+                name: name.clone(),
+                attrs,
+            });
+            if is_void {
+                tokens.push(Token::Close(name));
+            }
+            rest = after;
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            tokens.push(Token::Text(decode(&rest[..end])));
+            rest = &rest[end..];
+        }
+    }
+    tokens
+}
+
+/// Parse `name attr="value" ...>` into its parts and the remaining input
+fn parse_tag(input: &str) -> (String, Vec<(String, String)>, bool, &str) {
+    let name_end = input
+        .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+        .unwrap_or(input.len());
+    let name = input[..name_end].to_string();
+    let mut rest = &input[name_end..];
+    let mut attrs = Vec::new();
+
+    loop {
+        rest = rest.trim_start();
+        if let Some(after) = rest.strip_prefix("/>") {
+            return (name, attrs, true, after);
+        }
+        if let Some(after) = rest.strip_prefix('>') {
+            return (name, attrs, false, after);
+        }
+        if rest.is_empty() {
+            return (name, attrs, false, rest);
+        }
+        let key_end = rest
+            .find(|c: char| c == '=' || c == '>' || c == '/' || c.is_whitespace())
+            .unwrap_or(rest.len())
+            .max(1);
+        let key = rest[..key_end].to_string();
+        rest = &rest[key_end..];
+        let value = match rest.strip_prefix("=\"") {
+            Some(quoted) => {
+                let end = quoted.find('"').unwrap_or(quoted.len());
+                rest = quoted.get(end + 1..).unwrap_or("");
+                decode(&quoted[..end])
+            }
+            None => String::new(),
+        };
+        attrs.push((key, value));
+    }
+}
+
+/// Decode the entities dioxus-ssr escapes text and attributes with
+fn decode(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        let entity = &rest[start..];
+        let Some(end) = entity.find(';').filter(|&end| end <= 8) else {
+            out.push('&');
+            rest = &entity[1..];
+            continue;
+        };
+        let decoded = match &entity[1..end] {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "nbsp" => Some('\u{a0}'),
+            code => code
+                .strip_prefix('#')
+                .and_then(|n| n.parse().ok())
+                .and_then(char::from_u32),
+        };
+        match decoded {
+            Some(c) => {
+                out.push(c);
+                rest = &entity[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &entity[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// A rendered page, tokenized
+struct Page {
+    tokens: Vec<Token>,
+}
+
+impl Page {
+    fn render(route: &Route) -> Page {
+        Page {
+            tokens: tokenize(&render(route)),
+        }
+    }
+
+    /// Every opening tag named `name`, with its attributes
+    fn elements<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a [(String, String)]> {
+        self.tokens.iter().filter_map(move |token| match token {
+            Token::Open { name: n, attrs } if n == name => Some(attrs.as_slice()),
+            _ => None,
+        })
+    }
+
+    /// Text of every `name` element, whitespace collapsed
+    fn texts(&self, name: &str) -> Vec<String> {
+        (0..self.tokens.len())
+            .filter(|&i| matches!(&self.tokens[i], Token::Open { name: n, .. } if n == name))
+            .map(|i| self.text_from(i))
+            .collect()
+    }
+
+    fn hrefs(&self) -> Vec<String> {
+        self.elements("a")
+            .map(|attrs| attr(attrs, "href").unwrap_or_default().to_string())
+            .collect()
+    }
+
+    /// Text inside the element that opens at `start`
+    fn text_from(&self, start: usize) -> String {
+        let mut depth = 0;
+        let mut text = String::new();
+        for token in &self.tokens[start..] {
+            match token {
+                Token::Open { .. } => depth += 1,
+                Token::Close(_) => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                Token::Text(t) => {
+                    text.push(' ');
+                    text.push_str(t);
+                }
+            }
+        }
+        text.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    /// Headings, links and images inside the outermost `<main>` (or the
+    /// whole page without one), one per line
+    fn outline(&self) -> String {
+        let mut lines = Vec::new();
+        let has_main = self.elements("main").next().is_some();
+        let mut main_depth = if has_main { None } else { Some(0) };
+        let mut depth = 0usize;
+
+        for (i, token) in self.tokens.iter().enumerate() {
+            match token {
+                Token::Open { name, attrs } => {
+                    depth += 1;
+                    if name == "main" && main_depth.is_none() {
+                        main_depth = Some(depth);
+                    }
+                    if main_depth.is_none() {
+                        continue;
+                    }
+                    match name.as_str() {
+                        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                            lines.push(format!("{} {}", name, self.text_from(i)))
+                        }
+                        "a" => lines.push(format!(
+                            "a {} {}",
+                            attr(attrs, "href").unwrap_or_default(),
+                            self.text_from(i)
+                        )),
+                        "img" => lines.push(format!(
+                            "img {} \"{}\"",
+                            attr(attrs, "src").unwrap_or_default(),
+                            attr(attrs, "alt").unwrap_or_default()
+                        )),
+                        _ => {}
+                    }
+                }
+                Token::Close(_) => {
+                    if main_depth == Some(depth) {
+                        break;
+                    }
+                    depth -= 1;
+                }
+                Token::Text(_) => {}
+            }
+        }
+        lines.join("\n") + "\n"
+    }
+
+    /// Levels of every heading in document order, e.g. `[1, 2, 3, 2]`
+    fn heading_levels(&self) -> Vec<(u8, String)> {
+        (0..self.tokens.len())
+            .filter_map(|i| match &self.tokens[i] {
+                Token::Open { name, .. } if name.len() == 2 && name.starts_with('h') => name[1..]
+                    .parse()
+                    .ok()
+                    .filter(|level| (1..=6).contains(level))
+                    .map(|level| (level, self.text_from(i))),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Form controls with no accessible name: not inside a `<label>`, not
+    /// the target of one's `for`, and without `aria-label(ledby)`
+    fn unlabelled_controls(&self) -> Vec<String> {
+        let label_targets = self
+            .elements("label")
+            .filter_map(|attrs| attr(attrs, "for"))
+            .collect::<BTreeSet<_>>();
+        let mut unlabelled = Vec::new();
+        let mut open_labels = 0usize;
+        for token in &self.tokens {
+            match token {
+                Token::Open { name, .. } if name == "label" => open_labels += 1,
+                Token::Close(name) if name == "label" => open_labels -= 1,
+                Token::Open { name, attrs }
+                    if ["input", "select", "textarea"].contains(&name.as_str()) =>
+                {
+                    let kind = attr(attrs, "type").unwrap_or_default();
+                    let labelled = open_labels > 0
+                        || ["hidden", "submit", "button"].contains(&kind)
+                        || attr(attrs, "aria-label").is_some_and(|l| !l.is_empty())
+                        || attr(attrs, "aria-labelledby").is_some()
+                        || attr(attrs, "id").is_some_and(|id| label_targets.contains(id));
+                    if !labelled {
+                        unlabelled.push(format!(
+                            "{} {:?}",
+                            name,
+                            attr(attrs, "id")
+                                .or(attr(attrs, "placeholder"))
+                                .or(attr(attrs, "value"))
+                                .unwrap_or_default()
+                        ));
+                    }
+                }
+                _ => {}
+            }
+        }
+        unlabelled
+    }
+}
+
+fn attr<'a>(attrs: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    attrs
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.as_str())
+}
+
+// ==================== Snapshots ====================
+
+fn snapshot_path(route: &Route) -> PathBuf {
+    let path = route.to_string();
+    let name = path.trim_matches('/').replace('/', "__");
+    let name = if name.is_empty() { "index" } else { &name };
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src/snapshots")
+        .join(format!("{}.txt", name))
+}
+
+/// Compare with the stored snapshot, or describe how it differs
+fn check_snapshot(route: &Route, actual: &str) -> Result<(), String> {
+    let path = snapshot_path(route);
+    let expected = std::fs::read_to_string(&path).ok();
+    if expected.as_deref() == Some(actual) {
+        return Ok(());
+    }
+    // New snapshots are written locally; CI insists they're checked in
+    let create = expected.is_none() && std::env::var_os("CI").is_none();
+    if create || std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, actual).unwrap();
+        return Ok(());
+    }
+    let Some(expected) = expected else {
+        return Err(format!("{}: no snapshot at {}", route, path.display()));
+    };
+    let (line, (want, got)) = expected
+        .lines()
+        .chain(std::iter::repeat(""))
+        .zip(actual.lines().chain(std::iter::repeat("")))
+        .enumerate()
+        .find(|(_, (want, got))| want != got)
+        .unwrap_or((0, ("", "")));
+    Err(format!(
+        "{}: outline differs from {} at line {}\n  expected: {}\n  actual:   {}",
+        route,
+        path.display(),
+        line + 1,
+        want,
+        got
+    ))
+}
+
+// ==================== Tests ====================
+
+#[test]
+fn test_every_route_matches_its_snapshot() {
+    let failures = all_routes()
+        .iter()
+        .filter_map(|route| check_snapshot(route, &Page::render(route).outline()).err())
+        .collect::<Vec<_>>();
+    assert!(
+        failures.is_empty(),
+        "{}\n\nIf these changes are intended, rerun with UPDATE_SNAPSHOTS=1",
+        failures.join("\n")
+    );
+}
+
+/// Admin has its own layout and isn't crawled, so only public pages count
+#[test]
+fn test_every_public_page_has_one_h1() {
+    for route in all_routes() {
+        if route.to_string().starts_with("/admin") {
+            continue;
+        }
+        let h1s = Page::render(&route).texts("h1");
+        assert_eq!(h1s.len(), 1, "{} has h1s {:?}", route, h1s);
+        assert!(!h1s[0].is_empty(), "{} has an empty h1", route);
+    }
+}
+
+#[test]
+fn test_links_resolve() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    for route in all_routes() {
+        for href in Page::render(&route).hrefs() {
+            let path = href.split(['#', '?']).next().unwrap_or_default();
+            let ok = if href.is_empty() || href == "#" {
+                false
+            } else if href.starts_with('#')
+                || ["https://", "http://", "mailto:", "tel:"]
+                    .iter()
+                    .any(|scheme| href.starts_with(scheme))
+            {
+                true
+            } else if path.starts_with("/assets/") {
+                root.join(&path[1..]).is_file()
+            } else {
+                !matches!(path.parse::<Route>(), Ok(Route::NotFound { .. }) | Err(_))
+            };
+            assert!(ok, "{} links to {:?}, which goes nowhere", route, href);
+        }
+    }
+}
+
+#[test]
+fn test_in_page_anchors_resolve() {
+    for route in all_routes() {
+        let page = Page::render(&route);
+        let ids: BTreeSet<&str> = page
+            .tokens
+            .iter()
+            .filter_map(|token| match token {
+                Token::Open { attrs, .. } => attr(attrs, "id"),
+                _ => None,
+            })
+            .collect();
+        for href in page.hrefs() {
+            if let Some(id) = href.strip_prefix('#') {
+                assert!(
+                    ids.contains(id),
+                    "{} links to missing anchor {:?}",
+                    route,
+                    href
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_article_has_contents_reading_time_and_neighbors() {
+    let page = Page::render(&Route::ArticleDetail {
+        slug: "build-vs-buy-software".to_string(),
+    });
+    let toc = page
+        .elements("nav")
+        .find(|attrs| attr(attrs, "class") == Some("article-toc"));
+    assert!(toc.is_some(), "no table of contents");
+    let toc_links: Vec<String> = page
+        .elements("a")
+        .filter(|attrs| attr(attrs, "class") == Some("article-toc-link"))
+        .filter_map(|attrs| attr(attrs, "href").map(String::from))
+        .collect();
+    let headings = page.texts("h2").len() + page.texts("h3").len();
+    assert!(toc_links.len() > 1);
+    assert!(toc_links.len() <= headings);
+
+    // Every section has a labelled copy-link button
+    let copy_buttons: Vec<_> = page
+        .elements("button")
+        .filter(|attrs| attr(attrs, "class") == Some("heading-copy-link"))
+        .collect();
+    assert_eq!(copy_buttons.len(), toc_links.len());
+    assert!(copy_buttons
+        .iter()
+        .all(|attrs| attr(attrs, "aria-label").is_some_and(|l| l.starts_with("Copy link to"))));
+
+    assert!(page.texts("span").iter().any(|t| t.ends_with(" min read")));
+
+    // Strategy articles link to their neighbors by date
+    let neighbors: Vec<_> = page
+        .elements("a")
+        .filter(|attrs| matches!(attr(attrs, "rel"), Some("prev" | "next")))
+        .filter_map(|attrs| attr(attrs, "href"))
+        .collect();
+    assert!(!neighbors.is_empty());
+    assert!(neighbors.iter().all(|href| href.starts_with("/articles/")));
+}
+
+#[test]
+fn test_article_share_links_point_at_the_article() {
+    let page = Page::render(&Route::ArticleDetail {
+        slug: "build-vs-buy-software".to_string(),
+    });
+    let hrefs: Vec<&str> = page
+        .elements("a")
+        .filter(|attrs| attr(attrs, "class") == Some("share-btn"))
+        .filter_map(|attrs| attr(attrs, "href"))
+        .collect();
+    let encoded = "https%3A%2F%2Fpoundsconsulting.net%2Farticles%2Fbuild-vs-buy-software";
+    assert!(hrefs
+        .iter()
+        .any(|href| href.starts_with("https://www.linkedin.com/") && href.ends_with(encoded)));
+    assert!(hrefs.iter().any(
+        |href| href.starts_with("mailto:?subject=Build%20vs%20Buy") && href.ends_with(encoded)
+    ));
+
+    // The Spanish page shares the Spanish URL
+    let page = Page::render(&Route::LocalizedArticleDetail {
+        locale: Locale::Es,
+        slug: "build-vs-buy-software".to_string(),
+    });
+    assert!(page
+        .hrefs()
+        .iter()
+        .any(|href| href.ends_with("%2Fes%2Farticles%2Fbuild-vs-buy-software")));
+}
+
+#[test]
+fn test_images_have_alt_text_attribute() {
+    for route in all_routes() {
+        for img in Page::render(&route).elements("img") {
+            assert!(
+                attr(img, "alt").is_some(),
+                "{} has an image without alt: {:?}",
+                route,
+                attr(img, "src")
+            );
+        }
+    }
+}
+
+#[test]
+fn test_form_controls_are_labelled() {
+    let mut failures = Vec::new();
+    for route in all_routes() {
+        for control in Page::render(&route).unlabelled_controls() {
+            failures.push(format!("{}: {}", route, control));
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "form controls without a label:\n{}",
+        failures.join("\n")
+    );
+}
+
+/// Pages can be reordered from the keyboard, not only by dragging
+#[test]
+fn test_settings_page_order_has_move_buttons() {
+    let page = Page::render(&Route::AdminSettings {});
+    let moves: Vec<_> = page
+        .elements("button")
+        .filter(|attrs| attr(attrs, "aria-label").is_some_and(|l| l.starts_with("Move ")))
+        .map(|attrs| {
+            (
+                attr(attrs, "aria-label").unwrap(),
+                attr(attrs, "disabled").is_some_and(|d| d != "false"),
+            )
+        })
+        .collect();
+    let pages = crate::content::load_settings().pages_in_order();
+    assert_eq!(moves.len(), pages.len() * 2);
+    assert_eq!(
+        moves[0],
+        (format!("Move {} up", pages[0].label).as_str(), true)
+    );
+    assert!(!moves[1].1);
+    assert!(!moves[moves.len() - 2].1);
+    assert!(moves[moves.len() - 1].1);
+}
+
+/// Headings may step back up any number of levels but only down one at a
+/// time, so the outline has no gaps (an h2 never jumps to an h4)
+#[test]
+fn test_heading_levels_never_skip() {
+    let mut failures = Vec::new();
+    for route in all_routes() {
+        let mut previous = 1;
+        for (level, text) in Page::render(&route).heading_levels() {
+            if level > previous + 1 {
+                failures.push(format!(
+                    "{}: h{} \"{}\" follows an h{}",
+                    route, level, text, previous
+                ));
+            }
+            previous = level;
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn test_pages_with_navigation_start_with_a_skip_link() {
+    for route in all_routes() {
+        let page = Page::render(&route);
+        if page.elements("nav").next().is_none() {
+            continue;
+        }
+        let first_link = page.elements("a").next().unwrap();
+        assert_eq!(
+            attr(first_link, "href"),
+            Some(format!("#{}", MAIN_CONTENT_ID).as_str()),
+            "{} doesn't open with the skip link",
+            route
+        );
+        let main = page.elements("main").next().unwrap();
+        assert_eq!(attr(main, "id"), Some(MAIN_CONTENT_ID), "{}", route);
+    }
+}
+
+#[component]
+fn OpenThemeCustomizer() -> Element {
+    let is_open = use_signal(|| true);
+    rsx! {
+        ThemeCustomizer { is_open }
+    }
+}
+
+#[test]
+fn test_theme_customizer_is_a_labelled_dialog() {
+    let mut dom = VirtualDom::new(OpenThemeCustomizer);
+    dom.rebuild_in_place();
+    let page = Page {
+        tokens: tokenize(&dioxus_ssr::render(&dom)),
+    };
+
+    let dialog = page
+        .elements("div")
+        .find(|attrs| attr(attrs, "role") == Some("dialog"))
+        .expect("the panel is a dialog");
+    assert_eq!(attr(dialog, "aria-modal"), Some("true"));
+    let title = attr(dialog, "aria-labelledby").unwrap();
+    assert!(page
+        .elements("h3")
+        .any(|attrs| attr(attrs, "id") == Some(title)));
+    assert!(page
+        .elements("button")
+        .any(|attrs| attr(attrs, "aria-label") == Some("Close theme customizer")));
+    assert_eq!(page.unlabelled_controls(), Vec::<String>::new());
+}
+
+#[component]
+fn BrokenPage() -> Element {
+    let missing: Option<&str> = None;
+    let body = missing.context("content failed to load")?;
+    rsx! {
+        p { "{body}" }
+    }
+}
+
+#[component]
+fn BoundedBrokenPage() -> Element {
+    rsx! {
+        PageErrorBoundary { BrokenPage {} }
+    }
+}
+
+/// A router whose only page fails, since the fallback links through it
+#[derive(Routable, Clone, PartialEq)]
+enum BrokenRoute {
+    #[route("/")]
+    BoundedBrokenPage {},
+}
+
+#[component]
+fn BrokenRoot() -> Element {
+    use_context_provider(|| Rc::new(MemoryHistory::with_initial_path("/")) as Rc<dyn History>);
+    rsx! {
+        Router::<BrokenRoute> {}
+    }
+}
+
+#[test]
+fn test_page_that_fails_shows_the_error_fallback() {
+    let mut dom = VirtualDom::new(BrokenRoot);
+    dom.rebuild_in_place();
+    // The boundary catches the error on the first pass and shows the
+    // fallback on the next
+    dom.render_immediate(&mut dioxus::core::NoOpMutations);
+    let page = Page {
+        tokens: tokenize(&dioxus_ssr::render(&dom)),
+    };
+
+    assert_eq!(page.texts("h1"), vec!["Something Went Wrong"]);
+    assert!(page.texts("button").contains(&"Try Again".to_string()));
+    assert!(page.hrefs().contains(&"/".to_string()));
+    assert!(page
+        .texts("p")
+        .iter()
+        .all(|p| p != "content failed to load"));
+}
+
+#[test]
+fn test_article_detail_shows_published_only() {
+    let mut data = load_articles();
+    let published = data.articles[0].clone();
+    for (slug, status) in [
+        ("draft-article", ArticleStatus::Draft),
+        ("trashed-article", ArticleStatus::Trashed),
+    ] {
+        data.articles.push(Article {
+            id: slug.to_string(),
+            slug: slug.to_string(),
+            title: format!("Hidden {}", slug),
+            status,
+            ..published.clone()
+        });
+    }
+    save_articles(&data);
+
+    for slug in ["draft-article", "trashed-article"] {
+        let page = Page::render(&Route::ArticleDetail {
+            slug: slug.to_string(),
+        });
+        assert_eq!(page.texts("h1"), ["Article Not Found"], "{}", slug);
+        assert!(
+            !page.texts("h1").iter().any(|h| h.starts_with("Hidden")),
+            "{} rendered",
+            slug
+        );
+    }
+
+    let page = Page::render(&Route::ArticleDetail {
+        slug: published.slug.clone(),
+    });
+    assert_eq!(page.texts("h1"), std::slice::from_ref(&published.title));
+
+    let index = Page::render(&Route::Articles {}).hrefs();
+    assert!(index.contains(&format!("/articles/{}", published.slug)));
+    assert!(!index.iter().any(|href| href.ends_with("-article")));
+}
+
+#[test]
+fn test_dashboard_charts_recorded_visits() {
+    let unconfigured = Page::render(&Route::AdminDashboard {});
+    assert_eq!(unconfigured.elements("svg").count(), 0);
+    assert!(unconfigured
+        .texts("p")
+        .iter()
+        .any(|p| p.starts_with("No analytics backend configured")));
+
+    let sink = Rc::new(analytics::tests::MemorySink::default());
+    analytics::install(sink.clone());
+    let empty = Page::render(&Route::AdminDashboard {});
+    assert_eq!(empty.elements("svg").count(), 0);
+    assert!(empty
+        .texts("p")
+        .iter()
+        .any(|p| p.starts_with("No visits recorded")));
+
+    let mut stats = Stats::default();
+    for (path, from) in [
+        ("/services/web-development", None),
+        ("/contact", Some("/services/web-development")),
+    ] {
+        stats.add(&Hit {
+            day: analytics::today(),
+            event: Event::PageView {
+                path: path.to_string(),
+                from: from.map(String::from),
+                referrer: None,
+            },
+        });
+    }
+    stats.add(&Hit {
+        day: analytics::today(),
+        event: Event::Booking {
+            placement: "contact".to_string(),
+            path: "/contact".to_string(),
+            from: Some("/services/web-development".to_string()),
+        },
+    });
+    *sink.0.borrow_mut() = stats;
+
+    let page = Page::render(&Route::AdminDashboard {});
+    let chart = page.elements("svg").next().expect("a chart");
+    assert_eq!(attr(chart, "role"), Some("img"));
+    assert!(attr(chart, "aria-label").is_some_and(|l| l.contains("2 views and 1 bookings")));
+    // One bar per day in the default 30 day range
+    assert_eq!(page.elements("rect").count(), 30);
+    let cells = page.texts("td");
+    assert!(cells.iter().any(|c| c == "Web Development"));
+    assert!(cells.iter().any(|c| c == "100.0%"));
+}
+
+#[test]
+fn test_newsletter_links_confirm_and_unsubscribe() {
+    let unconfigured = Page::render(&Route::AdminSubscribers {});
+    assert!(unconfigured
+        .texts("p")
+        .iter()
+        .any(|p| p.starts_with("No newsletter backend configured")));
+    assert!(newsletter::subscribe("reader@example.com", Locale::Es).is_err());
+
+    let outbox = Rc::new(newsletter::tests::MemoryOutbox::default());
+    newsletter::install_store(Rc::new(newsletter::tests::MemoryStore::default()));
+    newsletter::install(outbox.clone());
+    let subscriber = newsletter::subscribe("reader@example.com", Locale::Es).unwrap();
+    assert_eq!(outbox.0.borrow().len(), 1);
+    let admin = Page::render(&Route::AdminSubscribers {});
+    assert!(!admin
+        .texts("p")
+        .iter()
+        .any(|p| p.starts_with("No newsletter backend configured")));
+    let cells = admin.texts("td");
+    assert!(cells.iter().any(|c| c == "reader@example.com"));
+    assert!(cells.iter().any(|c| c == "Pending"));
+
+    let confirm = Page::render(&Route::LocalizedNewsletterConfirm {
+        locale: Locale::Es,
+        token: subscriber.token.clone(),
+    });
+    assert_eq!(confirm.texts("h1"), ["Suscripción Confirmada"]);
+    let admin = Page::render(&Route::AdminSubscribers {});
+    assert!(admin.texts("td").iter().any(|c| c == "Confirmed"));
+    assert!(admin
+        .texts("button")
+        .iter()
+        .any(|b| b == "Send to 1 Subscribers"));
+
+    let unsubscribe = Page::render(&Route::LocalizedNewsletterUnsubscribe {
+        locale: Locale::Es,
+        token: subscriber.token,
+    });
+    assert_eq!(unsubscribe.texts("h1"), ["Suscripción Cancelada"]);
+    let admin = Page::render(&Route::AdminSubscribers {});
+    assert!(admin.texts("td").iter().any(|c| c == "Unsubscribed"));
+}
+
+#[test]
+fn test_tokenize() {
+    let tokens =
+        tokenize(r#"<p class="a">Tom &#38; Jerry<img src="/x.png" alt=""/></p><!-- note --><br>"#);
+    assert_eq!(
+        tokens,
+        [
+            Token::Open {
+                name: "p".to_string(),
+                attrs: vec![("class".to_string(), "a".to_string())],
+            },
+            Token::Text("Tom & Jerry".to_string()),
+            Token::Open {
+                name: "img".to_string(),
+                attrs: vec![
+                    ("src".to_string(), "/x.png".to_string()),
+                    ("alt".to_string(), String::new()),
+                ],
+            },
+            Token::Close("img".to_string()),
+            Token::Close("p".to_string()),
+            Token::Open {
+                name: "br".to_string(),
+                attrs: Vec::new(),
+            },
+            Token::Close("br".to_string()),
+        ]
+    );
+}
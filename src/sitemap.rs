@@ -0,0 +1,154 @@
+//! `sitemap.xml` in every language.
+//!
+//! The English entries are written by hand: which pages are listed, their
+//! order, `changefreq`, `priority` and `lastmod`. Everything else is derived
+//! from them: each page gets a `<url>` per locale with the same settings,
+//! and every entry lists its `hreflang` alternates.
+//!
+//! The test fails when the file is out of date; run
+//! `UPDATE_SNAPSHOTS=1 cargo test sitemap` to rewrite it.
+
+use crate::i18n::{alternate_paths, Locale, SITE_URL};
+use crate::Route;
+
+const PATH: &str = "sitemap.xml";
+
+/// One English `<url>`, with the comment heading its section if any
+#[derive(Debug, Clone, PartialEq)]
+struct Entry {
+    section: Option<String>,
+    path: String,
+    /// `changefreq`, `priority` and `lastmod`, as written
+    details: Vec<String>,
+}
+
+/// The hand-written English entries; generated entries are skipped
+fn parse(xml: &str) -> Result<Vec<Entry>, String> {
+    let mut entries = Vec::new();
+    let mut section = None;
+    let mut current: Option<Entry> = None;
+
+    for line in xml.lines().map(str::trim) {
+        if let Some(comment) = line
+            .strip_prefix("<!--")
+            .and_then(|c| c.strip_suffix("-->"))
+        {
+            section = Some(comment.trim().to_string());
+        } else if line == "<url>" {
+            current = Some(Entry {
+                section: section.take(),
+                path: String::new(),
+                details: Vec::new(),
+            });
+        } else if line == "</url>" {
+            let entry = current.take().ok_or("</url> without <url>")?;
+            let route: Route = entry
+                .path
+                .parse()
+                .map_err(|_| format!("{} is not a route", entry.path))?;
+            if route.locale() == Locale::DEFAULT {
+                entries.push(entry);
+            }
+        } else if let Some(entry) = current.as_mut() {
+            if let Some(loc) = line
+                .strip_prefix("<loc>")
+                .and_then(|l| l.strip_suffix("</loc>"))
+            {
+                let path = loc
+                    .strip_prefix(SITE_URL)
+                    .ok_or_else(|| format!("{} is not under {}", loc, SITE_URL))?;
+                entry.path = if path.is_empty() { "/" } else { path }.to_string();
+            } else if !line.starts_with("<xhtml:link") {
+                entry.details.push(line.to_string());
+            }
+        }
+    }
+    Ok(entries)
+}
+
+fn url(path: &str) -> String {
+    format!("{}{}", SITE_URL, path)
+}
+
+fn render(entries: &[Entry]) -> String {
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\"\n        \
+         xmlns:xhtml=\"http://www.w3.org/1999/xhtml\">\n",
+    );
+    for (i, entry) in entries.iter().enumerate() {
+        if let Some(section) = &entry.section {
+            if i > 0 {
+                xml.push('\n');
+            }
+            xml.push_str(&format!("  <!-- {} -->\n", section));
+        }
+        let route: Route = entry.path.parse().expect("checked by parse");
+        let alternates = alternate_paths(&route);
+        let default_path = &alternates
+            .iter()
+            .find(|(locale, _)| *locale == Locale::DEFAULT)
+            .expect("every locale has a path")
+            .1;
+
+        for (_, path) in &alternates {
+            xml.push_str("  <url>\n");
+            xml.push_str(&format!("    <loc>{}</loc>\n", url(path)));
+            for (locale, alternate) in &alternates {
+                xml.push_str(&format!(
+                    "    <xhtml:link rel=\"alternate\" hreflang=\"{}\" href=\"{}\"/>\n",
+                    locale,
+                    url(alternate)
+                ));
+            }
+            xml.push_str(&format!(
+                "    <xhtml:link rel=\"alternate\" hreflang=\"x-default\" href=\"{}\"/>\n",
+                url(default_path)
+            ));
+            for detail in &entry.details {
+                xml.push_str(&format!("    {}\n", detail));
+            }
+            xml.push_str("  </url>\n");
+        }
+    }
+    xml.push_str("</urlset>\n");
+    xml
+}
+
+#[test]
+fn test_sitemap_lists_every_locale_with_alternates() {
+    let xml = std::fs::read_to_string(PATH).expect("Failed to read sitemap.xml");
+    let entries = parse(&xml).unwrap();
+    assert!(!entries.is_empty(), "sitemap.xml has no English pages");
+
+    let expected = render(&entries);
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::write(PATH, expected).unwrap();
+        return;
+    }
+    assert!(
+        xml == expected,
+        "sitemap.xml is missing translated pages or hreflang links; \
+         rerun with UPDATE_SNAPSHOTS=1 to regenerate it"
+    );
+}
+
+#[test]
+fn test_generated_entries_are_ignored_when_parsing() {
+    let xml = std::fs::read_to_string(PATH).expect("Failed to read sitemap.xml");
+    let entries = parse(&xml).unwrap();
+    assert_eq!(parse(&render(&entries)).unwrap(), entries);
+}
+
+#[test]
+fn test_spanish_home_has_no_trailing_slash() {
+    let entries = [Entry {
+        section: None,
+        path: "/".to_string(),
+        details: vec!["<priority>1.0</priority>".to_string()],
+    }];
+    let xml = render(&entries);
+    assert!(xml.contains("<loc>https://poundsconsulting.net/es</loc>"));
+    assert!(xml.contains("hreflang=\"x-default\" href=\"https://poundsconsulting.net/\"/>"));
+    assert_eq!(xml.matches("<priority>1.0</priority>").count(), 2);
+}
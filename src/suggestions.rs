@@ -0,0 +1,222 @@
+use crate::content::{
+    load_articles, load_portfolio, load_services, load_settings, ArticleStatus, SiteSettings,
+};
+use crate::navigation::{is_route_enabled, nav_items};
+use crate::Route;
+
+/// Minimum score for a candidate to be suggested
+const MIN_SCORE: f32 = 0.5;
+/// Maximum number of suggestions shown on a not-found page
+const MAX_SUGGESTIONS: usize = 3;
+
+/// A page we think the visitor may have been looking for
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion {
+    pub label: String,
+    pub route: Route,
+    pub score: f32,
+}
+
+/// A linkable page and the key we match the missing path against
+struct Candidate {
+    key: String,
+    label: String,
+    route: Route,
+}
+
+/// Levenshtein edit distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+
+    for (i, ca) in a.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == cb { 0 } else { 1 };
+            curr[j + 1] = (prev[j + 1] + 1).min(curr[j] + 1).min(prev[j] + cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b.len()]
+}
+
+/// How closely `query` resembles `key`, from 0.0 (unrelated) to 1.0 (equal).
+///
+/// Combines normalized edit distance with a bonus for shared words, so
+/// `/articles/red-flags` still finds `red-flags-hiring-developer`.
+pub fn similarity(query: &str, key: &str) -> f32 {
+    let query = query.to_lowercase();
+    let key = key.to_lowercase();
+    if query.is_empty() || key.is_empty() {
+        return 0.0;
+    }
+    if query == key {
+        return 1.0;
+    }
+
+    let longest = query.chars().count().max(key.chars().count());
+    let edit_score = 1.0 - edit_distance(&query, &key) as f32 / longest as f32;
+
+    let key_words: Vec<&str> = key.split('-').collect();
+    let query_words: Vec<&str> = query.split('-').filter(|w| w.len() >= 3).collect();
+    let shared = query_words.iter().filter(|w| key_words.contains(w)).count();
+    let word_score = if query_words.is_empty() {
+        0.0
+    } else {
+        0.5 + 0.5 * shared as f32 / query_words.len() as f32
+    };
+
+    if shared > 0 {
+        edit_score.max(word_score)
+    } else {
+        edit_score
+    }
+}
+
+/// Every page a visitor can currently reach, keyed by its slug or id
+fn candidates(settings: &SiteSettings) -> Vec<Candidate> {
+    let mut candidates: Vec<Candidate> = nav_items(settings)
+        .into_iter()
+        .map(|item| Candidate {
+            key: item.id,
+            label: item.label,
+            route: item.route,
+        })
+        .collect();
+
+    for service in load_services().services {
+        candidates.push(Candidate {
+            key: service.slug.clone(),
+            label: service.title,
+            route: Route::ServiceDetail { slug: service.slug },
+        });
+    }
+
+    for project in load_portfolio().projects {
+        candidates.push(Candidate {
+            key: project.slug.clone(),
+            label: project.title,
+            route: Route::PortfolioDetail { slug: project.slug },
+        });
+    }
+
+    for article in load_articles()
+        .articles
+        .into_iter()
+        .filter(|a| matches!(a.status, ArticleStatus::Published))
+    {
+        candidates.push(Candidate {
+            key: article.slug.clone(),
+            label: article.title,
+            route: Route::ArticleDetail { slug: article.slug },
+        });
+    }
+
+    candidates
+        .into_iter()
+        .filter(|c| is_route_enabled(settings, &c.route))
+        .collect()
+}
+
+/// Best matches for a missing path, compared on its last segment
+pub fn suggest_for_path_with(settings: &SiteSettings, path: &str) -> Vec<Suggestion> {
+    let Some(query) = path.split('/').rfind(|s| !s.is_empty()) else {
+        return Vec::new();
+    };
+
+    let mut suggestions: Vec<Suggestion> = candidates(settings)
+        .into_iter()
+        .map(|c| Suggestion {
+            score: similarity(query, &c.key),
+            label: c.label,
+            route: c.route,
+        })
+        .filter(|s| s.score >= MIN_SCORE)
+        .collect();
+
+    suggestions.sort_by(|a, b| b.score.total_cmp(&a.score));
+    suggestions.truncate(MAX_SUGGESTIONS);
+    suggestions
+}
+
+/// Best matches for a missing path using the saved site settings
+pub fn suggest_for_path(path: &str) -> Vec<Suggestion> {
+    suggest_for_path_with(&load_settings(), path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("abc", ""), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("services", "services"), 0);
+    }
+
+    #[test]
+    fn test_similarity_exact_and_empty() {
+        assert_eq!(similarity("paytient", "paytient"), 1.0);
+        assert_eq!(similarity("", "paytient"), 0.0);
+    }
+
+    #[test]
+    fn test_similarity_typo() {
+        assert!(similarity("servces", "services") > 0.8);
+        assert!(similarity("Services", "services") == 1.0);
+    }
+
+    #[test]
+    fn test_similarity_shared_words() {
+        assert!(similarity("red-flags", "red-flags-hiring-developer") >= MIN_SCORE);
+    }
+
+    #[test]
+    fn test_similarity_unrelated() {
+        assert!(similarity("zzzz", "paytient") < MIN_SCORE);
+    }
+
+    #[test]
+    fn test_suggest_top_level_typo() {
+        let suggestions = suggest_for_path_with(&SiteSettings::default(), "/portfolo");
+        assert_eq!(suggestions[0].route, Route::Portfolio {});
+    }
+
+    #[test]
+    fn test_suggest_detail_slug() {
+        let suggestions = suggest_for_path_with(&SiteSettings::default(), "/portfolio/paytent");
+        assert_eq!(
+            suggestions[0].route,
+            Route::PortfolioDetail {
+                slug: "paytient".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_suggest_limits_results() {
+        let suggestions = suggest_for_path_with(&SiteSettings::default(), "/articles/website");
+        assert!(suggestions.len() <= MAX_SUGGESTIONS);
+    }
+
+    #[test]
+    fn test_suggest_skips_disabled_sections() {
+        let mut settings = SiteSettings::default();
+        settings.features.portfolio = false;
+
+        let suggestions = suggest_for_path_with(&settings, "/portfolio/paytent");
+        assert!(suggestions
+            .iter()
+            .all(|s| !matches!(s.route, Route::PortfolioDetail { .. })));
+    }
+
+    #[test]
+    fn test_suggest_root_path_is_empty() {
+        assert!(suggest_for_path_with(&SiteSettings::default(), "/").is_empty());
+    }
+}
@@ -0,0 +1,101 @@
+// Service worker: lets the site load offline once it has been visited.
+//
+// - The app itself (wasm bundle, JS glue, CSS, fonts, SVG icons and the web
+//   manifest) is listed in /precache.json by precache.sh at deploy time and
+//   cached on install, one cache per build version.
+// - Pages are fetched from the network, falling back to the cached app
+//   shell (the router takes it from there) and then to /offline.html.
+// - Everything else on this site, such as images, is served
+//   stale-while-revalidate: from the cache at once, refreshed behind it.
+
+const PRECACHE_PREFIX = 'precache-';
+const RUNTIME_CACHE = 'runtime';
+const SHELL_URL = '/';
+const OFFLINE_URL = '/offline.html';
+
+async function precacheList() {
+    const response = await fetch('/precache.json', { cache: 'no-store' });
+    if (!response.ok) {
+        throw new Error('precache.json: ' + response.status);
+    }
+    return response.json();
+}
+
+self.addEventListener('install', (event) => {
+    event.waitUntil((async () => {
+        const { version, files } = await precacheList();
+        const cache = await caches.open(PRECACHE_PREFIX + version);
+        await cache.addAll([SHELL_URL, OFFLINE_URL, ...files]);
+        await self.skipWaiting();
+    })());
+});
+
+self.addEventListener('activate', (event) => {
+    event.waitUntil((async () => {
+        // Only clear old builds when we know which one is current
+        try {
+            const { version } = await precacheList();
+            const current = PRECACHE_PREFIX + version;
+            for (const name of await caches.keys()) {
+                if (name.startsWith(PRECACHE_PREFIX) && name !== current) {
+                    await caches.delete(name);
+                }
+            }
+        } catch (_) {
+            // Offline while activating; tidy up next time
+        }
+        await self.clients.claim();
+    })());
+});
+
+async function fromPrecache(request) {
+    for (const name of await caches.keys()) {
+        if (name.startsWith(PRECACHE_PREFIX)) {
+            const cached = await (await caches.open(name)).match(request);
+            if (cached) {
+                return cached;
+            }
+        }
+    }
+    return undefined;
+}
+
+async function navigate(request) {
+    try {
+        return await fetch(request);
+    } catch (_) {
+        return (await fromPrecache(SHELL_URL))
+            || (await fromPrecache(OFFLINE_URL))
+            || Response.error();
+    }
+}
+
+async function staleWhileRevalidate(event) {
+    const cache = await caches.open(RUNTIME_CACHE);
+    const cached = await cache.match(event.request);
+    const refresh = fetch(event.request).then((response) => {
+        if (response.ok) {
+            cache.put(event.request, response.clone());
+        }
+        return response;
+    });
+    if (cached) {
+        event.waitUntil(refresh.catch(() => {}));
+        return cached;
+    }
+    return refresh;
+}
+
+self.addEventListener('fetch', (event) => {
+    const request = event.request;
+    if (request.method !== 'GET' || new URL(request.url).origin !== self.location.origin) {
+        return;
+    }
+    if (request.mode === 'navigate') {
+        event.respondWith(navigate(request));
+        return;
+    }
+    event.respondWith((async () => {
+        return (await fromPrecache(request)) || staleWhileRevalidate(event);
+    })());
+});